@@ -30,6 +30,7 @@ Supported shells:
   - zsh
   - fish
   - powershell
+  - nu (nushell)
 
 Examples:
   # Bash - add to ~/.bashrc
@@ -44,6 +45,9 @@ Examples:
   # PowerShell - add to $PROFILE
   Invoke-Expression (mvx activate powershell | Out-String)
 
+  # Nushell - save and source from config.nu
+  mvx activate nu | save -f ~/.config/nushell/mvx-hook.nu
+
 After adding to your shell configuration, restart your shell or source the file:
   source ~/.bashrc    # bash
   source ~/.zshrc     # zsh
@@ -57,7 +61,7 @@ Configuration:
 		shellType := args[0]
 
 		// Validate shell type
-		validShells := []string{"bash", "zsh", "fish", "powershell"}
+		validShells := []string{"bash", "zsh", "fish", "powershell", "nu"}
 		isValid := false
 		for _, s := range validShells {
 			if s == shellType {
@@ -68,7 +72,7 @@ Configuration:
 
 		if !isValid {
 			printError("Unsupported shell: %s", shellType)
-			printError("Supported shells: bash, zsh, fish, powershell")
+			printError("Supported shells: bash, zsh, fish, powershell, nu")
 			os.Exit(1)
 		}
 
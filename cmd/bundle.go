@@ -0,0 +1,410 @@
+package cmd
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"path"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// bundleCmd exports and imports air-gapped toolchain bundles
+var bundleCmd = &cobra.Command{
+	Use:   "bundle <create|install> <file>",
+	Short: "Export or import an air-gapped toolchain bundle",
+	Long: `Export or import a self-contained bundle of every tool archive, checksum
+and version pin the project needs, for build environments without network
+access.
+
+Subcommands:
+  create <file>    Download all tool archives and package them with a manifest
+  install <file>   Provision tools from a bundle with zero network access
+
+Create the bundle on a machine with network access, transfer it, then install
+it on the air-gapped host. install populates the local archive cache, writes
+.mvx/mvx.lock from the bundled pins and provisions every tool offline.
+
+Bundles are gzip-compressed tar archives regardless of the file extension.`,
+	Args: cobra.ExactArgs(2),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		switch args[0] {
+		case "create":
+			return createBundle(args[1])
+		case "install":
+			return installBundle(args[1])
+		default:
+			return fmt.Errorf("unknown bundle subcommand %s (supported: create, install)", args[0])
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(bundleCmd)
+}
+
+// bundleManifest describes the contents of a toolchain bundle
+type bundleManifest struct {
+	Created string       `json:"created"`
+	Project string       `json:"project,omitempty"`
+	Tools   []bundleTool `json:"tools"`
+}
+
+// bundleTool is one packaged tool archive with its provenance
+type bundleTool struct {
+	Name         string `json:"name"`
+	Spec         string `json:"spec"`
+	Version      string `json:"version"`
+	Distribution string `json:"distribution,omitempty"`
+	URL          string `json:"url"`
+	Checksum     string `json:"checksum,omitempty"` // "type:value"
+	Archive      string `json:"archive"`            // path inside the bundle
+}
+
+// createBundle downloads every tool archive the project needs and packages
+// them with a manifest into a gzip-compressed tar bundle
+func createBundle(outPath string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	stagingDir, err := os.MkdirTemp("", "mvx-bundle-*")
+	if err != nil {
+		return err
+	}
+	defer os.RemoveAll(stagingDir)
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	manifest := bundleManifest{
+		Created: time.Now().UTC().Format(time.RFC3339),
+		Project: cfg.Project.Name,
+	}
+
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			return fmt.Errorf("failed to resolve %s %s: %w", toolName, toolConfig.Version, err)
+		}
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			return err
+		}
+		url := tool.GetDownloadURL(resolved)
+		if url == "" {
+			printWarning("Skipping %s: no download URL for version %s", toolName, resolved)
+			continue
+		}
+
+		archiveName := path.Base(url)
+		if archiveName == "" || archiveName == "/" || archiveName == "." {
+			archiveName = fmt.Sprintf("%s-%s", toolName, resolved)
+		}
+		archivePath := filepath.Join(stagingDir, archiveName)
+
+		printInfo("⬇️  Fetching %s %s...", toolName, resolved)
+		downloadConfig := tools.DefaultDownloadConfig(url, archivePath)
+		downloadConfig.ToolName = toolName
+		downloadConfig.Version = resolved
+		downloadConfig.Config = toolConfig
+		downloadConfig.Tool = tool
+		if _, err := tools.RobustDownload(downloadConfig); err != nil {
+			return fmt.Errorf("failed to download %s %s: %w", toolName, resolved, err)
+		}
+
+		checksum, err := fileSHA256(archivePath)
+		if err != nil {
+			return err
+		}
+
+		manifest.Tools = append(manifest.Tools, bundleTool{
+			Name:         toolName,
+			Spec:         toolConfig.Version,
+			Version:      resolved,
+			Distribution: toolConfig.Distribution,
+			URL:          url,
+			Checksum:     "sha256:" + checksum,
+			Archive:      "archives/" + archiveName,
+		})
+	}
+
+	if len(manifest.Tools) == 0 {
+		return fmt.Errorf("no tools to bundle for this platform")
+	}
+
+	if err := writeBundleArchive(outPath, stagingDir, &manifest); err != nil {
+		return err
+	}
+
+	printSuccess("📦 Bundled %d tools into %s", len(manifest.Tools), outPath)
+	return nil
+}
+
+// installBundle provisions the project toolchain from a bundle: it fills the
+// local archive cache, writes the lockfile from the bundled pins and runs the
+// normal setup path with network access disabled
+func installBundle(bundlePath string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manifest, err := extractBundle(bundlePath)
+	if err != nil {
+		return err
+	}
+
+	// Write the lockfile from the bundled pins so version resolution never
+	// needs the network
+	lock := &tools.LockFile{Tools: make(map[string]tools.LockedTool, len(manifest.Tools))}
+	for _, entry := range manifest.Tools {
+		lock.Tools[entry.Name] = tools.LockedTool{
+			Spec:         entry.Spec,
+			Version:      entry.Version,
+			Distribution: entry.Distribution,
+			URL:          entry.URL,
+			Checksum:     entry.Checksum,
+		}
+	}
+	if err := lock.Save(projectRoot); err != nil {
+		return err
+	}
+	printInfo("🔒 Wrote %s from bundle pins", tools.LockFileName)
+
+	// Provision with network access disabled: everything must come from the
+	// archive cache the bundle just populated
+	os.Setenv("MVX_OFFLINE", "true")
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	if err := manager.EnsureTools(cfg, 0); err != nil {
+		return fmt.Errorf("failed to provision tools from bundle: %w", err)
+	}
+
+	printSuccess("✅ Provisioned %d tools from %s", len(manifest.Tools), bundlePath)
+	return nil
+}
+
+// writeBundleArchive packages the manifest and staged archives as a
+// gzip-compressed tar file
+func writeBundleArchive(outPath, stagingDir string, manifest *bundleManifest) error {
+	out, err := os.Create(outPath)
+	if err != nil {
+		return fmt.Errorf("failed to create bundle: %w", err)
+	}
+	defer out.Close()
+
+	gzWriter := gzip.NewWriter(out)
+	defer gzWriter.Close()
+	tarWriter := tar.NewWriter(gzWriter)
+	defer tarWriter.Close()
+
+	manifestData, err := json.MarshalIndent(manifest, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := tarWriter.WriteHeader(&tar.Header{
+		Name: "manifest.json",
+		Mode: 0644,
+		Size: int64(len(manifestData)),
+	}); err != nil {
+		return err
+	}
+	if _, err := tarWriter.Write(manifestData); err != nil {
+		return err
+	}
+
+	for _, entry := range manifest.Tools {
+		source := filepath.Join(stagingDir, path.Base(entry.Archive))
+		info, err := os.Stat(source)
+		if err != nil {
+			return err
+		}
+		if err := tarWriter.WriteHeader(&tar.Header{
+			Name: entry.Archive,
+			Mode: 0644,
+			Size: info.Size(),
+		}); err != nil {
+			return err
+		}
+		file, err := os.Open(source)
+		if err != nil {
+			return err
+		}
+		_, err = io.Copy(tarWriter, file)
+		file.Close()
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// extractBundle reads a bundle, placing every archive into the local archive
+// cache keyed by its manifest URL, and returns the parsed manifest
+func extractBundle(bundlePath string) (*bundleManifest, error) {
+	file, err := os.Open(bundlePath)
+	if err != nil {
+		return nil, fmt.Errorf("failed to open bundle: %w", err)
+	}
+	defer file.Close()
+
+	gzReader, err := gzip.NewReader(file)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read bundle %s: %w", bundlePath, err)
+	}
+	defer gzReader.Close()
+	tarReader := tar.NewReader(gzReader)
+
+	// Stage entries first: the manifest maps archive paths to URLs
+	stagingDir, err := os.MkdirTemp("", "mvx-bundle-*")
+	if err != nil {
+		return nil, err
+	}
+	defer os.RemoveAll(stagingDir)
+
+	var manifest *bundleManifest
+	staged := make(map[string]string) // bundle path -> staged file
+
+	for {
+		header, err := tarReader.Next()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return nil, fmt.Errorf("failed to read bundle %s: %w", bundlePath, err)
+		}
+		if header.Typeflag != tar.TypeReg {
+			continue
+		}
+
+		if header.Name == "manifest.json" {
+			data, err := io.ReadAll(tarReader)
+			if err != nil {
+				return nil, err
+			}
+			manifest = &bundleManifest{}
+			if err := json.Unmarshal(data, manifest); err != nil {
+				return nil, fmt.Errorf("failed to parse bundle manifest: %w", err)
+			}
+			continue
+		}
+
+		if !strings.HasPrefix(header.Name, "archives/") {
+			continue
+		}
+		stagedPath := filepath.Join(stagingDir, path.Base(header.Name))
+		out, err := os.Create(stagedPath)
+		if err != nil {
+			return nil, err
+		}
+		_, err = io.Copy(out, tarReader)
+		out.Close()
+		if err != nil {
+			return nil, err
+		}
+		staged[header.Name] = stagedPath
+	}
+
+	if manifest == nil {
+		return nil, fmt.Errorf("bundle %s has no manifest.json", bundlePath)
+	}
+
+	for _, entry := range manifest.Tools {
+		stagedPath, exists := staged[entry.Archive]
+		if !exists {
+			return nil, fmt.Errorf("bundle is missing archive %s for %s", entry.Archive, entry.Name)
+		}
+		cachePath := tools.CachedArchivePath(entry.URL)
+		if cachePath == "" {
+			return nil, fmt.Errorf("cannot locate the local archive cache (no home directory)")
+		}
+		if err := os.MkdirAll(filepath.Dir(cachePath), 0755); err != nil {
+			return nil, err
+		}
+		if err := copyBundleFile(stagedPath, cachePath); err != nil {
+			return nil, fmt.Errorf("failed to cache archive for %s: %w", entry.Name, err)
+		}
+		printVerbose("Cached %s archive from bundle", entry.Name)
+	}
+
+	return manifest, nil
+}
+
+// copyBundleFile copies a staged bundle file into the archive cache
+func copyBundleFile(src, dst string) error {
+	source, err := os.Open(src)
+	if err != nil {
+		return err
+	}
+	defer source.Close()
+	out, err := os.Create(dst)
+	if err != nil {
+		return err
+	}
+	defer out.Close()
+	_, err = io.Copy(out, source)
+	return err
+}
+
+// fileSHA256 returns the lowercase hex sha256 of a file
+func fileSHA256(path string) (string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return "", err
+	}
+	defer file.Close()
+	hash := sha256.New()
+	if _, err := io.Copy(hash, file); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(hash.Sum(nil)), nil
+}
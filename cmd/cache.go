@@ -0,0 +1,288 @@
+package cmd
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"crypto/sha256"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+var cacheExportOutput string
+
+// cacheManifest describes the contents of a cache archive: per-file SHA-256
+// checksums used to verify integrity on import.
+type cacheManifest struct {
+	Files map[string]string `json:"files"` // relative path -> sha256
+}
+
+// cacheCmd represents the cache command
+var cacheCmd = &cobra.Command{
+	Use:   "cache",
+	Short: "Export and import the installed tool cache",
+	Long: `Export installed tool versions to an archive and import them on another
+machine, e.g. when onboarding a new laptop or seeding a CI image.
+
+The archive records a SHA-256 checksum for every file, verified on import.
+
+Examples:
+  mvx cache export                        # Export all installed tools
+  mvx cache export java maven -o jdk.tar.gz
+  mvx cache import mvx-cache.tar.gz       # Import on the target machine`,
+}
+
+// cacheExportCmd represents the cache export subcommand
+var cacheExportCmd = &cobra.Command{
+	Use:   "export [tools...]",
+	Short: "Export installed tool versions to an archive",
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := exportCache(args, cacheExportOutput); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// cacheImportCmd represents the cache import subcommand
+var cacheImportCmd = &cobra.Command{
+	Use:   "import <archive>",
+	Short: "Import tool versions from a cache archive",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := importCache(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	cacheExportCmd.Flags().StringVarP(&cacheExportOutput, "output", "o", "mvx-cache.tar.gz", "output archive path")
+	cacheCmd.AddCommand(cacheExportCmd)
+	cacheCmd.AddCommand(cacheImportCmd)
+	rootCmd.AddCommand(cacheCmd)
+}
+
+// exportCache writes the installed versions of the named tools (all installed
+// tools when none are named) to a gzip-compressed tar archive.
+func exportCache(toolNames []string, output string) error {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	toolsDir := manager.GetToolsDir()
+	if len(toolNames) == 0 {
+		entries, err := os.ReadDir(toolsDir)
+		if err != nil {
+			return fmt.Errorf("no installed tools found in %s: %w", toolsDir, err)
+		}
+		for _, entry := range entries {
+			if entry.IsDir() {
+				toolNames = append(toolNames, entry.Name())
+			}
+		}
+	}
+	if len(toolNames) == 0 {
+		return fmt.Errorf("no installed tools to export")
+	}
+
+	outFile, err := os.Create(output)
+	if err != nil {
+		return fmt.Errorf("failed to create %s: %w", output, err)
+	}
+	defer outFile.Close()
+
+	gzWriter := gzip.NewWriter(outFile)
+	defer gzWriter.Close()
+	tarWriter := tar.NewWriter(gzWriter)
+	defer tarWriter.Close()
+
+	manifest := cacheManifest{Files: make(map[string]string)}
+	fileCount := 0
+
+	for _, toolName := range toolNames {
+		toolDir := filepath.Join(toolsDir, toolName)
+		if _, err := os.Stat(toolDir); err != nil {
+			return fmt.Errorf("tool %s is not installed (no %s)", toolName, toolDir)
+		}
+
+		printInfo("📦 Exporting %s...", toolName)
+		err := filepath.Walk(toolDir, func(path string, info os.FileInfo, err error) error {
+			if err != nil {
+				return err
+			}
+
+			relPath, err := filepath.Rel(toolsDir, path)
+			if err != nil {
+				return err
+			}
+			relPath = filepath.ToSlash(relPath)
+
+			header, err := tar.FileInfoHeader(info, "")
+			if err != nil {
+				return err
+			}
+			if info.Mode()&os.ModeSymlink != 0 {
+				target, err := os.Readlink(path)
+				if err != nil {
+					return err
+				}
+				header.Linkname = target
+			}
+			header.Name = relPath
+
+			if err := tarWriter.WriteHeader(header); err != nil {
+				return err
+			}
+
+			if info.Mode().IsRegular() {
+				file, err := os.Open(path)
+				if err != nil {
+					return err
+				}
+				defer file.Close()
+
+				hash := sha256.New()
+				if _, err := io.Copy(io.MultiWriter(tarWriter, hash), file); err != nil {
+					return err
+				}
+				manifest.Files[relPath] = fmt.Sprintf("%x", hash.Sum(nil))
+				fileCount++
+			}
+			return nil
+		})
+		if err != nil {
+			return fmt.Errorf("failed to export %s: %w", toolName, err)
+		}
+	}
+
+	// Append the manifest so import can verify every file
+	manifestData, err := json.MarshalIndent(manifest, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := tarWriter.WriteHeader(&tar.Header{
+		Name: "mvx-cache-manifest.json",
+		Mode: 0644,
+		Size: int64(len(manifestData)),
+	}); err != nil {
+		return err
+	}
+	if _, err := tarWriter.Write(manifestData); err != nil {
+		return err
+	}
+
+	printSuccess("✅ Exported %d tool(s), %d file(s) to %s", len(toolNames), fileCount, output)
+	return nil
+}
+
+// importCache extracts a cache archive into the tools directory and verifies
+// every file against the checksums recorded in the archive manifest.
+func importCache(archivePath string) error {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	toolsDir := manager.GetToolsDir()
+	if err := os.MkdirAll(toolsDir, 0755); err != nil {
+		return fmt.Errorf("failed to create tools directory: %w", err)
+	}
+
+	inFile, err := os.Open(archivePath)
+	if err != nil {
+		return fmt.Errorf("failed to open %s: %w", archivePath, err)
+	}
+	defer inFile.Close()
+
+	gzReader, err := gzip.NewReader(inFile)
+	if err != nil {
+		return fmt.Errorf("%s is not a gzip archive: %w", archivePath, err)
+	}
+	defer gzReader.Close()
+	tarReader := tar.NewReader(gzReader)
+
+	var manifest *cacheManifest
+	checksums := make(map[string]string) // relative path -> sha256 of extracted file
+	fileCount := 0
+
+	printInfo("📥 Importing tool cache from %s...", archivePath)
+	for {
+		header, err := tarReader.Next()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return fmt.Errorf("failed to read archive: %w", err)
+		}
+
+		if header.Name == "mvx-cache-manifest.json" {
+			manifest = &cacheManifest{}
+			if err := json.NewDecoder(tarReader).Decode(manifest); err != nil {
+				return fmt.Errorf("failed to parse archive manifest: %w", err)
+			}
+			continue
+		}
+
+		// Guard against path traversal in archive entries
+		cleanName := filepath.Clean(filepath.FromSlash(header.Name))
+		if strings.HasPrefix(cleanName, "..") || filepath.IsAbs(cleanName) {
+			return fmt.Errorf("archive entry %s escapes the tools directory", header.Name)
+		}
+		target := filepath.Join(toolsDir, cleanName)
+
+		switch header.Typeflag {
+		case tar.TypeDir:
+			if err := os.MkdirAll(target, os.FileMode(header.Mode)); err != nil {
+				return err
+			}
+		case tar.TypeSymlink:
+			os.Remove(target)
+			if err := os.Symlink(header.Linkname, target); err != nil {
+				return err
+			}
+		case tar.TypeReg:
+			if err := os.MkdirAll(filepath.Dir(target), 0755); err != nil {
+				return err
+			}
+			file, err := os.OpenFile(target, os.O_CREATE|os.O_TRUNC|os.O_WRONLY, os.FileMode(header.Mode))
+			if err != nil {
+				return err
+			}
+			hash := sha256.New()
+			_, err = io.Copy(io.MultiWriter(file, hash), tarReader)
+			file.Close()
+			if err != nil {
+				return err
+			}
+			checksums[header.Name] = fmt.Sprintf("%x", hash.Sum(nil))
+			fileCount++
+		}
+	}
+
+	if manifest == nil {
+		return fmt.Errorf("archive has no manifest — was it created by 'mvx cache export'?")
+	}
+
+	// Verify every extracted file against the manifest
+	for relPath, actual := range checksums {
+		expected, recorded := manifest.Files[relPath]
+		if !recorded {
+			return fmt.Errorf("file %s is not listed in the archive manifest", relPath)
+		}
+		if actual != expected {
+			return fmt.Errorf("checksum mismatch for %s (expected %s, got %s)", relPath, expected, actual)
+		}
+	}
+
+	printSuccess("✅ Imported and verified %d file(s) into %s", fileCount, toolsDir)
+	return nil
+}
@@ -0,0 +1,116 @@
+package cmd
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"runtime"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// cacheKeyCmd prints a stable hash of the resolved toolchain for use as a
+// CI cache key. Hashing the config file by hand misses alias and range
+// resolution ("lts", "3.9") — this key changes exactly when the installed
+// tool set would.
+var cacheKeyCmd = &cobra.Command{
+	Use:   "key",
+	Short: "Print a cache key for the resolved toolchain",
+	Long: `Print a stable SHA-256 hash of the resolved toolchain: every configured
+tool with its concrete resolved version and distribution, plus the platform.
+
+Use it as the cache key in CI, paired with 'mvx cache path' for what to
+cache:
+
+  key: mvx-${{ runner.os }}-$(mvx cache key)
+  path: $(mvx cache path)
+
+Unlike hashing the config file, the key tracks what version specs resolve
+to, so "lts" or "3.9" moving to a newer release invalidates the cache.`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runCacheKey(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// cachePathCmd prints the directory CI should cache
+var cachePathCmd = &cobra.Command{
+	Use:   "path",
+	Short: "Print the directory holding installed tools (what CI should cache)",
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runCachePath(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	cacheCmd.AddCommand(cacheKeyCmd)
+	cacheCmd.AddCommand(cachePathCmd)
+}
+
+// runCacheKey resolves every configured tool and prints the toolchain hash
+func runCacheKey() error {
+	_, cfg, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+	if len(cfg.Tools) == 0 {
+		return fmt.Errorf("no tools configured in the mvx configuration")
+	}
+
+	key, err := toolchainCacheKey(cfg.Tools, runtime.GOOS+"-"+runtime.GOARCH, func(tool string) (string, error) {
+		return manager.ResolveVersion(tool, cfg.Tools[tool])
+	})
+	if err != nil {
+		return err
+	}
+
+	fmt.Println(key)
+	return nil
+}
+
+// runCachePath prints the tools directory, the thing worth caching in CI
+func runCachePath() error {
+	_, _, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+	fmt.Println(manager.GetToolsDir())
+	return nil
+}
+
+// toolchainCacheKey hashes the resolved toolchain: one line per tool with
+// its concrete version (and distribution, when pinned), plus the platform.
+// Resolution failures are errors — a key computed from unresolved specs
+// would not change when the specs resolve differently.
+func toolchainCacheKey(tools map[string]config.ToolConfig, platform string, resolve func(tool string) (string, error)) (string, error) {
+	names := make([]string, 0, len(tools))
+	for name := range tools {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	lines := []string{"platform=" + platform}
+	for _, name := range names {
+		version, err := resolve(name)
+		if err != nil {
+			return "", fmt.Errorf("failed to resolve %s version for the cache key: %w", name, err)
+		}
+		line := name + "@" + version
+		if distribution := tools[name].Distribution; distribution != "" {
+			line += "@" + distribution
+		}
+		lines = append(lines, line)
+	}
+
+	sum := sha256.Sum256([]byte(strings.Join(lines, "\n")))
+	return hex.EncodeToString(sum[:]), nil
+}
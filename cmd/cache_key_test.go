@@ -0,0 +1,43 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestToolchainCacheKey(t *testing.T) {
+	tools := map[string]config.ToolConfig{
+		"java":  {Version: "21", Distribution: "temurin"},
+		"maven": {Version: "3.9"},
+	}
+	resolved := map[string]string{"java": "21.0.2", "maven": "3.9.9"}
+	resolve := func(tool string) (string, error) { return resolved[tool], nil }
+
+	key, err := toolchainCacheKey(tools, "linux-amd64", resolve)
+	if err != nil {
+		t.Fatalf("toolchainCacheKey: %v", err)
+	}
+	if len(key) != 64 {
+		t.Errorf("expected a sha256 hex key, got %q", key)
+	}
+
+	// Stable across calls (map iteration order must not leak into the key)
+	again, _ := toolchainCacheKey(tools, "linux-amd64", resolve)
+	if again != key {
+		t.Error("key is not stable across invocations")
+	}
+
+	// The key tracks resolved versions, not specs
+	resolved["maven"] = "3.9.10"
+	bumped, _ := toolchainCacheKey(tools, "linux-amd64", resolve)
+	if bumped == key {
+		t.Error("key did not change when a resolved version changed")
+	}
+
+	// And the platform
+	other, _ := toolchainCacheKey(tools, "darwin-arm64", resolve)
+	if other == key {
+		t.Error("key did not change with the platform")
+	}
+}
@@ -0,0 +1,300 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/version"
+	"github.com/spf13/cobra"
+)
+
+var (
+	cachePruneDryRun     bool
+	cachePruneUnusedDays int
+)
+
+// cacheUsageCmd reports per-tool/per-version disk usage
+var cacheUsageCmd = &cobra.Command{
+	Use:   "usage",
+	Short: "Show per-tool disk usage of installed versions",
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := cacheUsage(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// cachePruneCmd removes installed versions no project references anymore
+var cachePruneCmd = &cobra.Command{
+	Use:   "prune",
+	Short: "Remove installed tool versions no known project references",
+	Long: `Remove installed tool versions that are no longer referenced by any known
+project. A project becomes known the first time mvx runs in it; a version is
+referenced when it appears in a project's lockfile or matches a version spec
+in its configuration.
+
+With --unused-days N, versions that have not been used for N days are pruned
+even when still referenced (they will be re-installed on demand).
+
+Examples:
+  mvx cache prune --dry-run         # Show what would be removed
+  mvx cache prune                   # Remove unreferenced versions
+  mvx cache prune --unused-days 90  # Also remove versions unused for 90 days`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := cachePrune(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	cachePruneCmd.Flags().BoolVar(&cachePruneDryRun, "dry-run", false, "only report what would be removed")
+	cachePruneCmd.Flags().IntVar(&cachePruneUnusedDays, "unused-days", 0, "also prune versions unused for this many days")
+	cacheCmd.AddCommand(cacheUsageCmd)
+	cacheCmd.AddCommand(cachePruneCmd)
+}
+
+// installedVersion is one tool version directory in the local cache
+type installedVersion struct {
+	Tool         string
+	Version      string // version label, without the distribution suffix
+	Distribution string
+	Dir          string
+	Size         int64
+	LastUsed     time.Time
+}
+
+// cacheUsage prints a per-version disk usage table with a total
+func cacheUsage() error {
+	installed, err := listInstalledVersions()
+	if err != nil {
+		return err
+	}
+	if len(installed) == 0 {
+		printInfo("No tools installed in the local cache")
+		return nil
+	}
+
+	fmt.Printf("%-12s %-20s %10s   %s\n", "TOOL", "VERSION", "SIZE", "LAST USED")
+	var total int64
+	for _, entry := range installed {
+		label := entry.Version
+		if entry.Distribution != "" {
+			label = fmt.Sprintf("%s (%s)", entry.Version, entry.Distribution)
+		}
+		fmt.Printf("%-12s %-20s %10s   %s\n", entry.Tool, label, formatSize(entry.Size), formatLastUsed(entry.LastUsed))
+		total += entry.Size
+	}
+	fmt.Printf("\nTotal: %s across %d versions\n", formatSize(total), len(installed))
+	return nil
+}
+
+// cachePrune removes versions not referenced by any known project, and
+// optionally versions unused for --unused-days days
+func cachePrune() error {
+	installed, err := listInstalledVersions()
+	if err != nil {
+		return err
+	}
+	if len(installed) == 0 {
+		printInfo("No tools installed in the local cache")
+		return nil
+	}
+
+	referenced := referencedVersions(installed)
+
+	var pruned int
+	var freed int64
+	for _, entry := range installed {
+		key := installKey(entry.Tool, entry.Version, entry.Distribution)
+		reason := ""
+		if !referenced[key] {
+			reason = "not referenced by any known project"
+		} else if cachePruneUnusedDays > 0 && time.Since(entry.LastUsed) > time.Duration(cachePruneUnusedDays)*24*time.Hour {
+			reason = fmt.Sprintf("unused for more than %d days", cachePruneUnusedDays)
+		}
+		if reason == "" {
+			continue
+		}
+
+		// Files hardlinked into the content store (or other versions) are not
+		// freed by removing this directory, so count only the exclusive bytes
+		exclusive := tools.ExclusiveDirSize(entry.Dir)
+		if cachePruneDryRun {
+			printInfo("Would remove %s %s (%s): %s", entry.Tool, entry.Version, formatSize(entry.Size), reason)
+		} else {
+			if err := os.RemoveAll(entry.Dir); err != nil {
+				printWarning("Failed to remove %s: %v", entry.Dir, err)
+				continue
+			}
+			printInfo("🗑️  Removed %s %s (%s): %s", entry.Tool, entry.Version, formatSize(entry.Size), reason)
+		}
+		pruned++
+		freed += exclusive
+	}
+
+	// Removing versions may have left content store entries without any
+	// install linking to them; sweep those orphans and count their bytes
+	if pruned > 0 && !cachePruneDryRun {
+		if casRemoved, casFreed := tools.PruneContentStore(); casRemoved > 0 {
+			printInfo("🗑️  Removed %d orphaned content store entries (%s)", casRemoved, formatSize(casFreed))
+			freed += casFreed
+		}
+	}
+
+	if pruned == 0 {
+		printSuccess("Nothing to prune: all installed versions are referenced")
+	} else if cachePruneDryRun {
+		printSuccess("Would free %s by removing %d versions", formatSize(freed), pruned)
+	} else {
+		printSuccess("Freed %s by removing %d versions", formatSize(freed), pruned)
+	}
+	return nil
+}
+
+// listInstalledVersions walks the local tools directory
+func listInstalledVersions() ([]installedVersion, error) {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	toolsDir := manager.GetToolsDir()
+	toolEntries, err := os.ReadDir(toolsDir)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	var installed []installedVersion
+	for _, toolEntry := range toolEntries {
+		if !toolEntry.IsDir() {
+			continue
+		}
+		toolDir := filepath.Join(toolsDir, toolEntry.Name())
+		versionEntries, err := os.ReadDir(toolDir)
+		if err != nil {
+			continue
+		}
+		for _, versionEntry := range versionEntries {
+			if !versionEntry.IsDir() {
+				continue
+			}
+			dir := filepath.Join(toolDir, versionEntry.Name())
+			versionLabel, distribution, _ := strings.Cut(versionEntry.Name(), "@")
+			installed = append(installed, installedVersion{
+				Tool:         toolEntry.Name(),
+				Version:      versionLabel,
+				Distribution: distribution,
+				Dir:          dir,
+				Size:         dirSize(dir),
+				LastUsed:     lastUsed(dir),
+			})
+		}
+	}
+
+	sort.Slice(installed, func(i, j int) bool {
+		if installed[i].Tool != installed[j].Tool {
+			return installed[i].Tool < installed[j].Tool
+		}
+		return installed[i].Version < installed[j].Version
+	})
+	return installed, nil
+}
+
+// referencedVersions collects tool/version/distribution keys referenced by
+// any known project: lockfile pins directly, and config version specs
+// matched against the installed versions
+func referencedVersions(installed []installedVersion) map[string]bool {
+	referenced := make(map[string]bool)
+
+	for _, projectRoot := range tools.KnownProjects() {
+		if lock, err := tools.LoadLockFile(projectRoot); err == nil && lock != nil {
+			for toolName, entry := range lock.Tools {
+				referenced[installKey(toolName, entry.Version, entry.Distribution)] = true
+			}
+		}
+
+		cfg, err := config.LoadConfig(projectRoot)
+		if err != nil {
+			continue
+		}
+		for toolName, toolConfig := range cfg.Tools {
+			spec, err := version.ParseSpec(toolConfig.Version)
+			if err != nil {
+				continue
+			}
+			for _, entry := range installed {
+				if entry.Tool != toolName || entry.Distribution != toolConfig.Distribution {
+					continue
+				}
+				if v, err := version.ParseVersion(entry.Version); err == nil && spec.Matches(v) {
+					referenced[installKey(entry.Tool, entry.Version, entry.Distribution)] = true
+				}
+			}
+		}
+	}
+
+	return referenced
+}
+
+// installKey builds a lookup key for an installed version
+func installKey(toolName, versionLabel, distribution string) string {
+	return toolName + ":" + versionLabel + ":" + distribution
+}
+
+// lastUsed returns the last-use timestamp for a version directory: the usage
+// marker's mtime when present, the directory's mtime otherwise
+func lastUsed(dir string) time.Time {
+	if info, err := os.Stat(filepath.Join(dir, tools.UsageMarkerName)); err == nil {
+		return info.ModTime()
+	}
+	if info, err := os.Stat(dir); err == nil {
+		return info.ModTime()
+	}
+	return time.Time{}
+}
+
+// dirSize sums the file sizes under a directory (best effort)
+func dirSize(dir string) int64 {
+	var size int64
+	_ = filepath.Walk(dir, func(_ string, info os.FileInfo, err error) error {
+		if err == nil && !info.IsDir() {
+			size += info.Size()
+		}
+		return nil
+	})
+	return size
+}
+
+// formatSize renders a byte count in a human-friendly unit
+func formatSize(size int64) string {
+	switch {
+	case size >= 1<<30:
+		return fmt.Sprintf("%.1f GB", float64(size)/(1<<30))
+	case size >= 1<<20:
+		return fmt.Sprintf("%.1f MB", float64(size)/(1<<20))
+	case size >= 1<<10:
+		return fmt.Sprintf("%.1f KB", float64(size)/(1<<10))
+	default:
+		return fmt.Sprintf("%d B", size)
+	}
+}
+
+// formatLastUsed renders a last-use timestamp, or "-" when unknown
+func formatLastUsed(t time.Time) string {
+	if t.IsZero() {
+		return "-"
+	}
+	return t.Format("2006-01-02")
+}
@@ -0,0 +1,26 @@
+package cmd
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+)
+
+func TestExitCode(t *testing.T) {
+	if code := ExitCode(nil); code != 0 {
+		t.Errorf("expected 0 for nil error, got %d", code)
+	}
+	if code := ExitCode(errors.New("build failed")); code != ExitToolFailure {
+		t.Errorf("expected %d for a plain error, got %d", ExitToolFailure, code)
+	}
+
+	confErr := &configurationError{errors.New("bad config")}
+	if code := ExitCode(confErr); code != ExitConfigError {
+		t.Errorf("expected %d for a configuration error, got %d", ExitConfigError, code)
+	}
+	// Wrapping (as Execute does with auto-setup errors) must not hide it
+	wrapped := fmt.Errorf("auto-setup failed: %w", confErr)
+	if code := ExitCode(wrapped); code != ExitConfigError {
+		t.Errorf("expected %d for a wrapped configuration error, got %d", ExitConfigError, code)
+	}
+}
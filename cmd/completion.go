@@ -0,0 +1,93 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/spf13/cobra"
+)
+
+// completionCmd generates shell completion scripts. Custom project commands
+// are registered as real cobra commands before execution, so the generated
+// scripts complete them dynamically from the current project's configuration.
+var completionCmd = &cobra.Command{
+	Use:   "completion bash|zsh|fish|powershell|nu",
+	Short: "Generate a shell completion script",
+	Long: `Generate a shell completion script for mvx.
+
+The script completes built-in commands and flags, tool names for
+'mvx tools add', and — dynamically — the custom commands defined in the
+current project's configuration.
+
+Examples:
+  # Bash (add to ~/.bashrc)
+  source <(mvx completion bash)
+
+  # Zsh (add to ~/.zshrc)
+  source <(mvx completion zsh)
+
+  # Fish
+  mvx completion fish | source
+
+  # PowerShell (add to $PROFILE)
+  mvx completion powershell | Out-String | Invoke-Expression
+
+  # Nushell (save and source from config.nu)
+  mvx completion nu | save -f ~/.config/nushell/mvx-completions.nu`,
+
+	DisableFlagsInUseLine: true,
+	ValidArgs:             []string{"bash", "zsh", "fish", "powershell", "nu"},
+	Args:                  cobra.MatchAll(cobra.ExactArgs(1), cobra.OnlyValidArgs),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := generateCompletion(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(completionCmd)
+}
+
+// generateCompletion writes the completion script for a shell to stdout
+func generateCompletion(shellName string) error {
+	switch shellName {
+	case "bash":
+		return rootCmd.GenBashCompletionV2(os.Stdout, true)
+	case "zsh":
+		return rootCmd.GenZshCompletion(os.Stdout)
+	case "fish":
+		return rootCmd.GenFishCompletion(os.Stdout, true)
+	case "powershell":
+		return rootCmd.GenPowerShellCompletionWithDesc(os.Stdout)
+	case "nu", "nushell":
+		fmt.Print(nuCompletionScript)
+		return nil
+	default:
+		return fmt.Errorf("unsupported shell: %s (supported: bash, zsh, fish, powershell, nu)", shellName)
+	}
+}
+
+// nuCompletionScript registers an external completer that asks mvx itself
+// for suggestions (cobra's hidden __complete machinery), so dynamic project
+// commands complete in nushell too.
+const nuCompletionScript = `# mvx completions for nushell
+# Generated by: mvx completion nu
+
+let mvx_completer = {|spans|
+    mvx __complete ...($spans | skip 1)
+    | lines
+    | take until {|line| $line starts-with ":"}
+    | each {|line|
+        let parts = ($line | split column "\t" value description)
+        {value: $parts.value.0, description: ($parts | get -i description.0 | default "")}
+    }
+}
+
+$env.config = ($env.config | upsert completions.external {
+    enable: true
+    completer: $mvx_completer
+})
+`
@@ -0,0 +1,29 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/spf13/cobra"
+)
+
+func TestCompleteToolsArgsSubcommands(t *testing.T) {
+	suggestions, directive := completeToolsArgs(toolsCmd, nil, "")
+	if directive != cobra.ShellCompDirectiveNoFileComp {
+		t.Errorf("directive = %v, want NoFileComp", directive)
+	}
+	found := false
+	for _, s := range suggestions {
+		if s == "add" {
+			found = true
+		}
+	}
+	if !found {
+		t.Errorf("suggestions %v should include add", suggestions)
+	}
+}
+
+func TestGenerateCompletionUnsupportedShell(t *testing.T) {
+	if err := generateCompletion("tcsh"); err == nil {
+		t.Error("expected error for unsupported shell")
+	}
+}
@@ -0,0 +1,164 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+	"gopkg.in/yaml.v3"
+)
+
+// configGetCmd reads a value from the project configuration
+var configGetCmd = &cobra.Command{
+	Use:   "get <path>",
+	Short: "Read a value from the project configuration",
+	Long: `Read a value from .mvx/config by dotted path, for scripts and automation.
+
+Examples:
+  mvx config get tools.java.version
+  mvx config get commands.build.script
+  mvx config get tools                  # Prints the whole section as JSON`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := getConfigValue(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// configSetCmd writes a value into the project configuration
+var configSetCmd = &cobra.Command{
+	Use:   "set <path> <value>",
+	Short: "Set a value in the project configuration",
+	Long: `Set a value in .mvx/config by dotted path, so bots and release scripts
+can bump pinned versions without hand-editing.
+
+Examples:
+  mvx config set tools.maven.version 3.9.9
+  mvx config set tools.java.distribution temurin`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := setConfigValue(args[0], args[1]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	configCmd.AddCommand(configGetCmd)
+	configCmd.AddCommand(configSetCmd)
+}
+
+// loadRawProjectConfig reads the project config file into a generic document
+func loadRawProjectConfig() (string, map[string]interface{}, error) {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		return "", nil, err
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to read %s: %w", configPath, err)
+	}
+
+	raw, err := config.ParseRawConfig(data, strings.ToLower(filepath.Ext(configPath)))
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to parse %s: %w", configPath, err)
+	}
+	return configPath, raw, nil
+}
+
+// getConfigValue prints the value at a dotted path in the project config
+func getConfigValue(path string) error {
+	configPath, raw, err := loadRawProjectConfig()
+	if err != nil {
+		return err
+	}
+
+	var value interface{} = raw
+	for _, segment := range strings.Split(path, ".") {
+		table, ok := value.(map[string]interface{})
+		if !ok {
+			return fmt.Errorf("%s: %s is not an object", configPath, path)
+		}
+		value, ok = table[segment]
+		if !ok {
+			return fmt.Errorf("%s: no value at %s", configPath, path)
+		}
+	}
+
+	// Scalars print bare for easy shell consumption; structures print as JSON
+	switch v := value.(type) {
+	case string:
+		fmt.Println(v)
+	case bool, float64, int, int64:
+		fmt.Println(v)
+	default:
+		data, err := json.MarshalIndent(value, "", "  ")
+		if err != nil {
+			return err
+		}
+		fmt.Println(string(data))
+	}
+	return nil
+}
+
+// setConfigValue writes a value at a dotted path and saves the config file
+// in its own format. Comments are not preserved.
+func setConfigValue(path, value string) error {
+	configPath, raw, err := loadRawProjectConfig()
+	if err != nil {
+		return err
+	}
+
+	segments := strings.Split(path, ".")
+	current := raw
+	for _, segment := range segments[:len(segments)-1] {
+		child, exists := current[segment]
+		if !exists {
+			table := make(map[string]interface{})
+			current[segment] = table
+			current = table
+			continue
+		}
+		table, ok := child.(map[string]interface{})
+		if !ok {
+			return fmt.Errorf("%s: %s is already a value, not an object", configPath, segment)
+		}
+		current = table
+	}
+	current[segments[len(segments)-1]] = value
+
+	var data []byte
+	switch strings.ToLower(filepath.Ext(configPath)) {
+	case ".json5", ".json":
+		if data, err = json.MarshalIndent(raw, "", "  "); err != nil {
+			return err
+		}
+		data = append(data, '\n')
+	case ".yml", ".yaml":
+		if data, err = yaml.Marshal(raw); err != nil {
+			return err
+		}
+	default:
+		return fmt.Errorf("mvx config set does not support editing %s files yet", filepath.Ext(configPath))
+	}
+
+	if err := os.WriteFile(configPath, data, 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", configPath, err)
+	}
+
+	printSuccess("✅ Set %s = %s in %s", path, value, configPath)
+	return nil
+}
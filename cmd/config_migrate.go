@@ -0,0 +1,145 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// configMigrateCmd rewrites the project config to the current schema version
+var configMigrateCmd = &cobra.Command{
+	Use:   "migrate",
+	Short: "Migrate the project configuration to the current schema",
+	Long: `Migrate .mvx/config to the current schema version, rewriting deprecated
+keys and structures in place.
+
+Migrations are applied textually so comments and formatting in JSON5 and
+YAML files survive. The file is stamped with format_version afterwards, so
+older mvx builds fail loudly instead of silently misreading newer configs.
+
+Examples:
+  mvx config migrate            # Rewrite the config in place
+  mvx config migrate --dry-run  # Show what would change without writing`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runConfigMigrate(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var migrateDryRun bool
+
+func init() {
+	configMigrateCmd.Flags().BoolVar(&migrateDryRun, "dry-run", false, "show pending migrations without writing the file")
+	configCmd.AddCommand(configMigrateCmd)
+}
+
+// configMigration is one schema migration step. apply operates on the raw
+// file text (to preserve comments) and reports whether it changed anything.
+type configMigration struct {
+	description string
+	apply       func(content, ext string) (string, bool)
+}
+
+// configMigrations lists all schema migrations in order. New steps are
+// appended here as the format evolves; each must be idempotent so migrate
+// can be re-run safely.
+var configMigrations = []configMigration{
+	{
+		description: "stamp format_version so older mvx builds reject newer configs",
+		apply:       stampFormatVersion,
+	},
+}
+
+// runConfigMigrate applies pending migrations to the project config file
+func runConfigMigrate() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	ext := strings.ToLower(filepath.Ext(configPath))
+	if ext == ".toml" {
+		return fmt.Errorf("mvx config migrate does not support TOML files yet; edit %s by hand", configPath)
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", configPath, err)
+	}
+
+	content := string(data)
+	applied := 0
+	for _, migration := range configMigrations {
+		migrated, changed := migration.apply(content, ext)
+		if !changed {
+			continue
+		}
+		content = migrated
+		applied++
+		printInfo("  • %s", migration.description)
+	}
+
+	if applied == 0 {
+		printSuccess("✅ %s is already at format version %s, nothing to migrate", configPath, config.CurrentFormatVersion)
+		return nil
+	}
+
+	// Make sure the migrated text still parses before touching the file
+	if _, err := config.ParseRawConfig([]byte(content), ext); err != nil {
+		return fmt.Errorf("migration produced an unparseable config (this is a bug): %w", err)
+	}
+
+	if migrateDryRun {
+		printInfo("")
+		printInfo("Dry run: %d migration(s) pending for %s (no changes written)", applied, configPath)
+		return nil
+	}
+
+	if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", configPath, err)
+	}
+
+	printSuccess("✅ Migrated %s to format version %s (%d step(s) applied)", configPath, config.CurrentFormatVersion, applied)
+	return nil
+}
+
+var formatVersionPattern = regexp.MustCompile(`(?m)^\s*"?format_version"?\s*[:=]`)
+
+// stampFormatVersion inserts format_version at the top of the document when
+// it is missing, without disturbing comments or existing formatting
+func stampFormatVersion(content, ext string) (string, bool) {
+	if formatVersionPattern.MatchString(content) {
+		return content, false
+	}
+
+	switch ext {
+	case ".yml", ".yaml":
+		return fmt.Sprintf("format_version: %q\n%s", config.CurrentFormatVersion, content), true
+	default:
+		// JSON5/JSON: insert right after the opening brace, reusing the
+		// indentation of the first existing key
+		brace := strings.Index(content, "{")
+		if brace == -1 {
+			return content, false
+		}
+		indent := "  "
+		rest := content[brace+1:]
+		if m := regexp.MustCompile(`\n([ \t]+)\S`).FindStringSubmatch(rest); m != nil {
+			indent = m[1]
+		}
+		line := fmt.Sprintf("\n%sformat_version: %q,", indent, config.CurrentFormatVersion)
+		return content[:brace+1] + line + rest, true
+	}
+}
@@ -0,0 +1,118 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// configResolveCmd shows the effective configuration with provenance
+var configResolveCmd = &cobra.Command{
+	Use:   "resolve",
+	Short: "Show the effective configuration and where each value comes from",
+	Long: `Show every effective config value together with the source file that set
+it, across the whole stack: MVX_CONFIG_PATH baselines, the project config
+and local override files.
+
+Examples:
+  mvx config resolve
+  MVX_CONFIG_PATH=/etc/mvx/org.json5 mvx config resolve`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := resolveConfigProvenance(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	configCmd.AddCommand(configResolveCmd)
+}
+
+// resolveConfigProvenance merges all config sources generically, recording
+// which file set each leaf value, and prints the result sorted by path.
+func resolveConfigProvenance() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	sources, err := config.ConfigSources(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	printInfo("📋 Config sources (later overrides earlier):")
+	for i, source := range sources {
+		printInfo("  %d. %s", i+1, source)
+	}
+	printInfo("")
+
+	values := make(map[string]interface{})
+	provenance := make(map[string]string)
+
+	for _, source := range sources {
+		data, err := os.ReadFile(source)
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", source, err)
+		}
+		raw, err := config.ParseRawConfig(data, strings.ToLower(filepath.Ext(source)))
+		if err != nil {
+			return fmt.Errorf("failed to parse %s: %w", source, err)
+		}
+
+		for path, value := range flattenConfig("", raw) {
+			values[path] = value
+			provenance[path] = source
+		}
+	}
+
+	var paths []string
+	for path := range values {
+		paths = append(paths, path)
+	}
+	sort.Strings(paths)
+
+	for _, path := range paths {
+		rendered, err := json.Marshal(values[path])
+		if err != nil {
+			rendered = []byte(fmt.Sprintf("%v", values[path]))
+		}
+		printInfo("  %s = %s  (from %s)", path, string(rendered), provenance[path])
+	}
+
+	return nil
+}
+
+// flattenConfig flattens a parsed config document into dotted leaf paths.
+// Arrays and scalars are leaves; objects recurse.
+func flattenConfig(prefix string, value interface{}) map[string]interface{} {
+	leaves := make(map[string]interface{})
+
+	table, ok := value.(map[string]interface{})
+	if !ok {
+		leaves[prefix] = value
+		return leaves
+	}
+
+	for key, child := range table {
+		path := key
+		if prefix != "" {
+			path = prefix + "." + key
+		}
+		if childTable, isTable := child.(map[string]interface{}); isTable && len(childTable) > 0 {
+			for leafPath, leafValue := range flattenConfig(path, child) {
+				leaves[leafPath] = leafValue
+			}
+		} else {
+			leaves[path] = child
+		}
+	}
+	return leaves
+}
@@ -0,0 +1,159 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// configSchemaCmd prints the JSON Schema for the project config format
+var configSchemaCmd = &cobra.Command{
+	Use:   "schema",
+	Short: "Print the JSON Schema for the mvx config format",
+	Long: `Print a JSON Schema (draft-07) describing the .mvx/config format.
+
+Point your editor at the schema to get completion and inline validation:
+  mvx config schema > mvx-schema.json`,
+	Run: func(cmd *cobra.Command, args []string) {
+		schema, err := config.ConfigJSONSchema()
+		if err != nil {
+			printError("Failed to generate schema: %v", err)
+			os.Exit(1)
+		}
+		fmt.Println(schema)
+	},
+}
+
+// configValidateCmd validates the project configuration
+var configValidateCmd = &cobra.Command{
+	Use:   "validate",
+	Short: "Validate the project configuration",
+	Long: `Validate .mvx/config against the schema: unknown keys, mistyped values
+and unknown tool names are reported with the line they appear on.
+
+Examples:
+  mvx config validate`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := validateProjectConfig(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	configCmd.AddCommand(configSchemaCmd)
+	configCmd.AddCommand(configValidateCmd)
+}
+
+// validateProjectConfig checks the project config file for structural and
+// semantic problems, reporting each with a line number where possible.
+func validateProjectConfig() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", configPath, err)
+	}
+
+	printInfo("🔍 Validating %s...", configPath)
+
+	raw, err := config.ParseRawConfig(data, strings.ToLower(filepath.Ext(configPath)))
+	if err != nil {
+		return fmt.Errorf("failed to parse %s: %w", configPath, err)
+	}
+
+	var issues []string
+	issues = append(issues, config.ValidateStructure(raw)...)
+
+	// Semantic checks (version formats, custom tool requirements, ...)
+	cfg, loadErr := config.LoadConfig(projectRoot)
+	if loadErr != nil {
+		issues = append(issues, loadErr.Error())
+	} else {
+		// Unknown tool names (after custom tools and plugins are registered)
+		manager, err := tools.NewManager()
+		if err == nil {
+			manager.RegisterCustomTools(cfg)
+			manager.RegisterProjectPlugins(projectRoot, cfg)
+			manager.LoadProjectLock(projectRoot)
+			for toolName := range cfg.Tools {
+				if _, err := manager.GetTool(toolName); err != nil {
+					issues = append(issues, fmt.Sprintf("tools.%s: unknown tool name", toolName))
+				}
+			}
+		}
+	}
+
+	if len(issues) == 0 {
+		printSuccess("✅ Configuration is valid")
+		return nil
+	}
+
+	for _, issue := range issues {
+		if line := findConfigLine(data, issue); line > 0 {
+			printInfo("  ❌ line %d: %s", line, issue)
+		} else {
+			printInfo("  ❌ %s", issue)
+		}
+	}
+	return fmt.Errorf("found %d problem(s) in %s", len(issues), configPath)
+}
+
+// findProjectConfigFile locates the project config file, trying the same
+// names as config.LoadConfig
+func findProjectConfigFile(projectRoot string) (string, error) {
+	mvxDir := filepath.Join(projectRoot, ".mvx")
+	candidates := []string{
+		"config.json5", "config.yml", "config.yaml", "config.json",
+		"mvx.yaml", "mvx.yml", "mvx.json5", "mvx.toml",
+	}
+	for _, name := range candidates {
+		path := filepath.Join(mvxDir, name)
+		if _, err := os.Stat(path); err == nil {
+			return path, nil
+		}
+	}
+	return "", fmt.Errorf("no configuration file found in %s", mvxDir)
+}
+
+// findConfigLine locates the line a reported issue refers to by searching for
+// the last segment of its dotted path in the raw file (best effort — parsers
+// do not preserve positions).
+func findConfigLine(data []byte, issue string) int {
+	path, _, found := strings.Cut(issue, ":")
+	if !found {
+		return 0
+	}
+	segments := strings.Split(strings.TrimSpace(path), ".")
+	key := segments[len(segments)-1]
+	if idx := strings.IndexByte(key, '['); idx >= 0 {
+		key = key[:idx]
+	}
+	if key == "" {
+		return 0
+	}
+
+	for i, line := range strings.Split(string(data), "\n") {
+		trimmed := strings.TrimSpace(line)
+		if strings.HasPrefix(trimmed, key+":") || strings.HasPrefix(trimmed, "\""+key+"\":") ||
+			strings.HasPrefix(trimmed, key+" =") || strings.HasPrefix(trimmed, key+"=") ||
+			strings.HasPrefix(trimmed, "["+key+"]") {
+			return i + 1
+		}
+	}
+	return 0
+}
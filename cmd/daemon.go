@@ -0,0 +1,151 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/daemon"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// daemonCmd manages the optional background daemon that keeps the resolved
+// configuration and environment in memory between invocations
+var daemonCmd = &cobra.Command{
+	Use:   "daemon",
+	Short: "Manage the mvx background daemon (opt-in via MVX_DAEMON=true)",
+	Long: `Manage the optional mvx background daemon.
+
+With MVX_DAEMON=true, commands like 'mvx mvn' fetch the computed tool
+environment from a per-project daemon instead of re-resolving it on every
+invocation — editor tasks that invoke mvx dozens of times per minute see
+near-zero overhead. The daemon starts lazily on the first miss, watches the
+config and lockfile for changes, and exits after an idle timeout
+(MVX_DAEMON_IDLE_TIMEOUT, default 10m).`,
+}
+
+// daemonServeCmd runs the daemon loop in the foreground (normally launched
+// detached by the lazy start, not by hand)
+var daemonServeCmd = &cobra.Command{
+	Use:   "serve",
+	Short: "Run the daemon in the foreground (started automatically)",
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runDaemonServe(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// daemonStopCmd stops the current project's daemon
+var daemonStopCmd = &cobra.Command{
+	Use:   "stop",
+	Short: "Stop the daemon for this project",
+	Run: func(cmd *cobra.Command, args []string) {
+		projectRoot, err := findProjectRoot()
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		if err := daemon.Stop(projectRoot); err != nil {
+			printInfo("No daemon running for this project")
+			return
+		}
+		printSuccess("✅ Daemon stopped")
+	},
+}
+
+// daemonStatusCmd reports whether a daemon is serving this project
+var daemonStatusCmd = &cobra.Command{
+	Use:   "status",
+	Short: "Show whether a daemon is running for this project",
+	Run: func(cmd *cobra.Command, args []string) {
+		projectRoot, err := findProjectRoot()
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		pid, err := daemon.Ping(projectRoot)
+		if err != nil {
+			printInfo("No daemon running for this project")
+			return
+		}
+		printInfo("Daemon running (pid %d)", pid)
+	},
+}
+
+func init() {
+	daemonCmd.AddCommand(daemonServeCmd)
+	daemonCmd.AddCommand(daemonStopCmd)
+	daemonCmd.AddCommand(daemonStatusCmd)
+	rootCmd.AddCommand(daemonCmd)
+}
+
+// runDaemonServe serves the current project until stopped or idle
+func runDaemonServe() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	compute := func() (map[string]string, error) {
+		cfg, err := config.LoadConfig(projectRoot)
+		if err != nil {
+			return nil, fmt.Errorf("failed to load configuration: %w", err)
+		}
+		manager, err := tools.NewManager()
+		if err != nil {
+			return nil, err
+		}
+		manager.RegisterCustomTools(cfg)
+		manager.RegisterProjectPlugins(projectRoot, cfg)
+		manager.LoadProjectLock(projectRoot)
+		manager.ConfigureRegistries(cfg)
+		return manager.SetupEnvironment(cfg)
+	}
+
+	return daemon.Serve(projectRoot, daemon.IdleTimeout(), compute, func() string {
+		return configFingerprint(projectRoot)
+	})
+}
+
+// configFingerprint identifies the on-disk config state: size and mtime of
+// every merged config file plus the lockfile. A change invalidates the
+// daemon's cached environment.
+func configFingerprint(projectRoot string) string {
+	files, err := config.ConfigSources(projectRoot)
+	if err != nil {
+		return "unreadable"
+	}
+	files = append(files, tools.LockFilePath(projectRoot))
+
+	var parts []string
+	for _, file := range files {
+		if info, err := os.Stat(file); err == nil {
+			parts = append(parts, fmt.Sprintf("%s:%d:%d", file, info.Size(), info.ModTime().UnixNano()))
+		} else {
+			parts = append(parts, file+":absent")
+		}
+	}
+	return strings.Join(parts, "|")
+}
+
+// daemonEnvironment fetches the cached environment from the project daemon.
+// A miss starts the daemon in the background (so the next invocation is
+// fast) and reports false, leaving the caller on the normal path.
+func daemonEnvironment(projectRoot string) (map[string]string, bool) {
+	if !daemon.Enabled() {
+		return nil, false
+	}
+	env, err := daemon.RequestEnv(projectRoot)
+	if err != nil {
+		printVerbose("Daemon unavailable (%v), starting one for next time", err)
+		if startErr := daemon.Start(projectRoot); startErr != nil {
+			printVerbose("Failed to start daemon: %v", startErr)
+		}
+		return nil, false
+	}
+	return env, true
+}
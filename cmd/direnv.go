@@ -0,0 +1,70 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/spf13/cobra"
+)
+
+// direnvCmd integrates mvx with direnv, so entering the project directory
+// automatically exports the mvx-managed environment and keeps it in sync
+// when tool versions change.
+var direnvCmd = &cobra.Command{
+	Use:   "direnv [subcommand]",
+	Short: "Integrate mvx with direnv",
+	Long: `Integrate mvx with direnv (https://direnv.net).
+
+Subcommands:
+  hook     Print the use_mvx function for ~/.config/direnv/direnvrc
+  envrc    Print a suggested .envrc for the project
+
+Setup:
+  1. Add the stdlib function to your direnv configuration:
+       mvx direnv hook >> ~/.config/direnv/direnvrc
+  2. Create an .envrc in the project root containing:
+       use mvx
+     (or run 'mvx direnv envrc > .envrc')
+  3. Allow it:
+       direnv allow
+
+direnv then exports PATH, JAVA_HOME, etc. from the mvx configuration when
+you enter the directory, and re-exports them whenever the configuration or
+lockfile changes.`,
+
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		subcommand := "hook"
+		if len(args) > 0 {
+			subcommand = args[0]
+		}
+		switch subcommand {
+		case "hook":
+			fmt.Print(direnvHookSnippet)
+		case "envrc":
+			fmt.Print(direnvEnvrcSnippet)
+		default:
+			printError("unknown direnv subcommand: %s (expected hook or envrc)", subcommand)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(direnvCmd)
+}
+
+// direnvHookSnippet is the use_mvx stdlib function for direnvrc. It watches
+// the configuration files so direnv reloads the environment when tool
+// versions change, and evaluates 'mvx env' for the actual exports.
+const direnvHookSnippet = `# mvx integration for direnv (generated by 'mvx direnv hook')
+use_mvx() {
+  watch_file .mvx/config.json5 .mvx/config.yml .mvx/config.yaml mvx.json5 .mvx/mvx.lock .mvx/config.local.json5
+  eval "$(mvx env --shell bash)"
+}
+`
+
+// direnvEnvrcSnippet is a suggested project .envrc
+const direnvEnvrcSnippet = `# Export the mvx-managed environment via direnv (see 'mvx direnv --help')
+use mvx
+`
@@ -1,10 +1,12 @@
 package cmd
 
 import (
+	"encoding/json"
 	"fmt"
 	"os"
 	"path/filepath"
 	"runtime"
+	"sort"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
@@ -14,6 +16,8 @@ import (
 
 var (
 	envShell string
+	envJSON  bool
+	envHook  bool
 )
 
 // envCmd represents the env command
@@ -37,7 +41,16 @@ Examples:
   mvx env --shell fish | source
   
   # PowerShell
-  Invoke-Expression (mvx env --shell powershell | Out-String)`,
+  Invoke-Expression (mvx env --shell powershell | Out-String)
+
+  # Nushell
+  mvx env --shell nu | from json | load-env
+
+  # Windows cmd (write to a batch file and call it)
+  mvx env --shell cmd > mvx-env.bat && call mvx-env.bat
+
+  # JSON for IDE launchers and other tooling
+  mvx env --json`,
 
 	Run: func(cmd *cobra.Command, args []string) {
 		if err := outputEnvironment(); err != nil {
@@ -48,7 +61,10 @@ Examples:
 }
 
 func init() {
-	envCmd.Flags().StringVar(&envShell, "shell", detectShell(), "shell type (bash, zsh, fish, powershell)")
+	envCmd.Flags().StringVar(&envShell, "shell", detectShell(), "shell type (bash, zsh, fish, powershell, cmd, nu)")
+	envCmd.Flags().BoolVar(&envJSON, "json", false, "output the resolved environment as JSON for tooling")
+	envCmd.Flags().BoolVar(&envHook, "hook", false, "called from a shell activation hook (enforces project trust)")
+	_ = envCmd.Flags().MarkHidden("hook")
 }
 
 // detectShell attempts to detect the current shell
@@ -65,6 +81,9 @@ func detectShell() string {
 		if strings.Contains(shell, "fish") {
 			return "fish"
 		}
+		if strings.HasSuffix(shell, "/nu") || strings.HasSuffix(shell, "nushell") {
+			return "nu"
+		}
 	}
 
 	// Check for PowerShell on Windows
@@ -92,6 +111,13 @@ func outputEnvironment() error {
 		return nil
 	}
 
+	// Activation hooks only export trusted projects, so cd-ing into a fresh
+	// clone cannot silently change PATH (see 'mvx trust')
+	if envHook && !isProjectTrusted(projectRoot) {
+		fmt.Fprintf(os.Stderr, "mvx: %s is not trusted; run 'mvx trust' to enable automatic activation\n", projectRoot)
+		return nil
+	}
+
 	// Load configuration
 	cfg, err := config.LoadConfig(projectRoot)
 	if err != nil {
@@ -117,6 +143,9 @@ func outputEnvironment() error {
 	}
 
 	// Output environment in shell-specific format
+	if envJSON {
+		return outputJSONEnv(env)
+	}
 	switch envShell {
 	case "bash", "zsh":
 		return outputBashEnv(pathDirs, env)
@@ -124,11 +153,26 @@ func outputEnvironment() error {
 		return outputFishEnv(pathDirs, env)
 	case "powershell":
 		return outputPowerShellEnv(pathDirs, env)
+	case "cmd":
+		return outputCmdEnv(pathDirs, env)
+	case "nu", "nushell":
+		return outputNuEnv(pathDirs, env)
 	default:
 		return fmt.Errorf("unsupported shell: %s", envShell)
 	}
 }
 
+// sortedEnvKeys returns the environment keys in a stable order, so the
+// output is diffable and safe to cache in terminal profiles
+func sortedEnvKeys(env map[string]string) []string {
+	keys := make([]string, 0, len(env))
+	for key := range env {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
 // outputBashEnv outputs environment in bash/zsh format
 func outputBashEnv(pathDirs []string, env map[string]string) error {
 	// Export PATH
@@ -138,8 +182,9 @@ func outputBashEnv(pathDirs []string, env map[string]string) error {
 	}
 
 	// Export other environment variables
-	for key, value := range env {
+	for _, key := range sortedEnvKeys(env) {
 		if key != "PATH" {
+			value := env[key]
 			// Escape quotes in value
 			escapedValue := strings.ReplaceAll(value, `"`, `\"`)
 			fmt.Printf("export %s=\"%s\"\n", key, escapedValue)
@@ -159,8 +204,9 @@ func outputFishEnv(pathDirs []string, env map[string]string) error {
 	}
 
 	// Set other environment variables
-	for key, value := range env {
+	for _, key := range sortedEnvKeys(env) {
 		if key != "PATH" {
+			value := env[key]
 			// Escape quotes in value
 			escapedValue := strings.ReplaceAll(value, `"`, `\"`)
 			fmt.Printf("set -gx %s \"%s\"\n", key, escapedValue)
@@ -179,8 +225,9 @@ func outputPowerShellEnv(pathDirs []string, env map[string]string) error {
 	}
 
 	// Set other environment variables
-	for key, value := range env {
+	for _, key := range sortedEnvKeys(env) {
 		if key != "PATH" {
+			value := env[key]
 			// Escape quotes in value
 			escapedValue := strings.ReplaceAll(value, `"`, `\"`+"`"+`"`)
 			fmt.Printf("$env:%s = \"%s\"\n", key, escapedValue)
@@ -189,3 +236,46 @@ func outputPowerShellEnv(pathDirs []string, env map[string]string) error {
 
 	return nil
 }
+
+// outputCmdEnv outputs environment in Windows cmd format (batch set lines)
+func outputCmdEnv(pathDirs []string, env map[string]string) error {
+	if len(pathDirs) > 0 {
+		pathStr := strings.Join(pathDirs, string(os.PathListSeparator))
+		fmt.Printf("set \"PATH=%s;%%PATH%%\"\n", pathStr)
+	}
+
+	for _, key := range sortedEnvKeys(env) {
+		if key != "PATH" {
+			fmt.Printf("set \"%s=%s\"\n", key, env[key])
+		}
+	}
+
+	return nil
+}
+
+// outputJSONEnv outputs the resolved environment as a JSON object
+func outputJSONEnv(env map[string]string) error {
+	data, err := json.MarshalIndent(env, "", "  ")
+	if err != nil {
+		return err
+	}
+	fmt.Println(string(data))
+	return nil
+}
+
+// outputNuEnv outputs the environment as JSON for nushell's load-env:
+//
+//	mvx env --shell nu | from json | load-env
+//
+// Nushell has no eval, so instead of statements we emit a record with the
+// tool directories already merged into PATH.
+func outputNuEnv(pathDirs []string, env map[string]string) error {
+	merged := make(map[string]string, len(env))
+	for key, value := range env {
+		merged[key] = value
+	}
+	if len(pathDirs) > 0 {
+		merged["PATH"] = strings.Join(pathDirs, string(os.PathListSeparator)) + string(os.PathListSeparator) + os.Getenv("PATH")
+	}
+	return outputJSONEnv(merged)
+}
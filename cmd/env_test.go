@@ -1,6 +1,7 @@
 package cmd
 
 import (
+	"encoding/json"
 	"os"
 	"path/filepath"
 	"runtime"
@@ -336,3 +337,74 @@ func TestEnvCommandWithShellFlag(t *testing.T) {
 		})
 	}
 }
+
+func TestOutputCmdEnv(t *testing.T) {
+	pathDirs := []string{"C:\\tools\\java\\bin"}
+	env := map[string]string{
+		"JAVA_HOME": "C:\\tools\\java",
+	}
+
+	// Capture stdout
+	oldStdout := os.Stdout
+	r, w, _ := os.Pipe()
+	os.Stdout = w
+
+	err := outputCmdEnv(pathDirs, env)
+
+	// Restore stdout
+	w.Close()
+	os.Stdout = oldStdout
+
+	buf := make([]byte, 2000)
+	n, _ := r.Read(buf)
+	output := string(buf[:n])
+
+	if err != nil {
+		t.Fatalf("outputCmdEnv() error = %v", err)
+	}
+
+	expectedStrings := []string{
+		"set \"PATH=C:\\tools\\java\\bin",
+		"%PATH%",
+		"set \"JAVA_HOME=C:\\tools\\java\"",
+	}
+	for _, expected := range expectedStrings {
+		if !strings.Contains(output, expected) {
+			t.Errorf("Expected output to contain '%s', but it didn't.\nOutput:\n%s", expected, output)
+		}
+	}
+}
+
+func TestOutputJSONEnv(t *testing.T) {
+	env := map[string]string{
+		"JAVA_HOME":  "/path/to/java",
+		"MAVEN_HOME": "/path/to/maven",
+	}
+
+	// Capture stdout
+	oldStdout := os.Stdout
+	r, w, _ := os.Pipe()
+	os.Stdout = w
+
+	err := outputJSONEnv(env)
+
+	// Restore stdout
+	w.Close()
+	os.Stdout = oldStdout
+
+	buf := make([]byte, 2000)
+	n, _ := r.Read(buf)
+	output := string(buf[:n])
+
+	if err != nil {
+		t.Fatalf("outputJSONEnv() error = %v", err)
+	}
+
+	var parsed map[string]string
+	if err := json.Unmarshal([]byte(output), &parsed); err != nil {
+		t.Fatalf("output is not valid JSON: %v\nOutput:\n%s", err, output)
+	}
+	if parsed["JAVA_HOME"] != "/path/to/java" || parsed["MAVEN_HOME"] != "/path/to/maven" {
+		t.Errorf("parsed JSON = %v", parsed)
+	}
+}
@@ -0,0 +1,100 @@
+package cmd
+
+import (
+	"errors"
+	"os"
+	"strings"
+)
+
+// Stable error taxonomy: every failure maps to one code with its own exit
+// code and remediation hint, so wrappers and CI can branch on the cause
+// instead of parsing error prose. The codes and exit codes are part of the
+// CLI contract — never renumber them.
+type errorCode string
+
+const (
+	codeToolFailure         errorCode = "tool-failure"         // exit 1: a tool or build command failed
+	codeConfigInvalid       errorCode = "config-invalid"       // exit 2: the mvx configuration cannot be used
+	codeVersionNotFound     errorCode = "version-not-found"    // exit 3: no release satisfies the version spec
+	codeDownloadFailed      errorCode = "download-failed"      // exit 4: the network transfer failed
+	codeChecksumMismatch    errorCode = "checksum-mismatch"    // exit 5: the downloaded archive failed verification
+	codeUnsupportedPlatform errorCode = "unsupported-platform" // exit 6: no build for this OS/architecture
+)
+
+// exitCodes maps each error code to its process exit code
+var exitCodes = map[errorCode]int{
+	codeToolFailure:         ExitToolFailure,
+	codeConfigInvalid:       ExitConfigError,
+	codeVersionNotFound:     3,
+	codeDownloadFailed:      4,
+	codeChecksumMismatch:    5,
+	codeUnsupportedPlatform: 6,
+}
+
+// remediationHints suggests the usual fix for each failure class
+var remediationHints = map[errorCode]string{
+	codeConfigInvalid:       "Run 'mvx config validate' to locate the problem in the configuration.",
+	codeVersionNotFound:     "Run 'mvx tools search <tool>' to see available versions, or relax the version spec.",
+	codeDownloadFailed:      "Check network and proxy settings; re-run with --trace-http to see the failing requests.",
+	codeChecksumMismatch:    "Delete the partial download and retry; if it persists, the mirror may be serving a tampered or stale archive.",
+	codeUnsupportedPlatform: "Check the tool's platforms field, or exclude this tool on the current platform.",
+}
+
+// classifyError assigns an error to the taxonomy. Typed errors win; the
+// rest are classified by the stable phrases mvx uses when wrapping them.
+func classifyError(err error) errorCode {
+	if err == nil {
+		return codeToolFailure
+	}
+	var confErr *configurationError
+	if errors.As(err, &confErr) {
+		return codeConfigInvalid
+	}
+
+	message := strings.ToLower(err.Error())
+	switch {
+	case strings.Contains(message, "checksum mismatch"),
+		strings.Contains(message, "checksum verification failed"):
+		return codeChecksumMismatch
+	case strings.Contains(message, "unsupported platform"),
+		strings.Contains(message, "unsupported architecture"),
+		strings.Contains(message, "not available for platform"):
+		return codeUnsupportedPlatform
+	case strings.Contains(message, "failed to download"),
+		strings.Contains(message, "download failed"),
+		strings.Contains(message, "connection refused"),
+		strings.Contains(message, "context deadline exceeded"):
+		return codeDownloadFailed
+	case strings.Contains(message, "failed to resolve version"),
+		strings.Contains(message, "no matching version"),
+		strings.Contains(message, "version not found"),
+		strings.Contains(message, "no version satisfies"):
+		return codeVersionNotFound
+	}
+	return codeToolFailure
+}
+
+// exitWithError reports a fatal error — as a structured object under
+// --format json, otherwise as prose with the remediation hint — and exits
+// with the code's exit code
+func exitWithError(err error) {
+	code := classifyError(err)
+
+	if jsonOutput() {
+		printJSON(map[string]interface{}{
+			"error": map[string]interface{}{
+				"code":     string(code),
+				"message":  err.Error(),
+				"exitCode": exitCodes[code],
+				"hint":     remediationHints[code],
+			},
+		})
+		os.Exit(exitCodes[code])
+	}
+
+	printError("%v", err)
+	if hint := remediationHints[code]; hint != "" {
+		printError("   Hint: %s", hint)
+	}
+	os.Exit(exitCodes[code])
+}
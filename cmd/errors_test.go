@@ -0,0 +1,42 @@
+package cmd
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+)
+
+func TestClassifyError(t *testing.T) {
+	tests := []struct {
+		err  error
+		want errorCode
+	}{
+		{errors.New("mvn exited with status 1"), codeToolFailure},
+		{&configurationError{errors.New("bad config")}, codeConfigInvalid},
+		{errors.New("checksum mismatch: expected abc, got def"), codeChecksumMismatch},
+		{errors.New("tool not available for platform windows-arm64"), codeUnsupportedPlatform},
+		{errors.New("failed to download https://example.com/tool.tar.gz"), codeDownloadFailed},
+		{errors.New("failed to resolve version 99.9: no matching version"), codeVersionNotFound},
+	}
+	for _, test := range tests {
+		if got := classifyError(test.err); got != test.want {
+			t.Errorf("classifyError(%q) = %s, want %s", test.err, got, test.want)
+		}
+	}
+
+	// Wrapping must not change the classification
+	wrapped := fmt.Errorf("failed to install tools: %w", errors.New("checksum mismatch"))
+	if got := classifyError(wrapped); got != codeChecksumMismatch {
+		t.Errorf("expected checksum-mismatch for wrapped error, got %s", got)
+	}
+}
+
+func TestExitCodesAreDistinct(t *testing.T) {
+	seen := make(map[int]errorCode)
+	for code, exit := range exitCodes {
+		if other, dup := seen[exit]; dup {
+			t.Errorf("exit code %d shared by %s and %s", exit, code, other)
+		}
+		seen[exit] = code
+	}
+}
@@ -0,0 +1,90 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// execCmd runs an arbitrary program in the mvx-managed environment, so
+// tools not wrapped by mvx (IDEs, profilers, one-off scripts) still see the
+// pinned toolchain.
+var execCmd = &cobra.Command{
+	Use:   "exec -- <command> [args...]",
+	Short: "Run a program with the mvx-managed environment",
+	Long: `Run any binary with PATH, JAVA_HOME, etc. configured from the project
+config, passing arguments and the exit code through unchanged.
+
+Examples:
+  mvx exec -- java -version              # Pinned JDK, not the system one
+  mvx exec -- idea .                     # Launch an IDE with the toolchain
+  mvx exec -- ./scripts/profile.sh       # One-off scripts see the same env`,
+
+	DisableFlagParsing: true,
+	Run: func(cmd *cobra.Command, args []string) {
+		if len(args) > 0 && args[0] == "--" {
+			args = args[1:]
+		}
+		if len(args) == 0 || args[0] == "--help" || args[0] == "-h" {
+			_ = cmd.Help()
+			if len(args) == 0 {
+				os.Exit(1)
+			}
+			return
+		}
+		if err := runExec(args[0], args[1:]); err != nil {
+			if exitErr, ok := err.(*exec.ExitError); ok {
+				os.Exit(exitErr.ExitCode())
+			}
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(execCmd)
+}
+
+// runExec executes a binary with the project's resolved environment
+func runExec(binary string, args []string) error {
+	env := os.Environ()
+
+	projectRoot, err := findProjectRoot()
+	if err == nil {
+		cfg, err := config.LoadConfig(projectRoot)
+		if err != nil {
+			return fmt.Errorf("failed to load configuration: %w", err)
+		}
+		manager, err := tools.NewManager()
+		if err != nil {
+			return fmt.Errorf("failed to create tool manager: %w", err)
+		}
+		manager.RegisterCustomTools(cfg)
+		manager.RegisterProjectPlugins(projectRoot, cfg)
+		manager.LoadProjectLock(projectRoot)
+		manager.ConfigureRegistries(cfg)
+		env, err = setupShellEnvironment(cfg, manager, projectRoot)
+		if err != nil {
+			return fmt.Errorf("failed to setup environment: %w", err)
+		}
+	} else {
+		printVerbose("No project configuration found, using the current environment")
+	}
+
+	binaryPath, err := lookPathIn(binary, pathFromEnv(env))
+	if err != nil {
+		return fmt.Errorf("%s: command not found", binary)
+	}
+
+	execCommand := exec.Command(binaryPath, args...)
+	execCommand.Env = env
+	execCommand.Stdin = os.Stdin
+	execCommand.Stdout = os.Stdout
+	execCommand.Stderr = os.Stderr
+	return execCommand.Run()
+}
@@ -0,0 +1,180 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/spf13/cobra"
+)
+
+// exportCmd writes the pinned tools in formats other managers understand,
+// the inverse of mvx import, so mixed-tooling teams can keep asdf/mise in
+// sync with mvx as the source of truth.
+var exportCmd = &cobra.Command{
+	Use:   "export",
+	Short: "Export the pinned tools to asdf/mise formats",
+	Long: `Export the tools pinned in the mvx configuration to a format another
+version manager understands:
+
+  tool-versions    .tool-versions for asdf (also read by mise)
+  mise             mise.toml [tools] section
+
+Version specs are resolved to concrete versions first, so the exported file
+pins exactly what mvx installs. Custom URL-based tools have no equivalent
+elsewhere and are skipped.
+
+Examples:
+  mvx export                          # Write .tool-versions
+  mvx export --format mise            # Write mise.toml
+  mvx export --stdout                 # Print instead of writing`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runExport(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var (
+	exportFormat string
+	exportStdout bool
+)
+
+func init() {
+	exportCmd.Flags().StringVar(&exportFormat, "format", "tool-versions", "output format (tool-versions, mise)")
+	exportCmd.Flags().BoolVar(&exportStdout, "stdout", false, "print the exported file instead of writing it")
+	rootCmd.AddCommand(exportCmd)
+}
+
+// asdfToolNames maps mvx tool names onto their asdf plugin names
+var asdfToolNames = map[string]string{
+	"node": "nodejs",
+	"go":   "golang",
+}
+
+// exportJavaDistributions is the inverse of asdfJavaDistributions
+var exportJavaDistributions = map[string]string{
+	"temurin":    "temurin",
+	"zulu":       "zulu",
+	"corretto":   "corretto",
+	"liberica":   "liberica",
+	"microsoft":  "microsoft",
+	"semeru":     "semeru",
+	"oracle":     "oracle",
+	"graalvm_ce": "graalvm",
+}
+
+// runExport resolves the pinned tools and writes them in the chosen format
+func runExport() error {
+	if exportFormat != "tool-versions" && exportFormat != "mise" {
+		return fmt.Errorf("unsupported format %s (supported: tool-versions, mise)", exportFormat)
+	}
+
+	projectRoot, cfg, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+	if len(cfg.Tools) == 0 {
+		return fmt.Errorf("no tools configured in the mvx configuration")
+	}
+
+	exports := exportedVersions(cfg.Tools, func(tool string) string {
+		resolved, err := manager.ResolveVersion(tool, cfg.Tools[tool])
+		if err != nil {
+			// Without catalogs (e.g. offline) fall back to the raw spec
+			util.LogVerbose("Could not resolve %s version: %v", tool, err)
+			return cfg.Tools[tool].Version
+		}
+		return resolved
+	})
+	if len(exports) == 0 {
+		return fmt.Errorf("none of the configured tools can be exported")
+	}
+
+	var fileName, content string
+	switch exportFormat {
+	case "tool-versions":
+		fileName = ".tool-versions"
+		content = renderToolVersions(exports)
+	case "mise":
+		fileName = "mise.toml"
+		content = renderMiseToml(exports)
+	}
+
+	if exportStdout {
+		fmt.Print(content)
+		return nil
+	}
+
+	path := filepath.Join(projectRoot, fileName)
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	printSuccess("✅ Exported %d tool(s) to %s", len(exports), path)
+	return nil
+}
+
+// exportedVersion is one tool pin in a foreign manager's terms
+type exportedVersion struct {
+	Tool    string // foreign tool name (asdf plugin name)
+	Version string // version, with the java distribution prefix when pinned
+}
+
+// exportedVersions converts the configured tools to foreign pins, resolving
+// each version through resolve. Custom URL-based tools are skipped.
+func exportedVersions(tools map[string]config.ToolConfig, resolve func(tool string) string) []exportedVersion {
+	names := make([]string, 0, len(tools))
+	for name := range tools {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	var exports []exportedVersion
+	for _, name := range names {
+		toolConfig := tools[name]
+		if toolConfig.Type == "custom" || toolConfig.URL != "" || toolConfig.Repo != "" {
+			util.LogVerbose("Skipping custom tool %s: no asdf/mise equivalent", name)
+			continue
+		}
+
+		version := resolve(name)
+		if name == "java" && toolConfig.Distribution != "" {
+			if prefix, ok := exportJavaDistributions[toolConfig.Distribution]; ok {
+				version = prefix + "-" + version
+			}
+		}
+
+		exported := name
+		if foreign, ok := asdfToolNames[name]; ok {
+			exported = foreign
+		}
+		exports = append(exports, exportedVersion{Tool: exported, Version: version})
+	}
+	return exports
+}
+
+// renderToolVersions renders the asdf .tool-versions format
+func renderToolVersions(exports []exportedVersion) string {
+	var out strings.Builder
+	out.WriteString("# Generated by mvx export; mvx remains the source of truth\n")
+	for _, export := range exports {
+		fmt.Fprintf(&out, "%s %s\n", export.Tool, export.Version)
+	}
+	return out.String()
+}
+
+// renderMiseToml renders a mise.toml [tools] section
+func renderMiseToml(exports []exportedVersion) string {
+	var out strings.Builder
+	out.WriteString("# Generated by mvx export; mvx remains the source of truth\n")
+	out.WriteString("[tools]\n")
+	for _, export := range exports {
+		fmt.Fprintf(&out, "%s = %q\n", export.Tool, export.Version)
+	}
+	return out.String()
+}
@@ -0,0 +1,45 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestExportedVersions(t *testing.T) {
+	tools := map[string]config.ToolConfig{
+		"java":  {Version: "21", Distribution: "temurin"},
+		"node":  {Version: "20"},
+		"cloud": {Version: "1.0", Type: "custom", URL: "https://example.com/cloud-{version}.tar.gz"},
+	}
+	resolved := map[string]string{"java": "21.0.2", "node": "20.11.1"}
+
+	exports := exportedVersions(tools, func(tool string) string { return resolved[tool] })
+	if len(exports) != 2 {
+		t.Fatalf("expected 2 exports (custom tool skipped), got %d: %v", len(exports), exports)
+	}
+	if exports[0].Tool != "java" || exports[0].Version != "temurin-21.0.2" {
+		t.Errorf("unexpected java export: %+v", exports[0])
+	}
+	if exports[1].Tool != "nodejs" || exports[1].Version != "20.11.1" {
+		t.Errorf("unexpected node export: %+v", exports[1])
+	}
+}
+
+func TestRenderExportFormats(t *testing.T) {
+	exports := []exportedVersion{
+		{Tool: "java", Version: "temurin-21.0.2"},
+		{Tool: "nodejs", Version: "20.11.1"},
+	}
+
+	toolVersions := renderToolVersions(exports)
+	if !strings.Contains(toolVersions, "java temurin-21.0.2\n") || !strings.Contains(toolVersions, "nodejs 20.11.1\n") {
+		t.Errorf("unexpected .tool-versions output:\n%s", toolVersions)
+	}
+
+	mise := renderMiseToml(exports)
+	if !strings.Contains(mise, "[tools]\n") || !strings.Contains(mise, `java = "temurin-21.0.2"`) {
+		t.Errorf("unexpected mise.toml output:\n%s", mise)
+	}
+}
@@ -0,0 +1,116 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+
+	"github.com/spf13/cobra"
+)
+
+// foreachCmd runs the same mvx command across several project directories
+var foreachCmd = &cobra.Command{
+	Use:   "foreach <glob>... -- <command> [args...]",
+	Short: "Run an mvx command in several project directories",
+	Long: `Run the same mvx command in every directory matching the given glob(s),
+for maintaining many similar repositories or monorepo modules in one go.
+
+Only directories containing a .mvx directory are visited; other matches are
+skipped with a note. Each directory gets its own mvx process so tool versions
+and environments never leak between projects. Failures are collected and
+reported at the end, and the exit code is non-zero if any directory failed.
+
+Examples:
+  mvx foreach "repos/*" -- setup              # Setup every checked-out repo
+  mvx foreach "modules/*" -- build            # Build all monorepo modules
+  mvx foreach "services/*" "libs/*" -- test   # Multiple globs`,
+	Args: cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		dash := cmd.ArgsLenAtDash()
+		if dash < 1 || dash == len(args) {
+			printError("usage: mvx foreach <glob>... -- <command> [args...]")
+			os.Exit(1)
+		}
+		if err := runForeach(args[:dash], args[dash:]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(foreachCmd)
+}
+
+// runForeach expands the globs and runs the mvx command in each project dir
+func runForeach(globs, command []string) error {
+	dirs, err := foreachDirectories(globs)
+	if err != nil {
+		return err
+	}
+	if len(dirs) == 0 {
+		return fmt.Errorf("no mvx project directories matched %v", globs)
+	}
+
+	mvxBinary, err := os.Executable()
+	if err != nil {
+		return fmt.Errorf("failed to locate mvx binary: %w", err)
+	}
+
+	var failed []string
+	for _, dir := range dirs {
+		printInfo("")
+		printInfo("📁 %s", dir)
+
+		child := exec.Command(mvxBinary, command...)
+		child.Dir = dir
+		child.Stdout = os.Stdout
+		child.Stderr = os.Stderr
+		child.Stdin = os.Stdin
+
+		if err := child.Run(); err != nil {
+			printWarning("%s: %v", dir, err)
+			failed = append(failed, dir)
+		}
+	}
+
+	printInfo("")
+	if len(failed) > 0 {
+		return fmt.Errorf("command failed in %d of %d directories: %v", len(failed), len(dirs), failed)
+	}
+	printSuccess("✅ Command succeeded in all %d directories", len(dirs))
+	return nil
+}
+
+// foreachDirectories expands globs into a sorted, de-duplicated list of
+// directories containing a .mvx directory
+func foreachDirectories(globs []string) ([]string, error) {
+	seen := make(map[string]bool)
+	var dirs []string
+
+	for _, pattern := range globs {
+		matches, err := filepath.Glob(pattern)
+		if err != nil {
+			return nil, fmt.Errorf("invalid glob %q: %w", pattern, err)
+		}
+		for _, match := range matches {
+			info, err := os.Stat(match)
+			if err != nil || !info.IsDir() {
+				continue
+			}
+			if _, err := os.Stat(filepath.Join(match, ".mvx")); err != nil {
+				printVerbose("Skipping %s: no .mvx directory", match)
+				continue
+			}
+			if !seen[match] {
+				seen[match] = true
+				dirs = append(dirs, match)
+			}
+		}
+	}
+
+	sort.Strings(dirs)
+	return dirs, nil
+}
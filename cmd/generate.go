@@ -0,0 +1,167 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// generatePattern matches ${...} placeholders in template files
+var generatePattern = regexp.MustCompile(`\$\{([a-zA-Z][a-zA-Z0-9_.-]*)\}`)
+
+// generateCmd represents the generate command
+var generateCmd = &cobra.Command{
+	Use:   "generate",
+	Short: "Generate files from templates declared in the configuration",
+	Long: `Render the templates declared in the "generate" config section, which
+maps template files to output paths. Templates can interpolate:
+
+  ${project.name}          project name
+  ${project.dir}           absolute project root
+  ${tools.<name>.version}  resolved version of a configured tool
+  ${env.VARNAME}           environment variable
+  ${git.commit}            current commit hash (short)
+  ${git.branch}            current branch name
+
+Generation also runs automatically after 'mvx setup', keeping Dockerfiles,
+CI snippets and version banners in sync with the config.
+
+Examples:
+  mvx generate`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runGenerate(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(generateCmd)
+}
+
+// runGenerate loads the config and renders all declared templates
+func runGenerate() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	if len(cfg.Generate) == 0 {
+		printInfo("No templates declared. Add a \"generate\" section mapping templates to outputs.")
+		return nil
+	}
+
+	return generateFiles(projectRoot, cfg)
+}
+
+// generateFiles renders every declared template to its output path
+func generateFiles(projectRoot string, cfg *config.Config) error {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	values := templateValues(projectRoot, cfg, manager)
+
+	// Sort for deterministic output order
+	var templates []string
+	for template := range cfg.Generate {
+		templates = append(templates, template)
+	}
+	sort.Strings(templates)
+
+	for _, template := range templates {
+		output := cfg.Generate[template]
+
+		templatePath := template
+		if !filepath.IsAbs(templatePath) {
+			templatePath = filepath.Join(projectRoot, templatePath)
+		}
+		outputPath := output
+		if !filepath.IsAbs(outputPath) {
+			outputPath = filepath.Join(projectRoot, outputPath)
+		}
+
+		data, err := os.ReadFile(templatePath)
+		if err != nil {
+			return fmt.Errorf("failed to read template %s: %w", template, err)
+		}
+
+		rendered := generatePattern.ReplaceAllStringFunc(string(data), func(match string) string {
+			if value, exists := values[match[2:len(match)-1]]; exists {
+				return value
+			}
+			return match
+		})
+
+		if err := os.MkdirAll(filepath.Dir(outputPath), 0755); err != nil {
+			return err
+		}
+		if err := os.WriteFile(outputPath, []byte(rendered), 0644); err != nil {
+			return fmt.Errorf("failed to write %s: %w", output, err)
+		}
+		printInfo("  📝 %s -> %s", template, output)
+	}
+
+	printSuccess("✅ Generated %d file(s)", len(templates))
+	return nil
+}
+
+// templateValues builds the interpolation values available to templates
+func templateValues(projectRoot string, cfg *config.Config, manager *tools.Manager) map[string]string {
+	values := map[string]string{
+		"project.name": cfg.Project.Name,
+		"project.dir":  projectRoot,
+	}
+
+	// Resolved tool versions
+	for toolName, toolConfig := range cfg.Tools {
+		version := toolConfig.Version
+		if resolved, err := manager.ResolveVersion(toolName, toolConfig); err == nil {
+			version = resolved
+		}
+		values["tools."+toolName+".version"] = version
+	}
+
+	// Environment variables
+	for _, envVar := range os.Environ() {
+		if name, value, found := strings.Cut(envVar, "="); found {
+			values["env."+name] = value
+		}
+	}
+
+	// Git metadata (best effort — the project may not be a git checkout)
+	if commit := gitOutput(projectRoot, "rev-parse", "--short", "HEAD"); commit != "" {
+		values["git.commit"] = commit
+	}
+	if branch := gitOutput(projectRoot, "rev-parse", "--abbrev-ref", "HEAD"); branch != "" {
+		values["git.branch"] = branch
+	}
+
+	return values
+}
+
+// gitOutput runs a git command in the project root and returns trimmed output
+func gitOutput(projectRoot string, args ...string) string {
+	cmd := exec.Command("git", args...)
+	cmd.Dir = projectRoot
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
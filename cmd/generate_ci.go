@@ -0,0 +1,212 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// generateCICmd writes a CI pipeline that bootstraps mvx, restores the
+// toolchain cache and runs the project's mvx commands as jobs — the same
+// boilerplate every team otherwise hand-writes slightly differently
+var generateCICmd = &cobra.Command{
+	Use:   "ci",
+	Short: "Generate a CI pipeline that builds through mvx",
+	Long: `Generate a CI pipeline file for the chosen provider. The pipeline checks
+out the project, restores the tool cache keyed on the resolved toolchain,
+runs 'mvx setup', and wires each configured mvx command as a job.
+
+Providers:
+  github    .github/workflows/mvx.yml   (GitHub Actions)
+  gitlab    .gitlab-ci.yml              (GitLab CI)
+  jenkins   Jenkinsfile                 (Jenkins declarative pipeline)
+
+Examples:
+  mvx generate ci                        # GitHub Actions workflow
+  mvx generate ci --provider gitlab
+  mvx generate ci --provider jenkins --force`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runGenerateCI(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var generateCIProvider string
+
+func init() {
+	generateCICmd.Flags().StringVar(&generateCIProvider, "provider", "github", "CI provider: github, gitlab or jenkins")
+	generateCICmd.Flags().BoolVar(&generateContainerForce, "force", false, "overwrite the file even if it was edited by hand")
+	generateCmd.AddCommand(generateCICmd)
+}
+
+// runGenerateCI renders the pipeline file for the requested provider
+func runGenerateCI() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	// The GitLab cache key hashes the config file itself, so it needs the
+	// file's path relative to the project root
+	configRel := ".mvx/config.json5"
+	if configPath, err := findProjectConfigFile(projectRoot); err == nil {
+		if rel, err := filepath.Rel(projectRoot, configPath); err == nil {
+			configRel = filepath.ToSlash(rel)
+		}
+	}
+
+	var path, content string
+	switch generateCIProvider {
+	case "github":
+		path = filepath.Join(projectRoot, ".github", "workflows", "mvx.yml")
+		content = renderGitHubWorkflow(cfg)
+	case "gitlab":
+		path = filepath.Join(projectRoot, ".gitlab-ci.yml")
+		content = renderGitLabPipeline(cfg, configRel)
+	case "jenkins":
+		path = filepath.Join(projectRoot, "Jenkinsfile")
+		content = renderJenkinsfile(cfg)
+	default:
+		return fmt.Errorf("unknown provider %q (expected github, gitlab or jenkins)", generateCIProvider)
+	}
+
+	if err := writeGeneratedFile(path, content); err != nil {
+		return err
+	}
+	printSuccess("✅ Generated %s", path)
+	return nil
+}
+
+// ciCommandNames lists the project's configured commands in stable order,
+// falling back to "build" when none are configured
+func ciCommandNames(cfg *config.Config) []string {
+	if len(cfg.Commands) == 0 {
+		return []string{"build"}
+	}
+	names := make([]string, 0, len(cfg.Commands))
+	for name := range cfg.Commands {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// renderGitHubWorkflow builds a GitHub Actions workflow: one job per mvx
+// command, each restoring the toolchain cache keyed on 'mvx cache key'
+func renderGitHubWorkflow(cfg *config.Config) string {
+	var sb strings.Builder
+	fmt.Fprintf(&sb, `# %s — do not edit by hand
+# Pinned toolchain: %s
+name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+`, generatedFileMarker, pinnedToolsSummary(cfg))
+
+	for _, name := range ciCommandNames(cfg) {
+		fmt.Fprintf(&sb, `  %s:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - name: Compute toolchain cache key
+        id: mvx
+        run: |
+          echo "key=$(./mvx cache key)" >> "$GITHUB_OUTPUT"
+          echo "path=$(./mvx cache path)" >> "$GITHUB_OUTPUT"
+      - uses: actions/cache@v4
+        with:
+          path: ${{ steps.mvx.outputs.path }}
+          key: mvx-${{ runner.os }}-${{ steps.mvx.outputs.key }}
+      - run: ./mvx setup
+      - run: ./mvx %s
+`, name, name)
+	}
+	return sb.String()
+}
+
+// renderGitLabPipeline builds a .gitlab-ci.yml: a shared template installs
+// the toolchain into a project-local cache directory (GitLab only caches
+// paths inside the project), and each mvx command becomes a job
+func renderGitLabPipeline(cfg *config.Config, configRel string) string {
+	var sb strings.Builder
+	fmt.Fprintf(&sb, `# %s — do not edit by hand
+# Pinned toolchain: %s
+stages: [verify]
+
+.mvx:
+  image: ubuntu:24.04
+  stage: verify
+  variables:
+    # GitLab only caches paths inside the project directory
+    MVX_HOME: $CI_PROJECT_DIR/.mvx-cache
+  cache:
+    key:
+      files:
+        - %s
+    paths:
+      - .mvx-cache/
+  before_script:
+    - apt-get update && apt-get install -y --no-install-recommends ca-certificates curl git unzip
+    - ./mvx setup
+
+`, generatedFileMarker, pinnedToolsSummary(cfg), configRel)
+
+	for _, name := range ciCommandNames(cfg) {
+		fmt.Fprintf(&sb, `%s:
+  extends: .mvx
+  script:
+    - ./mvx %s
+
+`, name, name)
+	}
+	return strings.TrimRight(sb.String(), "\n") + "\n"
+}
+
+// renderJenkinsfile builds a declarative pipeline: the toolchain lands in a
+// workspace-local MVX_HOME so it survives between builds on the same agent
+func renderJenkinsfile(cfg *config.Config) string {
+	var sb strings.Builder
+	fmt.Fprintf(&sb, `// %s — do not edit by hand
+// Pinned toolchain: %s
+pipeline {
+    agent any
+    environment {
+        // Workspace-local so the toolchain survives between builds
+        MVX_HOME = "${WORKSPACE}/.mvx-cache"
+    }
+    stages {
+        stage('Setup') {
+            steps {
+                sh './mvx setup'
+            }
+        }
+`, generatedFileMarker, pinnedToolsSummary(cfg))
+
+	for _, name := range ciCommandNames(cfg) {
+		fmt.Fprintf(&sb, `        stage('%s') {
+            steps {
+                sh './mvx %s'
+            }
+        }
+`, name, name)
+	}
+	sb.WriteString(`    }
+}
+`)
+	return sb.String()
+}
@@ -0,0 +1,47 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestRenderCIPipelines(t *testing.T) {
+	cfg := &config.Config{
+		Tools: map[string]config.ToolConfig{
+			"java": {Version: "21"},
+		},
+		Commands: map[string]config.CommandConfig{
+			"build": {Script: "mvn install"},
+			"test":  {Script: "mvn test"},
+		},
+	}
+
+	github := renderGitHubWorkflow(cfg)
+	for _, want := range []string{generatedFileMarker, "mvx cache key", "./mvx setup", "./mvx build", "./mvx test", "actions/cache@v4"} {
+		if !strings.Contains(github, want) {
+			t.Errorf("GitHub workflow missing %q:\n%s", want, github)
+		}
+	}
+
+	gitlab := renderGitLabPipeline(cfg, ".mvx/config.json5")
+	for _, want := range []string{generatedFileMarker, ".mvx/config.json5", "MVX_HOME: $CI_PROJECT_DIR/.mvx-cache", "./mvx build", "extends: .mvx"} {
+		if !strings.Contains(gitlab, want) {
+			t.Errorf("GitLab pipeline missing %q:\n%s", want, gitlab)
+		}
+	}
+
+	jenkins := renderJenkinsfile(cfg)
+	for _, want := range []string{generatedFileMarker, "stage('Setup')", "stage('build')", "sh './mvx test'"} {
+		if !strings.Contains(jenkins, want) {
+			t.Errorf("Jenkinsfile missing %q:\n%s", want, jenkins)
+		}
+	}
+}
+
+func TestCICommandNames(t *testing.T) {
+	if got := ciCommandNames(&config.Config{}); len(got) != 1 || got[0] != "build" {
+		t.Errorf("expected fallback [build], got %v", got)
+	}
+}
@@ -0,0 +1,175 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// generatedFileMarker tags files mvx generated, so regeneration never
+// overwrites something hand-written
+const generatedFileMarker = "Generated by mvx generate"
+
+// generateDevcontainerCmd writes a devcontainer.json that bootstraps the
+// pinned toolchain, so Codespaces matches local builds exactly
+var generateDevcontainerCmd = &cobra.Command{
+	Use:   "devcontainer",
+	Short: "Generate .devcontainer/devcontainer.json bootstrapped through mvx",
+	Long: `Generate .devcontainer/devcontainer.json for Codespaces and other
+devcontainer-based environments. The container runs 'mvx setup' on create,
+so it gets exactly the pinned toolchain instead of whatever the base image
+ships.
+
+Examples:
+  mvx generate devcontainer
+  mvx generate devcontainer --force   # Overwrite a hand-edited file`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runGenerateContainer("devcontainer"); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// generateDockerfileCmd writes a Dockerfile that installs the pinned
+// toolchain via mvx in a cacheable layer
+var generateDockerfileCmd = &cobra.Command{
+	Use:   "dockerfile",
+	Short: "Generate a Dockerfile that installs the pinned toolchain via mvx",
+	Long: `Generate a Dockerfile that copies the mvx bootstrap and configuration
+first and runs 'mvx setup' in its own layer, so the (large) toolchain layer
+is cached until the pinned versions change.
+
+Examples:
+  mvx generate dockerfile
+  mvx generate dockerfile --force     # Overwrite a hand-edited file`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runGenerateContainer("dockerfile"); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var generateContainerForce bool
+
+func init() {
+	generateDevcontainerCmd.Flags().BoolVar(&generateContainerForce, "force", false, "overwrite the file even if it was edited by hand")
+	generateDockerfileCmd.Flags().BoolVar(&generateContainerForce, "force", false, "overwrite the file even if it was edited by hand")
+	generateCmd.AddCommand(generateDevcontainerCmd)
+	generateCmd.AddCommand(generateDockerfileCmd)
+}
+
+// runGenerateContainer renders the requested container file for the project
+func runGenerateContainer(kind string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	var path, content string
+	switch kind {
+	case "devcontainer":
+		path = filepath.Join(projectRoot, ".devcontainer", "devcontainer.json")
+		content = renderDevcontainer(cfg)
+	case "dockerfile":
+		path = filepath.Join(projectRoot, "Dockerfile")
+		content = renderDockerfile(cfg)
+	}
+
+	if err := writeGeneratedFile(path, content); err != nil {
+		return err
+	}
+	printSuccess("✅ Generated %s", path)
+	return nil
+}
+
+// renderDevcontainer builds devcontainer.json: a plain base image plus
+// 'mvx setup' on create, so the pinned toolchain is the only tool source
+func renderDevcontainer(cfg *config.Config) string {
+	name := cfg.Project.Name
+	if name == "" {
+		name = "mvx project"
+	}
+	return fmt.Sprintf(`{
+	// %s devcontainer — %s
+	// The toolchain comes from 'mvx setup', not the image: %s
+	"name": %q,
+	"image": "mcr.microsoft.com/devcontainers/base:ubuntu",
+	"postCreateCommand": "./mvx setup",
+	"remoteEnv": {
+		// Make the pinned tools visible in every shell and task
+		"PATH": "${containerWorkspaceFolder}/.mvx/shims:${containerEnv:PATH}"
+	}
+}
+`, generatedFileMarker, "do not edit by hand", pinnedToolsSummary(cfg), name)
+}
+
+// renderDockerfile builds a Dockerfile with the toolchain in its own layer:
+// bootstrap and .mvx config are copied before the sources, so the layer
+// caches until the pinned versions change
+func renderDockerfile(cfg *config.Config) string {
+	return fmt.Sprintf(`# %s dockerfile — do not edit by hand
+# Pinned toolchain: %s
+FROM ubuntu:24.04
+
+RUN apt-get update \
+ && apt-get install -y --no-install-recommends ca-certificates curl git unzip \
+ && rm -rf /var/lib/apt/lists/*
+
+WORKDIR /workspace
+
+# Bootstrap and pins first: this layer caches until the toolchain changes
+COPY mvx mvx.cmd ./
+COPY .mvx/ .mvx/
+RUN ./mvx setup
+
+COPY . .
+
+CMD ["./mvx", "run", "build"]
+`, generatedFileMarker, pinnedToolsSummary(cfg))
+}
+
+// pinnedToolsSummary renders the configured tools as "java 21, maven 3.9"
+func pinnedToolsSummary(cfg *config.Config) string {
+	if len(cfg.Tools) == 0 {
+		return "none"
+	}
+	names := make([]string, 0, len(cfg.Tools))
+	for name := range cfg.Tools {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	pins := make([]string, 0, len(names))
+	for _, name := range names {
+		pins = append(pins, name+" "+cfg.Tools[name].Version)
+	}
+	return strings.Join(pins, ", ")
+}
+
+// writeGeneratedFile writes content to path, refusing to overwrite a file
+// that does not carry the generated marker unless --force is given
+func writeGeneratedFile(path, content string) error {
+	if existing, err := os.ReadFile(path); err == nil && !generateContainerForce {
+		if !strings.Contains(string(existing), generatedFileMarker) {
+			return fmt.Errorf("%s exists and was not generated by mvx; use --force to overwrite", path)
+		}
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
@@ -0,0 +1,54 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestRenderContainerFiles(t *testing.T) {
+	cfg := &config.Config{
+		Project: config.ProjectConfig{Name: "demo"},
+		Tools: map[string]config.ToolConfig{
+			"java":  {Version: "21"},
+			"maven": {Version: "3.9"},
+		},
+	}
+
+	devcontainer := renderDevcontainer(cfg)
+	for _, want := range []string{generatedFileMarker, `"name": "demo"`, `"postCreateCommand": "./mvx setup"`} {
+		if !strings.Contains(devcontainer, want) {
+			t.Errorf("devcontainer.json misses %q:\n%s", want, devcontainer)
+		}
+	}
+
+	dockerfile := renderDockerfile(cfg)
+	for _, want := range []string{generatedFileMarker, "java 21, maven 3.9", "RUN ./mvx setup", "COPY .mvx/ .mvx/"} {
+		if !strings.Contains(dockerfile, want) {
+			t.Errorf("Dockerfile misses %q:\n%s", want, dockerfile)
+		}
+	}
+}
+
+func TestWriteGeneratedFileRefusesHandEdited(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "Dockerfile")
+
+	if err := writeGeneratedFile(path, "# "+generatedFileMarker+"\nFROM ubuntu\n"); err != nil {
+		t.Fatalf("initial write: %v", err)
+	}
+	// Regenerating a marker-carrying file is fine
+	if err := writeGeneratedFile(path, "# "+generatedFileMarker+"\nFROM debian\n"); err != nil {
+		t.Errorf("regeneration failed: %v", err)
+	}
+
+	// A hand-written file must not be overwritten
+	if err := os.WriteFile(path, []byte("FROM scratch\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := writeGeneratedFile(path, "# "+generatedFileMarker+"\nFROM ubuntu\n"); err == nil {
+		t.Error("expected an error for a hand-edited file")
+	}
+}
@@ -0,0 +1,109 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// gradleWrapperDistPattern extracts the Gradle version from the wrapper's
+// distributionUrl (.../gradle-8.5-bin.zip or -all.zip)
+var gradleWrapperDistPattern = regexp.MustCompile(`gradle-([0-9][0-9A-Za-z.\-]*?)-(?:bin|all)\.zip`)
+
+// gradleJavaPatterns find the Java version pinned in build.gradle(.kts):
+// a toolchain declaration or plain source compatibility
+var gradleJavaPatterns = []*regexp.Regexp{
+	regexp.MustCompile(`JavaLanguageVersion\.of\(\s*(\d+)\s*\)`),
+	regexp.MustCompile(`sourceCompatibility\s*=?\s*['"]?(?:1\.)?(\d+)['"]?`),
+}
+
+// detectGradleWrapper reports whether the project carries a Gradle Wrapper
+func detectGradleWrapper(projectRoot string) bool {
+	_, err := os.Stat(filepath.Join(projectRoot, "gradle", "wrapper", "gradle-wrapper.properties"))
+	return err == nil
+}
+
+// gradleWrapperConfig builds a config.json5 equivalent to an existing Gradle
+// Wrapper setup: the pinned Gradle version (installed as a custom tool from
+// services.gradle.org) and a suitable JDK detected from the build script
+func gradleWrapperConfig(projectRoot string) (string, error) {
+	gradleVersion, err := gradleWrapperVersion(projectRoot)
+	if err != nil {
+		return "", err
+	}
+
+	javaVersion := javaVersionFromGradle(projectRoot)
+	if javaVersion == "" {
+		javaVersion = "21"
+	}
+
+	return fmt.Sprintf(`{
+  // mvx configuration migrated from the Gradle Wrapper (gradle/wrapper)
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: %q,
+  },
+
+  tools: {
+    java: { version: %q, distribution: "temurin" },
+    gradle: {
+      type: "custom",
+      version: %q,
+      url: "https://services.gradle.org/distributions/gradle-{version}-bin.zip",
+    },
+  },
+
+  commands: {
+    build: {
+      description: "Build the project",
+      script: "gradle build",
+    },
+    test: {
+      description: "Run tests",
+      script: "gradle test",
+    },
+  },
+}
+`, filepath.Base(projectRoot), javaVersion, gradleVersion), nil
+}
+
+// gradleWrapperVersion reads the pinned Gradle version from
+// gradle/wrapper/gradle-wrapper.properties
+func gradleWrapperVersion(projectRoot string) (string, error) {
+	propertiesFile := filepath.Join(projectRoot, "gradle", "wrapper", "gradle-wrapper.properties")
+	content, err := os.ReadFile(propertiesFile)
+	if err != nil {
+		return "", fmt.Errorf("no Gradle Wrapper found (%s): %w", propertiesFile, err)
+	}
+
+	for _, line := range strings.Split(string(content), "\n") {
+		line = strings.TrimSpace(line)
+		if !strings.HasPrefix(line, "distributionUrl=") {
+			continue
+		}
+		if m := gradleWrapperDistPattern.FindStringSubmatch(line); m != nil {
+			return m[1], nil
+		}
+	}
+	return "", fmt.Errorf("could not determine the Gradle version from %s", propertiesFile)
+}
+
+// javaVersionFromGradle extracts the Java version pinned in the build
+// script (toolchain or source compatibility), if any
+func javaVersionFromGradle(projectRoot string) string {
+	for _, name := range []string{"build.gradle", "build.gradle.kts"} {
+		content, err := os.ReadFile(filepath.Join(projectRoot, name))
+		if err != nil {
+			continue
+		}
+		for _, pattern := range gradleJavaPatterns {
+			if m := pattern.FindSubmatch(content); m != nil {
+				return string(m[1])
+			}
+		}
+	}
+	return ""
+}
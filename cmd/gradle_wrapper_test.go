@@ -0,0 +1,51 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestGradleWrapperVersion(t *testing.T) {
+	root := t.TempDir()
+	writeWrapperFile(t, root, "gradle/wrapper/gradle-wrapper.properties",
+		"distributionBase=GRADLE_USER_HOME\ndistributionUrl=https\\://services.gradle.org/distributions/gradle-8.5-bin.zip\n")
+
+	version, err := gradleWrapperVersion(root)
+	if err != nil {
+		t.Fatalf("gradleWrapperVersion: %v", err)
+	}
+	if version != "8.5" {
+		t.Errorf("expected 8.5, got %s", version)
+	}
+
+	if !detectGradleWrapper(root) {
+		t.Error("expected detectGradleWrapper to report the wrapper")
+	}
+	if detectGradleWrapper(t.TempDir()) {
+		t.Error("expected detectGradleWrapper to be false without gradle/wrapper")
+	}
+}
+
+func TestGradleWrapperConfig(t *testing.T) {
+	root := t.TempDir()
+	writeWrapperFile(t, root, "gradle/wrapper/gradle-wrapper.properties",
+		"distributionUrl=https\\://services.gradle.org/distributions/gradle-8.10.2-all.zip\n")
+	writeWrapperFile(t, root, "build.gradle.kts",
+		"java {\n    toolchain {\n        languageVersion = JavaLanguageVersion.of(17)\n    }\n}\n")
+
+	content, err := gradleWrapperConfig(root)
+	if err != nil {
+		t.Fatalf("gradleWrapperConfig: %v", err)
+	}
+
+	for _, want := range []string{
+		`version: "8.10.2"`,
+		`java: { version: "17"`,
+		`url: "https://services.gradle.org/distributions/gradle-{version}-bin.zip"`,
+		`script: "gradle build"`,
+	} {
+		if !strings.Contains(content, want) {
+			t.Errorf("generated config misses %q:\n%s", want, content)
+		}
+	}
+}
@@ -0,0 +1,149 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+var graphFormat string
+
+// graphCmd represents the graph command
+var graphCmd = &cobra.Command{
+	Use:   "graph",
+	Short: "Render the command dependency graph",
+	Long: `Render the command dependency graph encoded in the configuration.
+
+Edges are derived from:
+  - command artifact contracts (a command consuming another's outputs)
+  - tool requirements (command -> tool)
+
+Examples:
+  mvx graph                      # DOT output (pipe to graphviz)
+  mvx graph --format mermaid     # Mermaid flowchart (paste into docs)
+  mvx graph | dot -Tsvg > build-graph.svg`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := showGraph(graphFormat); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	graphCmd.Flags().StringVar(&graphFormat, "format", "dot", "output format: dot or mermaid")
+	rootCmd.AddCommand(graphCmd)
+}
+
+// graphEdge is a directed edge in the command graph
+type graphEdge struct {
+	from, to string
+}
+
+// buildCommandGraph derives edges between commands (producer -> consumer via
+// artifact contracts) and from commands to the tools they require.
+func buildCommandGraph(cfg *config.Config) (commands []string, toolNodes []string, edges []graphEdge) {
+	for name := range cfg.Commands {
+		commands = append(commands, name)
+	}
+	sort.Strings(commands)
+
+	// Map output patterns to their producing command
+	producers := make(map[string]string)
+	for _, name := range commands {
+		for _, output := range cfg.Commands[name].Outputs {
+			producers[output] = name
+		}
+	}
+
+	toolSeen := make(map[string]bool)
+	for _, name := range commands {
+		cmdConfig := cfg.Commands[name]
+
+		for _, input := range cmdConfig.Inputs {
+			if producer, exists := producers[input]; exists && producer != name {
+				edges = append(edges, graphEdge{from: producer, to: name})
+			}
+		}
+
+		for _, toolName := range cmdConfig.Requires {
+			if !toolSeen[toolName] {
+				toolSeen[toolName] = true
+				toolNodes = append(toolNodes, toolName)
+			}
+			edges = append(edges, graphEdge{from: name, to: "tool:" + toolName})
+		}
+	}
+	sort.Strings(toolNodes)
+
+	return commands, toolNodes, edges
+}
+
+// showGraph renders the command graph in the requested format
+func showGraph(format string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	commands, toolNodes, edges := buildCommandGraph(cfg)
+	if len(commands) == 0 {
+		return fmt.Errorf("no commands defined in configuration")
+	}
+
+	switch format {
+	case "dot":
+		renderDot(cfg.Project.Name, commands, toolNodes, edges)
+	case "mermaid":
+		renderMermaid(commands, toolNodes, edges)
+	default:
+		return fmt.Errorf("unknown format %s (supported: dot, mermaid)", format)
+	}
+	return nil
+}
+
+// renderDot prints the graph in Graphviz DOT format
+func renderDot(projectName string, commands, toolNodes []string, edges []graphEdge) {
+	fmt.Printf("digraph %q {\n", projectName)
+	fmt.Println("  rankdir=LR;")
+	for _, name := range commands {
+		fmt.Printf("  %q [shape=box];\n", name)
+	}
+	for _, toolName := range toolNodes {
+		fmt.Printf("  %q [shape=ellipse, style=dashed];\n", "tool:"+toolName)
+	}
+	for _, edge := range edges {
+		fmt.Printf("  %q -> %q;\n", edge.from, edge.to)
+	}
+	fmt.Println("}")
+}
+
+// renderMermaid prints the graph as a Mermaid flowchart
+func renderMermaid(commands, toolNodes []string, edges []graphEdge) {
+	fmt.Println("flowchart LR")
+	for _, name := range commands {
+		fmt.Printf("  %s[%s]\n", mermaidID(name), name)
+	}
+	for _, toolName := range toolNodes {
+		fmt.Printf("  %s(%s)\n", mermaidID("tool:"+toolName), toolName)
+	}
+	for _, edge := range edges {
+		fmt.Printf("  %s --> %s\n", mermaidID(edge.from), mermaidID(edge.to))
+	}
+}
+
+// mermaidID sanitizes a node name into a Mermaid-safe identifier
+func mermaidID(name string) string {
+	replacer := strings.NewReplacer(":", "_", "-", "_", ".", "_", "/", "_", " ", "_")
+	return replacer.Replace(name)
+}
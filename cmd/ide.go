@@ -0,0 +1,189 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"runtime"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// ideCmd writes IDE configuration pointing at the mvx-managed tool paths,
+// so the IDE and the CLI stop building with different JDKs.
+var ideCmd = &cobra.Command{
+	Use:   "ide vscode|idea",
+	Short: "Generate IDE configuration for the mvx-managed toolchain",
+	Long: `Generate or update IDE configuration so the IDE uses the same toolchain
+as the command line.
+
+  mvx ide vscode    Update .vscode/settings.json: java.jdt.ls.java.home,
+                    the Maven executable and the integrated terminal
+                    environment point at the mvx-managed installations.
+  mvx ide idea      Update .idea/misc.xml with an mvx-named project JDK and
+                    print the path to register it under once in
+                    File > Project Structure.
+
+Re-run the command after changing tool versions to keep the IDE in sync.`,
+
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runIDE(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(ideCmd)
+}
+
+// runIDE resolves the managed tool paths and writes IDE configuration
+func runIDE(ide string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("no mvx project found in the current directory")
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.LoadProjectLock(projectRoot)
+
+	env, err := manager.SetupEnvironment(cfg)
+	if err != nil {
+		return fmt.Errorf("failed to setup environment: %w", err)
+	}
+
+	switch ide {
+	case "vscode":
+		return writeVSCodeSettings(projectRoot, cfg, env)
+	case "idea":
+		return writeIdeaSettings(projectRoot, cfg, manager, env)
+	default:
+		return fmt.Errorf("unsupported IDE: %s (supported: vscode, idea)", ide)
+	}
+}
+
+// writeVSCodeSettings merges the toolchain paths into .vscode/settings.json.
+// Existing settings (and JSONC comments' semantics, though not the comments
+// themselves) are preserved; only mvx-managed keys are overwritten.
+func writeVSCodeSettings(projectRoot string, cfg *config.Config, env map[string]string) error {
+	settingsPath := filepath.Join(projectRoot, ".vscode", "settings.json")
+
+	settings := make(map[string]interface{})
+	if data, err := os.ReadFile(settingsPath); err == nil {
+		if err := config.ParseJSON5(data, &settings); err != nil {
+			return fmt.Errorf("failed to parse %s: %w", settingsPath, err)
+		}
+	}
+
+	terminalEnv := make(map[string]interface{})
+	if javaHome := env["JAVA_HOME"]; javaHome != "" {
+		settings["java.jdt.ls.java.home"] = javaHome
+		settings["java.import.gradle.java.home"] = javaHome
+		terminalEnv["JAVA_HOME"] = javaHome
+	}
+	if mavenHome := env["MAVEN_HOME"]; mavenHome != "" {
+		mvn := filepath.Join(mavenHome, "bin", "mvn")
+		if runtime.GOOS == "windows" {
+			mvn += ".cmd"
+		}
+		settings["maven.executable.path"] = mvn
+	}
+	if pathValue := env["PATH"]; pathValue != "" {
+		terminalEnv["PATH"] = pathValue
+	}
+	if len(terminalEnv) > 0 {
+		platformKey := "linux"
+		switch runtime.GOOS {
+		case "darwin":
+			platformKey = "osx"
+		case "windows":
+			platformKey = "windows"
+		}
+		settings["terminal.integrated.env."+platformKey] = terminalEnv
+	}
+
+	data, err := json.MarshalIndent(settings, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(settingsPath), 0755); err != nil {
+		return err
+	}
+	if err := os.WriteFile(settingsPath, append(data, '\n'), 0644); err != nil {
+		return err
+	}
+	printSuccess("Updated %s with the mvx-managed toolchain", settingsPath)
+	return nil
+}
+
+// ideaJdkPattern matches the ProjectRootManager component in .idea/misc.xml
+var ideaJdkPattern = regexp.MustCompile(`<component name="ProjectRootManager"[^>]*>`)
+
+// writeIdeaSettings points the IntelliJ project JDK at an mvx-named SDK
+func writeIdeaSettings(projectRoot string, cfg *config.Config, manager *tools.Manager, env map[string]string) error {
+	javaHome := env["JAVA_HOME"]
+	if javaHome == "" {
+		return fmt.Errorf("no java tool configured (IntelliJ integration needs one)")
+	}
+
+	javaVersion := cfg.Tools["java"].Version
+	if resolved, err := manager.ResolveVersion("java", cfg.Tools["java"]); err == nil {
+		javaVersion = resolved
+	}
+	major := javaVersion
+	if idx := strings.IndexAny(major, ".+-"); idx > 0 {
+		major = major[:idx]
+	}
+	jdkName := fmt.Sprintf("mvx-java-%s", javaVersion)
+
+	component := fmt.Sprintf(`<component name="ProjectRootManager" version="2" languageLevel="JDK_%s" default="true" project-jdk-name="%s" project-jdk-type="JavaSDK">`, major, jdkName)
+
+	miscPath := filepath.Join(projectRoot, ".idea", "misc.xml")
+	if data, err := os.ReadFile(miscPath); err == nil {
+		if !ideaJdkPattern.Match(data) {
+			return fmt.Errorf("%s carries no ProjectRootManager component; update the project JDK manually", miscPath)
+		}
+		updated := ideaJdkPattern.ReplaceAll(data, []byte(component))
+		if err := os.WriteFile(miscPath, updated, 0644); err != nil {
+			return err
+		}
+	} else {
+		content := fmt.Sprintf(`<?xml version="1.0" encoding="UTF-8"?>
+<project version="4">
+  %s
+    <output url="file://$PROJECT_DIR$/out" />
+  </component>
+</project>
+`, component)
+		if err := os.MkdirAll(filepath.Dir(miscPath), 0755); err != nil {
+			return err
+		}
+		if err := os.WriteFile(miscPath, []byte(content), 0644); err != nil {
+			return err
+		}
+	}
+
+	printSuccess("Updated %s (project JDK: %s)", miscPath, jdkName)
+	printInfo("Register the SDK once in File > Project Structure > SDKs:")
+	printInfo("  Name: %s", jdkName)
+	printInfo("  Home: %s", javaHome)
+	if mavenHome := env["MAVEN_HOME"]; mavenHome != "" {
+		printInfo("Set Maven home (Settings > Build Tools > Maven) to:")
+		printInfo("  %s", mavenHome)
+	}
+	return nil
+}
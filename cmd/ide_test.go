@@ -0,0 +1,64 @@
+package cmd
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestWriteVSCodeSettingsMergesExisting(t *testing.T) {
+	tempDir := t.TempDir()
+	vscodeDir := filepath.Join(tempDir, ".vscode")
+	if err := os.MkdirAll(vscodeDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+	existing := `{
+  // user setting that must survive
+  "editor.formatOnSave": true,
+}`
+	if err := os.WriteFile(filepath.Join(vscodeDir, "settings.json"), []byte(existing), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	env := map[string]string{
+		"JAVA_HOME":  "/opt/mvx/java/21",
+		"MAVEN_HOME": "/opt/mvx/maven/3.9.9",
+		"PATH":       "/opt/mvx/java/21/bin:/usr/bin",
+	}
+	if err := writeVSCodeSettings(tempDir, &config.Config{}, env); err != nil {
+		t.Fatalf("writeVSCodeSettings() error = %v", err)
+	}
+
+	data, err := os.ReadFile(filepath.Join(vscodeDir, "settings.json"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	var settings map[string]interface{}
+	if err := json.Unmarshal(data, &settings); err != nil {
+		t.Fatalf("settings.json is not valid JSON: %v", err)
+	}
+	if settings["editor.formatOnSave"] != true {
+		t.Error("existing settings should be preserved")
+	}
+	if settings["java.jdt.ls.java.home"] != "/opt/mvx/java/21" {
+		t.Errorf("java.jdt.ls.java.home = %v", settings["java.jdt.ls.java.home"])
+	}
+	if mvn, _ := settings["maven.executable.path"].(string); !strings.Contains(mvn, "mvn") {
+		t.Errorf("maven.executable.path = %v", settings["maven.executable.path"])
+	}
+}
+
+func TestIdeaJdkPattern(t *testing.T) {
+	misc := `<?xml version="1.0" encoding="UTF-8"?>
+<project version="4">
+  <component name="ProjectRootManager" version="2" languageLevel="JDK_17" project-jdk-name="17" project-jdk-type="JavaSDK">
+  </component>
+</project>`
+	if !ideaJdkPattern.MatchString(misc) {
+		t.Error("pattern should match an existing ProjectRootManager component")
+	}
+}
@@ -0,0 +1,416 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/spf13/cobra"
+)
+
+// importCmd populates the mvx configuration from the version files other
+// managers leave behind, so migrating a project does not mean retyping
+// versions.
+var importCmd = &cobra.Command{
+	Use:   "import [file...]",
+	Short: "Import tool versions from .sdkmanrc, .tool-versions, .nvmrc or .java-version",
+	Long: `Import pinned tool versions from version files used by other managers
+and apply them to the mvx configuration:
+
+  .sdkmanrc        SDKMAN (java=21.0.2-tem, maven=3.9.9, ...)
+  .tool-versions   asdf / mise (java temurin-21.0.2, nodejs 20.11.1, ...)
+  .nvmrc           nvm (a single Node.js version)
+  .java-version    jenv and friends (a single Java version)
+
+Without arguments all of these files are auto-detected in the project root.
+An existing configuration is updated in place (comments survive); without
+one a fresh .mvx/config.json5 is created.
+
+Examples:
+  mvx import                # Auto-detect version files
+  mvx import .sdkmanrc      # Import a specific file
+  mvx import --dry-run      # Show what would change without writing`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runImport(args); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var importDryRun bool
+
+func init() {
+	importCmd.Flags().BoolVar(&importDryRun, "dry-run", false, "show imported versions without writing the config")
+	rootCmd.AddCommand(importCmd)
+}
+
+// toolImport is one tool version read from a foreign version file
+type toolImport struct {
+	Tool         string
+	Version      string
+	Distribution string // only set for java, when the file pins one
+	Source       string // file the version came from
+}
+
+// importToolNames maps foreign tool names onto mvx tool names
+var importToolNames = map[string]string{
+	"java":   "java",
+	"maven":  "maven",
+	"mvnd":   "mvnd",
+	"node":   "node",
+	"nodejs": "node",
+	"go":     "go",
+	"golang": "go",
+	"dotnet": "dotnet",
+}
+
+// sdkmanJavaDistributions maps SDKMAN java suffixes to mvx distributions
+var sdkmanJavaDistributions = map[string]string{
+	"tem":     "temurin",
+	"zulu":    "zulu",
+	"amzn":    "corretto",
+	"librca":  "liberica",
+	"ms":      "microsoft",
+	"sem":     "semeru",
+	"oracle":  "oracle",
+	"graal":   "graalvm_ce",
+	"graalce": "graalvm_ce",
+}
+
+// asdfJavaDistributions are the distribution prefixes asdf/mise use for java
+var asdfJavaDistributions = map[string]string{
+	"temurin":   "temurin",
+	"zulu":      "zulu",
+	"corretto":  "corretto",
+	"liberica":  "liberica",
+	"microsoft": "microsoft",
+	"semeru":    "semeru",
+	"oracle":    "oracle",
+	"graalvm":   "graalvm_ce",
+}
+
+// runImport reads the requested (or auto-detected) version files and applies
+// the versions to the project configuration
+func runImport(files []string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	autoDetected := len(files) == 0
+	if autoDetected {
+		for _, name := range []string{".sdkmanrc", ".tool-versions", ".java-version", ".nvmrc"} {
+			if _, err := os.Stat(filepath.Join(projectRoot, name)); err == nil {
+				files = append(files, name)
+			}
+		}
+		if len(files) == 0 {
+			return fmt.Errorf("no version files found in %s (looked for .sdkmanrc, .tool-versions, .java-version, .nvmrc)", projectRoot)
+		}
+	}
+
+	var imports []toolImport
+	for _, file := range files {
+		path := file
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(projectRoot, path)
+		}
+		fileImports, err := parseVersionFile(path)
+		if err != nil {
+			return err
+		}
+		imports = mergeImports(imports, fileImports)
+	}
+	if len(imports) == 0 {
+		return fmt.Errorf("no tool versions found in %s", strings.Join(files, ", "))
+	}
+
+	for _, imported := range imports {
+		if imported.Distribution != "" {
+			printInfo("  %s %s (%s) from %s", imported.Tool, imported.Version, imported.Distribution, imported.Source)
+		} else {
+			printInfo("  %s %s from %s", imported.Tool, imported.Version, imported.Source)
+		}
+	}
+
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		// No configuration yet: create a fresh one from the imports
+		if importDryRun {
+			printInfo("")
+			printInfo("Dry run: would create .mvx/config.json5 with %d tool(s)", len(imports))
+			return nil
+		}
+		return writeImportedConfig(projectRoot, imports)
+	}
+
+	return updateConfigWithImports(configPath, imports)
+}
+
+// parseVersionFile dispatches on the file name to the matching parser
+func parseVersionFile(path string) ([]toolImport, error) {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	name := filepath.Base(path)
+	switch name {
+	case ".sdkmanrc":
+		return parseSdkmanrc(string(content)), nil
+	case ".tool-versions":
+		return parseToolVersions(string(content)), nil
+	case ".nvmrc":
+		return parseNvmrc(string(content)), nil
+	case ".java-version":
+		return parseJavaVersionFile(string(content)), nil
+	default:
+		return nil, fmt.Errorf("unsupported version file %s (supported: .sdkmanrc, .tool-versions, .nvmrc, .java-version)", name)
+	}
+}
+
+// parseSdkmanrc parses SDKMAN's key=value format. Java versions carry the
+// distribution as a suffix (21.0.2-tem); known suffixes map to mvx
+// distributions, unknown ones stay part of the version.
+func parseSdkmanrc(content string) []toolImport {
+	var imports []toolImport
+	for _, line := range strings.Split(content, "\n") {
+		line = strings.TrimSpace(line)
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		key, value, found := strings.Cut(line, "=")
+		if !found {
+			continue
+		}
+		tool, known := importToolNames[strings.TrimSpace(key)]
+		if !known {
+			continue
+		}
+
+		imported := toolImport{Tool: tool, Version: strings.TrimSpace(value), Source: ".sdkmanrc"}
+		if tool == "java" {
+			if idx := strings.LastIndex(imported.Version, "-"); idx != -1 {
+				if distribution, ok := sdkmanJavaDistributions[imported.Version[idx+1:]]; ok {
+					imported.Distribution = distribution
+					imported.Version = imported.Version[:idx]
+				}
+			}
+		}
+		imports = append(imports, imported)
+	}
+	return imports
+}
+
+// parseToolVersions parses the asdf/mise .tool-versions format ("tool
+// version" per line). Java versions may carry a distribution prefix
+// (temurin-21.0.2).
+func parseToolVersions(content string) []toolImport {
+	var imports []toolImport
+	for _, line := range strings.Split(content, "\n") {
+		line = strings.TrimSpace(line)
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		fields := strings.Fields(line)
+		if len(fields) < 2 {
+			continue
+		}
+		tool, known := importToolNames[fields[0]]
+		if !known {
+			continue
+		}
+
+		imported := toolImport{Tool: tool, Version: fields[1], Source: ".tool-versions"}
+		if tool == "java" {
+			if prefix, rest, found := strings.Cut(imported.Version, "-"); found {
+				if distribution, ok := asdfJavaDistributions[prefix]; ok {
+					imported.Distribution = distribution
+					imported.Version = rest
+				}
+			}
+		}
+		imports = append(imports, imported)
+	}
+	return imports
+}
+
+// parseNvmrc parses nvm's single-version file. "lts/*" and named LTS lines
+// map to the "lts" alias mvx resolves against the Node.js index.
+func parseNvmrc(content string) []toolImport {
+	version := strings.TrimSpace(content)
+	if version == "" {
+		return nil
+	}
+	version = strings.TrimPrefix(version, "v")
+	if strings.HasPrefix(version, "lts") {
+		version = "lts"
+	}
+	return []toolImport{{Tool: "node", Version: version, Source: ".nvmrc"}}
+}
+
+// parseJavaVersionFile parses a single-version .java-version file, dropping
+// the legacy "1." prefix so "1.8" imports as Java 8
+func parseJavaVersionFile(content string) []toolImport {
+	version := strings.TrimSpace(content)
+	if version == "" {
+		return nil
+	}
+	if strings.HasPrefix(version, "1.") {
+		version = javaMajorVersion(version)
+	}
+	return []toolImport{{Tool: "java", Version: version, Source: ".java-version"}}
+}
+
+// mergeImports appends new imports, keeping the first version seen for each
+// tool and warning when a later file disagrees
+func mergeImports(existing, additions []toolImport) []toolImport {
+	for _, addition := range additions {
+		conflict := false
+		for _, imported := range existing {
+			if imported.Tool != addition.Tool {
+				continue
+			}
+			conflict = true
+			if imported.Version != addition.Version {
+				printWarning("%s pins %s %s but %s already pinned %s (keeping the latter)",
+					addition.Source, addition.Tool, addition.Version, imported.Source, imported.Version)
+			}
+			break
+		}
+		if !conflict {
+			existing = append(existing, addition)
+		}
+	}
+	return existing
+}
+
+// writeImportedConfig creates a fresh .mvx/config.json5 from the imports
+func writeImportedConfig(projectRoot string, imports []toolImport) error {
+	var tools strings.Builder
+	for _, imported := range imports {
+		if imported.Distribution != "" {
+			fmt.Fprintf(&tools, "    %s: { version: %q, distribution: %q }, // from %s\n",
+				imported.Tool, imported.Version, imported.Distribution, imported.Source)
+		} else {
+			fmt.Fprintf(&tools, "    %s: { version: %q }, // from %s\n",
+				imported.Tool, imported.Version, imported.Source)
+		}
+	}
+
+	content := fmt.Sprintf(`{
+  // mvx configuration imported from existing version files
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: %q,
+  },
+
+  tools: {
+%s  },
+}
+`, filepath.Base(projectRoot), tools.String())
+
+	mvxDir := filepath.Join(projectRoot, ".mvx")
+	if err := os.MkdirAll(mvxDir, 0755); err != nil {
+		return fmt.Errorf("failed to create .mvx directory: %w", err)
+	}
+	configPath := filepath.Join(mvxDir, "config.json5")
+	if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", configPath, err)
+	}
+	printSuccess("✅ Created %s with %d imported tool(s)", configPath, len(imports))
+	return nil
+}
+
+// updateConfigWithImports applies the imports to an existing config file
+// textually, so comments and formatting survive (like mvx config migrate)
+func updateConfigWithImports(configPath string, imports []toolImport) error {
+	ext := strings.ToLower(filepath.Ext(configPath))
+	if ext == ".toml" {
+		return fmt.Errorf("mvx import does not support TOML configs yet; edit %s by hand", configPath)
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", configPath, err)
+	}
+
+	content := string(data)
+	changed := 0
+	for _, imported := range imports {
+		updated, ok := setConfigToolVersion(content, ext, imported)
+		if !ok {
+			printWarning("Could not update %s in %s; add it by hand", imported.Tool, configPath)
+			continue
+		}
+		if updated != content {
+			content = updated
+			changed++
+		}
+	}
+
+	if changed == 0 {
+		printSuccess("✅ %s already matches the imported versions", configPath)
+		return nil
+	}
+
+	// Make sure the edited text still parses before touching the file
+	if _, err := config.ParseRawConfig([]byte(content), ext); err != nil {
+		return fmt.Errorf("import produced an unparseable config (this is a bug): %w", err)
+	}
+
+	if importDryRun {
+		printInfo("")
+		printInfo("Dry run: %d tool(s) would change in %s (no changes written)", changed, configPath)
+		return nil
+	}
+
+	if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", configPath, err)
+	}
+	printSuccess("✅ Updated %d tool(s) in %s", changed, configPath)
+	return nil
+}
+
+// setConfigToolVersion rewrites (or inserts) one tool's version in the raw
+// config text, returning ok=false when the text could not be edited safely
+func setConfigToolVersion(content, ext string, imported toolImport) (string, bool) {
+	switch ext {
+	case ".json5", ".json":
+		versionPattern := regexp.MustCompile(`(?s)("?` + imported.Tool + `"?\s*:\s*\{[^{}]*?version\s*:\s*")([^"]*)(")`)
+		if versionPattern.MatchString(content) {
+			return versionPattern.ReplaceAllString(content, "${1}"+imported.Version+"${3}"), true
+		}
+		toolsPattern := regexp.MustCompile(`(?m)^(\s*)tools\s*:\s*\{`)
+		if m := toolsPattern.FindStringSubmatchIndex(content); m != nil {
+			entry := fmt.Sprintf("\n%s  %s: { version: %q },", content[m[2]:m[3]], imported.Tool, imported.Version)
+			if imported.Distribution != "" {
+				entry = fmt.Sprintf("\n%s  %s: { version: %q, distribution: %q },",
+					content[m[2]:m[3]], imported.Tool, imported.Version, imported.Distribution)
+			}
+			return content[:m[1]] + entry + content[m[1]:], true
+		}
+		return content, false
+	case ".yml", ".yaml":
+		versionPattern := regexp.MustCompile(`(?m)^(\s+` + imported.Tool + `:\n(?:\s+\S.*\n)*?\s+version:\s*["']?)([^"'\n]*)(["']?\s*)$`)
+		if versionPattern.MatchString(content) {
+			return versionPattern.ReplaceAllString(content, "${1}"+imported.Version+"${3}"), true
+		}
+		toolsPattern := regexp.MustCompile(`(?m)^tools:\s*$`)
+		if m := toolsPattern.FindStringIndex(content); m != nil {
+			entry := fmt.Sprintf("\n  %s:\n    version: %q", imported.Tool, imported.Version)
+			if imported.Distribution != "" {
+				entry += fmt.Sprintf("\n    distribution: %q", imported.Distribution)
+			}
+			return content[:m[1]] + entry + content[m[1]:], true
+		}
+		return content, false
+	default:
+		return content, false
+	}
+}
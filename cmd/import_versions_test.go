@@ -0,0 +1,76 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestParseSdkmanrc(t *testing.T) {
+	imports := parseSdkmanrc("# Enable auto-env\njava=21.0.2-tem\nmaven=3.9.9\nscala=3.4.0\n")
+	if len(imports) != 2 {
+		t.Fatalf("expected 2 imports, got %d: %v", len(imports), imports)
+	}
+	if imports[0].Tool != "java" || imports[0].Version != "21.0.2" || imports[0].Distribution != "temurin" {
+		t.Errorf("unexpected java import: %+v", imports[0])
+	}
+	if imports[1].Tool != "maven" || imports[1].Version != "3.9.9" {
+		t.Errorf("unexpected maven import: %+v", imports[1])
+	}
+}
+
+func TestParseToolVersions(t *testing.T) {
+	imports := parseToolVersions("java temurin-21.0.2\nnodejs 20.11.1\nruby 3.3.0\n")
+	if len(imports) != 2 {
+		t.Fatalf("expected 2 imports, got %d: %v", len(imports), imports)
+	}
+	if imports[0].Tool != "java" || imports[0].Version != "21.0.2" || imports[0].Distribution != "temurin" {
+		t.Errorf("unexpected java import: %+v", imports[0])
+	}
+	if imports[1].Tool != "node" || imports[1].Version != "20.11.1" {
+		t.Errorf("unexpected node import: %+v", imports[1])
+	}
+}
+
+func TestParseNvmrc(t *testing.T) {
+	if imports := parseNvmrc("v20.11.1\n"); len(imports) != 1 || imports[0].Version != "20.11.1" {
+		t.Errorf("unexpected imports for v20.11.1: %v", imports)
+	}
+	if imports := parseNvmrc("lts/iron\n"); len(imports) != 1 || imports[0].Version != "lts" {
+		t.Errorf("unexpected imports for lts/iron: %v", imports)
+	}
+}
+
+func TestSetConfigToolVersionJSON5(t *testing.T) {
+	content := `{
+  tools: {
+    // the JDK
+    java: { version: "17", distribution: "temurin" },
+  },
+}
+`
+	updated, ok := setConfigToolVersion(content, ".json5", toolImport{Tool: "java", Version: "21.0.2"})
+	if !ok || !strings.Contains(updated, `java: { version: "21.0.2", distribution: "temurin" }`) {
+		t.Errorf("version not replaced (ok=%v):\n%s", ok, updated)
+	}
+	if !strings.Contains(updated, "// the JDK") {
+		t.Error("comment did not survive the edit")
+	}
+
+	updated, ok = setConfigToolVersion(updated, ".json5", toolImport{Tool: "node", Version: "20.11.1"})
+	if !ok || !strings.Contains(updated, `node: { version: "20.11.1" }`) {
+		t.Errorf("missing tool not inserted (ok=%v):\n%s", ok, updated)
+	}
+}
+
+func TestSetConfigToolVersionYAML(t *testing.T) {
+	content := "tools:\n  java:\n    version: \"17\"\n    distribution: temurin\n"
+	updated, ok := setConfigToolVersion(content, ".yml", toolImport{Tool: "java", Version: "21"})
+	if !ok || !strings.Contains(updated, `version: "21"`) {
+		t.Errorf("version not replaced (ok=%v):\n%s", ok, updated)
+	}
+
+	updated, ok = setConfigToolVersion(updated, ".yml", toolImport{Tool: "maven", Version: "3.9.9"})
+	if !ok || !strings.Contains(updated, "maven:\n    version: \"3.9.9\"") {
+		t.Errorf("missing tool not inserted (ok=%v):\n%s", ok, updated)
+	}
+}
@@ -3,11 +3,13 @@ package cmd
 import (
 	"fmt"
 	"os"
+	"sort"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
 	"github.com/gnodet/mvx/pkg/executor"
 	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/spf13/cobra"
 )
 
@@ -45,7 +47,34 @@ func init() {
 	rootCmd.AddCommand(infoCmd)
 }
 
-// showProjectInfo displays general project information
+// projectToolState is one tool's requested-versus-resolved state
+type projectToolState struct {
+	Name         string `json:"name"`
+	Requested    string `json:"requested"`
+	Resolved     string `json:"resolved,omitempty"`
+	Distribution string `json:"distribution,omitempty"`
+	Installed    bool   `json:"installed"`
+	InstallPath  string `json:"installPath,omitempty"`
+	SizeBytes    int64  `json:"sizeBytes,omitempty"`
+	Checksum     string `json:"checksum,omitempty"`   // from the lockfile, when written
+	Provenance   string `json:"provenance,omitempty"` // attestation verdict from the lockfile
+}
+
+// projectState is the fully resolved project state shown by 'mvx info'
+type projectState struct {
+	Name        string             `json:"name"`
+	Description string             `json:"description,omitempty"`
+	Root        string             `json:"root"`
+	ConfigFiles []string           `json:"configFiles"` // merge order, later files win
+	Tools       []projectToolState `json:"tools"`
+	Commands    []string           `json:"commands"`
+	CacheDir    string             `json:"cacheDir"`
+	Environment map[string]string  `json:"environment"` // secrets redacted
+}
+
+// showProjectInfo displays the fully resolved project state: config merge
+// order, requested vs resolved tool versions, install paths and sizes,
+// verification status, and the environment commands will see
 func showProjectInfo() error {
 	projectRoot, err := findProjectRoot()
 	if err != nil {
@@ -58,29 +87,73 @@ func showProjectInfo() error {
 		return fmt.Errorf("failed to load configuration: %w", err)
 	}
 
+	state, err := collectProjectState(projectRoot, cfg)
+	if err != nil {
+		return err
+	}
+
+	if jsonOutput() {
+		return printJSON(state)
+	}
+
 	printInfo("📋 Project Information")
 	printInfo("")
-	printInfo("Name:        %s", cfg.Project.Name)
-	printInfo("Description: %s", cfg.Project.Description)
-	printInfo("Root:        %s", projectRoot)
+	printInfo("Name:        %s", state.Name)
+	printInfo("Description: %s", state.Description)
+	printInfo("Root:        %s", state.Root)
+	printInfo("")
+
+	printInfo("🧾 Configuration (merge order, later files win):")
+	for i, file := range state.ConfigFiles {
+		printInfo("  %d. %s", i+1, file)
+	}
 	printInfo("")
 
-	// Show configured tools
-	if len(cfg.Tools) > 0 {
-		printInfo("🛠️  Configured Tools:")
-		for toolName, toolConfig := range cfg.Tools {
-			distribution := ""
-			if toolConfig.Distribution != "" {
-				distribution = fmt.Sprintf(" (%s)", toolConfig.Distribution)
+	if len(state.Tools) > 0 {
+		printInfo("🛠️  Tools:")
+		for _, tool := range state.Tools {
+			line := fmt.Sprintf("  %s: %s", tool.Name, tool.Requested)
+			if tool.Resolved != "" && tool.Resolved != tool.Requested {
+				line += " → " + tool.Resolved
 			}
-			printInfo("  %s: %s%s", toolName, toolConfig.Version, distribution)
+			if tool.Distribution != "" {
+				line += fmt.Sprintf(" (%s)", tool.Distribution)
+			}
+			printInfo("%s", line)
+			if tool.Installed {
+				printInfo("      ✅ installed at %s (%s)", tool.InstallPath, formatSize(tool.SizeBytes))
+			} else {
+				printInfo("      ❌ not installed")
+			}
+			if tool.Checksum != "" {
+				printInfo("      🔒 checksum %s", tool.Checksum)
+			}
+			if tool.Provenance != "" {
+				printInfo("      📜 provenance %s", tool.Provenance)
+			}
+		}
+		printInfo("")
+	}
+
+	printInfo("💾 Cache: %s (%s)", state.CacheDir, formatSize(dirSize(state.CacheDir)))
+	printInfo("")
+
+	if len(state.Environment) > 0 {
+		printInfo("🌍 Effective Environment:")
+		keys := make([]string, 0, len(state.Environment))
+		for key := range state.Environment {
+			keys = append(keys, key)
+		}
+		sort.Strings(keys)
+		for _, key := range keys {
+			printInfo("  %s=%s", key, state.Environment[key])
 		}
 		printInfo("")
 	}
 
 	// Show available commands
-	if len(cfg.Commands) > 0 {
-		printInfo("⚡ Available Commands: %d", len(cfg.Commands))
+	if len(state.Commands) > 0 {
+		printInfo("⚡ Available Commands: %d", len(state.Commands))
 		printInfo("  Run 'mvx run' to see all commands")
 		printInfo("  Run 'mvx info <command>' for command details")
 	}
@@ -88,6 +161,79 @@ func showProjectInfo() error {
 	return nil
 }
 
+// collectProjectState resolves every configured tool and the effective
+// environment into one reportable structure
+func collectProjectState(projectRoot string, cfg *config.Config) (*projectState, error) {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	sources, err := config.ConfigSources(projectRoot)
+	if err != nil {
+		return nil, err
+	}
+
+	lock, err := tools.LoadLockFile(projectRoot)
+	if err != nil || lock == nil {
+		lock = &tools.LockFile{}
+	}
+
+	state := &projectState{
+		Name:        cfg.Project.Name,
+		Description: cfg.Project.Description,
+		Root:        projectRoot,
+		ConfigFiles: sources,
+		CacheDir:    manager.GetToolsDir(),
+	}
+
+	names := make([]string, 0, len(cfg.Tools))
+	for name := range cfg.Tools {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	for _, name := range names {
+		toolConfig := cfg.Tools[name]
+		toolState := projectToolState{
+			Name:         name,
+			Requested:    toolConfig.Version,
+			Distribution: toolConfig.Distribution,
+		}
+		if resolved, err := manager.ResolveVersion(name, toolConfig); err == nil {
+			toolState.Resolved = resolved
+			if tool, err := manager.GetTool(name); err == nil && tool.IsInstalled(resolved, toolConfig) {
+				toolState.Installed = true
+				toolState.InstallPath = manager.GetToolVersionDir(name, resolved, toolConfig.Distribution)
+				toolState.SizeBytes = dirSize(toolState.InstallPath)
+			}
+		}
+		if entry, locked := lock.Tools[name]; locked {
+			toolState.Checksum = entry.Checksum
+			toolState.Provenance = entry.Provenance
+		}
+		state.Tools = append(state.Tools, toolState)
+	}
+
+	for name := range cfg.Commands {
+		state.Commands = append(state.Commands, name)
+	}
+	sort.Strings(state.Commands)
+
+	// The environment commands will see, with secrets redacted so the
+	// output is safe to share in bug reports
+	if env, err := manager.SetupEnvironment(cfg); err == nil {
+		state.Environment = make(map[string]string, len(env))
+		for key, value := range env {
+			state.Environment[key] = util.RedactEnvValue(key, value)
+		}
+	}
+
+	return state, nil
+}
+
 // showCommandInfo displays detailed information about a specific command
 func showCommandInfo(commandName string) error {
 	projectRoot, err := findProjectRoot()
@@ -10,8 +10,12 @@ import (
 
 var (
 	// Init command flags
-	initFormat string
-	initForce  bool
+	initFormat        string
+	initForce         bool
+	initTemplate      string
+	initFromWrapper   bool
+	initFromGradle    bool
+	initRemoveWrapper bool
 )
 
 // initCmd represents the init command
@@ -24,9 +28,14 @@ This creates a .mvx directory with a default configuration file that you can
 customize for your project's needs.
 
 Examples:
-  mvx init                    # Create config.json5 (default)
-  mvx init --format=yaml      # Create config.yml instead
-  mvx init --force            # Overwrite existing configuration`,
+  mvx init                            # Create config.json5 (default)
+  mvx init --format=yaml              # Create config.yml instead
+  mvx init --force                    # Overwrite existing configuration
+  mvx init --template maven-basic     # Scaffold from a built-in template
+  mvx init --template https://github.com/acme/mvx-template.git
+  mvx init --from-maven-wrapper       # Migrate an existing .mvn/wrapper setup
+  mvx init --from-maven-wrapper --remove-wrapper
+  mvx init --from-gradle-wrapper      # Migrate an existing gradle/wrapper setup`,
 
 	Run: func(cmd *cobra.Command, args []string) {
 		if err := initProject(); err != nil {
@@ -39,6 +48,10 @@ Examples:
 func init() {
 	initCmd.Flags().StringVar(&initFormat, "format", "json5", "configuration format (json5, yaml)")
 	initCmd.Flags().BoolVar(&initForce, "force", false, "overwrite existing configuration")
+	initCmd.Flags().StringVar(&initTemplate, "template", "", "scaffold from a built-in template (maven-basic, maven-quarkus, gradle, node, polyglot) or a git URL")
+	initCmd.Flags().BoolVar(&initFromWrapper, "from-maven-wrapper", false, "migrate an existing Maven Wrapper setup (.mvn/wrapper, jvm.config, maven.config)")
+	initCmd.Flags().BoolVar(&initFromGradle, "from-gradle-wrapper", false, "migrate an existing Gradle Wrapper setup (gradle/wrapper)")
+	initCmd.Flags().BoolVar(&initRemoveWrapper, "remove-wrapper", false, "remove mvnw, mvnw.cmd and .mvn/wrapper after migrating (with --from-maven-wrapper)")
 }
 
 func initProject() error {
@@ -69,6 +82,48 @@ func initProject() error {
 		return fmt.Errorf("unsupported format: %s (supported: json5, yaml)", initFormat)
 	}
 
+	// A template replaces the default scaffold (templates ship as JSON5)
+	if initTemplate != "" {
+		content, err := templateConfig(initTemplate)
+		if err != nil {
+			return err
+		}
+		configFile = "config.json5"
+		configContent = content
+	}
+
+	if initFromWrapper && initFromGradle {
+		return fmt.Errorf("--from-maven-wrapper and --from-gradle-wrapper cannot be combined")
+	}
+
+	// Migrating from the Gradle Wrapper derives the scaffold from the
+	// existing gradle/wrapper setup instead
+	if initFromGradle {
+		if !detectGradleWrapper(projectRoot) {
+			return fmt.Errorf("no Gradle Wrapper found (gradle/wrapper/gradle-wrapper.properties is missing)")
+		}
+		content, err := gradleWrapperConfig(projectRoot)
+		if err != nil {
+			return err
+		}
+		configFile = "config.json5"
+		configContent = content
+	}
+
+	// Migrating from the Maven Wrapper derives the scaffold from the
+	// existing .mvn/wrapper setup instead
+	if initFromWrapper {
+		if !detectMavenWrapper(projectRoot) {
+			return fmt.Errorf("no Maven Wrapper found (.mvn/wrapper/maven-wrapper.properties is missing)")
+		}
+		content, err := mavenWrapperConfig(projectRoot)
+		if err != nil {
+			return err
+		}
+		configFile = "config.json5"
+		configContent = content
+	}
+
 	configPath := filepath.Join(mvxDir, configFile)
 
 	// Check if config already exists
@@ -81,6 +136,35 @@ func initProject() error {
 		return fmt.Errorf("failed to write configuration file: %w", err)
 	}
 
+	// Keep per-user local overrides and mvx-generated state out of git
+	gitignorePath := filepath.Join(mvxDir, ".gitignore")
+	if _, err := os.Stat(gitignorePath); os.IsNotExist(err) {
+		gitignoreContent := `# Per-user local config overrides
+*.local.json5
+*.local.yml
+# mvx-generated state
+run-history.json
+setup-state.json
+`
+		if err := os.WriteFile(gitignorePath, []byte(gitignoreContent), 0644); err != nil {
+			return fmt.Errorf("failed to write .mvx/.gitignore: %w", err)
+		}
+	}
+
+	// The old wrapper files are only removed once the migrated config is
+	// safely written
+	if initFromWrapper && initRemoveWrapper {
+		if err := removeMavenWrapper(projectRoot); err != nil {
+			return err
+		}
+	}
+
+	// When the wrapper pins an mvx version, record the per-platform binary
+	// checksums so the bootstrap can verify what it downloads
+	if err := recordBootstrapChecksums(filepath.Join(mvxDir, "mvx.properties")); err != nil {
+		printWarning("Could not pin bootstrap binary checksums: %v", err)
+	}
+
 	printInfo("✅ Initialized mvx configuration in %s", configPath)
 	printInfo("")
 	printInfo("Next steps:")
@@ -105,7 +189,7 @@ func getDefaultJSON5Config() string {
     // Java configuration - flexible version specifications
     java: {
       version: "21",           // Latest Java 21.x (21.0.1, 21.0.2, etc.)
-      distribution: "temurin", // temurin, graalvm_ce, oracle, corretto, zulu, microsoft, liberica
+      distribution: "temurin", // temurin, graalvm_ce, oracle, corretto, zulu, microsoft, liberica, semeru
       // version: "17",        // Latest Java 17.x
       // version: "11",        // Latest Java 11.x
       // version: "25-ea",     // Early Access versions
@@ -159,7 +243,7 @@ tools:
   # Java configuration - flexible version specifications
   java:
     version: "21"           # Latest Java 21.x (21.0.1, 21.0.2, etc.)
-    distribution: temurin   # temurin, graalvm_ce, oracle, corretto, zulu, microsoft, liberica
+    distribution: temurin   # temurin, graalvm_ce, oracle, corretto, zulu, microsoft, liberica, semeru
     # version: "17"         # Latest Java 17.x
     # version: "11"         # Latest Java 11.x
     # version: "25-ea"      # Early Access versions
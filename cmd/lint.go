@@ -0,0 +1,240 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// lintCmd checks the project configuration for likely mistakes
+var lintCmd = &cobra.Command{
+	Use:   "lint",
+	Short: "Check the project configuration for likely mistakes",
+	Long: `Check the mvx configuration for problems that load and parse fine but
+bite later:
+
+  - tools that no mvx tool provider knows about
+  - version specs that no longer resolve to an available version
+  - environment variables that are declared but never referenced
+  - custom commands that shadow built-in mvx commands
+  - mvx-shell scripts using shell constructs the portable interpreter
+    does not support
+
+Examples:
+  mvx lint                  # Lint the current project
+  mvx lint --no-network     # Skip checks that need version catalogs`,
+	Run: func(cmd *cobra.Command, args []string) {
+		issues, err := runLint()
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		if len(issues) > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+var lintNoNetwork bool
+
+func init() {
+	lintCmd.Flags().BoolVar(&lintNoNetwork, "no-network", false, "skip checks that query version catalogs")
+	rootCmd.AddCommand(lintCmd)
+}
+
+// runLint performs all lint checks and prints the issues found
+func runLint() ([]string, error) {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return nil, fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return nil, fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	var issues []string
+	issues = append(issues, lintTools(cfg, manager)...)
+	issues = append(issues, lintEnvironment(cfg)...)
+	issues = append(issues, lintShadowedCommands(cfg)...)
+	issues = append(issues, lintShellScripts(cfg)...)
+
+	if len(issues) == 0 {
+		printSuccess("✅ No problems found in the mvx configuration")
+		return nil, nil
+	}
+
+	printInfo("Found %d problem(s):", len(issues))
+	for _, issue := range issues {
+		printInfo("  ⚠️  %s", issue)
+	}
+	return issues, nil
+}
+
+// lintTools flags unknown tools and version specs that no longer resolve
+func lintTools(cfg *config.Config, manager *tools.Manager) []string {
+	var issues []string
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if _, err := manager.GetTool(toolName); err != nil {
+			issues = append(issues, fmt.Sprintf("tools.%s: no tool provider registered for %s", toolName, toolName))
+			continue
+		}
+		if lintNoNetwork {
+			continue
+		}
+		if _, err := manager.ResolveVersion(toolName, toolConfig); err != nil {
+			issues = append(issues, fmt.Sprintf("tools.%s: version %s does not resolve: %v", toolName, toolConfig.Version, err))
+		}
+	}
+
+	return issues
+}
+
+// lintEnvironment flags declared environment variables that no script,
+// command environment or interpolation ever references
+func lintEnvironment(cfg *config.Config) []string {
+	if len(cfg.Environment) == 0 {
+		return nil
+	}
+
+	// Collect everything that can reference an environment variable
+	var haystack strings.Builder
+	for _, cmdConfig := range cfg.Commands {
+		for _, script := range commandScripts(cmdConfig) {
+			haystack.WriteString(script)
+			haystack.WriteByte('\n')
+		}
+		for _, value := range cmdConfig.Environment {
+			haystack.WriteString(string(value))
+			haystack.WriteByte('\n')
+		}
+	}
+	for _, value := range cfg.Environment {
+		haystack.WriteString(string(value))
+		haystack.WriteByte('\n')
+	}
+	text := haystack.String()
+
+	var names []string
+	for name := range cfg.Environment {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	var issues []string
+	for _, name := range names {
+		if strings.Contains(text, "$"+name) ||
+			strings.Contains(text, "${"+name+"}") ||
+			strings.Contains(text, "${env."+name+"}") ||
+			strings.Contains(text, "%"+name+"%") {
+			continue
+		}
+		issues = append(issues, fmt.Sprintf("environment.%s: declared but never referenced by a command or script", name))
+	}
+	return issues
+}
+
+// lintShadowedCommands flags custom commands whose names collide with
+// built-in mvx commands (the built-in always wins)
+func lintShadowedCommands(cfg *config.Config) []string {
+	builtins := make(map[string]bool)
+	for _, sub := range rootCmd.Commands() {
+		builtins[sub.Name()] = true
+	}
+
+	var names []string
+	for cmdName := range cfg.Commands {
+		names = append(names, cmdName)
+	}
+	sort.Strings(names)
+
+	var issues []string
+	for _, cmdName := range names {
+		if builtins[cmdName] {
+			issues = append(issues, fmt.Sprintf("commands.%s: shadows the built-in mvx %s command and will never run", cmdName, cmdName))
+		}
+	}
+	return issues
+}
+
+// nonPortableConstructs are shell constructs the mvx-shell interpreter does
+// not implement, paired with a short explanation
+var nonPortableConstructs = []struct {
+	pattern *regexp.Regexp
+	message string
+}{
+	{regexp.MustCompile(`\$\(`), "command substitution $(...)"},
+	{regexp.MustCompile("`"), "backtick command substitution"},
+	{regexp.MustCompile(`\[\[`), "bash [[ ]] conditionals"},
+	{regexp.MustCompile(`(^|\s)(if|for|while|case)\s`), "shell control flow (if/for/while/case)"},
+	{regexp.MustCompile(`[^&|><]>|<[^<]|<<`), "input/output redirection"},
+	{regexp.MustCompile(`(^|\s)export\s`), "export statements"},
+}
+
+// lintShellScripts flags non-portable shell constructs in commands that use
+// the portable mvx-shell interpreter
+func lintShellScripts(cfg *config.Config) []string {
+	var names []string
+	for cmdName := range cfg.Commands {
+		names = append(names, cmdName)
+	}
+	sort.Strings(names)
+
+	var issues []string
+	for _, cmdName := range names {
+		cmdConfig := cfg.Commands[cmdName]
+		if cmdConfig.Interpreter != "mvx-shell" {
+			continue
+		}
+		for _, script := range commandScripts(cmdConfig) {
+			for _, construct := range nonPortableConstructs {
+				if construct.pattern.MatchString(script) {
+					issues = append(issues, fmt.Sprintf("commands.%s: mvx-shell script uses %s, which the portable interpreter does not support", cmdName, construct.message))
+				}
+			}
+		}
+	}
+	return issues
+}
+
+// commandScripts returns all script strings of a command (plain string or
+// the variants of a platform-specific script object)
+func commandScripts(cmdConfig config.CommandConfig) []string {
+	switch script := cmdConfig.Script.(type) {
+	case string:
+		return []string{script}
+	case map[string]interface{}:
+		var scripts []string
+		for _, value := range script {
+			if s, ok := value.(string); ok {
+				scripts = append(scripts, s)
+			}
+		}
+		return scripts
+	}
+	return nil
+}
@@ -0,0 +1,70 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestLintShellScripts(t *testing.T) {
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"portable":  {Interpreter: "mvx-shell", Script: "mkdir -p dist && cp README.md dist"},
+			"subst":     {Interpreter: "mvx-shell", Script: "echo $(date)"},
+			"redirect":  {Interpreter: "mvx-shell", Script: "echo hi > out.txt"},
+			"native-ok": {Script: "echo $(date) > out.txt"}, // native shell, not linted
+		},
+	}
+
+	issues := lintShellScripts(cfg)
+
+	joined := strings.Join(issues, "\n")
+	if !strings.Contains(joined, "commands.subst") {
+		t.Errorf("expected command substitution to be flagged, got: %v", issues)
+	}
+	if !strings.Contains(joined, "commands.redirect") {
+		t.Errorf("expected redirection to be flagged, got: %v", issues)
+	}
+	if strings.Contains(joined, "commands.portable") {
+		t.Errorf("expected portable script to pass, got: %v", issues)
+	}
+	if strings.Contains(joined, "commands.native-ok") {
+		t.Errorf("expected native script to be skipped, got: %v", issues)
+	}
+}
+
+func TestLintEnvironment(t *testing.T) {
+	cfg := &config.Config{
+		Environment: map[string]config.EnvValue{
+			"USED_VAR":   "x",
+			"UNUSED_VAR": "y",
+		},
+		Commands: map[string]config.CommandConfig{
+			"build": {Script: "echo $USED_VAR"},
+		},
+	}
+
+	issues := lintEnvironment(cfg)
+	if len(issues) != 1 || !strings.Contains(issues[0], "UNUSED_VAR") {
+		t.Errorf("expected only UNUSED_VAR to be flagged, got: %v", issues)
+	}
+}
+
+func TestLintShadowedCommands(t *testing.T) {
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"setup": {Script: "echo custom setup"},
+			"build": {Script: "echo build"},
+		},
+	}
+
+	issues := lintShadowedCommands(cfg)
+	joined := strings.Join(issues, "\n")
+	if !strings.Contains(joined, "commands.setup") {
+		t.Errorf("expected shadowed setup command to be flagged, got: %v", issues)
+	}
+	if strings.Contains(joined, "commands.build") {
+		t.Errorf("expected build not to be flagged, got: %v", issues)
+	}
+}
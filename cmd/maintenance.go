@@ -0,0 +1,303 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/spf13/cobra"
+)
+
+// maintenanceTasks are the periodic jobs the lightweight scheduler knows about
+var maintenanceTasks = []string{"cache-prune", "registry-refresh", "toolchain-audit"}
+
+// defaultMaintenanceInterval is how often due tasks run when no interval is configured
+const defaultMaintenanceInterval = 7 * 24 * time.Hour
+
+// httpCacheMaxAge is how long disk-cached API responses are kept by cache-prune
+const httpCacheMaxAge = 30 * 24 * time.Hour
+
+// maintenanceCmd represents the maintenance command
+var maintenanceCmd = &cobra.Command{
+	Use:   "maintenance [run [task]]",
+	Short: "Run periodic maintenance tasks",
+	Long: `Run periodic maintenance tasks: cache pruning, registry refresh and a
+toolchain audit.
+
+When enabled in the configuration, due tasks also run automatically on
+invocation — there is no background daemon, the schedule is simply checked
+each time mvx runs:
+
+  maintenance: {
+    enabled: true,
+    interval: "168h",  // optional, defaults to 7 days
+  }
+
+Examples:
+  mvx maintenance run                  # Run all tasks now
+  mvx maintenance run cache-prune      # Run a single task`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		if len(args) == 0 || args[0] != "run" {
+			cmd.Help()
+			return
+		}
+		task := ""
+		if len(args) >= 2 {
+			task = args[1]
+		}
+		if err := runMaintenance(task); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(maintenanceCmd)
+}
+
+// maintenanceStatePath returns the path of the per-user maintenance state file
+func maintenanceStatePath() string {
+	mvxHome, err := util.MvxHome()
+	if err != nil {
+		return ""
+	}
+	return filepath.Join(mvxHome, "maintenance.json")
+}
+
+// loadMaintenanceState loads last-run timestamps per task (empty map on error)
+func loadMaintenanceState() map[string]time.Time {
+	state := make(map[string]time.Time)
+	path := maintenanceStatePath()
+	if path == "" {
+		return state
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return state
+	}
+	if err := json.Unmarshal(data, &state); err != nil {
+		return make(map[string]time.Time)
+	}
+	return state
+}
+
+// saveMaintenanceState persists last-run timestamps per task
+func saveMaintenanceState(state map[string]time.Time) {
+	path := maintenanceStatePath()
+	if path == "" {
+		return
+	}
+	if data, err := json.MarshalIndent(state, "", "  "); err == nil {
+		_ = os.WriteFile(path, data, 0644)
+	}
+}
+
+// maintenanceInterval returns the configured scheduler interval
+func maintenanceInterval(cfg *config.Config) time.Duration {
+	if cfg.Maintenance != nil && cfg.Maintenance.Interval != "" {
+		if interval, err := time.ParseDuration(cfg.Maintenance.Interval); err == nil && interval > 0 {
+			return interval
+		}
+		printWarning("invalid maintenance interval %q, using default", cfg.Maintenance.Interval)
+	}
+	return defaultMaintenanceInterval
+}
+
+// runDueMaintenance runs tasks whose interval has elapsed. It is called on
+// every invocation when maintenance is enabled in the configuration.
+func runDueMaintenance(cfg *config.Config) {
+	if cfg.Maintenance == nil || !cfg.Maintenance.Enabled {
+		return
+	}
+
+	interval := maintenanceInterval(cfg)
+	state := loadMaintenanceState()
+	for _, task := range maintenanceTasks {
+		if time.Since(state[task]) < interval {
+			continue
+		}
+		printInfo("🧹 Running scheduled maintenance task: %s", task)
+		if err := runMaintenanceTask(task); err != nil {
+			printWarning("maintenance task %s failed: %v", task, err)
+		}
+		state[task] = time.Now()
+	}
+	saveMaintenanceState(state)
+}
+
+// runMaintenance runs one or all maintenance tasks and records the run
+func runMaintenance(task string) error {
+	tasksToRun := maintenanceTasks
+	if task != "" {
+		found := false
+		for _, known := range maintenanceTasks {
+			if known == task {
+				found = true
+				break
+			}
+		}
+		if !found {
+			return fmt.Errorf("unknown maintenance task %s (available: %v)", task, maintenanceTasks)
+		}
+		tasksToRun = []string{task}
+	}
+
+	state := loadMaintenanceState()
+	for _, taskName := range tasksToRun {
+		printInfo("🧹 Running maintenance task: %s", taskName)
+		if err := runMaintenanceTask(taskName); err != nil {
+			return fmt.Errorf("task %s failed: %w", taskName, err)
+		}
+		state[taskName] = time.Now()
+	}
+	saveMaintenanceState(state)
+
+	printSuccess("✅ Maintenance complete")
+	return nil
+}
+
+// runMaintenanceTask dispatches a single maintenance task
+func runMaintenanceTask(task string) error {
+	switch task {
+	case "cache-prune":
+		return pruneHTTPCache()
+	case "registry-refresh":
+		return refreshVersionRegistry()
+	case "toolchain-audit":
+		return auditToolchain()
+	default:
+		return fmt.Errorf("unknown maintenance task: %s", task)
+	}
+}
+
+// pruneHTTPCache removes stale disk-cached API responses
+func pruneHTTPCache() error {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return err
+	}
+
+	cacheDir := filepath.Join(manager.GetCacheDir(), "cache", "metadata")
+	entries, err := os.ReadDir(cacheDir)
+	if err != nil {
+		if os.IsNotExist(err) {
+			return nil
+		}
+		return err
+	}
+
+	pruned := 0
+	for _, entry := range entries {
+		info, err := entry.Info()
+		if err != nil {
+			continue
+		}
+		if time.Since(info.ModTime()) > httpCacheMaxAge {
+			if err := os.Remove(filepath.Join(cacheDir, entry.Name())); err == nil {
+				pruned++
+			}
+		}
+	}
+
+	printInfo("  Pruned %d stale cache entries", pruned)
+	return nil
+}
+
+// refreshVersionRegistry re-fetches version metadata for configured tools
+func refreshVersionRegistry() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return err
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return err
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	os.Setenv("MVX_FORCE_REFRESH", "true")
+	defer os.Unsetenv("MVX_FORCE_REFRESH")
+
+	for toolName := range cfg.Tools {
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			continue
+		}
+		if _, err := tool.ListVersions(); err != nil {
+			printWarning("failed to refresh versions for %s: %v", toolName, err)
+		} else {
+			printInfo("  Refreshed versions for %s", toolName)
+		}
+	}
+	return nil
+}
+
+// auditToolchain verifies every configured, installed tool still works
+func auditToolchain() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return err
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return err
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	failures := 0
+	for toolName, toolConfig := range cfg.Tools {
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			printWarning("  %s: failed to resolve version: %v", toolName, err)
+			failures++
+			continue
+		}
+		resolvedConfig := toolConfig
+		resolvedConfig.Version = resolved
+
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			printWarning("  %s: %v", toolName, err)
+			failures++
+			continue
+		}
+		if !tool.IsInstalled(resolved, resolvedConfig) {
+			printInfo("  %s %s: not installed (run 'mvx setup')", toolName, resolved)
+			continue
+		}
+		if err := tool.Verify(resolved, resolvedConfig); err != nil {
+			printWarning("  %s %s: verification failed: %v", toolName, resolved, err)
+			failures++
+		} else {
+			printInfo("  %s %s: ok", toolName, resolved)
+		}
+	}
+
+	if failures > 0 {
+		return fmt.Errorf("%d tool(s) failed the audit", failures)
+	}
+	return nil
+}
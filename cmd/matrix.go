@@ -0,0 +1,155 @@
+package cmd
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/executor"
+	"github.com/gnodet/mvx/pkg/tools"
+)
+
+// Matrix runs: `mvx run build --java 17,21,24-ea` executes the command once
+// per listed version (cartesian product across tools when several matrix
+// flags are given), provisioning each version on demand. Output is prefixed
+// per combination and a summary table closes the run.
+
+// runCommandWithMatrix detects tool-version matrix flags among the command
+// arguments and fans the command out accordingly; without matrix flags the
+// command runs normally
+func runCommandWithMatrix(commandName string, args []string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	matrix, rest := extractToolMatrix(cfg, args)
+	if len(matrix) == 0 {
+		return runCustomCommand(commandName, args)
+	}
+
+	combos := matrixCombinations(matrix)
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	type matrixResult struct {
+		label    string
+		duration time.Duration
+		err      error
+	}
+	results := make([]matrixResult, 0, len(combos))
+
+	for _, combo := range combos {
+		label := strings.Join(combo, " ")
+		printInfo("▶ %s (%s)", commandName, label)
+
+		// Each combination gets a fresh config with its versions pinned,
+		// so overrides never leak between runs
+		runCfg, err := config.LoadConfig(projectRoot)
+		if err != nil {
+			return fmt.Errorf("failed to load configuration: %w", err)
+		}
+		if err := runCfg.ApplyToolOverrides(combo); err != nil {
+			return err
+		}
+
+		exec := executor.NewExecutor(runCfg, manager, projectRoot)
+		started := time.Now()
+		runErr := exec.ExecuteCommandWithPrefix(commandName, rest, "["+label+"] ")
+		results = append(results, matrixResult{label: label, duration: time.Since(started), err: runErr})
+	}
+
+	// Summary table
+	maxLen := 0
+	for _, result := range results {
+		if len(result.label) > maxLen {
+			maxLen = len(result.label)
+		}
+	}
+	failed := 0
+	printInfo("")
+	printInfo("Matrix summary for '%s':", commandName)
+	for _, result := range results {
+		padding := strings.Repeat(" ", maxLen-len(result.label)+2)
+		if result.err != nil {
+			failed++
+			printInfo("  %s%s❌ failed (%s): %v", result.label, padding, result.duration.Round(time.Millisecond), result.err)
+		} else {
+			printInfo("  %s%s✅ ok (%s)", result.label, padding, result.duration.Round(time.Millisecond))
+		}
+	}
+
+	if failed > 0 {
+		return fmt.Errorf("%d of %d matrix runs failed", failed, len(results))
+	}
+	return nil
+}
+
+// extractToolMatrix splits matrix flags (--java 17,21 or --java=17,21) from
+// the remaining command arguments. Only flags naming a configured tool are
+// treated as matrix specs; everything else passes through to the command.
+func extractToolMatrix(cfg *config.Config, args []string) (map[string][]string, []string) {
+	matrix := make(map[string][]string)
+	var rest []string
+	for i := 0; i < len(args); i++ {
+		arg := args[i]
+		if !strings.HasPrefix(arg, "--") {
+			rest = append(rest, arg)
+			continue
+		}
+
+		name := strings.TrimPrefix(arg, "--")
+		value := ""
+		consumed := 0
+		if n, v, ok := strings.Cut(name, "="); ok {
+			name, value = n, v
+		} else if i+1 < len(args) {
+			value = args[i+1]
+			consumed = 1
+		}
+
+		if _, configured := cfg.Tools[name]; !configured || value == "" {
+			rest = append(rest, arg)
+			continue
+		}
+
+		for _, version := range strings.Split(value, ",") {
+			if version = strings.TrimSpace(version); version != "" {
+				matrix[name] = append(matrix[name], version)
+			}
+		}
+		i += consumed
+	}
+	return matrix, rest
+}
+
+// matrixCombinations expands the matrix into tool@version override sets,
+// the cartesian product across tools in stable order
+func matrixCombinations(matrix map[string][]string) [][]string {
+	names := make([]string, 0, len(matrix))
+	for name := range matrix {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	combos := [][]string{{}}
+	for _, name := range names {
+		var expanded [][]string
+		for _, combo := range combos {
+			for _, version := range matrix[name] {
+				next := append(append([]string{}, combo...), name+"@"+version)
+				expanded = append(expanded, next)
+			}
+		}
+		combos = expanded
+	}
+	return combos
+}
@@ -0,0 +1,58 @@
+package cmd
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func matrixTestConfig() *config.Config {
+	return &config.Config{
+		Tools: map[string]config.ToolConfig{
+			"java": {Version: "21"},
+			"node": {Version: "20"},
+		},
+	}
+}
+
+func TestExtractToolMatrix(t *testing.T) {
+	cfg := matrixTestConfig()
+
+	matrix, rest := extractToolMatrix(cfg, []string{"--java", "17,21", "--module", "api"})
+	if !reflect.DeepEqual(matrix, map[string][]string{"java": {"17", "21"}}) {
+		t.Errorf("unexpected matrix: %v", matrix)
+	}
+	if !reflect.DeepEqual(rest, []string{"--module", "api"}) {
+		t.Errorf("unexpected rest args: %v", rest)
+	}
+
+	// --tool=versions form, and non-tool flags pass through
+	matrix, rest = extractToolMatrix(cfg, []string{"--node=18,20", "--verbose"})
+	if !reflect.DeepEqual(matrix, map[string][]string{"node": {"18", "20"}}) {
+		t.Errorf("unexpected matrix: %v", matrix)
+	}
+	if !reflect.DeepEqual(rest, []string{"--verbose"}) {
+		t.Errorf("unexpected rest args: %v", rest)
+	}
+
+	matrix, rest = extractToolMatrix(cfg, []string{"build-arg", "value"})
+	if len(matrix) != 0 || !reflect.DeepEqual(rest, []string{"build-arg", "value"}) {
+		t.Errorf("expected passthrough, got matrix %v rest %v", matrix, rest)
+	}
+}
+
+func TestMatrixCombinations(t *testing.T) {
+	combos := matrixCombinations(map[string][]string{
+		"java": {"17", "21"},
+		"node": {"20"},
+	})
+
+	want := [][]string{
+		{"java@17", "node@20"},
+		{"java@21", "node@20"},
+	}
+	if !reflect.DeepEqual(combos, want) {
+		t.Errorf("unexpected combinations: %v", combos)
+	}
+}
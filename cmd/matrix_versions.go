@@ -0,0 +1,116 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/gnodet/mvx/pkg/version"
+	"github.com/spf13/cobra"
+)
+
+// matrixCmd prints the versions a tool's configured spec expands to, as
+// JSON, for feeding a GitHub Actions matrix strategy. CI coverage then
+// tracks the config: widening the range widens the matrix.
+var matrixCmd = &cobra.Command{
+	Use:   "matrix",
+	Short: "Print the versions satisfying a tool's configured spec (JSON, for CI matrices)",
+	Long: `Print the set of available versions that satisfy a tool's configured
+version specification, as a JSON array ready for a GitHub Actions matrix:
+
+  versions=$(mvx matrix --tool java)
+  # strategy: matrix: java: ${{ fromJson(needs.prepare.outputs.versions) }}
+
+By default only the newest version of each major line is listed (what a CI
+matrix usually wants); --all lists every matching version. Aliases that
+name a single version ("lts", "latest") expand to that one version.
+
+Examples:
+  mvx matrix --tool java          # e.g. ["17.0.10", "21.0.2"]
+  mvx matrix --tool java --all    # every version in the configured range`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runMatrixVersions(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var (
+	matrixTool string
+	matrixAll  bool
+)
+
+func init() {
+	matrixCmd.Flags().StringVar(&matrixTool, "tool", "", "configured tool to expand (required)")
+	matrixCmd.Flags().BoolVar(&matrixAll, "all", false, "list every matching version, not just the newest per major line")
+	matrixCmd.MarkFlagRequired("tool")
+	rootCmd.AddCommand(matrixCmd)
+}
+
+// runMatrixVersions expands the configured spec against the tool's version
+// catalog and prints the result as JSON
+func runMatrixVersions() error {
+	_, cfg, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+
+	toolConfig, configured := cfg.Tools[matrixTool]
+	if !configured {
+		return fmt.Errorf("tool %s is not configured in this project", matrixTool)
+	}
+
+	spec, err := version.ParseSpec(toolConfig.Version)
+	if err != nil {
+		// Tool-specific aliases ("lts") resolve to a single version
+		resolved, resolveErr := manager.ResolveVersion(matrixTool, toolConfig)
+		if resolveErr != nil {
+			return fmt.Errorf("cannot expand version spec %q: %w", toolConfig.Version, resolveErr)
+		}
+		return printJSON([]string{resolved})
+	}
+	spec.IncludePrereleases = spec.IncludePrereleases || toolConfig.AllowPrerelease
+
+	tool, err := manager.GetTool(matrixTool)
+	if err != nil {
+		return err
+	}
+	available, err := tool.ListVersions()
+	if err != nil {
+		return fmt.Errorf("failed to list %s versions: %w", matrixTool, err)
+	}
+
+	versions := matrixVersions(available, spec, matrixAll)
+	if len(versions) == 0 {
+		return fmt.Errorf("no available %s version satisfies %q", matrixTool, toolConfig.Version)
+	}
+	return printJSON(versions)
+}
+
+// matrixVersions filters the available versions by spec, oldest first. When
+// all is false only the newest version of each major line is kept.
+func matrixVersions(available []string, spec *version.Spec, all bool) []string {
+	var matched []string
+	seenMajor := make(map[int]bool)
+
+	// SortVersions is newest-first, so the first hit per major is its newest
+	for _, raw := range version.SortVersions(available) {
+		v, err := version.ParseVersion(raw)
+		if err != nil || !spec.Matches(v) {
+			continue
+		}
+		if !all {
+			if seenMajor[v.Major] {
+				continue
+			}
+			seenMajor[v.Major] = true
+		}
+		matched = append(matched, raw)
+	}
+
+	// Oldest first reads naturally in a matrix
+	for i, j := 0, len(matched)-1; i < j; i, j = i+1, j-1 {
+		matched[i], matched[j] = matched[j], matched[i]
+	}
+	return matched
+}
@@ -0,0 +1,37 @@
+package cmd
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+func TestMatrixVersions(t *testing.T) {
+	available := []string{"22.0.1", "21.0.2", "21.0.1", "17.0.10", "17.0.9", "11.0.22", "not-a-version"}
+
+	spec, err := version.ParseSpec(">=17 <22")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+
+	got := matrixVersions(available, spec, false)
+	want := []string{"17.0.10", "21.0.2"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("expected newest per major %v, got %v", want, got)
+	}
+
+	got = matrixVersions(available, spec, true)
+	want = []string{"17.0.9", "17.0.10", "21.0.1", "21.0.2"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("expected all matches %v, got %v", want, got)
+	}
+
+	narrow, err := version.ParseSpec("23")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+	if got := matrixVersions(available, narrow, false); len(got) != 0 {
+		t.Errorf("expected no matches, got %v", got)
+	}
+}
@@ -0,0 +1,60 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// mavenExtensionsMarker identifies an extensions.xml that mvx generated and
+// may therefore rewrite; hand-maintained files are left alone
+const mavenExtensionsMarker = "Generated by mvx from maven.extensions"
+
+// renderMavenExtensionsXML builds the .mvn/extensions.xml content for the
+// declared Maven core extensions
+func renderMavenExtensionsXML(extensions []config.MavenExtensionConfig) string {
+	var b strings.Builder
+	b.WriteString("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+	fmt.Fprintf(&b, "<!-- %s - edit the mvx config instead -->\n", mavenExtensionsMarker)
+	b.WriteString("<extensions>\n")
+	for _, ext := range extensions {
+		b.WriteString("  <extension>\n")
+		writeXMLElement(&b, "    ", "groupId", ext.GroupID)
+		writeXMLElement(&b, "    ", "artifactId", ext.ArtifactID)
+		writeXMLElement(&b, "    ", "version", ext.Version)
+		b.WriteString("  </extension>\n")
+	}
+	b.WriteString("</extensions>\n")
+	return b.String()
+}
+
+// materializeMavenExtensions writes .mvn/extensions.xml from the
+// maven.extensions declaration. A pre-existing file without the mvx marker
+// is never overwritten; Maven itself resolves the extension jars from the
+// configured repositories on the next build.
+func materializeMavenExtensions(projectRoot string, cfg *config.Config) error {
+	if cfg.Maven == nil || len(cfg.Maven.Extensions) == 0 {
+		return nil
+	}
+
+	extensionsPath := filepath.Join(projectRoot, ".mvn", "extensions.xml")
+	if existing, err := os.ReadFile(extensionsPath); err == nil {
+		if !strings.Contains(string(existing), mavenExtensionsMarker) {
+			return fmt.Errorf("%s exists but was not generated by mvx; merge maven.extensions manually or remove the file", extensionsPath)
+		}
+	}
+
+	content := renderMavenExtensionsXML(cfg.Maven.Extensions)
+	if err := os.MkdirAll(filepath.Dir(extensionsPath), 0755); err != nil {
+		return err
+	}
+	if err := os.WriteFile(extensionsPath, []byte(content), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", extensionsPath, err)
+	}
+
+	printInfo("📦 Wrote %s (%d core extension(s))", extensionsPath, len(cfg.Maven.Extensions))
+	return nil
+}
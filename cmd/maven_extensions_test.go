@@ -0,0 +1,54 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func extensionsTestConfig() *config.Config {
+	return &config.Config{
+		Maven: &config.MavenConfig{
+			Extensions: []config.MavenExtensionConfig{
+				{GroupID: "org.apache.maven.extensions", ArtifactID: "maven-build-cache-extension", Version: "1.2.0"},
+			},
+		},
+	}
+}
+
+func TestMaterializeMavenExtensions(t *testing.T) {
+	root := t.TempDir()
+
+	if err := materializeMavenExtensions(root, extensionsTestConfig()); err != nil {
+		t.Fatalf("materializeMavenExtensions: %v", err)
+	}
+
+	data, err := os.ReadFile(filepath.Join(root, ".mvn", "extensions.xml"))
+	if err != nil {
+		t.Fatalf("read extensions.xml: %v", err)
+	}
+	for _, want := range []string{
+		"<artifactId>maven-build-cache-extension</artifactId>",
+		"<version>1.2.0</version>",
+		mavenExtensionsMarker,
+	} {
+		if !strings.Contains(string(data), want) {
+			t.Errorf("extensions.xml misses %q:\n%s", want, data)
+		}
+	}
+
+	// A regenerated file is fine; a hand-maintained one must be refused
+	if err := materializeMavenExtensions(root, extensionsTestConfig()); err != nil {
+		t.Errorf("regenerating an mvx-generated file should succeed: %v", err)
+	}
+	handEdited := filepath.Join(root, ".mvn", "extensions.xml")
+	if err := os.WriteFile(handEdited, []byte("<extensions/>"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := materializeMavenExtensions(root, extensionsTestConfig()); err == nil {
+		t.Error("expected an error for a hand-maintained extensions.xml")
+	}
+}
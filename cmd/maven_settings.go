@@ -0,0 +1,136 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/secrets"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// settingsPlaceholderPattern matches ${env.NAME} and ${secret.NAME}
+// placeholders in maven.settings credential fields
+var settingsPlaceholderPattern = regexp.MustCompile(`\$\{(env|secret)\.([a-zA-Z][a-zA-Z0-9_.-]*)\}`)
+
+// resolveSettingsValue expands ${env.NAME} and ${secret.NAME} placeholders.
+// Unresolvable placeholders are left untouched so the generated XML makes
+// the gap visible instead of silently sending empty credentials.
+func resolveSettingsValue(value string) string {
+	return settingsPlaceholderPattern.ReplaceAllStringFunc(value, func(match string) string {
+		groups := settingsPlaceholderPattern.FindStringSubmatch(match)
+		switch groups[1] {
+		case "env":
+			if v := os.Getenv(groups[2]); v != "" {
+				return v
+			}
+		case "secret":
+			if v, err := secrets.Resolve(groups[2]); err == nil {
+				// Never let resolved secrets reach logs or reports
+				util.RegisterSensitiveValue(v)
+				return v
+			}
+		}
+		return match
+	})
+}
+
+// xmlEscaper escapes text for XML element content
+var xmlEscaper = strings.NewReplacer("&", "&amp;", "<", "&lt;", ">", "&gt;", `"`, "&quot;")
+
+// renderMavenSettingsXML builds a settings.xml from the maven.settings
+// declaration, resolving credential placeholders
+func renderMavenSettingsXML(settings *config.MavenSettingsConfig) string {
+	var b strings.Builder
+	b.WriteString("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+	b.WriteString("<!-- Generated by mvx from maven.settings - do not edit or commit -->\n")
+	b.WriteString("<settings>\n")
+
+	if len(settings.Mirrors) > 0 {
+		b.WriteString("  <mirrors>\n")
+		for _, mirror := range settings.Mirrors {
+			b.WriteString("    <mirror>\n")
+			writeXMLElement(&b, "      ", "id", mirror.ID)
+			writeXMLElement(&b, "      ", "mirrorOf", mirror.MirrorOf)
+			writeXMLElement(&b, "      ", "url", mirror.URL)
+			b.WriteString("    </mirror>\n")
+		}
+		b.WriteString("  </mirrors>\n")
+	}
+
+	if len(settings.Servers) > 0 {
+		b.WriteString("  <servers>\n")
+		for _, server := range settings.Servers {
+			b.WriteString("    <server>\n")
+			writeXMLElement(&b, "      ", "id", server.ID)
+			writeXMLElement(&b, "      ", "username", resolveSettingsValue(server.Username))
+			writeXMLElement(&b, "      ", "password", resolveSettingsValue(server.Password))
+			b.WriteString("    </server>\n")
+		}
+		b.WriteString("  </servers>\n")
+	}
+
+	if len(settings.Proxies) > 0 {
+		b.WriteString("  <proxies>\n")
+		for _, proxy := range settings.Proxies {
+			protocol := proxy.Protocol
+			if protocol == "" {
+				protocol = "http"
+			}
+			b.WriteString("    <proxy>\n")
+			writeXMLElement(&b, "      ", "id", proxy.ID)
+			writeXMLElement(&b, "      ", "active", "true")
+			writeXMLElement(&b, "      ", "protocol", protocol)
+			writeXMLElement(&b, "      ", "host", proxy.Host)
+			if proxy.Port != 0 {
+				writeXMLElement(&b, "      ", "port", fmt.Sprintf("%d", proxy.Port))
+			}
+			writeXMLElement(&b, "      ", "username", resolveSettingsValue(proxy.Username))
+			writeXMLElement(&b, "      ", "password", resolveSettingsValue(proxy.Password))
+			writeXMLElement(&b, "      ", "nonProxyHosts", proxy.NonProxyHosts)
+			b.WriteString("    </proxy>\n")
+		}
+		b.WriteString("  </proxies>\n")
+	}
+
+	b.WriteString("</settings>\n")
+	return b.String()
+}
+
+// writeXMLElement writes an indented element, skipping empty values
+func writeXMLElement(b *strings.Builder, indent, name, value string) {
+	if value == "" {
+		return
+	}
+	fmt.Fprintf(b, "%s<%s>%s</%s>\n", indent, name, xmlEscaper.Replace(value), name)
+}
+
+// writeMavenSettingsFile renders the generated settings.xml into a private
+// temporary file (it may contain resolved credentials) and returns its path
+// with a cleanup function
+func writeMavenSettingsFile(settings *config.MavenSettingsConfig) (string, func(), error) {
+	file, err := os.CreateTemp("", "mvx-settings-*.xml")
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to create settings.xml: %w", err)
+	}
+	path := file.Name()
+	cleanup := func() { os.Remove(path) }
+
+	if err := os.Chmod(path, 0600); err != nil {
+		file.Close()
+		cleanup()
+		return "", nil, err
+	}
+	if _, err := file.WriteString(renderMavenSettingsXML(settings)); err != nil {
+		file.Close()
+		cleanup()
+		return "", nil, fmt.Errorf("failed to write settings.xml: %w", err)
+	}
+	if err := file.Close(); err != nil {
+		cleanup()
+		return "", nil, err
+	}
+	return path, cleanup, nil
+}
@@ -0,0 +1,64 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestRenderMavenSettingsXML(t *testing.T) {
+	t.Setenv("NEXUS_USER", "deployer")
+
+	settings := &config.MavenSettingsConfig{
+		Mirrors: []config.MavenMirrorConfig{
+			{ID: "corp", MirrorOf: "central", URL: "https://nexus.corp.example/repo"},
+		},
+		Servers: []config.MavenServerConfig{
+			{ID: "corp", Username: "${env.NEXUS_USER}", Password: "${secret.MISSING_SECRET}"},
+		},
+		Proxies: []config.MavenProxyConfig{
+			{ID: "default", Host: "proxy.corp.example", Port: 3128, NonProxyHosts: "*.corp.example"},
+		},
+	}
+
+	xml := renderMavenSettingsXML(settings)
+	for _, want := range []string{
+		"<mirrorOf>central</mirrorOf>",
+		"<url>https://nexus.corp.example/repo</url>",
+		"<username>deployer</username>",
+		// Unresolvable placeholders stay visible instead of becoming ""
+		"<password>${secret.MISSING_SECRET}</password>",
+		"<host>proxy.corp.example</host>",
+		"<port>3128</port>",
+		"<nonProxyHosts>*.corp.example</nonProxyHosts>",
+	} {
+		if !strings.Contains(xml, want) {
+			t.Errorf("generated settings.xml misses %q:\n%s", want, xml)
+		}
+	}
+}
+
+func TestRenderMavenSettingsXMLEscaping(t *testing.T) {
+	settings := &config.MavenSettingsConfig{
+		Servers: []config.MavenServerConfig{
+			{ID: "corp", Username: "a&b", Password: "p<w>d"},
+		},
+	}
+	xml := renderMavenSettingsXML(settings)
+	if !strings.Contains(xml, "<username>a&amp;b</username>") {
+		t.Errorf("expected escaped username:\n%s", xml)
+	}
+	if !strings.Contains(xml, "<password>p&lt;w&gt;d</password>") {
+		t.Errorf("expected escaped password:\n%s", xml)
+	}
+}
+
+func TestHasSettingsArg(t *testing.T) {
+	if hasSettingsArg([]string{"clean", "install"}) {
+		t.Error("expected no settings arg")
+	}
+	if !hasSettingsArg([]string{"-s", "my.xml"}) {
+		t.Error("expected -s to be detected")
+	}
+}
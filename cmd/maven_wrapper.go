@@ -0,0 +1,138 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// mavenWrapperDistPattern extracts the Maven version from the wrapper's
+// distributionUrl (.../apache-maven-3.9.9-bin.zip)
+var mavenWrapperDistPattern = regexp.MustCompile(`apache-maven-([0-9][0-9A-Za-z.\-]*?)-bin\.`)
+
+// pomJavaPattern finds the compiler release/source pinned in pom.xml
+var pomJavaPattern = regexp.MustCompile(`<(?:maven\.compiler\.release|maven\.compiler\.source|release)>\s*(1\.)?(\d+)\s*<`)
+
+// detectMavenWrapper reports whether the project carries a Maven Wrapper
+func detectMavenWrapper(projectRoot string) bool {
+	_, err := os.Stat(filepath.Join(projectRoot, ".mvn", "wrapper", "maven-wrapper.properties"))
+	return err == nil
+}
+
+// mavenWrapperConfig builds a config.json5 equivalent to an existing Maven
+// Wrapper setup: the pinned Maven version from maven-wrapper.properties,
+// JVM options from .mvn/jvm.config (as MAVEN_OPTS) and default CLI
+// arguments from .mvn/maven.config (as MAVEN_ARGS, honored by Maven 3.9+).
+func mavenWrapperConfig(projectRoot string) (string, error) {
+	mavenVersion, err := mavenWrapperVersion(projectRoot)
+	if err != nil {
+		return "", err
+	}
+
+	javaVersion := javaVersionFromPom(projectRoot)
+	if javaVersion == "" {
+		javaVersion = "21"
+	}
+
+	var environment strings.Builder
+	if opts := wrapperConfigLine(filepath.Join(projectRoot, ".mvn", "jvm.config")); opts != "" {
+		fmt.Fprintf(&environment, "    // JVM options migrated from .mvn/jvm.config\n")
+		fmt.Fprintf(&environment, "    MAVEN_OPTS: %q,\n", opts)
+	}
+	if args := wrapperConfigLine(filepath.Join(projectRoot, ".mvn", "maven.config")); args != "" {
+		fmt.Fprintf(&environment, "    // Default CLI arguments migrated from .mvn/maven.config\n")
+		fmt.Fprintf(&environment, "    MAVEN_ARGS: %q,\n", args)
+	}
+	environmentSection := ""
+	if environment.Len() > 0 {
+		environmentSection = fmt.Sprintf("\n  environment: {\n%s  },\n", environment.String())
+	}
+
+	return fmt.Sprintf(`{
+  // mvx configuration migrated from the Maven Wrapper (.mvn/wrapper)
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: %q,
+  },
+
+  tools: {
+    java: { version: %q, distribution: "temurin" },
+    maven: { version: %q },
+  },
+%s
+  commands: {
+    build: {
+      description: "Build the project",
+      script: "mvn clean install",
+    },
+    test: {
+      description: "Run tests",
+      script: "mvn verify",
+    },
+  },
+}
+`, filepath.Base(projectRoot), javaVersion, mavenVersion, environmentSection), nil
+}
+
+// mavenWrapperVersion reads the pinned Maven version from
+// .mvn/wrapper/maven-wrapper.properties
+func mavenWrapperVersion(projectRoot string) (string, error) {
+	propertiesFile := filepath.Join(projectRoot, ".mvn", "wrapper", "maven-wrapper.properties")
+	content, err := os.ReadFile(propertiesFile)
+	if err != nil {
+		return "", fmt.Errorf("no Maven Wrapper found (%s): %w", propertiesFile, err)
+	}
+
+	for _, line := range strings.Split(string(content), "\n") {
+		line = strings.TrimSpace(line)
+		if !strings.HasPrefix(line, "distributionUrl=") {
+			continue
+		}
+		if m := mavenWrapperDistPattern.FindStringSubmatch(line); m != nil {
+			return m[1], nil
+		}
+	}
+	return "", fmt.Errorf("could not determine the Maven version from %s", propertiesFile)
+}
+
+// javaVersionFromPom extracts the compiler release pinned in pom.xml, if any
+func javaVersionFromPom(projectRoot string) string {
+	content, err := os.ReadFile(filepath.Join(projectRoot, "pom.xml"))
+	if err != nil {
+		return ""
+	}
+	if m := pomJavaPattern.FindSubmatch(content); m != nil {
+		return string(m[2])
+	}
+	return ""
+}
+
+// wrapperConfigLine flattens a .mvn/*.config file (one argument per line in
+// recent wrappers) into a single space-separated string
+func wrapperConfigLine(path string) string {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return ""
+	}
+	return strings.Join(strings.Fields(string(content)), " ")
+}
+
+// removeMavenWrapper deletes the wrapper scripts and .mvn/wrapper directory
+// once the migrated configuration replaces them
+func removeMavenWrapper(projectRoot string) error {
+	for _, path := range []string{"mvnw", "mvnw.cmd"} {
+		full := filepath.Join(projectRoot, path)
+		if err := os.Remove(full); err != nil && !os.IsNotExist(err) {
+			return fmt.Errorf("failed to remove %s: %w", path, err)
+		}
+	}
+	wrapperDir := filepath.Join(projectRoot, ".mvn", "wrapper")
+	if err := os.RemoveAll(wrapperDir); err != nil {
+		return fmt.Errorf("failed to remove %s: %w", wrapperDir, err)
+	}
+	printInfo("🧹 Removed mvnw, mvnw.cmd and .mvn/wrapper")
+	return nil
+}
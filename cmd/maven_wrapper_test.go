@@ -0,0 +1,66 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func writeWrapperFile(t *testing.T, root, rel, content string) {
+	t.Helper()
+	path := filepath.Join(root, rel)
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatalf("mkdir: %v", err)
+	}
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		t.Fatalf("write %s: %v", rel, err)
+	}
+}
+
+func TestMavenWrapperVersion(t *testing.T) {
+	root := t.TempDir()
+	writeWrapperFile(t, root, ".mvn/wrapper/maven-wrapper.properties",
+		"wrapperVersion=3.3.2\ndistributionUrl=https://repo.maven.apache.org/maven2/org/apache/maven/apache-maven/3.9.9/apache-maven-3.9.9-bin.zip\n")
+
+	version, err := mavenWrapperVersion(root)
+	if err != nil {
+		t.Fatalf("mavenWrapperVersion: %v", err)
+	}
+	if version != "3.9.9" {
+		t.Errorf("expected 3.9.9, got %s", version)
+	}
+
+	if !detectMavenWrapper(root) {
+		t.Error("expected detectMavenWrapper to report the wrapper")
+	}
+	if detectMavenWrapper(t.TempDir()) {
+		t.Error("expected detectMavenWrapper to be false without .mvn/wrapper")
+	}
+}
+
+func TestMavenWrapperConfig(t *testing.T) {
+	root := t.TempDir()
+	writeWrapperFile(t, root, ".mvn/wrapper/maven-wrapper.properties",
+		"distributionUrl=https://repo.maven.apache.org/maven2/org/apache/maven/apache-maven/3.9.6/apache-maven-3.9.6-bin.zip\n")
+	writeWrapperFile(t, root, ".mvn/jvm.config", "-Xmx2g\n-XX:+UseG1GC\n")
+	writeWrapperFile(t, root, ".mvn/maven.config", "-T1C\n--no-transfer-progress\n")
+	writeWrapperFile(t, root, "pom.xml",
+		"<project><properties><maven.compiler.release>17</maven.compiler.release></properties></project>")
+
+	content, err := mavenWrapperConfig(root)
+	if err != nil {
+		t.Fatalf("mavenWrapperConfig: %v", err)
+	}
+
+	for _, want := range []string{
+		`maven: { version: "3.9.6" }`,
+		`java: { version: "17"`,
+		`MAVEN_OPTS: "-Xmx2g -XX:+UseG1GC"`,
+		`MAVEN_ARGS: "-T1C --no-transfer-progress"`,
+	} {
+		if !strings.Contains(content, want) {
+			t.Errorf("generated config misses %q:\n%s", want, content)
+		}
+	}
+}
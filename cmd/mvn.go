@@ -2,6 +2,7 @@ package cmd
 
 import (
 	"fmt"
+	"io"
 	"os"
 	"os/exec"
 	"path/filepath"
@@ -22,6 +23,10 @@ var mvnCmd = &cobra.Command{
 All arguments are passed directly to Maven without interpretation, allowing
 Maven flags like -V, -X, -P, etc. to work naturally.
 
+With maven.useDaemon set in the config and an mvnd tool pinned, the
+invocation is routed through the Maven daemon transparently (falling back
+to regular Maven when the daemon is unavailable).
+
 Examples:
   mvx mvn clean install
   mvx mvn -V
@@ -35,6 +40,18 @@ Examples:
 			return err
 		}
 
+		// --fix opts into applying suggested config changes after a recognized failure
+		applyFix := false
+		var filteredArgs []string
+		for _, arg := range mavenArgs {
+			if arg == "--fix" {
+				applyFix = true
+				continue
+			}
+			filteredArgs = append(filteredArgs, arg)
+		}
+		mavenArgs = filteredArgs
+
 		// Handle backward compatibility: remove '--' separator if present and warn
 		if len(mavenArgs) > 0 && mavenArgs[0] == "--" {
 			printWarning("The '--' separator is no longer needed with 'mvx mvn'. You can remove it for cleaner syntax.")
@@ -69,13 +86,23 @@ Examples:
 		if _, hasJava := cfg.Tools["java"]; hasJava {
 			requiredTools = append(requiredTools, "java")
 		}
-
-		if err := mgr.InstallSpecificTools(cfg, requiredTools); err != nil {
-			return fmt.Errorf("failed to install required tools: %w", err)
+		if cfg.Maven != nil && cfg.Maven.UseDaemon {
+			if _, hasMvnd := cfg.Tools["mvnd"]; hasMvnd {
+				requiredTools = append(requiredTools, "mvnd")
+			}
 		}
-		envMap, err := mgr.SetupEnvironment(cfg)
-		if err != nil {
-			return err
+
+		// Daemon fast path: a warm daemon hands back the computed environment
+		// and vouches that the tools behind it are installed
+		envMap, fromDaemon := daemonEnvironment(projectRoot)
+		if !fromDaemon {
+			if err := mgr.InstallSpecificTools(cfg, requiredTools); err != nil {
+				return fmt.Errorf("failed to install required tools: %w", err)
+			}
+			envMap, err = mgr.SetupEnvironment(cfg)
+			if err != nil {
+				return err
+			}
 		}
 
 		// Create environment map starting with existing environment
@@ -98,34 +125,145 @@ Examples:
 			env = append(env, fmt.Sprintf("%s=%s", k, v))
 		}
 
-		mvnTool, _ := mgr.GetTool("maven")
-
-		// Resolve Maven version to handle any overrides
-		resolvedVersion, err := mgr.ResolveVersion("maven", toolCfg)
+		mvnExe, err := mavenExecutable(cfg, mgr, toolCfg)
 		if err != nil {
-			return fmt.Errorf("failed to resolve Maven version: %w", err)
+			return err
 		}
 
-		// Create resolved config for Maven
-		resolvedToolCfg := toolCfg
-		resolvedToolCfg.Version = resolvedVersion
+		// Pass the generated toolchains.xml (see 'mvx toolchains') unless
+		// the invocation already carries its own toolchains flag
+		if !hasToolchainsArg(mavenArgs) {
+			if tc := toolchainsFile(mgr); fileReadable(tc) {
+				mavenArgs = append(mavenArgs, "--toolchains", tc)
+			}
+		}
 
-		bin, err := mvnTool.GetPath(resolvedVersion, resolvedToolCfg)
-		if err != nil {
-			return err
+		// Generate settings.xml from maven.settings unless the invocation
+		// already selects one; resolved credentials only live in a private
+		// temporary file for the duration of the build
+		if cfg.Maven != nil && cfg.Maven.Settings != nil && !hasSettingsArg(mavenArgs) {
+			settingsPath, cleanup, err := writeMavenSettingsFile(cfg.Maven.Settings)
+			if err != nil {
+				return err
+			}
+			defer cleanup()
+			mavenArgs = append(mavenArgs, "--settings", settingsPath)
 		}
-		mvnExe := filepath.Join(bin, mvnTool.GetBinaryName())
 
 		c := exec.Command(mvnExe, mavenArgs...)
 		c.Dir = projectRoot
 		c.Env = env
+
+		// Tee the output tail so recognizable errors can drive a version
+		// suggestion; only worth capturing when a managed Java could be
+		// suggested against
 		c.Stdout = os.Stdout
 		c.Stderr = os.Stderr
+		var outputTail *tailBuffer
+		if _, hasJava := cfg.Tools["java"]; hasJava {
+			outputTail = &tailBuffer{}
+			c.Stdout = io.MultiWriter(os.Stdout, outputTail)
+			c.Stderr = io.MultiWriter(os.Stderr, outputTail)
+		}
 		c.Stdin = os.Stdin
-		return c.Run()
+
+		runErr := c.Run()
+		if runErr != nil && outputTail != nil {
+			suggestJavaFix(projectRoot, cfg, outputTail.String(), applyFix)
+		}
+		return runErr
 	},
 }
 
+// mavenExecutable picks the binary 'mvx mvn' runs: the pinned mvnd when
+// maven.useDaemon is set and the daemon is available, otherwise mvn. An
+// unavailable daemon degrades to regular Maven with a warning instead of
+// failing the build.
+func mavenExecutable(cfg *config.Config, mgr *tools.Manager, toolCfg config.ToolConfig) (string, error) {
+	if cfg.Maven != nil && cfg.Maven.UseDaemon {
+		exe, err := mvndExecutable(cfg, mgr)
+		if err == nil {
+			printVerbose("Routing Maven invocation through mvnd: %s", exe)
+			return exe, nil
+		}
+		printWarning("maven.useDaemon is set but mvnd is unavailable (%v); falling back to mvn", err)
+	}
+
+	mvnTool, err := mgr.GetTool("maven")
+	if err != nil {
+		return "", err
+	}
+
+	// Resolve Maven version to handle any overrides
+	resolvedVersion, err := mgr.ResolveVersion("maven", toolCfg)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve Maven version: %w", err)
+	}
+
+	// Create resolved config for Maven
+	resolvedToolCfg := toolCfg
+	resolvedToolCfg.Version = resolvedVersion
+
+	bin, err := mvnTool.GetPath(resolvedVersion, resolvedToolCfg)
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(bin, mvnTool.GetBinaryName()), nil
+}
+
+// mvndExecutable resolves the pinned Maven daemon binary
+func mvndExecutable(cfg *config.Config, mgr *tools.Manager) (string, error) {
+	toolCfg, ok := cfg.Tools["mvnd"]
+	if !ok {
+		return "", fmt.Errorf("no mvnd tool configured in this project")
+	}
+	mvndTool, err := mgr.GetTool("mvnd")
+	if err != nil {
+		return "", err
+	}
+	version, err := mgr.ResolveVersion("mvnd", toolCfg)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve mvnd version: %w", err)
+	}
+	resolved := toolCfg
+	resolved.Version = version
+	bin, err := mvndTool.GetPath(version, resolved)
+	if err != nil {
+		return "", fmt.Errorf("mvnd %s is not installed", version)
+	}
+	return filepath.Join(bin, mvndTool.GetBinaryName()), nil
+}
+
+// hasToolchainsArg reports whether the Maven arguments already select a
+// toolchains file
+func hasToolchainsArg(args []string) bool {
+	for _, arg := range args {
+		switch arg {
+		case "-t", "--toolchains", "-gt", "--global-toolchains":
+			return true
+		}
+	}
+	return false
+}
+
+// hasSettingsArg reports whether the Maven arguments already select a
+// settings file
+func hasSettingsArg(args []string) bool {
+	for _, arg := range args {
+		switch arg {
+		case "-s", "--settings", "-gs", "--global-settings":
+			return true
+		}
+	}
+	return false
+}
+
+// fileReadable reports whether a regular file exists at path
+func fileReadable(path string) bool {
+	info, err := os.Stat(path)
+	return err == nil && info.Mode().IsRegular()
+}
+
 // parseHybridArgs parses os.Args to extract mvx global flags and Maven arguments
 // This allows commands like: mvx --verbose mvn -V clean install
 func parseHybridArgs() ([]string, error) {
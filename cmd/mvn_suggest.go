@@ -0,0 +1,130 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// Patterns in Maven/javac output that indicate a Java version mismatch
+var (
+	invalidReleasePattern   = regexp.MustCompile(`invalid (?:source|target) release: (\d+)`)
+	releaseNotSupportedOut  = regexp.MustCompile(`release version (\d+) not supported`)
+	classFileVersionPattern = regexp.MustCompile(`Unsupported class file major version (\d+)`)
+)
+
+// suggestTailCapacity bounds how much build output is retained for
+// diagnosis. The recognized errors appear in the last lines Maven prints,
+// so a small tail suffices even for very long builds.
+const suggestTailCapacity = 64 << 10 // 64 KiB
+
+// tailBuffer is a bounded writer keeping only the last suggestTailCapacity
+// bytes, so teeing a build's output never grows with the build. Stdout and
+// stderr write concurrently, hence the lock.
+type tailBuffer struct {
+	mu   sync.Mutex
+	data []byte
+}
+
+func (t *tailBuffer) Write(p []byte) (int, error) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	if len(p) >= suggestTailCapacity {
+		t.data = append(t.data[:0], p[len(p)-suggestTailCapacity:]...)
+		return len(p), nil
+	}
+	if overflow := len(t.data) + len(p) - suggestTailCapacity; overflow > 0 {
+		t.data = append(t.data[:0], t.data[overflow:]...)
+	}
+	t.data = append(t.data, p...)
+	return len(p), nil
+}
+
+func (t *tailBuffer) String() string {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	return string(t.data)
+}
+
+// diagnoseJavaVersion scans build output for version mismatch errors and
+// returns the Java version that would fix them (empty when nothing matched).
+func diagnoseJavaVersion(output string) (version string, reason string) {
+	if match := invalidReleasePattern.FindStringSubmatch(output); match != nil {
+		return match[1], fmt.Sprintf("the build targets Java %s", match[1])
+	}
+	if match := releaseNotSupportedOut.FindStringSubmatch(output); match != nil {
+		return match[1], fmt.Sprintf("the build targets Java %s", match[1])
+	}
+	if match := classFileVersionPattern.FindStringSubmatch(output); match != nil {
+		// Class file major version 52 = Java 8, 61 = Java 17, 65 = Java 21, ...
+		major, err := strconv.Atoi(match[1])
+		if err != nil || major < 45 {
+			return "", ""
+		}
+		javaVersion := strconv.Itoa(major - 44)
+		return javaVersion, fmt.Sprintf("a dependency was compiled for Java %s (class file major version %s)", javaVersion, match[1])
+	}
+	return "", ""
+}
+
+// suggestJavaFix prints (and with applyFix, applies) the java version change
+// that would fix a recognized version mismatch in the captured build output.
+func suggestJavaFix(projectRoot string, cfg *config.Config, output string, applyFix bool) {
+	suggested, reason := diagnoseJavaVersion(output)
+	if suggested == "" {
+		return
+	}
+
+	current := cfg.Tools["java"].Version
+	if current == suggested {
+		return
+	}
+
+	printInfo("")
+	printInfo("💡 This looks like a Java version mismatch: %s, but mvx is using Java %s.", reason, current)
+	printInfo("   To fix it, change .mvx/config:")
+	printInfo("")
+	printInfo("     tools: { java: { version: \"%s\" } }", suggested)
+	printInfo("")
+
+	if !applyFix {
+		printInfo("   Or rerun with 'mvx mvn --fix ...' to apply this change automatically.")
+		return
+	}
+
+	// Edit the project's own config file in place, preserving comments and
+	// formatting. cfg is the merged runtime view (extends, profiles, local
+	// and --with overlays applied), so serializing it would persist values
+	// that belong elsewhere.
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		printError("Failed to locate the configuration file: %v", err)
+		return
+	}
+	ext := strings.ToLower(filepath.Ext(configPath))
+	if ext != ".json5" && ext != ".json" {
+		printError("--fix only edits JSON5/JSON configuration; set java to %s in %s manually", suggested, configPath)
+		return
+	}
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		printError("Failed to read %s: %v", configPath, err)
+		return
+	}
+	edited, err := config.SetJSON5Value(string(data), []string{"tools", "java", "version"}, fmt.Sprintf("%q", suggested))
+	if err != nil {
+		printError("Failed to update %s: %v", configPath, err)
+		return
+	}
+	if err := os.WriteFile(configPath, []byte(edited), 0644); err != nil {
+		printError("Failed to write %s: %v", configPath, err)
+		return
+	}
+	printSuccess("✅ Updated java version to %s in %s — rerun the build to use it", suggested, configPath)
+}
@@ -141,3 +141,72 @@ func TestParseHybridArgsEdgeCases(t *testing.T) {
 		}
 	})
 }
+
+func TestDiagnoseJavaVersion(t *testing.T) {
+	tests := []struct {
+		name     string
+		output   string
+		expected string
+	}{
+		{
+			name:     "invalid target release",
+			output:   "[ERROR] Failure executing javac: invalid target release: 21",
+			expected: "21",
+		},
+		{
+			name:     "invalid source release",
+			output:   "[ERROR] invalid source release: 17",
+			expected: "17",
+		},
+		{
+			name:     "release not supported",
+			output:   "[ERROR] error: release version 21 not supported",
+			expected: "21",
+		},
+		{
+			name:     "unsupported class file version",
+			output:   "Caused by: java.lang.IllegalArgumentException: Unsupported class file major version 65",
+			expected: "21",
+		},
+		{
+			name:     "unrelated failure",
+			output:   "[ERROR] Failed to execute goal: compilation failure",
+			expected: "",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			version, _ := diagnoseJavaVersion(tt.output)
+			if version != tt.expected {
+				t.Errorf("diagnoseJavaVersion() = %q, expected %q", version, tt.expected)
+			}
+		})
+	}
+}
+
+func TestTailBufferKeepsOnlyTheTail(t *testing.T) {
+	var tail tailBuffer
+
+	// Fill past capacity with line-sized writes
+	line := make([]byte, 1024)
+	for i := range line {
+		line[i] = 'x'
+	}
+	for written := 0; written < suggestTailCapacity*2; written += len(line) {
+		if _, err := tail.Write(line); err != nil {
+			t.Fatalf("write failed: %v", err)
+		}
+	}
+	if _, err := tail.Write([]byte("invalid target release: 21\n")); err != nil {
+		t.Fatalf("write failed: %v", err)
+	}
+
+	captured := tail.String()
+	if len(captured) > suggestTailCapacity {
+		t.Errorf("tail grew past capacity: %d bytes", len(captured))
+	}
+	if version, _ := diagnoseJavaVersion(captured); version != "21" {
+		t.Errorf("expected the diagnosis pattern to survive in the tail, got %q", version)
+	}
+}
@@ -0,0 +1,174 @@
+package cmd
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// detectedTool represents a tool suggestion derived from build files in the project
+type detectedTool struct {
+	Name    string
+	Version string
+	Reason  string // which build file triggered the suggestion
+}
+
+// isInteractive reports whether stdin is a terminal, so we never prompt in
+// CI — CI mode counts as non-interactive even when stdin looks like a TTY
+func isInteractive() bool {
+	if util.IsCI() {
+		return false
+	}
+	info, err := os.Stdin.Stat()
+	if err != nil {
+		return false
+	}
+	return info.Mode()&os.ModeCharDevice != 0
+}
+
+// detectBuildFiles inspects the project directory for well-known build files
+// and returns tool suggestions for a minimal configuration
+func detectBuildFiles(projectRoot string) []detectedTool {
+	var detected []detectedTool
+
+	exists := func(name string) bool {
+		_, err := os.Stat(filepath.Join(projectRoot, name))
+		return err == nil
+	}
+
+	if exists("pom.xml") {
+		detected = append(detected,
+			detectedTool{Name: "java", Version: "21", Reason: "pom.xml"},
+			detectedTool{Name: "maven", Version: "3.9", Reason: "pom.xml"})
+	} else if exists("build.gradle") || exists("build.gradle.kts") {
+		detected = append(detected, detectedTool{Name: "java", Version: "21", Reason: "build.gradle"})
+	}
+	if exists("package.json") {
+		detected = append(detected, detectedTool{Name: "node", Version: "lts", Reason: "package.json"})
+	}
+	if exists("go.mod") {
+		detected = append(detected, detectedTool{Name: "go", Version: "1.24", Reason: "go.mod"})
+	}
+
+	return detected
+}
+
+// offerOnboarding runs a guided first-run flow when mvx is invoked in a
+// project without configuration: detect build files, propose a minimal
+// config, and optionally install a git hook that keeps tools in sync.
+// Returns true if a configuration was created.
+func offerOnboarding(projectRoot string) bool {
+	if !isInteractive() {
+		return false
+	}
+
+	reader := bufio.NewReader(os.Stdin)
+
+	printInfo("👋 No mvx configuration found in this project.")
+	printInfo("")
+
+	detected := detectBuildFiles(projectRoot)
+	if len(detected) > 0 {
+		printInfo("Detected build files suggest the following tools:")
+		for _, tool := range detected {
+			printInfo("  %s %s (from %s)", tool.Name, tool.Version, tool.Reason)
+		}
+	} else {
+		printInfo("No build files detected; a sample configuration will be created.")
+	}
+	printInfo("")
+
+	if !askYesNo(reader, "Create .mvx/config.json5 with this configuration?") {
+		return false
+	}
+
+	if err := writeOnboardingConfig(projectRoot, detected); err != nil {
+		printError("failed to write configuration: %v", err)
+		return false
+	}
+	printSuccess("✅ Created .mvx/config.json5")
+
+	// Offer a git hook that re-runs setup after checkouts so tool versions
+	// stay in sync with the branch being worked on
+	if _, err := os.Stat(filepath.Join(projectRoot, ".git")); err == nil {
+		if askYesNo(reader, "Install a git post-checkout hook that runs 'mvx setup'?") {
+			if err := installSetupGitHook(projectRoot); err != nil {
+				printWarning("failed to install git hook: %v", err)
+			} else {
+				printSuccess("✅ Installed .git/hooks/post-checkout")
+			}
+		}
+	}
+
+	printInfo("")
+	printInfo("Next steps:")
+	printInfo("  1. Review .mvx/config.json5 and adjust tool versions")
+	printInfo("  2. Run 'mvx setup' to install the tools")
+
+	return true
+}
+
+// askYesNo prompts the user with a yes/no question (default: no)
+func askYesNo(reader *bufio.Reader, question string) bool {
+	fmt.Printf("%s [y/N] ", question)
+	answer, err := reader.ReadString('\n')
+	if err != nil {
+		return false
+	}
+	answer = strings.ToLower(strings.TrimSpace(answer))
+	return answer == "y" || answer == "yes"
+}
+
+// writeOnboardingConfig writes a minimal config based on detected tools,
+// falling back to the default sample config when nothing was detected
+func writeOnboardingConfig(projectRoot string, detected []detectedTool) error {
+	mvxDir := filepath.Join(projectRoot, ".mvx")
+	if err := os.MkdirAll(mvxDir, 0755); err != nil {
+		return fmt.Errorf("failed to create .mvx directory: %w", err)
+	}
+
+	content := getDefaultJSON5Config()
+	if len(detected) > 0 {
+		var sb strings.Builder
+		sb.WriteString("{\n")
+		sb.WriteString("  // mvx configuration (generated by first-run onboarding)\n")
+		sb.WriteString("  // See: https://github.com/gnodet/mvx for documentation\n\n")
+		sb.WriteString("  project: {\n")
+		sb.WriteString(fmt.Sprintf("    name: %q,\n", filepath.Base(projectRoot)))
+		sb.WriteString("  },\n\n")
+		sb.WriteString("  tools: {\n")
+		for _, tool := range detected {
+			sb.WriteString(fmt.Sprintf("    // detected from %s\n", tool.Reason))
+			sb.WriteString(fmt.Sprintf("    %s: {\n      version: %q,\n    },\n", tool.Name, tool.Version))
+		}
+		sb.WriteString("  },\n")
+		sb.WriteString("}\n")
+		content = sb.String()
+	}
+
+	configPath := filepath.Join(mvxDir, "config.json5")
+	if _, err := os.Stat(configPath); err == nil {
+		return fmt.Errorf("configuration file already exists: %s", configPath)
+	}
+	return os.WriteFile(configPath, []byte(content), 0644)
+}
+
+// installSetupGitHook writes a post-checkout hook that keeps tools in sync
+func installSetupGitHook(projectRoot string) error {
+	hooksDir := filepath.Join(projectRoot, ".git", "hooks")
+	if err := os.MkdirAll(hooksDir, 0755); err != nil {
+		return err
+	}
+
+	hookPath := filepath.Join(hooksDir, "post-checkout")
+	if _, err := os.Stat(hookPath); err == nil {
+		return fmt.Errorf("hook already exists: %s", hookPath)
+	}
+
+	hook := "#!/bin/sh\n# Installed by mvx onboarding: keep tools in sync with the checked-out branch\nmvx setup --tools-only >/dev/null 2>&1 || true\n"
+	return os.WriteFile(hookPath, []byte(hook), 0755)
+}
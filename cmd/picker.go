@@ -0,0 +1,145 @@
+package cmd
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// runRecord remembers when a command last ran and whether it succeeded,
+// shown in the interactive picker
+type runRecord struct {
+	LastRun time.Time `json:"last_run"`
+	Success bool      `json:"success"`
+}
+
+// runHistoryPath returns the path of the local run history file
+func runHistoryPath(projectRoot string) string {
+	return filepath.Join(projectRoot, ".mvx", "run-history.json")
+}
+
+// loadRunHistory loads the per-project run history (empty map on any error)
+func loadRunHistory(projectRoot string) map[string]runRecord {
+	history := make(map[string]runRecord)
+	data, err := os.ReadFile(runHistoryPath(projectRoot))
+	if err != nil {
+		return history
+	}
+	if err := json.Unmarshal(data, &history); err != nil {
+		return make(map[string]runRecord)
+	}
+	return history
+}
+
+// recordRun updates the run history after a command execution
+func recordRun(projectRoot, commandName string, success bool) {
+	history := loadRunHistory(projectRoot)
+	history[commandName] = runRecord{LastRun: time.Now(), Success: success}
+	if data, err := json.MarshalIndent(history, "", "  "); err == nil {
+		_ = os.WriteFile(runHistoryPath(projectRoot), data, 0644)
+	}
+}
+
+// fuzzyMatch reports whether query matches name as a case-insensitive
+// subsequence, so "bt" matches "build-test"
+func fuzzyMatch(query, name string) bool {
+	query = strings.ToLower(query)
+	name = strings.ToLower(name)
+	for _, r := range query {
+		idx := strings.IndexRune(name, r)
+		if idx < 0 {
+			return false
+		}
+		name = name[idx+1:]
+	}
+	return true
+}
+
+// formatRunStatus renders the last-run column for the picker
+func formatRunStatus(record runRecord, known bool) string {
+	if !known {
+		return ""
+	}
+	status := "✅"
+	if !record.Success {
+		status = "❌"
+	}
+	return fmt.Sprintf("%s %s", status, record.LastRun.Format("2006-01-02 15:04"))
+}
+
+// pickCommand runs an interactive fuzzy picker over the configured commands
+// and returns the selected command name. Typing narrows the list; a number
+// selects an entry; an empty line runs the single remaining match.
+func pickCommand(projectRoot string, cfg *config.Config) (string, error) {
+	var names []string
+	for name := range cfg.Commands {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	history := loadRunHistory(projectRoot)
+	reader := bufio.NewReader(os.Stdin)
+	query := ""
+
+	for {
+		var matches []string
+		for _, name := range names {
+			if fuzzyMatch(query, name) {
+				matches = append(matches, name)
+			}
+		}
+
+		fmt.Println()
+		if len(matches) == 0 {
+			fmt.Printf("No commands match %q\n", query)
+		}
+		maxLen := 0
+		for _, name := range matches {
+			if len(name) > maxLen {
+				maxLen = len(name)
+			}
+		}
+		for i, name := range matches {
+			description := cfg.Commands[name].Description
+			record, known := history[name]
+			line := fmt.Sprintf("  %2d) %-*s  %s", i+1, maxLen, name, description)
+			if status := formatRunStatus(record, known); status != "" {
+				line += fmt.Sprintf("  (last run %s)", status)
+			}
+			fmt.Println(line)
+		}
+
+		fmt.Printf("\nFilter (or number to run, empty runs single match, q quits) [%s]: ", query)
+		input, err := reader.ReadString('\n')
+		if err != nil {
+			return "", fmt.Errorf("failed to read selection: %w", err)
+		}
+		input = strings.TrimSpace(input)
+
+		switch {
+		case input == "q" || input == "quit":
+			return "", fmt.Errorf("no command selected")
+		case input == "":
+			if len(matches) == 1 {
+				return matches[0], nil
+			}
+		default:
+			if index, err := strconv.Atoi(input); err == nil {
+				if index >= 1 && index <= len(matches) {
+					return matches[index-1], nil
+				}
+				fmt.Printf("No entry %d\n", index)
+				continue
+			}
+			query = input
+		}
+	}
+}
@@ -1,15 +1,18 @@
 package cmd
 
 import (
+	"encoding/json"
 	"fmt"
 	"os"
 	"path/filepath"
 	"runtime"
+	"sort"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
 	"github.com/gnodet/mvx/pkg/executor"
 	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/spf13/cobra"
 )
 
@@ -22,6 +25,7 @@ var (
 	// Global flags
 	verbose bool
 	quiet   bool
+	profile string
 
 	// Auto-setup cache to avoid repeated setup
 	autoSetupDone bool
@@ -53,6 +57,45 @@ For more information, visit: https://github.com/gnodet/mvx`,
 // Execute adds all child commands to the root command and sets flags appropriately.
 // This is called by main.main(). It only needs to happen once to the rootCmd.
 func Execute() error {
+	// Only mvx's own leading tokens are scanned, so flags forwarded to a
+	// wrapped tool ('mvx mvn --offline clean install') are never picked up
+	mvxArgs := preParseArgs()
+
+	// The --profile flag must be visible before cobra parses flags, because
+	// auto-setup below already loads the configuration
+	applyProfileFlag(mvxArgs)
+
+	// Export --quiet early so command execution can buffer step output
+	applyQuietFlag(mvxArgs)
+
+	// Export --include-prereleases so version resolution during auto-setup
+	// already sees RCs and betas
+	applyPrereleaseFlag(mvxArgs)
+
+	// Export --offline so auto-setup never touches the network
+	applyOfflineFlag(mvxArgs)
+
+	// Export --with overrides so configuration loading during auto-setup
+	// already sees the temporary tool versions
+	applyWithFlag(mvxArgs)
+
+	// Export --hermetic so every command runs with the minimal environment
+	applyHermeticFlag(mvxArgs)
+
+	// Export --ci so prompts and progress animations are disabled from the
+	// start (auto-detected CI environments need no flag)
+	applyCIFlag(mvxArgs)
+
+	// Export --format json early: it implies quiet, so human-formatted
+	// progress never mixes into machine-readable stdout
+	applyFormatFlag(mvxArgs)
+
+	// Export --trace-http before auto-setup so its downloads are traced too
+	applyTraceHTTPFlag(mvxArgs)
+
+	// Export --no-parallel-extract before auto-setup installs anything
+	applyNoParallelExtractFlag(mvxArgs)
+
 	// Auto-setup tools and environment before executing any command
 	if err := autoSetupEnvironment(); err != nil {
 		// If auto-setup fails, we should fail the command execution
@@ -75,6 +118,28 @@ func Execute() error {
 	return rootCmd.Execute()
 }
 
+// Exit codes distinguish configuration problems from tool and build
+// failures, so CI pipelines can branch on why mvx stopped
+const (
+	ExitToolFailure = 1
+	ExitConfigError = 2
+)
+
+// configurationError marks an error as a configuration problem for ExitCode
+type configurationError struct{ err error }
+
+func (e *configurationError) Error() string { return e.err.Error() }
+func (e *configurationError) Unwrap() error { return e.err }
+
+// ExitCode maps an error returned by Execute (or printed by a command
+// handler) to the process exit code, via the error taxonomy in errors.go
+func ExitCode(err error) int {
+	if err == nil {
+		return 0
+	}
+	return exitCodes[classifyError(err)]
+}
+
 // SetVersionInfo sets the version information from main
 func SetVersionInfo(v, c, d string) {
 	version = v
@@ -84,10 +149,210 @@ func SetVersionInfo(v, c, d string) {
 
 func isWindows() bool { return runtime.GOOS == "windows" }
 
+// preParseArgs returns the tokens of this invocation that belong to mvx
+// itself, for the pre-parse flag exporters below. Scanning stops at a "--"
+// separator and at the first token naming a command that forwards its
+// arguments verbatim (mvn, exec, run, the dynamic tool commands), so a
+// wrapped tool's own --offline or -q is never mistaken for an mvx flag.
+// Dynamic tool and custom commands are not registered yet when this runs,
+// so any unknown bare token also stops the scan; built-in subcommands that
+// parse flags normally (setup, tools, ...) keep it going, because flags
+// after them are still mvx's.
+func preParseArgs() []string {
+	var own []string
+	for i := 1; i < len(os.Args); i++ {
+		arg := os.Args[i]
+		if arg == "--" {
+			break
+		}
+		if !strings.HasPrefix(arg, "-") {
+			cmd := findStaticCommand(arg)
+			if cmd == nil || cmd.DisableFlagParsing {
+				break
+			}
+			own = append(own, arg)
+			continue
+		}
+		own = append(own, arg)
+		// These global flags take their value as the next argument
+		if (arg == "--profile" || arg == "--with" || arg == "--format") && i+1 < len(os.Args) {
+			own = append(own, os.Args[i+1])
+			i++
+		}
+	}
+	return own
+}
+
+// findStaticCommand looks up a registered subcommand by name or alias
+func findStaticCommand(name string) *cobra.Command {
+	for _, cmd := range rootCmd.Commands() {
+		if cmd.Name() == name || cmd.HasAlias(name) {
+			return cmd
+		}
+	}
+	return nil
+}
+
+// applyProfileFlag exports --profile as MVX_PROFILE before flag parsing, so
+// configuration loading during auto-setup already sees the active profile
+func applyProfileFlag(args []string) {
+	for i, arg := range args {
+		if arg == "--profile" && i+1 < len(args) {
+			os.Setenv("MVX_PROFILE", args[i+1])
+			return
+		}
+		if strings.HasPrefix(arg, "--profile=") {
+			os.Setenv("MVX_PROFILE", strings.TrimPrefix(arg, "--profile="))
+			return
+		}
+	}
+}
+
+// applyWithFlag exports --with tool@version overrides as MVX_WITH before
+// flag parsing, so configuration loading during auto-setup already applies
+// the per-invocation versions
+func applyWithFlag(args []string) {
+	var overrides []string
+	for i, arg := range args {
+		if arg == "--with" && i+1 < len(args) {
+			overrides = append(overrides, args[i+1])
+		}
+		if strings.HasPrefix(arg, "--with=") {
+			overrides = append(overrides, strings.TrimPrefix(arg, "--with="))
+		}
+	}
+	if len(overrides) > 0 {
+		os.Setenv("MVX_WITH", strings.Join(overrides, ","))
+	}
+}
+
+// applyCIFlag exports --ci as MVX_CI before flag parsing, so progress
+// rendering and prompts during auto-setup already behave CI-safely. Without
+// the flag, CI environments are auto-detected (CI, GITHUB_ACTIONS, GITLAB_CI).
+func applyCIFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--ci" {
+			os.Setenv("MVX_CI", "true")
+			return
+		}
+	}
+}
+
+// applyTraceHTTPFlag exports --trace-http as MVX_TRACE_HTTP before flag
+// parsing, so requests made during auto-setup are already traced
+func applyTraceHTTPFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--trace-http" {
+			os.Setenv("MVX_TRACE_HTTP", "true")
+			return
+		}
+	}
+}
+
+// applyNoParallelExtractFlag exports --no-parallel-extract as
+// MVX_NO_PARALLEL_EXTRACT, the escape hatch for network filesystems where
+// concurrent archive writes hurt more than they help
+func applyNoParallelExtractFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--no-parallel-extract" {
+			os.Setenv("MVX_NO_PARALLEL_EXTRACT", "true")
+			return
+		}
+	}
+}
+
+// applyFormatFlag exports --format as MVX_OUTPUT_FORMAT before flag
+// parsing. JSON output implies quiet mode, so commands that emit structured
+// results keep stdout parseable.
+func applyFormatFlag(args []string) {
+	for i, arg := range args {
+		value := ""
+		if arg == "--format" && i+1 < len(args) {
+			value = args[i+1]
+		} else if strings.HasPrefix(arg, "--format=") {
+			value = strings.TrimPrefix(arg, "--format=")
+		}
+		if value == "json" {
+			os.Setenv("MVX_OUTPUT_FORMAT", "json")
+			os.Setenv("MVX_QUIET", "true")
+			return
+		}
+	}
+}
+
+// jsonOutput reports whether --format json is active for this invocation
+func jsonOutput() bool {
+	return os.Getenv("MVX_OUTPUT_FORMAT") == "json"
+}
+
+// printJSON renders a machine-readable result on stdout
+func printJSON(v interface{}) error {
+	data, err := json.MarshalIndent(v, "", "  ")
+	if err != nil {
+		return err
+	}
+	fmt.Println(string(data))
+	return nil
+}
+
+// applyHermeticFlag exports --hermetic as MVX_HERMETIC before flag parsing,
+// so every command this invocation runs strips the inherited environment
+func applyHermeticFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--hermetic" {
+			os.Setenv("MVX_HERMETIC", "true")
+			return
+		}
+	}
+}
+
+// applyQuietFlag exports --quiet as MVX_QUIET before flag parsing, so the
+// executor buffers command output (printed only on failure) from the start
+func applyQuietFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--quiet" || arg == "-q" {
+			os.Setenv("MVX_QUIET", "true")
+			return
+		}
+	}
+}
+
+// applyOfflineFlag exports --offline as MVX_OFFLINE before flag parsing, so
+// auto-setup and version resolution never touch the network
+func applyOfflineFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--offline" {
+			os.Setenv("MVX_OFFLINE", "true")
+			return
+		}
+	}
+}
+
+// applyPrereleaseFlag exports --include-prereleases as
+// MVX_INCLUDE_PRERELEASES before flag parsing, so version resolution can
+// surface RCs, betas and milestone builds
+func applyPrereleaseFlag(args []string) {
+	for _, arg := range args {
+		if arg == "--include-prereleases" {
+			os.Setenv("MVX_INCLUDE_PRERELEASES", "true")
+			return
+		}
+	}
+}
+
 func init() {
 	// Global flags
 	rootCmd.PersistentFlags().BoolVarP(&verbose, "verbose", "v", false, "verbose output")
 	rootCmd.PersistentFlags().BoolVarP(&quiet, "quiet", "q", false, "quiet output (errors only)")
+	rootCmd.PersistentFlags().StringVar(&profile, "profile", "", "configuration profile to activate (also MVX_PROFILE)")
+	rootCmd.PersistentFlags().Bool("include-prereleases", false, "let version resolution surface RCs, betas and milestone builds (also MVX_INCLUDE_PRERELEASES)")
+	rootCmd.PersistentFlags().Bool("offline", false, "forbid network access, using only the lockfile and cached data (also MVX_OFFLINE)")
+	rootCmd.PersistentFlags().StringArray("with", nil, "override a pinned tool version for this invocation, e.g. --with java@21 (repeatable, also MVX_WITH)")
+	rootCmd.PersistentFlags().Bool("hermetic", false, "run commands with a minimal mvx-controlled environment (also MVX_HERMETIC)")
+	rootCmd.PersistentFlags().Bool("ci", false, "CI mode: no prompts or progress animations, annotation-friendly output (also MVX_CI, auto-detected)")
+	rootCmd.PersistentFlags().String("format", "text", "output format: text or json (json implies --quiet, also MVX_OUTPUT_FORMAT)")
+	rootCmd.PersistentFlags().Bool("trace-http", false, "log every HTTP request with status, duration, retries, proxy and cache verdict, secrets redacted (also MVX_TRACE_HTTP)")
+	rootCmd.PersistentFlags().Bool("no-parallel-extract", false, "extract archives sequentially, for network filesystems (also MVX_NO_PARALLEL_EXTRACT)")
 
 	// Add subcommands
 	rootCmd.AddCommand(versionCmd)
@@ -103,7 +368,7 @@ func init() {
 // Helper functions for output
 func printVerbose(format string, args ...interface{}) {
 	if verbose && !quiet {
-		fmt.Fprintf(os.Stderr, "[VERBOSE] "+format+"\n", args...)
+		fmt.Fprint(os.Stderr, util.RedactText(fmt.Sprintf("[VERBOSE] "+format+"\n", args...)))
 	}
 }
 
@@ -114,7 +379,32 @@ func printInfo(format string, args ...interface{}) {
 }
 
 func printError(format string, args ...interface{}) {
-	fmt.Fprintf(os.Stderr, "Error: "+format+"\n", args...)
+	message := util.RedactText(fmt.Sprintf(format, args...))
+	fmt.Fprint(os.Stderr, "Error: "+message+"\n")
+	emitAnnotation("error", message)
+}
+
+// emitAnnotation prints a GitHub Actions workflow command so errors and
+// warnings surface as annotations on the run. Only active in CI mode under
+// GitHub Actions; workflow commands go to stdout.
+func emitAnnotation(level, message string) {
+	if !util.IsCI() || !util.IsGitHubActions() {
+		return
+	}
+	// Newlines and percent signs must be escaped in workflow command data
+	escaped := strings.NewReplacer("%", "%25", "\r", "%0D", "\n", "%0A").Replace(message)
+	fmt.Printf("::%s::%s\n", level, escaped)
+}
+
+// printGroup starts a collapsible log group (GitHub Actions ::group:: in CI
+// mode, a plain info line otherwise); the returned func closes it
+func printGroup(title string) func() {
+	if util.IsCI() && util.IsGitHubActions() {
+		fmt.Printf("::group::%s\n", title)
+		return func() { fmt.Println("::endgroup::") }
+	}
+	printInfo("%s", title)
+	return func() {}
 }
 
 // autoSetupEnvironment automatically installs tools and sets up environment
@@ -148,6 +438,14 @@ func autoSetupEnvironment() error {
 		return nil
 	}
 
+	// Fail fast when the project requires a newer mvx (mvxVersion field)
+	if err := cfg.CheckMvxVersion(version); err != nil {
+		return &configurationError{err}
+	}
+
+	// Run any due maintenance tasks (opt-in, interval-gated, no daemon)
+	runDueMaintenance(cfg)
+
 	// Skip if no tools configured
 	if len(cfg.Tools) == 0 {
 		printVerbose("No tools configured, skipping auto-setup")
@@ -160,6 +458,24 @@ func autoSetupEnvironment() error {
 		return fmt.Errorf("failed to create tool manager: %w", err)
 	}
 
+	// Register custom (URL-based) tools declared in the configuration
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	// Lockfile fast path: when every pin is installed and verified, skip
+	// installation checks and online resolution entirely — no-op invocations
+	// must not stall on slow corporate networks
+	if manager.LockFileSatisfied(cfg) {
+		printVerbose("Lockfile satisfied by verified installed tools, skipping setup checks")
+		if err := setupGlobalEnvironment(cfg, manager); err != nil {
+			return fmt.Errorf("failed to setup global environment: %w", err)
+		}
+		autoSetupDone = true
+		return nil
+	}
+
 	// Check if tools need installation (excluding system tools)
 	toolsToInstall, err := manager.GetToolsNeedingInstallation(cfg)
 	if err != nil {
@@ -177,6 +493,16 @@ func autoSetupEnvironment() error {
 		filteredToolsToInstall[toolName] = toolConfig
 	}
 
+	// Lazy setup: leave missing tools uninstalled until a command actually
+	// needs them (the executor installs on first use via EnsureTool)
+	if cfg.LazySetup() {
+		os.Setenv("MVX_SETUP_LAZY", "true")
+		if len(filteredToolsToInstall) > 0 {
+			printVerbose("Lazy setup: deferring installation of %d tool(s) until first use", len(filteredToolsToInstall))
+			filteredToolsToInstall = nil
+		}
+	}
+
 	// Install missing tools if any
 	if len(filteredToolsToInstall) > 0 {
 		printInfo("🔧 Auto-installing %d missing tool(s)...", len(filteredToolsToInstall))
@@ -232,9 +558,11 @@ func setupGlobalEnvironment(cfg *config.Config, manager *tools.Manager) error {
 }
 
 func printWarning(format string, args ...interface{}) {
+	message := fmt.Sprintf(format, args...)
 	if !quiet {
-		fmt.Fprintf(os.Stderr, "Warning: "+format+"\n", args...)
+		fmt.Fprint(os.Stderr, "Warning: "+message+"\n")
 	}
+	emitAnnotation("warning", message)
 }
 
 func printSuccess(format string, args ...interface{}) {
@@ -290,6 +618,21 @@ func addCustomCommands() error {
 
 	exec := executor.NewExecutor(cfg, manager, projectRoot)
 
+	// Register help-output sections for the groups commands declare, so the
+	// help text stays readable once a project has dozens of commands
+	registered := make(map[string]bool)
+	var groups []string
+	for _, cmdConfig := range cfg.Commands {
+		if cmdConfig.Group != "" && !registered[cmdConfig.Group] {
+			registered[cmdConfig.Group] = true
+			groups = append(groups, cmdConfig.Group)
+		}
+	}
+	sort.Strings(groups)
+	for _, group := range groups {
+		rootCmd.AddGroup(&cobra.Group{ID: group, Title: groupTitle(group)})
+	}
+
 	// Add each custom command as a top-level command
 	for cmdName, cmdConfig := range cfg.Commands {
 		// Skip commands with spaces (they're subcommands, handled by their parent command)
@@ -300,12 +643,18 @@ func addCustomCommands() error {
 
 		// Create a new cobra command for this custom command
 		customCmd := createCustomCommand(cmdName, cmdConfig, exec)
+		customCmd.GroupID = cmdConfig.Group
 		rootCmd.AddCommand(customCmd)
 	}
 
 	return nil
 }
 
+// groupTitle renders a command group id as a help-output section header
+func groupTitle(group string) string {
+	return strings.ToUpper(group[:1]) + group[1:] + " commands:"
+}
+
 // createCustomCommand creates a cobra command for a custom command
 func createCustomCommand(cmdName string, cmdConfig config.CommandConfig, exec *executor.Executor) *cobra.Command {
 	cmd := &cobra.Command{
@@ -350,6 +699,12 @@ func addToolCommands() error {
 		return fmt.Errorf("failed to create tool manager: %w", err)
 	}
 
+	// Register custom (URL-based) tools so they get tool commands too
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
 	// Create executor
 	exec := executor.NewExecutor(cfg, manager, projectRoot)
 
@@ -0,0 +1,63 @@
+package cmd
+
+import (
+	"os"
+	"reflect"
+	"testing"
+)
+
+func TestPreParseArgs(t *testing.T) {
+	originalArgs := os.Args
+	defer func() { os.Args = originalArgs }()
+
+	tests := []struct {
+		name     string
+		args     []string
+		expected []string
+	}{
+		{
+			name:     "global flags before a forwarding command",
+			args:     []string{"mvx", "--verbose", "--offline", "mvn", "clean", "install"},
+			expected: []string{"--verbose", "--offline"},
+		},
+		{
+			name:     "tool flags after mvn are not mvx's",
+			args:     []string{"mvx", "mvn", "--offline", "clean", "install"},
+			expected: nil,
+		},
+		{
+			name:     "quiet inside run args is not mvx's",
+			args:     []string{"mvx", "run", "build", "--", "-q"},
+			expected: nil,
+		},
+		{
+			name:     "unknown command (dynamic tool) stops the scan",
+			args:     []string{"mvx", "npm", "install", "--offline"},
+			expected: nil,
+		},
+		{
+			name:     "flags after a flag-parsing subcommand stay mvx's",
+			args:     []string{"mvx", "setup", "--offline"},
+			expected: []string{"setup", "--offline"},
+		},
+		{
+			name:     "double dash stops the scan",
+			args:     []string{"mvx", "--verbose", "--", "--offline"},
+			expected: []string{"--verbose"},
+		},
+		{
+			name:     "value-taking flags keep their value",
+			args:     []string{"mvx", "--profile", "ci", "mvn", "verify"},
+			expected: []string{"--profile", "ci"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			os.Args = tt.args
+			if got := preParseArgs(); !reflect.DeepEqual(got, tt.expected) {
+				t.Errorf("preParseArgs() = %v, expected %v", got, tt.expected)
+			}
+		})
+	}
+}
@@ -5,6 +5,7 @@ import (
 	"os"
 	"sort"
 	"strings"
+	"time"
 
 	"github.com/gnodet/mvx/pkg/config"
 	"github.com/gnodet/mvx/pkg/executor"
@@ -25,11 +26,57 @@ Examples:
   mvx run build              # Run the build command
   mvx run test               # Run the test command  
   mvx run demo gogo          # Run demo command with arguments
+  mvx run build --module api # Pass a declared named argument
+  mvx run build --help       # Show a command's declared arguments
+  mvx run build --java 17,21,24-ea        # Matrix: run once per Java version
+  mvx run --parallel lint test docs       # Run several commands concurrently
+  mvx run --parallel --continue-on-error lint test  # Don't stop at the first failure
   mvx run                    # List all available commands`,
 
+	DisableFlagParsing: true, // command arguments (--module etc.) belong to the command
 	Run: func(cmd *cobra.Command, args []string) {
+		if len(args) > 0 && (args[0] == "--help" || args[0] == "-h") {
+			cmd.Help()
+			return
+		}
+
+		// Manual flag scan: flag parsing is disabled so command arguments
+		// pass through untouched
+		parallel := false
+		continueOnError := false
+		for len(args) > 0 {
+			if args[0] == "--parallel" {
+				parallel = true
+			} else if args[0] == "--continue-on-error" {
+				continueOnError = true
+			} else {
+				break
+			}
+			args = args[1:]
+		}
+
+		if parallel {
+			if len(args) == 0 {
+				printError("--parallel requires at least one command")
+				os.Exit(1)
+			}
+			if err := runCommandsParallel(args, continueOnError); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+			return
+		}
+
 		if len(args) == 0 {
-			// No command specified, list available commands
+			// No command specified: open the fuzzy picker on a TTY,
+			// fall back to a plain list otherwise
+			if isInteractive() {
+				if err := pickAndRunCommand(); err != nil {
+					printError("%v", err)
+					os.Exit(1)
+				}
+				return
+			}
 			if err := listCommands(); err != nil {
 				printError("%v", err)
 				os.Exit(1)
@@ -40,9 +87,19 @@ Examples:
 		commandName := args[0]
 		commandArgs := args[1:]
 
-		if err := runCustomCommand(commandName, commandArgs); err != nil {
-			printError("%v", err)
-			os.Exit(1)
+		// `mvx run build --help` documents the command's declared arguments
+		for _, arg := range commandArgs {
+			if arg == "--help" || arg == "-h" {
+				if err := showCommandHelp(commandName); err != nil {
+					printError("%v", err)
+					os.Exit(1)
+				}
+				return
+			}
+		}
+
+		if err := runCommandWithMatrix(commandName, commandArgs); err != nil {
+			exitWithError(err)
 		}
 	},
 }
@@ -79,26 +136,30 @@ func listCommands() error {
 		return nil
 	}
 
-	printInfo("Available commands:")
-	printInfo("")
-
-	// Sort commands for consistent output
-	var names []string
+	// Sort commands for consistent output, bucketed by their declared group
+	// (ungrouped commands come first)
+	byGroup := make(map[string][]string)
 	for name := range commands {
-		names = append(names, name)
+		byGroup[cfg.Commands[name].Group] = append(byGroup[cfg.Commands[name].Group], name)
+	}
+	var groups []string
+	for group := range byGroup {
+		sort.Strings(byGroup[group])
+		if group != "" {
+			groups = append(groups, group)
+		}
 	}
-	sort.Strings(names)
+	sort.Strings(groups)
 
 	// Find the longest command name for alignment
 	maxLen := 0
-	for _, name := range names {
+	for name := range commands {
 		if len(name) > maxLen {
 			maxLen = len(name)
 		}
 	}
 
-	// Print commands with descriptions
-	for _, name := range names {
+	printCommand := func(name string) {
 		description := commands[name]
 		if description == "" {
 			description = "No description"
@@ -109,13 +170,130 @@ func listCommands() error {
 		printInfo("  %s%s%s", name, padding, description)
 	}
 
-	printInfo("")
+	if len(byGroup[""]) > 0 {
+		printInfo("Available commands:")
+		printInfo("")
+		for _, name := range byGroup[""] {
+			printCommand(name)
+		}
+		printInfo("")
+	}
+	for _, group := range groups {
+		printInfo("%s commands:", strings.ToUpper(group[:1])+group[1:])
+		printInfo("")
+		for _, name := range byGroup[group] {
+			printCommand(name)
+		}
+		printInfo("")
+	}
+
 	printInfo("Usage: mvx run <command> [args...]")
 	printInfo("   or: mvx <command> [args...]  (for built-in commands)")
 
 	return nil
 }
 
+// runCommandsParallel executes several configured commands concurrently with
+// prefixed output
+func runCommandsParallel(names []string, continueOnError bool) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	exec := executor.NewExecutor(cfg, manager, projectRoot)
+	return exec.ExecuteCommandsParallel(names, tools.GetDefaultConcurrency(), continueOnError)
+}
+
+// showCommandHelp prints a command's description and declared arguments
+func showCommandHelp(commandName string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	cmdConfig, exists := cfg.Commands[commandName]
+	if !exists {
+		return fmt.Errorf("unknown command: %s", commandName)
+	}
+
+	printInfo("Usage: mvx run %s [options] [args...]", commandName)
+	if cmdConfig.Description != "" {
+		printInfo("")
+		printInfo("  %s", cmdConfig.Description)
+	}
+
+	if len(cmdConfig.Args) > 0 {
+		printInfo("")
+		printInfo("Options:")
+		for _, arg := range cmdConfig.Args {
+			usage := "--" + arg.Name
+			if arg.Type != "bool" {
+				usage += " <value>"
+			}
+			var notes []string
+			if arg.Required {
+				notes = append(notes, "required")
+			}
+			if arg.Default != "" {
+				notes = append(notes, "default: "+arg.Default)
+			}
+			description := arg.Description
+			if len(notes) > 0 {
+				description = strings.TrimSpace(description + " (" + strings.Join(notes, ", ") + ")")
+			}
+			printInfo("  %-24s %s", usage, description)
+		}
+	}
+
+	if len(cmdConfig.DependsOn) > 0 {
+		printInfo("")
+		printInfo("Runs after: %s", strings.Join(cmdConfig.DependsOn, ", "))
+	}
+	return nil
+}
+
+// pickAndRunCommand opens the interactive fuzzy picker and runs the selection
+func pickAndRunCommand() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	if len(cfg.Commands) == 0 {
+		printInfo("No custom commands defined in configuration.")
+		printInfo("Add commands to your .mvx/config file to get started.")
+		return nil
+	}
+
+	commandName, err := pickCommand(projectRoot, cfg)
+	if err != nil {
+		return err
+	}
+
+	return runCustomCommand(commandName, nil)
+}
+
 // runCustomCommand executes a custom command with arguments
 func runCustomCommand(commandName string, args []string) error {
 	projectRoot, err := findProjectRoot()
@@ -130,7 +308,7 @@ func runCustomCommand(commandName string, args []string) error {
 	// Load configuration
 	cfg, err := config.LoadConfig(projectRoot)
 	if err != nil {
-		return fmt.Errorf("failed to load configuration: %w", err)
+		return &configurationError{fmt.Errorf("failed to load configuration: %w", err)}
 	}
 
 	// Create tool manager
@@ -143,5 +321,24 @@ func runCustomCommand(commandName string, args []string) error {
 	exec := executor.NewExecutor(cfg, manager, projectRoot)
 
 	// Execute command (tools are auto-installed via EnsureTool)
-	return exec.ExecuteCommand(commandName, args)
+	installsBefore := manager.InstallCount()
+	started := time.Now()
+	runErr := exec.ExecuteCommand(commandName, args)
+	installs := manager.InstallCount() - installsBefore
+
+	// Remember the outcome for the interactive picker
+	recordRun(projectRoot, commandName, runErr == nil)
+
+	// Local cold/warm-start telemetry for mvx stats (never uploaded)
+	recordStats(statsEntry{
+		Command:    commandName,
+		Project:    cfg.Project.Name,
+		ColdStart:  installs > 0,
+		Installs:   installs,
+		DurationMs: time.Since(started).Milliseconds(),
+		Success:    runErr == nil,
+		Timestamp:  started.UTC(),
+	})
+
+	return runErr
 }
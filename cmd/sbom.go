@@ -0,0 +1,279 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// sbomCmd produces a software bill of materials for the provisioned toolchain
+var sbomCmd = &cobra.Command{
+	Use:   "sbom",
+	Short: "Generate an SBOM for the project toolchain",
+	Long: `Generate a software bill of materials listing every tool mvx provisions
+for this project: name, resolved version, download URL and archive checksum.
+
+Supported formats:
+  cyclonedx   CycloneDX 1.5 JSON (default)
+  spdx        SPDX 2.3 JSON
+
+Versions, URLs and checksums come from .mvx/mvx.lock when present (run
+'mvx tools lock' first for fully pinned output) and are resolved live
+otherwise.
+
+Examples:
+  mvx sbom                           # CycloneDX to stdout
+  mvx sbom --format spdx            # SPDX to stdout
+  mvx sbom --output toolchain.json  # Write to a file`,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		return runSBOM()
+	},
+}
+
+var (
+	sbomFormat string
+	sbomOutput string
+)
+
+func init() {
+	sbomCmd.Flags().StringVar(&sbomFormat, "format", "cyclonedx", "SBOM format (cyclonedx, spdx)")
+	sbomCmd.Flags().StringVarP(&sbomOutput, "output", "o", "", "write the SBOM to a file instead of stdout")
+	rootCmd.AddCommand(sbomCmd)
+}
+
+// sbomComponent is one provisioned tool in format-neutral form
+type sbomComponent struct {
+	Name       string
+	Version    string
+	URL        string
+	Checksum   string // "type:value" as stored in the lockfile
+	Provenance string // attestation verification result from the lockfile
+}
+
+func runSBOM() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	components, err := collectSBOMComponents(projectRoot, cfg)
+	if err != nil {
+		return err
+	}
+
+	var document interface{}
+	switch sbomFormat {
+	case "cyclonedx":
+		document = cycloneDXDocument(cfg, components)
+	case "spdx":
+		document = spdxDocument(cfg, components)
+	default:
+		return fmt.Errorf("unsupported SBOM format %s (supported: cyclonedx, spdx)", sbomFormat)
+	}
+
+	data, err := json.MarshalIndent(document, "", "  ")
+	if err != nil {
+		return err
+	}
+	data = append(data, '\n')
+
+	if sbomOutput != "" {
+		if err := os.WriteFile(sbomOutput, data, 0644); err != nil {
+			return fmt.Errorf("failed to write SBOM: %w", err)
+		}
+		printSuccess("📋 Wrote %s SBOM to %s (%d components)", sbomFormat, sbomOutput, len(components))
+		return nil
+	}
+	fmt.Print(string(data))
+	return nil
+}
+
+// collectSBOMComponents gathers tool provenance, preferring lockfile pins
+// and resolving live only for tools the lockfile does not cover
+func collectSBOMComponents(projectRoot string, cfg *config.Config) ([]sbomComponent, error) {
+	lock, err := tools.LoadLockFile(projectRoot)
+	if err != nil {
+		return nil, err
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	var components []sbomComponent
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
+		component := sbomComponent{Name: toolName}
+		if lock != nil {
+			if entry, exists := lock.Tools[toolName]; exists && entry.Spec == toolConfig.Version && entry.Distribution == toolConfig.Distribution {
+				component.Version = entry.Version
+				component.URL = entry.URL
+				component.Checksum = entry.Checksum
+				component.Provenance = entry.Provenance
+			}
+		}
+
+		if component.Version == "" {
+			resolved, err := manager.ResolveVersion(toolName, toolConfig)
+			if err != nil {
+				return nil, fmt.Errorf("failed to resolve %s %s: %w", toolName, toolConfig.Version, err)
+			}
+			component.Version = resolved
+			if tool, err := manager.GetTool(toolName); err == nil {
+				component.URL = tool.GetDownloadURL(resolved)
+			}
+		}
+
+		components = append(components, component)
+	}
+	return components, nil
+}
+
+// sbomChecksumParts splits a "type:value" checksum into algorithm and value
+func sbomChecksumParts(checksum string) (string, string) {
+	checksumType, value, found := strings.Cut(checksum, ":")
+	if !found || value == "" {
+		return "", ""
+	}
+	return checksumType, value
+}
+
+// cycloneDXDocument renders components as a CycloneDX 1.5 JSON BOM
+func cycloneDXDocument(cfg *config.Config, components []sbomComponent) interface{} {
+	type cdxHash struct {
+		Alg     string `json:"alg"`
+		Content string `json:"content"`
+	}
+	type cdxExternalRef struct {
+		Type string `json:"type"`
+		URL  string `json:"url"`
+	}
+	type cdxProperty struct {
+		Name  string `json:"name"`
+		Value string `json:"value"`
+	}
+	type cdxComponent struct {
+		Type         string           `json:"type"`
+		Name         string           `json:"name"`
+		Version      string           `json:"version"`
+		PURL         string           `json:"purl,omitempty"`
+		Hashes       []cdxHash        `json:"hashes,omitempty"`
+		ExternalRefs []cdxExternalRef `json:"externalReferences,omitempty"`
+		Properties   []cdxProperty    `json:"properties,omitempty"`
+	}
+
+	var cdxComponents []cdxComponent
+	for _, component := range components {
+		entry := cdxComponent{
+			Type:    "application",
+			Name:    component.Name,
+			Version: component.Version,
+			PURL:    fmt.Sprintf("pkg:generic/%s@%s", component.Name, component.Version),
+		}
+		if alg, value := sbomChecksumParts(component.Checksum); alg != "" {
+			entry.Hashes = []cdxHash{{Alg: strings.ToUpper(strings.Replace(alg, "sha", "SHA-", 1)), Content: value}}
+		}
+		if component.URL != "" {
+			entry.ExternalRefs = []cdxExternalRef{{Type: "distribution", URL: component.URL}}
+		}
+		if component.Provenance != "" {
+			entry.Properties = []cdxProperty{{Name: "mvx:provenance", Value: component.Provenance}}
+		}
+		cdxComponents = append(cdxComponents, entry)
+	}
+
+	return map[string]interface{}{
+		"bomFormat":   "CycloneDX",
+		"specVersion": "1.5",
+		"version":     1,
+		"metadata": map[string]interface{}{
+			"timestamp": time.Now().UTC().Format(time.RFC3339),
+			"tools":     []map[string]string{{"name": "mvx", "version": version}},
+			"component": map[string]string{
+				"type": "application",
+				"name": cfg.Project.Name,
+			},
+		},
+		"components": cdxComponents,
+	}
+}
+
+// spdxDocument renders components as an SPDX 2.3 JSON document
+func spdxDocument(cfg *config.Config, components []sbomComponent) interface{} {
+	type spdxChecksum struct {
+		Algorithm     string `json:"algorithm"`
+		ChecksumValue string `json:"checksumValue"`
+	}
+	type spdxPackage struct {
+		SPDXID           string         `json:"SPDXID"`
+		Name             string         `json:"name"`
+		VersionInfo      string         `json:"versionInfo"`
+		DownloadLocation string         `json:"downloadLocation"`
+		Checksums        []spdxChecksum `json:"checksums,omitempty"`
+		LicenseConcluded string         `json:"licenseConcluded"`
+	}
+
+	var packages []spdxPackage
+	for _, component := range components {
+		downloadLocation := component.URL
+		if downloadLocation == "" {
+			downloadLocation = "NOASSERTION"
+		}
+		entry := spdxPackage{
+			SPDXID:           fmt.Sprintf("SPDXRef-Package-%s", component.Name),
+			Name:             component.Name,
+			VersionInfo:      component.Version,
+			DownloadLocation: downloadLocation,
+			LicenseConcluded: "NOASSERTION",
+		}
+		if alg, value := sbomChecksumParts(component.Checksum); alg != "" {
+			entry.Checksums = []spdxChecksum{{Algorithm: strings.ToUpper(alg), ChecksumValue: value}}
+		}
+		packages = append(packages, entry)
+	}
+
+	documentName := cfg.Project.Name
+	if documentName == "" {
+		documentName = "mvx-toolchain"
+	}
+
+	return map[string]interface{}{
+		"spdxVersion":       "SPDX-2.3",
+		"SPDXID":            "SPDXRef-DOCUMENT",
+		"dataLicense":       "CC0-1.0",
+		"name":              documentName,
+		"documentNamespace": fmt.Sprintf("https://mvx.dev/spdx/%s/%d", documentName, time.Now().Unix()),
+		"creationInfo": map[string]interface{}{
+			"created":  time.Now().UTC().Format(time.RFC3339),
+			"creators": []string{fmt.Sprintf("Tool: mvx-%s", version)},
+		},
+		"packages": packages,
+	}
+}
@@ -0,0 +1,117 @@
+package cmd
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/secrets"
+	"github.com/spf13/cobra"
+)
+
+// secretCmd represents the secret command
+var secretCmd = &cobra.Command{
+	Use:   "secret",
+	Short: "Manage encrypted secrets",
+	Long: `Manage secrets referenced from config via ${secret.NAME}.
+
+Secrets are stored encrypted (AES-256-GCM) in ~/.mvx/secrets.enc and are
+never written to config files or logs. Environment variables with the same
+name take precedence, so CI can inject secrets without a key file.
+
+Examples:
+  mvx secret set NEXUS_TOKEN          # Prompts for the value
+  mvx secret get NEXUS_TOKEN
+  mvx secret list
+  mvx secret remove NEXUS_TOKEN`,
+}
+
+// secretSetCmd stores a secret
+var secretSetCmd = &cobra.Command{
+	Use:   "set <name> [value]",
+	Short: "Store a secret (prompts when no value is given)",
+	Args:  cobra.RangeArgs(1, 2),
+	Run: func(cmd *cobra.Command, args []string) {
+		name := args[0]
+		var value string
+		if len(args) == 2 {
+			value = args[1]
+		} else {
+			fmt.Printf("Value for %s: ", name)
+			reader := bufio.NewReader(os.Stdin)
+			line, err := reader.ReadString('\n')
+			if err != nil {
+				printError("Failed to read value: %v", err)
+				os.Exit(1)
+			}
+			value = strings.TrimRight(line, "\r\n")
+		}
+		if value == "" {
+			printError("Refusing to store an empty secret")
+			os.Exit(1)
+		}
+		if err := secrets.Set(name, value); err != nil {
+			printError("Failed to store secret: %v", err)
+			os.Exit(1)
+		}
+		printSuccess("✅ Stored secret %s", name)
+	},
+}
+
+// secretGetCmd prints a secret value
+var secretGetCmd = &cobra.Command{
+	Use:   "get <name>",
+	Short: "Print a secret value",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		value, err := secrets.Resolve(args[0])
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		fmt.Println(value)
+	},
+}
+
+// secretListCmd lists stored secret names
+var secretListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List stored secret names (never values)",
+	Run: func(cmd *cobra.Command, args []string) {
+		names, err := secrets.List()
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		if len(names) == 0 {
+			printInfo("No secrets stored. Add one with 'mvx secret set <name>'.")
+			return
+		}
+		for _, name := range names {
+			printInfo("  %s", name)
+		}
+	},
+}
+
+// secretRemoveCmd deletes a secret
+var secretRemoveCmd = &cobra.Command{
+	Use:   "remove <name>",
+	Short: "Delete a secret",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := secrets.Remove(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		printSuccess("✅ Removed secret %s", args[0])
+	},
+}
+
+func init() {
+	secretCmd.AddCommand(secretSetCmd)
+	secretCmd.AddCommand(secretGetCmd)
+	secretCmd.AddCommand(secretListCmd)
+	secretCmd.AddCommand(secretRemoveCmd)
+	rootCmd.AddCommand(secretCmd)
+}
@@ -1,12 +1,19 @@
 package cmd
 
 import (
+	"encoding/json"
 	"fmt"
 	"os"
 	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+	"time"
 
 	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/executor"
 	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/spf13/cobra"
 )
 
@@ -31,6 +38,8 @@ Examples:
   mvx setup --tools-only      # Only install tools, skip environment setup
   mvx setup --parallel 5      # Use 5 concurrent downloads
   mvx setup --sequential      # Install tools one by one
+  mvx setup --dest /opt/tools # Install into a directory for container image layers
+  mvx setup --report setup.json  # Also write a structured report for CI artifacts
 
 Environment Variables:
   MVX_PARALLEL_DOWNLOADS      # Default number of parallel downloads (default: 3)`,
@@ -41,9 +50,25 @@ Environment Variables:
 			os.Setenv("MVX_VERBOSE", "true")
 		}
 
-		if err := setupEnvironment(); err != nil {
-			printError("%v", err)
-			os.Exit(1)
+		if setupDryRun {
+			if err := setupDryRunPlan(); err != nil {
+				exitWithError(err)
+			}
+			return
+		}
+
+		err := setupEnvironment()
+
+		// The report is written on failure too: which tool broke and after
+		// how long is exactly what CI artifacts are for
+		if setupReportPath != "" {
+			if reportErr := writeSetupReport(setupReportPath, err); reportErr != nil {
+				printWarning("Failed to write setup report: %v", reportErr)
+			}
+		}
+
+		if err != nil {
+			exitWithError(err)
 		}
 	},
 }
@@ -52,12 +77,183 @@ var (
 	toolsOnly         bool
 	parallelDownloads int
 	sequentialInstall bool
+	setupDest         string
+	setupReportPath   string
+	setupDryRun       bool
 )
 
 func init() {
 	setupCmd.Flags().BoolVar(&toolsOnly, "tools-only", false, "only install tools, skip environment setup")
 	setupCmd.Flags().IntVar(&parallelDownloads, "parallel", 0, "number of parallel downloads (0 = auto, 1 = sequential)")
 	setupCmd.Flags().BoolVar(&sequentialInstall, "sequential", false, "install tools sequentially instead of in parallel")
+	setupCmd.Flags().StringVar(&setupDest, "dest", "", "install tools into this directory and write an env fragment (for container image layers)")
+	setupCmd.Flags().StringVar(&setupReportPath, "report", "", "write a JSON report of the setup (tools, durations, cache hits, failures) to this file")
+	setupCmd.Flags().BoolVar(&setupDryRun, "dry-run", false, "resolve versions and print what would be installed, without downloading or writing anything")
+}
+
+// setupDryRunPlan resolves every configured tool and prints what setup would
+// install — versions, URLs and download sizes — without downloading archives
+// or writing anything. Only metadata requests (version catalogs, HEAD for
+// sizes) touch the network, so the plan is reviewable on regulated machines.
+func setupDryRunPlan() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return &configurationError{fmt.Errorf("failed to load configuration: %w", err)}
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	type plannedAction struct {
+		Tool         string `json:"tool"`
+		Requested    string `json:"requested"`
+		Resolved     string `json:"resolved,omitempty"`
+		Distribution string `json:"distribution,omitempty"`
+		Action       string `json:"action"` // "install" or "up-to-date"
+		URL          string `json:"url,omitempty"`
+		SizeBytes    int64  `json:"sizeBytes,omitempty"`
+		Error        string `json:"error,omitempty"`
+	}
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	var plan []plannedAction
+	var totalBytes int64
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+		action := plannedAction{Tool: toolName, Requested: toolConfig.Version, Distribution: toolConfig.Distribution}
+
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			action.Error = fmt.Sprintf("resolution failed: %v", err)
+			plan = append(plan, action)
+			continue
+		}
+		action.Resolved = resolved
+
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			action.Error = err.Error()
+			plan = append(plan, action)
+			continue
+		}
+		if tool.IsInstalled(resolved, toolConfig) {
+			action.Action = "up-to-date"
+			plan = append(plan, action)
+			continue
+		}
+
+		action.Action = "install"
+		if customTool, ok := tool.(*tools.CustomTool); ok {
+			action.URL, _ = customTool.ResolveDownloadURL(resolved)
+		} else {
+			action.URL = tool.GetDownloadURL(resolved)
+		}
+		if action.URL != "" && !util.IsOffline() {
+			action.SizeBytes = remoteContentLength(action.URL)
+			totalBytes += action.SizeBytes
+		}
+		plan = append(plan, action)
+	}
+
+	if jsonOutput() {
+		return printJSON(map[string]interface{}{"plan": plan, "downloadBytes": totalBytes})
+	}
+
+	printInfo("🔍 Setup plan (dry run)")
+	printInfo("")
+	installs := 0
+	failures := 0
+	for _, action := range plan {
+		switch {
+		case action.Error != "":
+			failures++
+			printInfo("  ❌ %s %s — %s", action.Tool, action.Requested, action.Error)
+		case action.Action == "up-to-date":
+			printInfo("  ✅ %s %s (already installed)", action.Tool, action.Resolved)
+		default:
+			installs++
+			size := ""
+			if action.SizeBytes > 0 {
+				size = fmt.Sprintf(" (%s)", util.FormatBytes(action.SizeBytes))
+			}
+			printInfo("  📦 %s %s → %s%s", action.Tool, action.Requested, action.Resolved, size)
+			printInfo("      %s", action.URL)
+		}
+	}
+	printInfo("")
+	if installs > 0 {
+		printInfo("Would download %d tool(s), %s total.", installs, util.FormatBytes(totalBytes))
+	} else if failures == 0 {
+		printInfo("Everything is already installed.")
+	}
+	printInfo("Nothing was downloaded or written (dry run).")
+
+	if failures > 0 {
+		return fmt.Errorf("%d tool(s) could not be resolved", failures)
+	}
+	return nil
+}
+
+// remoteContentLength asks the server for the download size via a HEAD
+// request; 0 means unknown
+func remoteContentLength(url string) int64 {
+	resp, err := util.HTTPClient(30 * time.Second).Head(url)
+	if err != nil {
+		return 0
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode >= 400 || resp.ContentLength < 0 {
+		return 0
+	}
+	return resp.ContentLength
+}
+
+// writeSetupReport emits the structured setup report consumed as a CI
+// artifact: one record per tool with version, duration, cache hit, download
+// size and failure reason, plus the overall outcome.
+func writeSetupReport(path string, setupErr error) error {
+	report := struct {
+		Timestamp  string                `json:"timestamp"`
+		MvxVersion string                `json:"mvxVersion"`
+		Platform   string                `json:"platform"`
+		Status     string                `json:"status"`
+		Error      string                `json:"error,omitempty"`
+		Tools      []tools.InstallRecord `json:"tools"`
+	}{
+		Timestamp:  time.Now().UTC().Format(time.RFC3339),
+		MvxVersion: version,
+		Platform:   runtime.GOOS + "-" + runtime.GOARCH,
+		Status:     "ok",
+		Tools:      tools.InstallRecords(),
+	}
+	if setupErr != nil {
+		report.Status = "failed"
+		report.Error = setupErr.Error()
+	}
+
+	data, err := json.MarshalIndent(report, "", "  ")
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(path, append(data, '\n'), 0644)
 }
 
 func setupEnvironment() error {
@@ -71,26 +267,53 @@ func setupEnvironment() error {
 	// Check if .mvx directory exists
 	mvxDir := filepath.Join(projectRoot, ".mvx")
 	if _, err := os.Stat(mvxDir); os.IsNotExist(err) {
-		return fmt.Errorf("no mvx configuration found. Run 'mvx init' first")
+		// Offer the guided first-run flow before giving up with a bare error
+		if !offerOnboarding(projectRoot) {
+			return fmt.Errorf("no mvx configuration found. Run 'mvx init' first")
+		}
 	}
 
 	// Load configuration
 	printInfo("🔍 Loading configuration...")
 	cfg, err := config.LoadConfig(projectRoot)
 	if err != nil {
-		return fmt.Errorf("failed to load configuration: %w\n\nHint: Run 'mvx init' to create a configuration file first", err)
+		return &configurationError{fmt.Errorf("failed to load configuration: %w\n\nHint: Run 'mvx init' to create a configuration file first", err)}
 	}
 
 	printVerbose("Loaded configuration for project: %s", cfg.Project.Name)
 
+	// Relocate the tool cache before the manager is created so every install
+	// lands under the destination directory
+	if setupDest != "" {
+		absDest, err := filepath.Abs(setupDest)
+		if err != nil {
+			return fmt.Errorf("invalid --dest directory: %w", err)
+		}
+		setupDest = absDest
+		os.Setenv("MVX_HOME", setupDest)
+	}
+
 	// Create tool manager
 	manager, err := tools.NewManager()
 	if err != nil {
 		return fmt.Errorf("failed to create tool manager: %w", err)
 	}
 
-	// Install tools with options
-	printInfo("📦 Installing tools...")
+	// Register custom (URL-based) tools declared in the configuration
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	exec := executor.NewExecutor(cfg, manager, projectRoot)
+
+	// Project hook before any tool is installed
+	if err := exec.RunLifecycleHook("preSetup"); err != nil {
+		return fmt.Errorf("preSetup hook failed: %w", err)
+	}
+
+	// Install tools with options (a collapsible log group in CI)
+	endGroup := printGroup("📦 Installing tools...")
 
 	// Configure concurrency
 	maxConcurrent := parallelDownloads
@@ -104,8 +327,10 @@ func setupEnvironment() error {
 	}
 
 	if err := manager.EnsureTools(cfg, maxConcurrent); err != nil {
+		endGroup()
 		return fmt.Errorf("failed to install tools: %w", err)
 	}
+	endGroup()
 
 	if !toolsOnly {
 		printInfo("🔧 Setting up environment...")
@@ -118,13 +343,78 @@ func setupEnvironment() error {
 		if verbose {
 			printVerbose("Environment variables:")
 			for key, value := range env {
-				printVerbose("  %s=%s", key, value)
+				printVerbose("  %s=%s", key, util.RedactEnvValue(key, value))
 			}
 		}
 
 		printInfo("  ✅ Environment variables configured")
 	}
 
+	// Project hook after tools install, with the tool environment resolved
+	if err := exec.RunLifecycleHook("postSetup"); err != nil {
+		return fmt.Errorf("postSetup hook failed: %w", err)
+	}
+
+	// Record the resolved state so `mvx status` can report what changed
+	if err := saveSetupState(projectRoot, cfg, manager); err != nil {
+		printVerbose("Failed to record setup state: %v", err)
+	}
+
+	// Pin the resolved versions so later runs reproduce this environment
+	if err := manager.WriteLockFile(projectRoot, cfg); err != nil {
+		printVerbose("Failed to write %s: %v", tools.LockFileName, err)
+	} else {
+		printInfo("🔒 Locked tool versions in %s", tools.LockFileName)
+	}
+
+	// Container image layering: emit the env fragment for the destination layout
+	if setupDest != "" {
+		if err := writeDestEnvFragment(setupDest, manager, cfg); err != nil {
+			printWarning("Failed to write environment fragment: %v", err)
+		}
+	}
+
+	// Materialize declared Maven core extensions into .mvn/extensions.xml
+	if err := materializeMavenExtensions(projectRoot, cfg); err != nil {
+		printWarning("Failed to write .mvn/extensions.xml: %v", err)
+	}
+
+	// Re-render declared templates so generated files track the config
+	if len(cfg.Generate) > 0 {
+		printInfo("")
+		printInfo("📝 Generating files from templates...")
+		if err := generateFiles(projectRoot, cfg); err != nil {
+			printWarning("Template generation failed: %v", err)
+		}
+	}
+
+	// Machine-readable summary of the installed toolchain
+	if jsonOutput() {
+		type installedTool struct {
+			Name         string `json:"name"`
+			Version      string `json:"version"`
+			Distribution string `json:"distribution,omitempty"`
+		}
+		var installed []installedTool
+		var names []string
+		for toolName := range cfg.Tools {
+			names = append(names, toolName)
+		}
+		sort.Strings(names)
+		for _, toolName := range names {
+			toolConfig := cfg.Tools[toolName]
+			if !toolConfig.MatchesPlatform() {
+				continue
+			}
+			resolved, err := manager.ResolveVersion(toolName, toolConfig)
+			if err != nil {
+				resolved = toolConfig.Version
+			}
+			installed = append(installed, installedTool{Name: toolName, Version: resolved, Distribution: toolConfig.Distribution})
+		}
+		return printJSON(map[string]interface{}{"status": "ok", "tools": installed})
+	}
+
 	printInfo("")
 	printInfo("✅ Setup complete! Your build environment is ready.")
 	printInfo("")
@@ -134,3 +424,57 @@ func setupEnvironment() error {
 
 	return nil
 }
+
+// writeDestEnvFragment emits <dest>/mvx-env.sh with the environment needed to
+// use the tools installed under --dest, and trims the download cache so
+// container layers stay lean
+func writeDestEnvFragment(dest string, manager *tools.Manager, cfg *config.Config) error {
+	env, err := manager.SetupEnvironment(cfg)
+	if err != nil {
+		return err
+	}
+
+	lines := []string{"# Generated by mvx setup --dest " + dest}
+
+	keys := make([]string, 0, len(env))
+	for key := range env {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	for _, key := range keys {
+		if key == "PATH" {
+			continue
+		}
+		// Inherited variables don't belong in the fragment, only tool and
+		// project environment
+		if os.Getenv(key) == env[key] {
+			continue
+		}
+		lines = append(lines, fmt.Sprintf("export %s=%q", key, env[key]))
+	}
+
+	// Only PATH entries under the destination belong in the fragment
+	var pathEntries []string
+	for _, dir := range strings.Split(env["PATH"], string(os.PathListSeparator)) {
+		if strings.HasPrefix(dir, dest+string(os.PathSeparator)) {
+			pathEntries = append(pathEntries, dir)
+		}
+	}
+	if len(pathEntries) > 0 {
+		lines = append(lines, fmt.Sprintf("export PATH=\"%s:$PATH\"", strings.Join(pathEntries, ":")))
+	}
+
+	fragment := filepath.Join(dest, "mvx-env.sh")
+	if err := os.WriteFile(fragment, []byte(strings.Join(lines, "\n")+"\n"), 0644); err != nil {
+		return err
+	}
+
+	// Download caches have no business inside an image layer
+	if err := os.RemoveAll(filepath.Join(dest, "cache")); err != nil {
+		printVerbose("Failed to trim cache under %s: %v", dest, err)
+	}
+
+	printInfo("📄 Environment fragment written to %s", fragment)
+	printInfo("   Source it in later layers: . %s", fragment)
+	return nil
+}
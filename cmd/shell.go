@@ -3,6 +3,9 @@ package cmd
 import (
 	"fmt"
 	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
@@ -17,10 +20,13 @@ var shellCmd = &cobra.Command{
 	Short: "Execute shell commands in the mvx environment",
 	Long: `Execute shell commands with the mvx-managed tools and environment setup.
 
-This command runs shell commands using the mvx-shell interpreter with access to
-all mvx-managed tools and their environment variables.
+With arguments, this command runs them using the mvx-shell interpreter with
+access to all mvx-managed tools and their environment variables. Without
+arguments, it spawns your shell with PATH, JAVA_HOME, etc. configured from the
+project config — type 'exit' to leave.
 
 Examples:
+  mvx shell                             # Enter a subshell with the toolchain active
   mvx shell echo '$JAVA_HOME'           # Show Java home directory
   mvx shell env                         # Show all environment variables
   mvx shell "mvn --version"             # Run Maven with mvx environment
@@ -66,11 +72,15 @@ func runShellCommand(args []string) error {
 		return fmt.Errorf("failed to setup environment: %w", err)
 	}
 
+	// Without arguments, enter an interactive subshell with the project
+	// toolchain active
+	if len(args) == 0 {
+		return enterInteractiveShell(cfg, manager, workDir, env)
+	}
+
 	// Join all arguments into a single command string
 	var command string
-	if len(args) == 0 {
-		return fmt.Errorf("no command specified. Use 'mvx shell <command>' to execute shell commands")
-	} else if len(args) == 1 {
+	if len(args) == 1 {
 		command = args[0]
 	} else {
 		command = strings.Join(args, " ")
@@ -85,6 +95,72 @@ func runShellCommand(args []string) error {
 	return mvxShell.Execute(command)
 }
 
+// enterInteractiveShell spawns the user's shell with the project toolchain
+// active, the workflow SDKMAN and nix-shell users expect
+func enterInteractiveShell(cfg *config.Config, manager *tools.Manager, workDir string, env []string) error {
+	if os.Getenv("MVX_SHELL_ACTIVE") == "1" {
+		return fmt.Errorf("already inside an mvx shell (type 'exit' to leave it first)")
+	}
+
+	// Make sure the toolchain is actually installed before handing it out
+	for toolName, toolConfig := range cfg.Tools {
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+		if _, err := manager.EnsureTool(toolName, toolConfig); err != nil {
+			printWarning("Failed to ensure tool %s: %v", toolName, err)
+		}
+	}
+	// Re-resolve the environment in case tools were just installed
+	env, err := setupShellEnvironment(cfg, manager, workDir)
+	if err != nil {
+		return fmt.Errorf("failed to setup environment: %w", err)
+	}
+
+	marker := "mvx"
+	if cfg.Project.Name != "" {
+		marker = fmt.Sprintf("mvx:%s", cfg.Project.Name)
+	}
+	// A best-effort prompt marker; shells whose rc files rewrite PS1 keep
+	// their own prompt, which is why we also print a banner
+	env = mergeEnvironment(env, map[string]string{
+		"MVX_SHELL_ACTIVE": "1",
+		"PS1":              fmt.Sprintf("(%s) %s", marker, os.Getenv("PS1")),
+	})
+
+	shellPath := userShell()
+	printInfo("🐚 Entering %s with the %s toolchain active (type 'exit' to leave)", filepath.Base(shellPath), marker)
+
+	shellCmd := exec.Command(shellPath)
+	shellCmd.Dir = workDir
+	shellCmd.Env = env
+	shellCmd.Stdin = os.Stdin
+	shellCmd.Stdout = os.Stdout
+	shellCmd.Stderr = os.Stderr
+	if err := shellCmd.Run(); err != nil {
+		if exitErr, ok := err.(*exec.ExitError); ok {
+			os.Exit(exitErr.ExitCode())
+		}
+		return fmt.Errorf("failed to start shell %s: %w", shellPath, err)
+	}
+	printInfo("👋 Left the %s shell", marker)
+	return nil
+}
+
+// userShell picks the user's login shell, with platform fallbacks
+func userShell() string {
+	if shellPath := os.Getenv("SHELL"); shellPath != "" {
+		return shellPath
+	}
+	if runtime.GOOS == "windows" {
+		if comspec := os.Getenv("COMSPEC"); comspec != "" {
+			return comspec
+		}
+		return "cmd"
+	}
+	return "/bin/sh"
+}
+
 // setupShellEnvironment sets up the environment for shell execution
 func setupShellEnvironment(cfg *config.Config, manager *tools.Manager, workDir string) ([]string, error) {
 	// Start with current environment
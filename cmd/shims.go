@@ -0,0 +1,261 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/spf13/cobra"
+)
+
+// shimBinaries maps tool names to the binaries a shim should front for them
+var shimBinaries = map[string][]string{
+	tools.ToolJava:       {tools.BinaryJava, "javac", "jar", "jshell"},
+	tools.ToolMaven:      {tools.BinaryMaven},
+	tools.ToolMvnd:       {tools.BinaryMvnd},
+	tools.ToolNode:       {tools.BinaryNode, "npm", "npx"},
+	tools.ToolGo:         {tools.BinaryGo, "gofmt"},
+	tools.ToolDotNet:     {tools.BinaryDotNet},
+	tools.ToolJReleaser:  {tools.BinaryJReleaser},
+	tools.ToolAndroidSdk: {tools.BinarySdkmanager},
+}
+
+// shimsCmd manages shims in ~/.mvx/shims that dispatch to the tool version
+// pinned by the nearest project configuration, so plain 'mvn clean install'
+// uses the mvx-managed toolchain without typing 'mvx mvn'.
+var shimsCmd = &cobra.Command{
+	Use:   "shims [subcommand]",
+	Short: "Manage PATH shims for mvx-managed tools",
+	Long: `Manage shims that dispatch to the tool version pinned by the nearest
+project configuration.
+
+Subcommands:
+  install    Write shims for the managed tools to ~/.mvx/shims (default)
+  list       Show the installed shims
+  remove     Delete the shims directory
+
+After installing, put the shims directory early on your PATH (the
+'mvx activate' shell hooks do this for you):
+
+  export PATH="$HOME/.mvx/shims:$PATH"
+
+Inside a project, a shimmed binary like mvn or node resolves through the
+project's mvx configuration; outside one, it falls through to the system
+installation.`,
+
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		subcommand := "install"
+		if len(args) > 0 {
+			subcommand = args[0]
+		}
+		var err error
+		switch subcommand {
+		case "install":
+			err = installShims()
+		case "list":
+			err = listShims()
+		case "remove":
+			err = removeShims()
+		default:
+			err = fmt.Errorf("unknown shims subcommand: %s (expected install, list or remove)", subcommand)
+		}
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// shimExecCmd is the hidden dispatch target the shim scripts invoke
+var shimExecCmd = &cobra.Command{
+	Use:                "shim-exec <binary> [args...]",
+	Hidden:             true,
+	DisableFlagParsing: true,
+	Args:               cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runShimExec(args[0], args[1:]); err != nil {
+			if exitErr, ok := err.(*exec.ExitError); ok {
+				os.Exit(exitErr.ExitCode())
+			}
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(shimsCmd)
+	rootCmd.AddCommand(shimExecCmd)
+}
+
+// shimsDir returns the shims directory (~/.mvx/shims)
+func shimsDir() (string, error) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to determine home directory: %w", err)
+	}
+	return filepath.Join(home, ".mvx", "shims"), nil
+}
+
+// installShims writes a shim for every known managed binary
+func installShims() error {
+	dir, err := shimsDir()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create shims directory: %w", err)
+	}
+
+	var binaries []string
+	for _, names := range shimBinaries {
+		binaries = append(binaries, names...)
+	}
+	sort.Strings(binaries)
+
+	for _, binary := range binaries {
+		if err := writeShim(dir, binary); err != nil {
+			return err
+		}
+	}
+
+	printSuccess("Installed %d shims in %s", len(binaries), dir)
+	printInfo("Add the directory to the front of your PATH to use them:")
+	if runtime.GOOS == "windows" {
+		printInfo("  set PATH=%s;%%PATH%%", dir)
+	} else {
+		printInfo("  export PATH=\"%s:$PATH\"", dir)
+	}
+	return nil
+}
+
+// writeShim writes one shim script dispatching to mvx shim-exec. The shim
+// references the installing mvx binary by absolute path, quoted so install
+// locations containing spaces work, and falls back to PATH lookup when the
+// executable path cannot be determined.
+func writeShim(dir, binary string) error {
+	mvxPath := "mvx"
+	if exe, err := os.Executable(); err == nil {
+		mvxPath = exe
+	}
+	if runtime.GOOS == "windows" {
+		path := filepath.Join(dir, binary+".cmd")
+		content := fmt.Sprintf("@echo off\r\n%s shim-exec %s %%*\r\n", util.QuoteCmdArg(mvxPath), binary)
+		return os.WriteFile(path, []byte(content), 0755)
+	}
+	path := filepath.Join(dir, binary)
+	content := fmt.Sprintf("#!/bin/sh\nexec %s shim-exec %s \"$@\"\n", util.QuotePosixArg(mvxPath), binary)
+	return os.WriteFile(path, []byte(content), 0755)
+}
+
+// listShims prints the installed shims
+func listShims() error {
+	dir, err := shimsDir()
+	if err != nil {
+		return err
+	}
+	entries, err := os.ReadDir(dir)
+	if os.IsNotExist(err) {
+		printInfo("No shims installed (run 'mvx shims install')")
+		return nil
+	}
+	if err != nil {
+		return fmt.Errorf("failed to read shims directory: %w", err)
+	}
+
+	printInfo("Shims in %s:", dir)
+	for _, entry := range entries {
+		printInfo("  %s", entry.Name())
+	}
+	return nil
+}
+
+// removeShims deletes the shims directory
+func removeShims() error {
+	dir, err := shimsDir()
+	if err != nil {
+		return err
+	}
+	if err := os.RemoveAll(dir); err != nil {
+		return fmt.Errorf("failed to remove shims directory: %w", err)
+	}
+	printSuccess("Removed %s", dir)
+	return nil
+}
+
+// runShimExec resolves a binary through the nearest project configuration
+// and executes it; outside a project it falls through to the system binary
+func runShimExec(binary string, args []string) error {
+	env := os.Environ()
+
+	if projectRoot, err := findProjectRoot(); err == nil {
+		if cfg, err := config.LoadConfig(projectRoot); err == nil {
+			if manager, err := tools.NewManager(); err == nil {
+				manager.RegisterCustomTools(cfg)
+				manager.RegisterProjectPlugins(projectRoot, cfg)
+				manager.LoadProjectLock(projectRoot)
+				manager.ConfigureRegistries(cfg)
+				if shellEnv, err := setupShellEnvironment(cfg, manager, projectRoot); err == nil {
+					env = shellEnv
+				}
+			}
+		}
+	}
+
+	// Keep the shims directory out of the lookup PATH, or the shim would
+	// recurse into itself
+	dir, err := shimsDir()
+	if err != nil {
+		return err
+	}
+	searchPath := stripPathDir(pathFromEnv(env), dir)
+
+	binaryPath, err := lookPathIn(binary, searchPath)
+	if err != nil {
+		return fmt.Errorf("%s: not found via the mvx configuration or the system PATH", binary)
+	}
+
+	execCmd := exec.Command(binaryPath, args...)
+	execCmd.Env = env
+	execCmd.Stdin = os.Stdin
+	execCmd.Stdout = os.Stdout
+	execCmd.Stderr = os.Stderr
+	return execCmd.Run()
+}
+
+// pathFromEnv extracts the PATH value from an environment slice
+func pathFromEnv(env []string) string {
+	for _, envVar := range env {
+		if value, found := strings.CutPrefix(envVar, "PATH="); found {
+			return value
+		}
+	}
+	return os.Getenv("PATH")
+}
+
+// stripPathDir removes a directory from a PATH-style list
+func stripPathDir(pathValue, dir string) string {
+	var kept []string
+	for _, entry := range strings.Split(pathValue, string(os.PathListSeparator)) {
+		if entry != "" && entry != dir {
+			kept = append(kept, entry)
+		}
+	}
+	return strings.Join(kept, string(os.PathListSeparator))
+}
+
+// lookPathIn finds a binary on an explicit PATH value
+func lookPathIn(binary, pathValue string) (string, error) {
+	originalPath := os.Getenv("PATH")
+	os.Setenv("PATH", pathValue)
+	defer os.Setenv("PATH", originalPath)
+	return exec.LookPath(binary)
+}
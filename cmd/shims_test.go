@@ -0,0 +1,40 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+)
+
+func TestWriteShim(t *testing.T) {
+	tempDir := t.TempDir()
+	if err := writeShim(tempDir, "mvn"); err != nil {
+		t.Fatalf("writeShim() error = %v", err)
+	}
+
+	name := "mvn"
+	if runtime.GOOS == "windows" {
+		name = "mvn.cmd"
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, name))
+	if err != nil {
+		t.Fatalf("shim not written: %v", err)
+	}
+	if !strings.Contains(string(content), "mvx shim-exec mvn") {
+		t.Errorf("shim content = %q, should dispatch to mvx shim-exec", content)
+	}
+}
+
+func TestStripPathDir(t *testing.T) {
+	sep := string(os.PathListSeparator)
+	path := "/usr/bin" + sep + "/home/user/.mvx/shims" + sep + "/bin"
+	stripped := stripPathDir(path, "/home/user/.mvx/shims")
+	if strings.Contains(stripped, ".mvx/shims") {
+		t.Errorf("stripPathDir() = %q, shims dir should be removed", stripped)
+	}
+	if !strings.Contains(stripped, "/usr/bin") || !strings.Contains(stripped, "/bin") {
+		t.Errorf("stripPathDir() = %q, other entries should remain", stripped)
+	}
+}
@@ -0,0 +1,173 @@
+package cmd
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/spf13/cobra"
+)
+
+// maxStatsEntries caps the local telemetry log
+const maxStatsEntries = 2000
+
+// statsEntry records one command invocation for local telemetry
+type statsEntry struct {
+	Command    string    `json:"command"`
+	Project    string    `json:"project"`
+	ColdStart  bool      `json:"cold_start"` // true when tools had to be installed
+	Installs   int       `json:"installs"`   // number of installs performed
+	DurationMs int64     `json:"duration_ms"`
+	Success    bool      `json:"success"`
+	Timestamp  time.Time `json:"timestamp"`
+}
+
+// statsCmd represents the stats command
+var statsCmd = &cobra.Command{
+	Use:   "stats",
+	Short: "Show aggregated command execution statistics",
+	Long: `Show aggregated statistics over recorded command invocations: how often
+runs were cold (tools had to be installed) versus warm, and how long each
+kind took.
+
+Telemetry is recorded locally in ~/.mvx/stats.json and never uploaded.
+
+Examples:
+  mvx stats`,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := showStats(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(statsCmd)
+}
+
+// statsPath returns the path of the local telemetry log
+func statsPath() (string, error) {
+	mvxHome, err := util.MvxHome()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(mvxHome, "stats.json"), nil
+}
+
+// loadStats reads the local telemetry log (empty when absent)
+func loadStats() []statsEntry {
+	path, err := statsPath()
+	if err != nil {
+		return nil
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil
+	}
+	var entries []statsEntry
+	if err := json.Unmarshal(data, &entries); err != nil {
+		return nil
+	}
+	return entries
+}
+
+// recordStats appends one invocation to the local telemetry log (best effort)
+func recordStats(entry statsEntry) {
+	path, err := statsPath()
+	if err != nil {
+		return
+	}
+
+	entries := append(loadStats(), entry)
+	if len(entries) > maxStatsEntries {
+		entries = entries[len(entries)-maxStatsEntries:]
+	}
+
+	data, err := json.Marshal(entries)
+	if err != nil {
+		return
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return
+	}
+	_ = os.WriteFile(path, data, 0644)
+}
+
+// showStats aggregates and prints the recorded invocations
+func showStats() error {
+	entries := loadStats()
+	if len(entries) == 0 {
+		printInfo("No command executions recorded yet. Run some commands first.")
+		return nil
+	}
+
+	type aggregate struct {
+		coldRuns, warmRuns int
+		coldMs, warmMs     int64
+		failures, installs int
+	}
+	perCommand := make(map[string]*aggregate)
+	total := &aggregate{}
+
+	for _, entry := range entries {
+		agg, exists := perCommand[entry.Command]
+		if !exists {
+			agg = &aggregate{}
+			perCommand[entry.Command] = agg
+		}
+		for _, a := range []*aggregate{agg, total} {
+			if entry.ColdStart {
+				a.coldRuns++
+				a.coldMs += entry.DurationMs
+			} else {
+				a.warmRuns++
+				a.warmMs += entry.DurationMs
+			}
+			if !entry.Success {
+				a.failures++
+			}
+			a.installs += entry.Installs
+		}
+	}
+
+	avg := func(totalMs int64, runs int) time.Duration {
+		if runs == 0 {
+			return 0
+		}
+		return time.Duration(totalMs/int64(runs)) * time.Millisecond
+	}
+
+	printInfo("📊 Command execution statistics (%d recorded run(s)):", len(entries))
+	printInfo("")
+
+	var names []string
+	for name := range perCommand {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	for _, name := range names {
+		agg := perCommand[name]
+		printInfo("  %s:", name)
+		printInfo("    cold runs: %d (avg %s), warm runs: %d (avg %s)",
+			agg.coldRuns, avg(agg.coldMs, agg.coldRuns),
+			agg.warmRuns, avg(agg.warmMs, agg.warmRuns))
+		if agg.failures > 0 {
+			printInfo("    failures: %d", agg.failures)
+		}
+	}
+
+	printInfo("")
+	coldShare := 0
+	if runs := total.coldRuns + total.warmRuns; runs > 0 {
+		coldShare = total.coldRuns * 100 / runs
+	}
+	printInfo("  Overall: %d cold / %d warm (%d%% cold), %d tool install(s), avg warm run %s",
+		total.coldRuns, total.warmRuns, coldShare, total.installs, avg(total.warmMs, total.warmRuns))
+
+	return nil
+}
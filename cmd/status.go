@@ -0,0 +1,240 @@
+package cmd
+
+import (
+	"crypto/sha256"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// setupState is a snapshot of the resolved project state recorded after the
+// last successful setup, used by `mvx status` to report what changed
+type setupState struct {
+	Tools    map[string]string `json:"tools"`    // tool name -> resolved version (version@distribution)
+	Commands map[string]string `json:"commands"` // command name -> script hash
+}
+
+var (
+	statusBadge string
+	statusCheck bool
+)
+
+// statusCmd represents the status command
+var statusCmd = &cobra.Command{
+	Use:   "status",
+	Short: "Show what changed since the last setup",
+	Long: `Compare the current configuration against the installed tools and the
+last successful setup, listing tools that need to be installed, upgraded or
+removed and commands that were added or changed.
+
+Run this after a git pull to know whether you need to rerun 'mvx setup'.
+
+Examples:
+  mvx status                 # Show pending changes
+  mvx status --check         # Exit non-zero when changes are pending (CI gate)
+  mvx status --badge svg     # Emit an SVG badge for the repo README
+  mvx status --badge json    # Emit a JSON summary for dashboards`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		// Badge output replaces the human-readable report
+		if statusBadge != "" {
+			quiet = true
+		}
+
+		changes, err := showStatus()
+		if err != nil {
+			quiet = false
+			printError("%v", err)
+			os.Exit(1)
+		}
+
+		if statusBadge != "" {
+			quiet = false
+			if err := printStatusBadge(statusBadge, changes); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		}
+
+		if statusCheck && changes > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	statusCmd.Flags().StringVar(&statusBadge, "badge", "", "emit a status badge: svg or json")
+	statusCmd.Flags().BoolVar(&statusCheck, "check", false, "exit with status 1 when changes are pending")
+	rootCmd.AddCommand(statusCmd)
+}
+
+// setupStatePath returns the path of the setup state snapshot
+func setupStatePath(projectRoot string) string {
+	return filepath.Join(projectRoot, ".mvx", "setup-state.json")
+}
+
+// loadSetupState loads the snapshot from the last successful setup (may be nil)
+func loadSetupState(projectRoot string) *setupState {
+	data, err := os.ReadFile(setupStatePath(projectRoot))
+	if err != nil {
+		return nil
+	}
+	var state setupState
+	if err := json.Unmarshal(data, &state); err != nil {
+		return nil
+	}
+	return &state
+}
+
+// saveSetupState records the resolved project state after a successful setup
+func saveSetupState(projectRoot string, cfg *config.Config, manager *tools.Manager) error {
+	state := setupState{
+		Tools:    make(map[string]string),
+		Commands: make(map[string]string),
+	}
+
+	for toolName, toolConfig := range cfg.Tools {
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			resolved = toolConfig.Version
+		}
+		state.Tools[toolName] = versionKey(resolved, toolConfig.Distribution)
+	}
+	for cmdName, cmdConfig := range cfg.Commands {
+		state.Commands[cmdName] = commandHash(cmdConfig)
+	}
+
+	data, err := json.MarshalIndent(state, "", "  ")
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(setupStatePath(projectRoot), data, 0644)
+}
+
+// versionKey builds the "version@distribution" key used in snapshots
+func versionKey(version, distribution string) string {
+	if distribution != "" {
+		return version + "@" + distribution
+	}
+	return version
+}
+
+// commandHash hashes the parts of a command definition that affect execution
+func commandHash(cmdConfig config.CommandConfig) string {
+	data, _ := json.Marshal(cmdConfig)
+	return fmt.Sprintf("%x", sha256.Sum256(data))
+}
+
+// showStatus compares config, installed state and the last setup snapshot,
+// returning the number of pending changes
+func showStatus() (int, error) {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return 0, fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return 0, fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return 0, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	lastState := loadSetupState(projectRoot)
+
+	printInfo("📋 Project status for %s", cfg.Project.Name)
+	printInfo("")
+
+	changes := 0
+
+	// Tools that need installation or upgrade
+	for toolName, toolConfig := range cfg.Tools {
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			printWarning("tool %s: failed to resolve version %s: %v", toolName, toolConfig.Version, err)
+			continue
+		}
+
+		resolvedConfig := toolConfig
+		resolvedConfig.Version = resolved
+
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			printWarning("tool %s: %v", toolName, err)
+			continue
+		}
+
+		key := versionKey(resolved, toolConfig.Distribution)
+		switch {
+		case !tool.IsInstalled(resolved, resolvedConfig):
+			printInfo("  📥 install  %s %s", toolName, key)
+			changes++
+		case lastState != nil && lastState.Tools[toolName] != "" && lastState.Tools[toolName] != key:
+			printInfo("  ⬆️  upgrade  %s %s -> %s", toolName, lastState.Tools[toolName], key)
+			changes++
+		}
+	}
+
+	// Tools from the last setup that were removed from the configuration
+	if lastState != nil {
+		for toolName, version := range lastState.Tools {
+			if _, exists := cfg.Tools[toolName]; !exists {
+				printInfo("  🗑️  removed  %s %s (no longer configured)", toolName, version)
+				changes++
+			}
+		}
+
+		// Commands added or changed since the last setup
+		for cmdName, cmdConfig := range cfg.Commands {
+			previous, existed := lastState.Commands[cmdName]
+			if !existed {
+				printInfo("  ➕ command  %s (new)", cmdName)
+				changes++
+			} else if previous != commandHash(cmdConfig) {
+				printInfo("  ✏️  command  %s (changed)", cmdName)
+				changes++
+			}
+		}
+		for cmdName := range lastState.Commands {
+			if _, exists := cfg.Commands[cmdName]; !exists {
+				printInfo("  ➖ command  %s (removed)", cmdName)
+				changes++
+			}
+		}
+	}
+
+	// Maintenance scheduler status (when opted in)
+	if cfg.Maintenance != nil && cfg.Maintenance.Enabled {
+		printInfo("")
+		printInfo("🧹 Maintenance (interval %s):", maintenanceInterval(cfg))
+		state := loadMaintenanceState()
+		for _, task := range maintenanceTasks {
+			if lastRun, ok := state[task]; ok {
+				printInfo("  %s: last run %s", task, lastRun.Format("2006-01-02 15:04"))
+			} else {
+				printInfo("  %s: never run", task)
+			}
+		}
+	}
+
+	printInfo("")
+	if changes == 0 {
+		printSuccess("✅ Everything is up to date, no setup needed")
+	} else {
+		printInfo("%d pending change(s) — run 'mvx setup' to apply", changes)
+	}
+
+	return changes, nil
+}
@@ -0,0 +1,69 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"time"
+)
+
+// statusBadgeSummary is the JSON form of the toolchain status badge
+type statusBadgeSummary struct {
+	Status    string    `json:"status"`  // "up-to-date" or "pending"
+	Pending   int       `json:"pending"` // number of pending changes
+	CheckedAt time.Time `json:"checked_at"`
+}
+
+// printStatusBadge emits the toolchain status as an SVG badge or JSON summary
+func printStatusBadge(format string, changes int) error {
+	switch format {
+	case "svg":
+		message := "up to date"
+		color := "#4c1" // shields.io green
+		if changes > 0 {
+			message = fmt.Sprintf("%d pending", changes)
+			color = "#fe7d37" // shields.io orange
+		}
+		fmt.Println(renderBadgeSVG("mvx", message, color))
+		return nil
+
+	case "json":
+		summary := statusBadgeSummary{
+			Status:    "up-to-date",
+			Pending:   changes,
+			CheckedAt: time.Now().UTC(),
+		}
+		if changes > 0 {
+			summary.Status = "pending"
+		}
+		data, err := json.MarshalIndent(summary, "", "  ")
+		if err != nil {
+			return err
+		}
+		fmt.Println(string(data))
+		return nil
+	}
+
+	return fmt.Errorf("unknown badge format %s (supported: svg, json)", format)
+}
+
+// renderBadgeSVG renders a flat shields.io-style badge
+func renderBadgeSVG(label, message, color string) string {
+	// Approximate character width used by shields.io flat badges
+	labelWidth := 6*len(label) + 10
+	messageWidth := 6*len(message) + 10
+	totalWidth := labelWidth + messageWidth
+
+	return fmt.Sprintf(`<svg xmlns="http://www.w3.org/2000/svg" width="%d" height="20" role="img" aria-label="%s: %s">
+  <rect width="%d" height="20" fill="#555"/>
+  <rect x="%d" width="%d" height="20" fill="%s"/>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="%d" y="14">%s</text>
+    <text x="%d" y="14">%s</text>
+  </g>
+</svg>`,
+		totalWidth, label, message,
+		labelWidth,
+		labelWidth, messageWidth, color,
+		labelWidth/2, label,
+		labelWidth+messageWidth/2, message)
+}
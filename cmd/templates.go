@@ -0,0 +1,224 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// Built-in project templates for `mvx init --template`. Each scaffolds a
+// configuration with sensible tools, commands and hooks for a stack, so new
+// adopters start from something that works instead of a blank file.
+
+var builtinTemplates = map[string]string{
+	"maven-basic": `{
+  // Maven project managed by mvx
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: "my-project",
+    description: "A Maven project",
+  },
+
+  tools: {
+    java: { version: "21", distribution: "temurin" },
+    maven: { version: "3.9.9" },
+  },
+
+  commands: {
+    build: {
+      description: "Build the project",
+      script: "mvn -q clean install",
+    },
+    test: {
+      description: "Run tests",
+      script: "mvn -q verify",
+    },
+  },
+}
+`,
+	"maven-quarkus": `{
+  // Quarkus project managed by mvx
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: "my-quarkus-app",
+    description: "A Quarkus application",
+  },
+
+  tools: {
+    java: { version: "21", distribution: "temurin" },
+    maven: { version: "3.9.9" },
+  },
+
+  commands: {
+    build: {
+      description: "Build the application",
+      script: "mvn -q clean install",
+    },
+    dev: {
+      description: "Run in Quarkus dev mode (live reload)",
+      script: "mvn quarkus:dev",
+    },
+    "native": {
+      description: "Build a native executable",
+      script: "mvn -q clean install -Dnative",
+    },
+  },
+}
+`,
+	"gradle": `{
+  // Gradle project managed by mvx (uses the project's Gradle wrapper)
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: "my-project",
+    description: "A Gradle project",
+  },
+
+  tools: {
+    java: { version: "21", distribution: "temurin" },
+  },
+
+  commands: {
+    build: {
+      description: "Build the project",
+      script: {
+        unix: "./gradlew build",
+        windows: "gradlew.bat build",
+      },
+    },
+    test: {
+      description: "Run tests",
+      script: {
+        unix: "./gradlew test",
+        windows: "gradlew.bat test",
+      },
+    },
+  },
+}
+`,
+	"node": `{
+  // Node.js project managed by mvx
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: "my-app",
+    description: "A Node.js application",
+  },
+
+  tools: {
+    node: { version: "22" },
+  },
+
+  hooks: {
+    // Install dependencies right after the toolchain lands
+    postSetup: "npm ci",
+  },
+
+  commands: {
+    build: {
+      description: "Build the application",
+      script: "npm run build",
+    },
+    test: {
+      description: "Run tests",
+      script: "npm test",
+    },
+    dev: {
+      description: "Start the development server",
+      script: "npm run dev",
+    },
+  },
+}
+`,
+	"polyglot": `{
+  // Polyglot (Java backend + Node frontend) project managed by mvx
+  // See: https://github.com/gnodet/mvx for documentation
+
+  project: {
+    name: "my-project",
+    description: "A polyglot project",
+  },
+
+  tools: {
+    java: { version: "21", distribution: "temurin" },
+    maven: { version: "3.9.9" },
+    node: { version: "22" },
+  },
+
+  hooks: {
+    postSetup: "npm --prefix frontend ci",
+  },
+
+  commands: {
+    build: {
+      description: "Build backend and frontend",
+      script: [
+        "mvn -q clean install",
+        "npm --prefix frontend run build",
+      ],
+    },
+    test: {
+      description: "Run all tests",
+      script: [
+        "mvn -q verify",
+        "npm --prefix frontend test",
+      ],
+    },
+  },
+}
+`,
+}
+
+// templateNames lists the built-in template names, sorted
+func templateNames() []string {
+	names := make([]string, 0, len(builtinTemplates))
+	for name := range builtinTemplates {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// templateConfig resolves a template reference: a built-in name, or a git URL
+// whose repository carries an mvx configuration to copy
+func templateConfig(ref string) (string, error) {
+	if content, ok := builtinTemplates[ref]; ok {
+		return content, nil
+	}
+	if strings.Contains(ref, "://") || strings.HasSuffix(ref, ".git") {
+		return fetchTemplateFromGit(ref)
+	}
+	return "", fmt.Errorf("unknown template %q (built-in: %s, or pass a git URL)", ref, strings.Join(templateNames(), ", "))
+}
+
+// fetchTemplateFromGit clones the repository (depth 1) and returns the mvx
+// configuration it carries
+func fetchTemplateFromGit(url string) (string, error) {
+	tmpDir, err := os.MkdirTemp("", "mvx-template-*")
+	if err != nil {
+		return "", err
+	}
+	defer os.RemoveAll(tmpDir)
+
+	cloneCmd := exec.Command("git", "clone", "--depth", "1", url, tmpDir)
+	if output, err := cloneCmd.CombinedOutput(); err != nil {
+		return "", fmt.Errorf("failed to clone template %s: %v\n%s", url, err, strings.TrimSpace(string(output)))
+	}
+
+	for _, candidate := range []string{
+		filepath.Join(".mvx", "config.json5"),
+		filepath.Join(".mvx", "config.yml"),
+		"mvx.json5",
+	} {
+		content, err := os.ReadFile(filepath.Join(tmpDir, candidate))
+		if err == nil {
+			return string(content), nil
+		}
+	}
+	return "", fmt.Errorf("template repository %s carries no mvx configuration (.mvx/config.json5, .mvx/config.yml or mvx.json5)", url)
+}
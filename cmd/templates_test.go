@@ -0,0 +1,38 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestBuiltinTemplatesAreValid(t *testing.T) {
+	for name, content := range builtinTemplates {
+		t.Run(name, func(t *testing.T) {
+			tempDir := t.TempDir()
+			mvxDir := filepath.Join(tempDir, ".mvx")
+			if err := os.MkdirAll(mvxDir, 0755); err != nil {
+				t.Fatal(err)
+			}
+			if err := os.WriteFile(filepath.Join(mvxDir, "config.json5"), []byte(content), 0644); err != nil {
+				t.Fatal(err)
+			}
+
+			cfg, err := config.LoadConfig(tempDir)
+			if err != nil {
+				t.Fatalf("template %s does not load: %v", name, err)
+			}
+			if len(cfg.Tools) == 0 || len(cfg.Commands) == 0 {
+				t.Errorf("template %s should declare tools and commands", name)
+			}
+		})
+	}
+}
+
+func TestTemplateConfigUnknownName(t *testing.T) {
+	if _, err := templateConfig("no-such-template"); err == nil {
+		t.Error("expected error for unknown template name")
+	}
+}
@@ -0,0 +1,165 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// toolchainsCmd generates a Maven toolchains.xml covering every mvx-managed
+// JDK, so maven-toolchains-plugin based builds pick up the pinned JDKs
+// without hand-edited files in the user home.
+var toolchainsCmd = &cobra.Command{
+	Use:   "toolchains",
+	Short: "Generate a Maven toolchains.xml for the mvx-managed JDKs",
+	Long: `Generate a Maven toolchains.xml with a <toolchain> entry for every JDK
+installed by mvx.
+
+The file is written to ~/.mvx/toolchains.xml and, once it exists, 'mvx mvn'
+passes it to Maven automatically (unless the invocation already carries
+-t/--toolchains). Re-run the command after installing new JDK versions.
+
+Examples:
+  mvx toolchains           # (re)generate ~/.mvx/toolchains.xml
+  mvx mvn verify           # toolchains-aware plugins now find the pinned JDKs`,
+
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runToolchains(); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(toolchainsCmd)
+}
+
+// toolchainsFile returns where the generated toolchains.xml lives
+func toolchainsFile(manager *tools.Manager) string {
+	return filepath.Join(manager.GetCacheDir(), "toolchains.xml")
+}
+
+// jdkEntry describes one installed mvx-managed JDK
+type jdkEntry struct {
+	version      string
+	distribution string
+	home         string
+}
+
+// installedJDKs scans the java tool cache (version or version@distribution
+// directories) and returns the JDKs with a resolvable home
+func installedJDKs(manager *tools.Manager) ([]jdkEntry, error) {
+	javaTool, err := manager.GetTool("java")
+	if err != nil {
+		return nil, err
+	}
+
+	entries, err := os.ReadDir(manager.GetToolDir("java"))
+	if err != nil {
+		if os.IsNotExist(err) {
+			return nil, nil
+		}
+		return nil, err
+	}
+
+	var jdks []jdkEntry
+	for _, entry := range entries {
+		if !entry.IsDir() {
+			continue
+		}
+		version := entry.Name()
+		distribution := ""
+		if v, d, ok := strings.Cut(entry.Name(), "@"); ok {
+			version, distribution = v, d
+		}
+
+		binDir, err := javaTool.GetPath(version, config.ToolConfig{Version: version, Distribution: distribution})
+		if err != nil {
+			continue // partial or broken installation
+		}
+		home := binDir
+		if filepath.Base(home) == "bin" {
+			home = filepath.Dir(home)
+		}
+		if distribution == "" {
+			distribution = "temurin"
+		}
+		jdks = append(jdks, jdkEntry{version: version, distribution: distribution, home: home})
+	}
+
+	sort.Slice(jdks, func(i, j int) bool {
+		if jdks[i].version != jdks[j].version {
+			return jdks[i].version < jdks[j].version
+		}
+		return jdks[i].distribution < jdks[j].distribution
+	})
+	return jdks, nil
+}
+
+// javaMajorVersion reduces a full JDK version to the major toolchains-plugin
+// requirements usually name ("21.0.2" -> "21", "1.8.0_392" -> "8")
+func javaMajorVersion(version string) string {
+	version = strings.TrimPrefix(version, "1.")
+	if idx := strings.IndexAny(version, ".+-_"); idx > 0 {
+		version = version[:idx]
+	}
+	return version
+}
+
+// renderToolchainsXML builds the toolchains.xml content for the given JDKs
+func renderToolchainsXML(jdks []jdkEntry) string {
+	var b strings.Builder
+	b.WriteString("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+	b.WriteString("<!-- Generated by 'mvx toolchains'. Re-run it after installing new JDKs. -->\n")
+	b.WriteString("<toolchains>\n")
+	for _, jdk := range jdks {
+		b.WriteString("  <toolchain>\n")
+		b.WriteString("    <type>jdk</type>\n")
+		b.WriteString("    <provides>\n")
+		fmt.Fprintf(&b, "      <version>%s</version>\n", javaMajorVersion(jdk.version))
+		fmt.Fprintf(&b, "      <vendor>%s</vendor>\n", jdk.distribution)
+		b.WriteString("    </provides>\n")
+		b.WriteString("    <configuration>\n")
+		fmt.Fprintf(&b, "      <jdkHome>%s</jdkHome>\n", jdk.home)
+		b.WriteString("    </configuration>\n")
+		b.WriteString("  </toolchain>\n")
+	}
+	b.WriteString("</toolchains>\n")
+	return b.String()
+}
+
+// runToolchains writes the toolchains.xml covering the installed JDKs
+func runToolchains() error {
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+
+	jdks, err := installedJDKs(manager)
+	if err != nil {
+		return fmt.Errorf("failed to scan installed JDKs: %w", err)
+	}
+	if len(jdks) == 0 {
+		return fmt.Errorf("no mvx-managed JDKs installed yet (run 'mvx setup' first)")
+	}
+
+	path := toolchainsFile(manager)
+	if err := os.WriteFile(path, []byte(renderToolchainsXML(jdks)), 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+
+	printSuccess("Wrote %s with %d JDK toolchain(s):", path, len(jdks))
+	for _, jdk := range jdks {
+		printInfo("  - %s (%s): %s", javaMajorVersion(jdk.version), jdk.distribution, jdk.home)
+	}
+	printInfo("'mvx mvn' now passes it to Maven automatically.")
+	return nil
+}
@@ -0,0 +1,55 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestJavaMajorVersion(t *testing.T) {
+	cases := map[string]string{
+		"21":        "21",
+		"21.0.2":    "21",
+		"17.0.9+9":  "17",
+		"1.8.0_392": "8",
+		"25-ea":     "25",
+	}
+	for input, want := range cases {
+		if got := javaMajorVersion(input); got != want {
+			t.Errorf("javaMajorVersion(%q) = %q, want %q", input, got, want)
+		}
+	}
+}
+
+func TestRenderToolchainsXML(t *testing.T) {
+	xml := renderToolchainsXML([]jdkEntry{
+		{version: "17.0.9", distribution: "temurin", home: "/home/u/.mvx/tools/java/17.0.9@temurin"},
+		{version: "21.0.2", distribution: "zulu", home: "/home/u/.mvx/tools/java/21.0.2@zulu"},
+	})
+
+	for _, want := range []string{
+		"<toolchains>",
+		"<version>17</version>",
+		"<vendor>temurin</vendor>",
+		"<jdkHome>/home/u/.mvx/tools/java/21.0.2@zulu</jdkHome>",
+		"<vendor>zulu</vendor>",
+	} {
+		if !strings.Contains(xml, want) {
+			t.Errorf("generated toolchains.xml misses %q:\n%s", want, xml)
+		}
+	}
+	if strings.Count(xml, "<toolchain>") != 2 {
+		t.Errorf("expected 2 toolchain entries:\n%s", xml)
+	}
+}
+
+func TestHasToolchainsArg(t *testing.T) {
+	if hasToolchainsArg([]string{"clean", "install"}) {
+		t.Error("expected no toolchains arg")
+	}
+	if !hasToolchainsArg([]string{"-t", "my.xml", "verify"}) {
+		t.Error("expected -t to be detected")
+	}
+	if !hasToolchainsArg([]string{"--global-toolchains", "my.xml"}) {
+		t.Error("expected --global-toolchains to be detected")
+	}
+}
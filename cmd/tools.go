@@ -3,6 +3,8 @@ package cmd
 import (
 	"fmt"
 	"os"
+	"path/filepath"
+	"sort"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
@@ -20,7 +22,16 @@ Subcommands:
   list       List available tools and their versions
   search     Search for specific tool versions
   info       Show detailed information about a tool
-  add        Add a tool to the project configuration`,
+  add        Add a tool to the project configuration
+  remove     Remove a tool from the project configuration
+  lock       Pin resolved tool versions in .mvx/mvx.lock
+  outdated   Show locked tools with newer versions available
+  update     Re-resolve version specs and refresh the lockfile
+  resolve    Resolve a tool's download URL without installing (dry run)
+  verify     Re-check installed tools, reinstalling corrupted ones
+
+add and remove edit the config file in place, preserving comments, key order
+and formatting.`,
 
 	Run: func(cmd *cobra.Command, args []string) {
 		if len(args) == 0 {
@@ -44,7 +55,12 @@ Subcommands:
 				printError("search requires a tool name")
 				os.Exit(1)
 			}
-			if err := searchTool(args[1], args[2:]); err != nil {
+			// --lts is sugar for the "lts" filter understood by the tools
+			filters := args[2:]
+			if searchLTSOnly {
+				filters = append(append([]string{}, filters...), "lts")
+			}
+			if err := searchTool(args[1], filters); err != nil {
 				printError("%v", err)
 				os.Exit(1)
 			}
@@ -71,6 +87,58 @@ Subcommands:
 				printError("%v", err)
 				os.Exit(1)
 			}
+		case "remove":
+			if len(args) < 2 {
+				printError("remove requires a tool name")
+				printError("Usage: mvx tools remove <tool>")
+				os.Exit(1)
+			}
+			if err := removeTool(args[1]); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		case "lock":
+			if err := lockTools(); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		case "outdated":
+			if err := outdatedTools(false); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		case "update":
+			// Dry run reports the upgrades without refreshing the lockfile
+			if err := outdatedTools(!toolsDryRun); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		case "resolve":
+			// --dry-run is accepted for clarity; resolve never downloads
+			var rest []string
+			for _, arg := range args[1:] {
+				if arg != "--dry-run" {
+					rest = append(rest, arg)
+				}
+			}
+			if len(rest) < 1 {
+				printError("resolve requires a tool name")
+				printError("Usage: mvx tools resolve <tool> [version] [--dry-run]")
+				os.Exit(1)
+			}
+			toolVersion := ""
+			if len(rest) >= 2 {
+				toolVersion = rest[1]
+			}
+			if err := resolveTool(rest[0], toolVersion); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
+		case "verify":
+			if err := verifyInstalledTools(); err != nil {
+				printError("%v", err)
+				os.Exit(1)
+			}
 		default:
 			printError("unknown subcommand: %s", subcommand)
 			cmd.Help()
@@ -79,10 +147,39 @@ Subcommands:
 	},
 }
 
+var (
+	searchLTSOnly bool
+	toolsDryRun   bool
+)
+
 func init() {
+	toolsCmd.Flags().BoolVar(&searchLTSOnly, "lts", false, "restrict search results to LTS releases")
+	toolsCmd.Flags().BoolVar(&toolsDryRun, "dry-run", false, "for add and update: print what would change without writing anything")
+	toolsCmd.ValidArgsFunction = completeToolsArgs
 	rootCmd.AddCommand(toolsCmd)
 }
 
+// completeToolsArgs completes the tools subcommand and, where the next
+// argument is a tool, the registered tool names
+func completeToolsArgs(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	if len(args) == 0 {
+		return []string{"list", "search", "info", "add", "remove", "lock", "outdated", "update", "resolve", "verify"}, cobra.ShellCompDirectiveNoFileComp
+	}
+	if len(args) == 1 {
+		switch args[0] {
+		case "search", "info", "add", "remove", "resolve":
+			manager, err := tools.NewManager()
+			if err != nil {
+				return nil, cobra.ShellCompDirectiveNoFileComp
+			}
+			names := manager.GetToolNames()
+			sort.Strings(names)
+			return names, cobra.ShellCompDirectiveNoFileComp
+		}
+	}
+	return nil, cobra.ShellCompDirectiveNoFileComp
+}
+
 // listTools shows all available tools
 func listTools() error {
 	manager, err := tools.NewManager()
@@ -90,15 +187,38 @@ func listTools() error {
 		return fmt.Errorf("failed to create tool manager: %w", err)
 	}
 
-	printInfo("🛠️  Available Tools")
-	printInfo("")
-
 	// Get all tools from manager
 	allTools := manager.GetAllTools()
 
 	// Define tool order for consistent display
 	toolOrder := []string{tools.ToolJava, tools.ToolMaven, tools.ToolMvnd, tools.ToolNode, tools.ToolGo}
 
+	if jsonOutput() {
+		type toolEntry struct {
+			Name          string   `json:"name"`
+			DisplayName   string   `json:"displayName"`
+			Distributions []string `json:"distributions,omitempty"`
+		}
+		var entries []toolEntry
+		for _, toolName := range toolOrder {
+			tool, exists := allTools[toolName]
+			if !exists {
+				continue
+			}
+			entry := toolEntry{Name: toolName, DisplayName: tool.GetDisplayName()}
+			if distProvider, ok := tool.(tools.DistributionProvider); ok {
+				for _, dist := range distProvider.GetDistributions() {
+					entry.Distributions = append(entry.Distributions, dist.Name)
+				}
+			}
+			entries = append(entries, entry)
+		}
+		return printJSON(map[string]interface{}{"tools": entries})
+	}
+
+	printInfo("🛠️  Available Tools")
+	printInfo("")
+
 	for _, toolName := range toolOrder {
 		tool, exists := allTools[toolName]
 		if !exists {
@@ -180,6 +300,10 @@ func searchTool(toolName string, filters []string) error {
 		return err
 	}
 
+	if jsonOutput() {
+		return printJSON(map[string]interface{}{"tool": toolName, "versions": versions})
+	}
+
 	// Print the search results
 	printInfo("🔍 %s Versions", strings.Title(toolName))
 	printInfo("")
@@ -254,6 +378,260 @@ func showToolInfo(toolName string) error {
 	return nil
 }
 
+// resolveTool resolves a tool's version and download URL without installing,
+// so URL templates and asset patterns can be validated against real releases
+func resolveTool(toolName, versionSpec string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	toolConfig := cfg.Tools[toolName]
+	if versionSpec != "" {
+		toolConfig.Version = versionSpec
+	}
+	if toolConfig.Version == "" {
+		return fmt.Errorf("no version given and tool %s is not configured in this project", toolName)
+	}
+
+	resolved, err := manager.ResolveVersion(toolName, toolConfig)
+	if err != nil {
+		return fmt.Errorf("failed to resolve version %s: %w", toolConfig.Version, err)
+	}
+
+	tool, err := manager.GetTool(toolName)
+	if err != nil {
+		return err
+	}
+
+	printInfo("🔍 %s %s", toolName, toolConfig.Version)
+	printInfo("  Resolved version: %s", resolved)
+
+	var url string
+	if customTool, ok := tool.(*tools.CustomTool); ok {
+		url, err = customTool.ResolveDownloadURL(resolved)
+		if err != nil {
+			return err
+		}
+	} else {
+		url = tool.GetDownloadURL(resolved)
+		if url == "" {
+			return fmt.Errorf("failed to determine download URL for %s %s", toolName, resolved)
+		}
+	}
+	printInfo("  Download URL:     %s", url)
+	printInfo("")
+	printInfo("Nothing was downloaded (dry run).")
+
+	return nil
+}
+
+// verifyInstalledTools re-runs each installed tool's verification command
+// (java -version, mvn -v, node --version, ...) and reinstalls installations
+// that no longer work — half-extracted archives, broken symlinks
+func verifyInstalledTools() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	broken := 0
+	printInfo("🔍 Verifying installed tools...")
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
+		resolved, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			printWarning("  %s: failed to resolve version %s: %v", toolName, toolConfig.Version, err)
+			broken++
+			continue
+		}
+		resolvedConfig := toolConfig
+		resolvedConfig.Version = resolved
+
+		tool, err := manager.GetTool(toolName)
+		if err != nil {
+			printWarning("  %s: %v", toolName, err)
+			broken++
+			continue
+		}
+		if !tool.IsInstalled(resolved, resolvedConfig) {
+			printInfo("  ⏭️  %s %s: not installed (run 'mvx setup')", toolName, resolved)
+			continue
+		}
+
+		if err := tool.Verify(resolved, resolvedConfig); err == nil {
+			printInfo("  ✅ %s %s", toolName, resolved)
+			continue
+		} else {
+			printWarning("  ❌ %s %s is corrupted: %v", toolName, resolved, err)
+		}
+
+		printInfo("  🔄 Reinstalling %s %s...", toolName, resolved)
+		if err := manager.RepairTool(toolName, toolConfig); err != nil {
+			printError("  Failed to reinstall %s %s: %v", toolName, resolved, err)
+			broken++
+			continue
+		}
+		printSuccess("  ✅ %s %s reinstalled", toolName, resolved)
+	}
+
+	if broken > 0 {
+		return fmt.Errorf("%d tool(s) remain broken", broken)
+	}
+	printSuccess("✅ All installed tools are healthy")
+	return nil
+}
+
+// lockTools resolves every configured tool and pins the results in the
+// project lockfile
+func lockTools() error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.ConfigureRegistries(cfg)
+
+	if err := manager.WriteLockFile(projectRoot, cfg); err != nil {
+		return err
+	}
+
+	printSuccess("🔒 Locked tool versions in %s", tools.LockFileName)
+	return nil
+}
+
+// outdatedTools compares locked versions against what each version spec
+// resolves to today and prints the available upgrades. With update=true it
+// also refreshes the lockfile to the new resolutions.
+func outdatedTools(update bool) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	lock, err := tools.LoadLockFile(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	// Deliberately skip LoadProjectLock: resolution must see current upstream
+	// releases, not the pins we are checking against
+	manager, err := tools.NewManager()
+	if err != nil {
+		return fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.ConfigureRegistries(cfg)
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	outdated := 0
+	printInfo("%-12s %-14s %-14s %s", "TOOL", "SPEC", "LOCKED", "LATEST")
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
+		locked := "-"
+		if lock != nil {
+			if entry, exists := lock.Tools[toolName]; exists && entry.Spec == toolConfig.Version && entry.Distribution == toolConfig.Distribution {
+				locked = entry.Version
+			}
+		}
+
+		latest, err := manager.ResolveVersion(toolName, toolConfig)
+		if err != nil {
+			printInfo("%-12s %-14s %-14s (resolution failed: %v)", toolName, toolConfig.Version, locked, err)
+			continue
+		}
+
+		marker := ""
+		if locked != "-" && locked != latest {
+			marker = "  ⬆️"
+			outdated++
+		}
+		printInfo("%-12s %-14s %-14s %s%s", toolName, toolConfig.Version, locked, latest, marker)
+	}
+
+	if outdated == 0 {
+		printInfo("")
+		printSuccess("✅ All locked tools are up to date")
+		return nil
+	}
+
+	printInfo("")
+	if !update {
+		printInfo("%d tool(s) can be upgraded. Run 'mvx tools update' to refresh the lockfile.", outdated)
+		return nil
+	}
+
+	if err := manager.WriteLockFile(projectRoot, cfg); err != nil {
+		return err
+	}
+	printSuccess("🔒 Updated %s with %d new version(s). Run 'mvx setup' to install them.", tools.LockFileName, outdated)
+	return nil
+}
+
 // addTool adds a tool to the project configuration
 func addTool(toolName, version, distribution string) error {
 	// Find project root
@@ -303,11 +681,35 @@ func addTool(toolName, version, distribution string) error {
 		printInfo("Updating to version '%s'", version)
 	}
 
+	// Dry run: show the resolved change without touching the config file
+	if toolsDryRun {
+		configFile := ".mvx/config.json5"
+		if path, err := findProjectConfigFile(projectRoot); err == nil {
+			configFile = path
+		}
+		printInfo("Would set %s to %s in %s", toolName, version, configFile)
+		if resolved, err := manager.ResolveVersion(toolName, toolConfig); err == nil && resolved != version {
+			printInfo("  Resolves to: %s", resolved)
+		}
+		printInfo("Nothing was written (dry run). Run without --dry-run to apply.")
+		return nil
+	}
+
 	// Add/update the tool
 	cfg.Tools[toolName] = toolConfig
 
-	// Save the configuration
-	if err := config.SaveConfig(cfg, projectRoot); err != nil {
+	// Save the configuration, editing JSON5 files in place so comments and
+	// formatting survive
+	if err := saveToolEdit(projectRoot, cfg, func(content string) (string, error) {
+		content, err := config.SetJSON5Value(content, []string{"tools", toolName, "version"}, fmt.Sprintf("%q", version))
+		if err != nil {
+			return "", err
+		}
+		if toolConfig.Distribution != "" {
+			return config.SetJSON5Value(content, []string{"tools", toolName, "distribution"}, fmt.Sprintf("%q", toolConfig.Distribution))
+		}
+		return content, nil
+	}); err != nil {
 		return fmt.Errorf("failed to save configuration: %w", err)
 	}
 
@@ -321,3 +723,58 @@ func addTool(toolName, version, distribution string) error {
 
 	return nil
 }
+
+// removeTool removes a tool from the project configuration
+func removeTool(toolName string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	if _, exists := cfg.Tools[toolName]; !exists {
+		return fmt.Errorf("tool %s is not configured in this project", toolName)
+	}
+	delete(cfg.Tools, toolName)
+
+	if err := saveToolEdit(projectRoot, cfg, func(content string) (string, error) {
+		return config.RemoveJSON5Key(content, []string{"tools", toolName})
+	}); err != nil {
+		return fmt.Errorf("failed to save configuration: %w", err)
+	}
+
+	printSuccess("✅ Removed %s from project configuration", toolName)
+	return nil
+}
+
+// saveToolEdit persists a tools change. JSON5/JSON config files are edited
+// textually (preserving comments, key order and formatting). YAML/TOML
+// configs are refused rather than rewritten: SaveConfig would emit a
+// config.json5 rendered from the merged runtime config, which shadows the
+// user's file on every subsequent load.
+func saveToolEdit(projectRoot string, cfg *config.Config, edit func(string) (string, error)) error {
+	configPath, err := findProjectConfigFile(projectRoot)
+	if err != nil {
+		// No config file yet: write a fresh one
+		return config.SaveConfig(cfg, projectRoot)
+	}
+
+	ext := strings.ToLower(filepath.Ext(configPath))
+	if ext != ".json5" && ext != ".json" {
+		return fmt.Errorf("automatic edits only support JSON5/JSON configuration; edit %s manually", configPath)
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return err
+	}
+	edited, err := edit(string(data))
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(configPath, []byte(edited), 0644)
+}
@@ -0,0 +1,155 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/spf13/cobra"
+)
+
+var trustRevoke bool
+
+// trustCmd marks a project as trusted for automatic environment activation.
+// The shell hooks from 'mvx activate' refuse to activate projects that have
+// not been trusted, so cloning a repository cannot silently change PATH.
+var trustCmd = &cobra.Command{
+	Use:   "trust [path]",
+	Short: "Trust a project for automatic environment activation",
+	Long: `Trust a project for automatic environment activation.
+
+The shell hooks installed by 'mvx activate' only export a project's
+environment after it has been trusted once, so simply cd-ing into a freshly
+cloned repository cannot change PATH or other variables. Trust is recorded
+per project directory in ~/.mvx/trusted.
+
+Examples:
+  mvx trust                 # Trust the current project
+  mvx trust ~/work/app      # Trust a specific project
+  mvx trust --revoke        # Stop trusting the current project`,
+
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runTrust(args); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	trustCmd.Flags().BoolVar(&trustRevoke, "revoke", false, "remove the project from the trusted list")
+	rootCmd.AddCommand(trustCmd)
+}
+
+// runTrust adds or removes a project from the trusted list
+func runTrust(args []string) error {
+	var projectRoot string
+	var err error
+	if len(args) > 0 {
+		projectRoot, err = filepath.Abs(args[0])
+		if err != nil {
+			return err
+		}
+	} else {
+		projectRoot, err = findProjectRoot()
+		if err != nil {
+			return fmt.Errorf("no mvx project found in the current directory")
+		}
+	}
+
+	if trustRevoke {
+		if err := setProjectTrusted(projectRoot, false); err != nil {
+			return err
+		}
+		printSuccess("Revoked trust for %s", projectRoot)
+		return nil
+	}
+
+	if err := setProjectTrusted(projectRoot, true); err != nil {
+		return err
+	}
+	printSuccess("Trusted %s for automatic activation", projectRoot)
+	return nil
+}
+
+// trustFile returns the path of the trusted-projects list (~/.mvx/trusted)
+func trustFile() (string, error) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to determine home directory: %w", err)
+	}
+	return filepath.Join(home, ".mvx", "trusted"), nil
+}
+
+// trustedProjects reads the trusted-projects list
+func trustedProjects() ([]string, error) {
+	path, err := trustFile()
+	if err != nil {
+		return nil, err
+	}
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	var projects []string
+	for _, line := range strings.Split(string(data), "\n") {
+		line = strings.TrimSpace(line)
+		if line != "" {
+			projects = append(projects, line)
+		}
+	}
+	return projects, nil
+}
+
+// isProjectTrusted reports whether a project directory is on the trusted list
+func isProjectTrusted(projectRoot string) bool {
+	projects, err := trustedProjects()
+	if err != nil {
+		return false
+	}
+	cleaned := filepath.Clean(projectRoot)
+	for _, project := range projects {
+		if filepath.Clean(project) == cleaned {
+			return true
+		}
+	}
+	return false
+}
+
+// setProjectTrusted adds or removes a project directory on the trusted list
+func setProjectTrusted(projectRoot string, trusted bool) error {
+	projects, err := trustedProjects()
+	if err != nil {
+		return err
+	}
+
+	cleaned := filepath.Clean(projectRoot)
+	var kept []string
+	for _, project := range projects {
+		if filepath.Clean(project) != cleaned {
+			kept = append(kept, project)
+		}
+	}
+	if trusted {
+		kept = append(kept, cleaned)
+	}
+
+	path, err := trustFile()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+	content := strings.Join(kept, "\n")
+	if content != "" {
+		content += "\n"
+	}
+	return os.WriteFile(path, []byte(content), 0644)
+}
@@ -0,0 +1,36 @@
+package cmd
+
+import (
+	"path/filepath"
+	"runtime"
+	"testing"
+)
+
+func TestProjectTrustRoundTrip(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("HOME override is not reliable on Windows")
+	}
+	t.Setenv("HOME", t.TempDir())
+
+	project := filepath.Join("/", "work", "app")
+	if isProjectTrusted(project) {
+		t.Error("project should not be trusted initially")
+	}
+
+	if err := setProjectTrusted(project, true); err != nil {
+		t.Fatalf("setProjectTrusted() error = %v", err)
+	}
+	if !isProjectTrusted(project) {
+		t.Error("project should be trusted after setProjectTrusted(true)")
+	}
+	if isProjectTrusted(filepath.Join("/", "work", "other")) {
+		t.Error("unrelated project should not be trusted")
+	}
+
+	if err := setProjectTrusted(project, false); err != nil {
+		t.Fatalf("setProjectTrusted() error = %v", err)
+	}
+	if isProjectTrusted(project) {
+		t.Error("project should not be trusted after revocation")
+	}
+}
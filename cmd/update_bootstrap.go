@@ -7,9 +7,11 @@ import (
 	"net/http"
 	"os"
 	"path/filepath"
+	"sort"
 	"strings"
 	"time"
 
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/spf13/cobra"
 )
 
@@ -25,9 +27,19 @@ This command will:
   - Update the local mvx and mvx.cmd files
   - Update version configuration files
 
+The version the wrapper bootstraps is pinned in .mvx/mvx.properties, so a
+new mvx release never changes behavior until the pin is updated and
+committed. By default only stable releases are considered; projects that
+want release candidates can opt in with --channel rc (the choice is
+recorded as updateChannel in .mvx/mvx.properties).
+
 Examples:
-  mvx update-bootstrap           # Update to latest version
-  mvx update-bootstrap --check   # Only check for updates, don't update`,
+  mvx update-bootstrap                 # Update the pin to the latest stable release
+  mvx update-bootstrap --check         # Only check for updates, don't update
+  mvx update-bootstrap --check --json  # Machine-readable check result for bots
+  mvx update-bootstrap --check --json | jq -r .latest
+  mvx update-bootstrap --version 0.9.2 # Pin an exact version
+  mvx update-bootstrap --channel rc    # Follow release candidates too`,
 
 	Run: func(cmd *cobra.Command, args []string) {
 		if err := updateBootstrap(); err != nil {
@@ -38,18 +50,33 @@ Examples:
 }
 
 var (
-	checkOnly bool
+	checkOnly     bool
+	updateJSON    bool
+	pinVersion    string
+	updateChannel string
 )
 
 func init() {
 	updateBootstrapCmd.Flags().BoolVar(&checkOnly, "check", false, "only check for updates, don't update")
+	updateBootstrapCmd.Flags().BoolVar(&updateJSON, "json", false, "with --check, report current/latest versions as JSON")
+	updateBootstrapCmd.Flags().StringVar(&pinVersion, "version", "", "pin an exact mvx version instead of the channel's latest")
+	updateBootstrapCmd.Flags().StringVar(&updateChannel, "channel", "", "update channel: stable (default) or rc")
+}
+
+// updateCheckReport is the machine-readable result of --check --json
+type updateCheckReport struct {
+	Current         string `json:"current"`
+	Latest          string `json:"latest"`
+	Channel         string `json:"channel"`
+	UpdateAvailable bool   `json:"updateAvailable"`
 }
 
 // GitHubRelease represents a GitHub release
 type GitHubRelease struct {
-	TagName string `json:"tag_name"`
-	Name    string `json:"name"`
-	HTMLURL string `json:"html_url"`
+	TagName    string `json:"tag_name"`
+	Name       string `json:"name"`
+	HTMLURL    string `json:"html_url"`
+	Prerelease bool   `json:"prerelease"`
 }
 
 // getLatestRelease fetches the latest release information from GitHub
@@ -58,15 +85,51 @@ func getLatestRelease() (*GitHubRelease, error) {
 
 	printVerbose("Fetching latest release from: %s", url)
 
-	client := &http.Client{
-		Timeout: 30 * time.Second,
+	client := util.HTTPClient(30 * time.Second)
+	resp, err := client.Get(url)
+	if err != nil {
+		return nil, fmt.Errorf("failed to fetch release information: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("GitHub API returned status %d", resp.StatusCode)
 	}
+
+	body, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read response body: %w", err)
+	}
+
+	var release GitHubRelease
+	if err := json.Unmarshal(body, &release); err != nil {
+		return nil, fmt.Errorf("failed to parse release information: %w", err)
+	}
+
+	return &release, nil
+}
+
+// getReleaseByTag fetches a specific release, so --version can pin builds
+// that are no longer the latest
+func getReleaseByTag(version string) (*GitHubRelease, error) {
+	tag := version
+	if !strings.HasPrefix(tag, "v") {
+		tag = "v" + tag
+	}
+	url := fmt.Sprintf("https://api.github.com/repos/gnodet/mvx/releases/tags/%s", tag)
+
+	printVerbose("Fetching release from: %s", url)
+
+	client := util.HTTPClient(30 * time.Second)
 	resp, err := client.Get(url)
 	if err != nil {
 		return nil, fmt.Errorf("failed to fetch release information: %w", err)
 	}
 	defer resp.Body.Close()
 
+	if resp.StatusCode == http.StatusNotFound {
+		return nil, fmt.Errorf("no mvx release %s exists", tag)
+	}
 	if resp.StatusCode != http.StatusOK {
 		return nil, fmt.Errorf("GitHub API returned status %d", resp.StatusCode)
 	}
@@ -84,37 +147,102 @@ func getLatestRelease() (*GitHubRelease, error) {
 	return &release, nil
 }
 
+// getLatestReleaseForChannel resolves the newest release eligible on a
+// channel. The stable channel maps to GitHub's releases/latest (which never
+// returns prereleases); the rc channel scans the release list so release
+// candidates count too.
+func getLatestReleaseForChannel(channel string) (*GitHubRelease, error) {
+	if channel == "stable" {
+		return getLatestRelease()
+	}
+
+	url := "https://api.github.com/repos/gnodet/mvx/releases?per_page=20"
+
+	printVerbose("Fetching releases from: %s", url)
+
+	client := util.HTTPClient(30 * time.Second)
+	resp, err := client.Get(url)
+	if err != nil {
+		return nil, fmt.Errorf("failed to fetch release information: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("GitHub API returned status %d", resp.StatusCode)
+	}
+
+	body, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read response body: %w", err)
+	}
+
+	var releases []GitHubRelease
+	if err := json.Unmarshal(body, &releases); err != nil {
+		return nil, fmt.Errorf("failed to parse release information: %w", err)
+	}
+
+	release := pickReleaseForChannel(releases, channel)
+	if release == nil {
+		return nil, fmt.Errorf("no release found on the %s channel", channel)
+	}
+	return release, nil
+}
+
+// pickReleaseForChannel returns the first (newest) release eligible on the
+// channel: stable skips prereleases, rc accepts them too
+func pickReleaseForChannel(releases []GitHubRelease, channel string) *GitHubRelease {
+	for i, release := range releases {
+		if release.Prerelease && channel != "rc" {
+			continue
+		}
+		return &releases[i]
+	}
+	return nil
+}
+
+// resolveUpdateChannel picks the channel: the --channel flag wins, then the
+// updateChannel recorded in .mvx/mvx.properties, then stable
+func resolveUpdateChannel() (string, error) {
+	channel := updateChannel
+	if channel == "" {
+		channel = getPropertiesValue(".mvx/mvx.properties", "updateChannel")
+	}
+	if channel == "" {
+		channel = "stable"
+	}
+	if channel != "stable" && channel != "rc" {
+		return "", fmt.Errorf("unknown update channel %q (supported: stable, rc)", channel)
+	}
+	return channel, nil
+}
+
 // getCurrentVersion reads the current version from .mvx/mvx.properties file
 func getCurrentVersion() (string, error) {
-	propertiesFile := ".mvx/mvx.properties"
-	if _, err := os.Stat(propertiesFile); os.IsNotExist(err) {
-		return "", nil // No properties file means no current version
-	}
+	return getPropertiesValue(".mvx/mvx.properties", "mvxVersion"), nil
+}
 
+// getPropertiesValue reads one key from a properties file; a missing file or
+// key is simply the empty string
+func getPropertiesValue(propertiesFile, key string) string {
 	content, err := os.ReadFile(propertiesFile)
 	if err != nil {
-		return "", fmt.Errorf("failed to read properties file: %w", err)
+		return ""
 	}
 
-	// Parse the properties file to find mvxVersion
-	lines := strings.Split(string(content), "\n")
-	for _, line := range lines {
+	for _, line := range strings.Split(string(content), "\n") {
 		line = strings.TrimSpace(line)
-		if strings.HasPrefix(line, "mvxVersion=") {
-			return strings.TrimSpace(strings.TrimPrefix(line, "mvxVersion=")), nil
+		if strings.HasPrefix(line, key+"=") {
+			return strings.TrimSpace(strings.TrimPrefix(line, key+"="))
 		}
 	}
-
-	return "", nil // No mvxVersion found
+	return ""
 }
 
 // downloadFile downloads a file from the given URL and saves it to the specified path
 func downloadFile(url, filepath string) error {
 	printVerbose("Downloading %s to %s", url, filepath)
 
-	client := &http.Client{
-		Timeout: 300 * time.Second, // 5 minute timeout for file downloads
-	}
+	client := util.HTTPClient(300 * time.Second) // 5 minute timeout for file downloads
 	resp, err := client.Get(url)
 	if err != nil {
 		return fmt.Errorf("failed to download %s: %w", url, err)
@@ -150,7 +278,9 @@ func downloadFile(url, filepath string) error {
 }
 
 // updatePropertiesFile updates the mvx.properties file with the new version
-func updatePropertiesFile(propertiesFile, version, baseURL string) error {
+// and, when a non-default channel is in use, records it so later updates
+// stay on the same channel
+func updatePropertiesFile(propertiesFile, version, channel, baseURL string) error {
 	if _, err := os.Stat(propertiesFile); os.IsNotExist(err) {
 		// Download properties file if it doesn't exist
 		printVerbose("Downloading mvx.properties...")
@@ -168,20 +298,9 @@ func updatePropertiesFile(propertiesFile, version, baseURL string) error {
 		return fmt.Errorf("failed to read properties file: %w", err)
 	}
 
-	// Update the mvxVersion line
-	lines := strings.Split(string(content), "\n")
-	updated := false
-	for i, line := range lines {
-		if strings.HasPrefix(strings.TrimSpace(line), "mvxVersion=") {
-			lines[i] = fmt.Sprintf("mvxVersion=%s", version)
-			updated = true
-			break
-		}
-	}
-
-	// If mvxVersion line wasn't found, add it
-	if !updated {
-		lines = append(lines, fmt.Sprintf("mvxVersion=%s", version))
+	lines := setPropertiesLine(strings.Split(string(content), "\n"), "mvxVersion", version)
+	if channel != "" && channel != "stable" {
+		lines = setPropertiesLine(lines, "updateChannel", channel)
 	}
 
 	// Write back the updated content
@@ -189,18 +308,140 @@ func updatePropertiesFile(propertiesFile, version, baseURL string) error {
 	return os.WriteFile(propertiesFile, []byte(updatedContent), 0644)
 }
 
+// fetchReleaseChecksums downloads the checksums.txt asset of a release and
+// returns SHA-256 digests keyed by platform (linux-amd64, windows-amd64, ...)
+func fetchReleaseChecksums(version string) (map[string]string, error) {
+	url := fmt.Sprintf("https://github.com/gnodet/mvx/releases/download/v%s/checksums.txt", version)
+
+	printVerbose("Fetching checksums from: %s", url)
+
+	client := util.HTTPClient(30 * time.Second)
+	resp, err := client.Get(url)
+	if err != nil {
+		return nil, fmt.Errorf("failed to fetch checksums: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("no checksums.txt published for version %s (HTTP %d)", version, resp.StatusCode)
+	}
+
+	body, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read checksums: %w", err)
+	}
+
+	checksums := parseChecksums(string(body))
+	if len(checksums) == 0 {
+		return nil, fmt.Errorf("checksums.txt for version %s contains no mvx binaries", version)
+	}
+	return checksums, nil
+}
+
+// parseChecksums parses sha256sum output ("<digest>  mvx-<platform>[.exe]")
+func parseChecksums(content string) map[string]string {
+	checksums := make(map[string]string)
+	for _, line := range strings.Split(content, "\n") {
+		fields := strings.Fields(line)
+		if len(fields) != 2 {
+			continue
+		}
+		name := strings.TrimSuffix(fields[1], ".exe")
+		platform, ok := strings.CutPrefix(name, "mvx-")
+		if !ok {
+			continue
+		}
+		checksums[platform] = fields[0]
+	}
+	return checksums
+}
+
+// recordBootstrapChecksums pins per-platform binary checksums in
+// mvx.properties (mvxChecksum.<platform>=<sha256>), so the wrapper scripts
+// can verify the binary they download before executing it. Floating
+// versions (latest, dev) have nothing stable to pin against and are skipped.
+func recordBootstrapChecksums(propertiesFile string) error {
+	version := getPropertiesValue(propertiesFile, "mvxVersion")
+	if version == "" || version == "latest" || version == "dev" {
+		return nil
+	}
+	if util.IsOffline() {
+		printVerbose("Offline mode, skipping checksum pinning")
+		return nil
+	}
+
+	checksums, err := fetchReleaseChecksums(version)
+	if err != nil {
+		return err
+	}
+
+	content, err := os.ReadFile(propertiesFile)
+	if err != nil {
+		return fmt.Errorf("failed to read properties file: %w", err)
+	}
+
+	lines := strings.Split(string(content), "\n")
+	platforms := make([]string, 0, len(checksums))
+	for platform := range checksums {
+		platforms = append(platforms, platform)
+	}
+	sort.Strings(platforms)
+	for _, platform := range platforms {
+		lines = setPropertiesLine(lines, "mvxChecksum."+platform, checksums[platform])
+	}
+
+	return os.WriteFile(propertiesFile, []byte(strings.Join(lines, "\n")), 0644)
+}
+
+// setPropertiesLine replaces a key's line in properties-file content, or
+// appends one when the key is absent
+func setPropertiesLine(lines []string, key, value string) []string {
+	for i, line := range lines {
+		if strings.HasPrefix(strings.TrimSpace(line), key+"=") {
+			lines[i] = fmt.Sprintf("%s=%s", key, value)
+			return lines
+		}
+	}
+	// Append before a trailing empty line when there is one
+	if n := len(lines); n > 0 && strings.TrimSpace(lines[n-1]) == "" {
+		return append(lines[:n-1], fmt.Sprintf("%s=%s", key, value), "")
+	}
+	return append(lines, fmt.Sprintf("%s=%s", key, value))
+}
+
 // updateBootstrap performs the bootstrap update
 func updateBootstrap() error {
-	printInfo("🔍 Checking for mvx bootstrap updates...")
+	if updateJSON && !checkOnly {
+		return fmt.Errorf("--json is only meaningful with --check")
+	}
 
-	// Get latest release
-	release, err := getLatestRelease()
+	channel, err := resolveUpdateChannel()
 	if err != nil {
-		return fmt.Errorf("failed to get latest release: %w", err)
+		return err
+	}
+
+	// Resolve the target release: an explicit pin wins over the channel
+	var release *GitHubRelease
+	if pinVersion != "" {
+		if !updateJSON {
+			printInfo("🔍 Resolving mvx release %s...", pinVersion)
+		}
+		release, err = getReleaseByTag(pinVersion)
+		if err != nil {
+			return err
+		}
+	} else {
+		if !updateJSON {
+			printInfo("🔍 Checking for mvx bootstrap updates (%s channel)...", channel)
+		}
+		release, err = getLatestReleaseForChannel(channel)
+		if err != nil {
+			return fmt.Errorf("failed to get latest release: %w", err)
+		}
 	}
 
 	latestVersion := strings.TrimPrefix(release.TagName, "v")
-	printVerbose("Latest version: %s", latestVersion)
+	printVerbose("Target version: %s", latestVersion)
 
 	// Get current version
 	currentVersion, err := getCurrentVersion()
@@ -208,6 +449,22 @@ func updateBootstrap() error {
 		return fmt.Errorf("failed to get current version: %w", err)
 	}
 
+	// Machine-readable check result for bots and CI
+	if checkOnly && updateJSON {
+		report := updateCheckReport{
+			Current:         currentVersion,
+			Latest:          latestVersion,
+			Channel:         channel,
+			UpdateAvailable: currentVersion != latestVersion,
+		}
+		data, err := json.MarshalIndent(report, "", "  ")
+		if err != nil {
+			return err
+		}
+		fmt.Println(string(data))
+		return nil
+	}
+
 	if currentVersion == "" {
 		printInfo("No current version found, will update to latest")
 	} else {
@@ -255,10 +512,15 @@ func updateBootstrap() error {
 
 	// Update mvx.properties with new version
 	propertiesFile := filepath.Join(mvxDir, "mvx.properties")
-	if err := updatePropertiesFile(propertiesFile, latestVersion, baseURL); err != nil {
+	if err := updatePropertiesFile(propertiesFile, latestVersion, channel, baseURL); err != nil {
 		return fmt.Errorf("failed to update properties file: %w", err)
 	}
 
+	// Re-pin the per-platform binary checksums for the new version
+	if err := recordBootstrapChecksums(propertiesFile); err != nil {
+		printWarning("Could not pin binary checksums: %v", err)
+	}
+
 	printInfo("✅ Bootstrap scripts updated successfully to version %s", latestVersion)
 	printInfo("📝 Files updated:")
 	printInfo("  - mvx (Unix/Linux/macOS script)")
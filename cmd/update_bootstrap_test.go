@@ -0,0 +1,58 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestPickReleaseForChannel(t *testing.T) {
+	releases := []GitHubRelease{
+		{TagName: "v0.10.0-rc1", Prerelease: true},
+		{TagName: "v0.9.2"},
+		{TagName: "v0.9.1"},
+	}
+
+	if got := pickReleaseForChannel(releases, "stable"); got == nil || got.TagName != "v0.9.2" {
+		t.Errorf("stable channel picked %+v, want v0.9.2", got)
+	}
+	if got := pickReleaseForChannel(releases, "rc"); got == nil || got.TagName != "v0.10.0-rc1" {
+		t.Errorf("rc channel picked %+v, want v0.10.0-rc1", got)
+	}
+	if got := pickReleaseForChannel(nil, "stable"); got != nil {
+		t.Errorf("expected nil for empty release list, got %+v", got)
+	}
+}
+
+func TestParseChecksums(t *testing.T) {
+	content := `d2f0a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c  mvx-linux-amd64
+a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e  mvx-windows-amd64.exe
+not a checksum line
+0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef  other-tool
+`
+
+	checksums := parseChecksums(content)
+	if len(checksums) != 2 {
+		t.Fatalf("expected 2 checksums, got %d: %v", len(checksums), checksums)
+	}
+	if got := checksums["linux-amd64"]; got != "d2f0a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c" {
+		t.Errorf("unexpected linux-amd64 checksum: %s", got)
+	}
+	// The .exe suffix is stripped so the key matches the platform name
+	if _, ok := checksums["windows-amd64"]; !ok {
+		t.Errorf("expected a windows-amd64 entry, got %v", checksums)
+	}
+}
+
+func TestSetPropertiesLine(t *testing.T) {
+	// Replace an existing key in place
+	lines := setPropertiesLine([]string{"# mvx Configuration", "mvxVersion=0.9.1", ""}, "mvxVersion", "0.9.2")
+	if got := strings.Join(lines, "\n"); got != "# mvx Configuration\nmvxVersion=0.9.2\n" {
+		t.Errorf("unexpected content after replace:\n%s", got)
+	}
+
+	// Append a missing key before the trailing empty line
+	lines = setPropertiesLine(lines, "updateChannel", "rc")
+	if got := strings.Join(lines, "\n"); got != "# mvx Configuration\nmvxVersion=0.9.2\nupdateChannel=rc\n" {
+		t.Errorf("unexpected content after append:\n%s", got)
+	}
+}
@@ -0,0 +1,306 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/gnodet/mvx/pkg/version"
+	"github.com/spf13/cobra"
+)
+
+// verifyCmd cross-checks what the project's build files require against what
+// mvx actually pins, so a mismatch fails in seconds instead of ten minutes
+// into the build.
+var verifyCmd = &cobra.Command{
+	Use:   "verify",
+	Short: "Check project requirements against the pinned tools",
+	Long: `Check version requirements declared by the project's build files against
+the tools mvx has pinned:
+
+  - maven-enforcer requireJavaVersion / requireMavenVersion ranges in pom.xml
+  - maven.compiler.release (or source) in pom.xml
+  - "engines" in package.json
+
+A conflict (e.g. the pom requires Java 17+ but mvx pins Java 11) fails with
+guidance on which side to change.
+
+Examples:
+  mvx verify           # Verify the current project`,
+	Run: func(cmd *cobra.Command, args []string) {
+		conflicts, err := runVerify()
+		if err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+		if conflicts > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(verifyCmd)
+}
+
+// toolRequirement is one version requirement a build file places on a tool
+type toolRequirement struct {
+	Tool   string // mvx tool name the requirement applies to
+	Source string // where the requirement was declared (file + element)
+	Spec   string // the declared requirement, verbatim
+	Kind   string // "maven-range", "compiler-release" or "node-spec"
+}
+
+// enforcer rule version ranges in pom.xml
+var (
+	requireJavaPattern  = regexp.MustCompile(`(?s)<requireJavaVersion>.*?<version>\s*([^<]+?)\s*</version>`)
+	requireMavenPattern = regexp.MustCompile(`(?s)<requireMavenVersion>.*?<version>\s*([^<]+?)\s*</version>`)
+)
+
+// runVerify collects the project's requirements, checks each against the
+// pinned tool version and prints any conflicts. The returned count is the
+// number of conflicts found.
+func runVerify() (int, error) {
+	projectRoot, cfg, manager, err := managedProject()
+	if err != nil {
+		return 0, err
+	}
+
+	requirements, err := projectRequirements(projectRoot)
+	if err != nil {
+		return 0, err
+	}
+	if len(requirements) == 0 {
+		printInfo("No version requirements found in pom.xml or package.json")
+		return 0, nil
+	}
+
+	conflicts := 0
+	for _, req := range requirements {
+		toolConfig, pinned := cfg.Tools[req.Tool]
+		if !pinned {
+			printWarning("%s requires %s %s but no %s tool is configured in mvx",
+				req.Source, req.Tool, req.Spec, req.Tool)
+			conflicts++
+			continue
+		}
+
+		actual, err := manager.ResolveVersion(req.Tool, toolConfig)
+		if err != nil {
+			// Without catalogs (e.g. offline) fall back to the raw spec;
+			// concrete pins still verify, floating specs are skipped below
+			util.LogVerbose("Could not resolve %s version: %v", req.Tool, err)
+			actual = toolConfig.Version
+		}
+
+		ok, err := requirementSatisfied(req, actual)
+		if err != nil {
+			util.LogVerbose("Skipping %s requirement %q: %v", req.Source, req.Spec, err)
+			continue
+		}
+		if ok {
+			printSuccess("%s: %s %s satisfies %s", req.Source, req.Tool, actual, req.Spec)
+			continue
+		}
+
+		conflicts++
+		printError("%s requires %s %s but mvx pins %s %s",
+			req.Source, req.Tool, req.Spec, req.Tool, actual)
+		printError("  → update tools.%s.version in .mvx/config, or relax the requirement in %s",
+			req.Tool, strings.SplitN(req.Source, " ", 2)[0])
+	}
+
+	if conflicts == 0 {
+		printSuccess("✅ Pinned tools satisfy all project requirements")
+	} else {
+		printError("%d requirement(s) conflict with the pinned tools", conflicts)
+	}
+	return conflicts, nil
+}
+
+// projectRequirements extracts version requirements from the project's
+// pom.xml and package.json (when present)
+func projectRequirements(projectRoot string) ([]toolRequirement, error) {
+	var requirements []toolRequirement
+
+	if content, err := os.ReadFile(filepath.Join(projectRoot, "pom.xml")); err == nil {
+		requirements = append(requirements, pomRequirements(content)...)
+	}
+	if content, err := os.ReadFile(filepath.Join(projectRoot, "package.json")); err == nil {
+		reqs, err := packageJSONRequirements(content)
+		if err != nil {
+			return nil, fmt.Errorf("failed to parse package.json: %w", err)
+		}
+		requirements = append(requirements, reqs...)
+	}
+
+	return requirements, nil
+}
+
+// pomRequirements extracts enforcer ranges and the compiler release from a pom
+func pomRequirements(content []byte) []toolRequirement {
+	var requirements []toolRequirement
+
+	if m := requireJavaPattern.FindSubmatch(content); m != nil {
+		requirements = append(requirements, toolRequirement{
+			Tool:   "java",
+			Source: "pom.xml (requireJavaVersion)",
+			Spec:   string(m[1]),
+			Kind:   "maven-range",
+		})
+	}
+	if m := requireMavenPattern.FindSubmatch(content); m != nil {
+		requirements = append(requirements, toolRequirement{
+			Tool:   "maven",
+			Source: "pom.xml (requireMavenVersion)",
+			Spec:   string(m[1]),
+			Kind:   "maven-range",
+		})
+	}
+	if m := pomJavaPattern.FindSubmatch(content); m != nil {
+		requirements = append(requirements, toolRequirement{
+			Tool:   "java",
+			Source: "pom.xml (maven.compiler.release)",
+			Spec:   string(m[2]),
+			Kind:   "compiler-release",
+		})
+	}
+
+	return requirements
+}
+
+// packageJSONRequirements extracts engine requirements from package.json
+func packageJSONRequirements(content []byte) ([]toolRequirement, error) {
+	var pkg struct {
+		Engines struct {
+			Node string `json:"node"`
+		} `json:"engines"`
+	}
+	if err := json.Unmarshal(content, &pkg); err != nil {
+		return nil, err
+	}
+
+	if pkg.Engines.Node == "" {
+		return nil, nil
+	}
+	return []toolRequirement{{
+		Tool:   "node",
+		Source: "package.json (engines.node)",
+		Spec:   pkg.Engines.Node,
+		Kind:   "node-spec",
+	}}, nil
+}
+
+// requirementSatisfied reports whether the actual tool version satisfies a
+// requirement. Floating versions ("latest", "lts") cannot be checked and
+// return an error so the caller skips them.
+func requirementSatisfied(req toolRequirement, actual string) (bool, error) {
+	actualVersion, err := version.ParseVersion(actual)
+	if err != nil {
+		// Legacy JDK versions ("1.8.0_392") only parse as their major
+		actualVersion, err = version.ParseVersion(javaMajorVersion(actual))
+		if err != nil {
+			return false, fmt.Errorf("cannot compare pinned version %q", actual)
+		}
+	}
+
+	switch req.Kind {
+	case "compiler-release":
+		// Compiling for release N needs a JDK of at least N
+		release, err := strconv.Atoi(req.Spec)
+		if err != nil {
+			return false, fmt.Errorf("invalid release %q", req.Spec)
+		}
+		major, err := strconv.Atoi(javaMajorVersion(actual))
+		if err != nil {
+			return false, fmt.Errorf("cannot compare pinned version %q", actual)
+		}
+		return major >= release, nil
+	case "maven-range":
+		return mavenRangeSatisfied(req.Spec, actualVersion)
+	case "node-spec":
+		spec, err := version.ParseSpec(req.Spec)
+		if err != nil {
+			return false, fmt.Errorf("unsupported engines spec %q", req.Spec)
+		}
+		return spec.Matches(actualVersion), nil
+	default:
+		return false, fmt.Errorf("unknown requirement kind %q", req.Kind)
+	}
+}
+
+// mavenRangeSetPattern matches one bracketed set of a Maven version range
+var mavenRangeSetPattern = regexp.MustCompile(`[\[(][^\[\]()]*[\])]`)
+
+// mavenRangeSatisfied checks a Maven/enforcer version range. A bare version
+// is a minimum ("3.6.3" means 3.6.3 or newer); bracketed sets use Maven's
+// range syntax ("[17,)", "[3.8,4.0)", "[1.8]") and a union of sets is
+// satisfied when any set matches.
+func mavenRangeSatisfied(spec string, actual *version.Version) (bool, error) {
+	sets := mavenRangeSetPattern.FindAllString(spec, -1)
+	if len(sets) == 0 {
+		minimum, err := version.ParseVersion(strings.TrimSpace(spec))
+		if err != nil {
+			return false, fmt.Errorf("unsupported version range %q", spec)
+		}
+		return actual.Compare(minimum) >= 0, nil
+	}
+
+	for _, set := range sets {
+		ok, err := mavenRangeSetSatisfied(set, actual)
+		if err != nil {
+			return false, err
+		}
+		if ok {
+			return true, nil
+		}
+	}
+	return false, nil
+}
+
+// mavenRangeSetSatisfied checks a single bracketed range set against actual
+func mavenRangeSetSatisfied(set string, actual *version.Version) (bool, error) {
+	lowerInclusive := strings.HasPrefix(set, "[")
+	upperInclusive := strings.HasSuffix(set, "]")
+	inner := set[1 : len(set)-1]
+
+	lowerRaw, upperRaw, hasComma := strings.Cut(inner, ",")
+	lowerRaw = strings.TrimSpace(lowerRaw)
+	upperRaw = strings.TrimSpace(upperRaw)
+
+	// "[1.8]" pins an exact version
+	if !hasComma {
+		exact, err := version.ParseVersion(lowerRaw)
+		if err != nil {
+			return false, fmt.Errorf("unsupported version range %q", set)
+		}
+		return actual.Compare(exact) == 0, nil
+	}
+
+	if lowerRaw != "" {
+		lower, err := version.ParseVersion(lowerRaw)
+		if err != nil {
+			return false, fmt.Errorf("unsupported version range %q", set)
+		}
+		cmp := actual.Compare(lower)
+		if cmp < 0 || (cmp == 0 && !lowerInclusive) {
+			return false, nil
+		}
+	}
+	if upperRaw != "" {
+		upper, err := version.ParseVersion(upperRaw)
+		if err != nil {
+			return false, fmt.Errorf("unsupported version range %q", set)
+		}
+		cmp := actual.Compare(upper)
+		if cmp > 0 || (cmp == 0 && !upperInclusive) {
+			return false, nil
+		}
+	}
+	return true, nil
+}
@@ -0,0 +1,106 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+func TestPomRequirements(t *testing.T) {
+	pom := []byte(`<project>
+  <properties>
+    <maven.compiler.release>17</maven.compiler.release>
+  </properties>
+  <build><plugins><plugin>
+    <artifactId>maven-enforcer-plugin</artifactId>
+    <configuration><rules>
+      <requireMavenVersion>
+        <version>[3.8.0,)</version>
+      </requireMavenVersion>
+      <requireJavaVersion>
+        <version>[17,18)</version>
+      </requireJavaVersion>
+    </rules></configuration>
+  </plugin></plugins></build>
+</project>`)
+
+	requirements := pomRequirements(pom)
+	if len(requirements) != 3 {
+		t.Fatalf("expected 3 requirements, got %d: %v", len(requirements), requirements)
+	}
+	if requirements[0].Tool != "java" || requirements[0].Spec != "[17,18)" {
+		t.Errorf("unexpected requireJavaVersion requirement: %+v", requirements[0])
+	}
+	if requirements[1].Tool != "maven" || requirements[1].Spec != "[3.8.0,)" {
+		t.Errorf("unexpected requireMavenVersion requirement: %+v", requirements[1])
+	}
+	if requirements[2].Kind != "compiler-release" || requirements[2].Spec != "17" {
+		t.Errorf("unexpected compiler release requirement: %+v", requirements[2])
+	}
+}
+
+func TestPackageJSONRequirements(t *testing.T) {
+	requirements, err := packageJSONRequirements([]byte(`{"name": "app", "engines": {"node": ">=18"}}`))
+	if err != nil {
+		t.Fatalf("packageJSONRequirements: %v", err)
+	}
+	if len(requirements) != 1 || requirements[0].Tool != "node" || requirements[0].Spec != ">=18" {
+		t.Errorf("unexpected requirements: %+v", requirements)
+	}
+
+	requirements, err = packageJSONRequirements([]byte(`{"name": "app"}`))
+	if err != nil || len(requirements) != 0 {
+		t.Errorf("expected no requirements without engines, got %v (%v)", requirements, err)
+	}
+}
+
+func TestMavenRangeSatisfied(t *testing.T) {
+	tests := []struct {
+		spec   string
+		actual string
+		want   bool
+	}{
+		{"3.6.3", "3.9.9", true}, // bare version is a minimum
+		{"3.6.3", "3.5.0", false},
+		{"[17,)", "21.0.2", true},
+		{"[17,)", "11.0.22", false},
+		{"[17,18)", "17.0.10", true},
+		{"[17,18)", "18.0.0", false}, // exclusive upper bound
+		{"[1.8]", "1.8.0", true},     // exact pin
+		{"[1.8]", "11.0.0", false},
+		{"(3.8,4.0]", "3.8.0", false},       // exclusive lower bound
+		{"[3.2,3.3),[3.9,)", "3.9.9", true}, // union of sets
+	}
+	for _, test := range tests {
+		actual, err := version.ParseVersion(test.actual)
+		if err != nil {
+			t.Fatalf("ParseVersion(%s): %v", test.actual, err)
+		}
+		got, err := mavenRangeSatisfied(test.spec, actual)
+		if err != nil {
+			t.Errorf("mavenRangeSatisfied(%q, %s): %v", test.spec, test.actual, err)
+			continue
+		}
+		if got != test.want {
+			t.Errorf("mavenRangeSatisfied(%q, %s) = %v, want %v", test.spec, test.actual, got, test.want)
+		}
+	}
+}
+
+func TestRequirementSatisfied(t *testing.T) {
+	release := toolRequirement{Tool: "java", Kind: "compiler-release", Spec: "17"}
+	if ok, err := requirementSatisfied(release, "21.0.2"); err != nil || !ok {
+		t.Errorf("expected JDK 21 to satisfy release 17 (ok=%v, err=%v)", ok, err)
+	}
+	if ok, err := requirementSatisfied(release, "11.0.22"); err != nil || ok {
+		t.Errorf("expected JDK 11 to fail release 17 (ok=%v, err=%v)", ok, err)
+	}
+
+	node := toolRequirement{Tool: "node", Kind: "node-spec", Spec: ">=18"}
+	if ok, err := requirementSatisfied(node, "20.11.1"); err != nil || !ok {
+		t.Errorf("expected Node 20 to satisfy >=18 (ok=%v, err=%v)", ok, err)
+	}
+	if ok, err := requirementSatisfied(node, "16.20.0"); err != nil || ok {
+		t.Errorf("expected Node 16 to fail >=18 (ok=%v, err=%v)", ok, err)
+	}
+}
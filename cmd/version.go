@@ -19,6 +19,18 @@ commit hash, build date, and runtime information.`,
 }
 
 func showVersion() {
+	if jsonOutput() {
+		printJSON(map[string]string{
+			"version":   version,
+			"commit":    commit,
+			"date":      date,
+			"goVersion": runtime.Version(),
+			"os":        runtime.GOOS,
+			"arch":      runtime.GOARCH,
+		})
+		return
+	}
+
 	fmt.Printf("mvx version %s\n", version)
 
 	if verbose {
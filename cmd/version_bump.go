@@ -0,0 +1,202 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+)
+
+// versionBumpCmd bumps the project version across known manifest files
+var versionBumpCmd = &cobra.Command{
+	Use:   "bump [major|minor|patch]",
+	Short: "Bump the project version and tag the release",
+	Long: `Bump the semantic version in the project's manifest files and create a
+release commit and tag, replacing per-repo release scripts.
+
+Known manifests (pom.xml, package.json, Cargo.toml) are updated through
+file-type adapters; every manifest present in the project root is kept in
+sync. The first manifest found provides the current version. Only the first
+version declaration in each file is rewritten, which matches the project
+version in all three formats.
+
+Examples:
+  mvx version bump patch              # 1.2.3 -> 1.2.4, commit + tag v1.2.4
+  mvx version bump minor --no-tag     # Bump and commit without tagging
+  mvx version bump major --no-commit  # Only rewrite the manifest files`,
+	Args:      cobra.ExactArgs(1),
+	ValidArgs: []string{"major", "minor", "patch"},
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runVersionBump(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+var (
+	bumpNoCommit  bool
+	bumpNoTag     bool
+	bumpTagPrefix string
+)
+
+func init() {
+	versionBumpCmd.Flags().BoolVar(&bumpNoCommit, "no-commit", false, "rewrite manifests without committing")
+	versionBumpCmd.Flags().BoolVar(&bumpNoTag, "no-tag", false, "commit without creating a tag")
+	versionBumpCmd.Flags().StringVar(&bumpTagPrefix, "tag-prefix", "v", "prefix for the release tag")
+	versionCmd.AddCommand(versionBumpCmd)
+}
+
+// versionAdapter locates and rewrites the version string in one manifest
+// format. New formats are supported by appending an adapter here.
+type versionAdapter struct {
+	file    string
+	pattern *regexp.Regexp // first submatch is the version string
+}
+
+var versionAdapters = []versionAdapter{
+	{"pom.xml", regexp.MustCompile(`<version>([^<$]+)</version>`)},
+	{"package.json", regexp.MustCompile(`"version"\s*:\s*"([^"]+)"`)},
+	{"Cargo.toml", regexp.MustCompile(`(?m)^version\s*=\s*"([^"]+)"`)},
+}
+
+// runVersionBump rewrites manifests, then commits and tags the result
+func runVersionBump(part string) error {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return fmt.Errorf("failed to find project root: %w", err)
+	}
+
+	current, err := currentProjectVersion(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	next, err := bumpSemver(current, part)
+	if err != nil {
+		return err
+	}
+
+	var updated []string
+	for _, adapter := range versionAdapters {
+		path := filepath.Join(projectRoot, adapter.file)
+		data, err := os.ReadFile(path)
+		if err != nil {
+			continue
+		}
+
+		changed := false
+		content := replaceFirstSubmatch(string(data), adapter.pattern, next, &changed)
+		if !changed {
+			continue
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			return fmt.Errorf("failed to write %s: %w", path, err)
+		}
+		updated = append(updated, adapter.file)
+		printInfo("  📝 %s: %s -> %s", adapter.file, current, next)
+	}
+
+	if len(updated) == 0 {
+		return fmt.Errorf("no manifest files (pom.xml, package.json, Cargo.toml) found in %s", projectRoot)
+	}
+
+	if bumpNoCommit {
+		printSuccess("✅ Bumped version to %s (no commit requested)", next)
+		return nil
+	}
+
+	if err := runGit(projectRoot, append([]string{"add", "--"}, updated...)...); err != nil {
+		return err
+	}
+	if err := runGit(projectRoot, "commit", "-m", fmt.Sprintf("Release %s", next)); err != nil {
+		return err
+	}
+	printInfo("  📦 Created release commit for %s", next)
+
+	if !bumpNoTag {
+		tag := bumpTagPrefix + next
+		if err := runGit(projectRoot, "tag", tag); err != nil {
+			return err
+		}
+		printInfo("  🏷️  Tagged %s", tag)
+	}
+
+	printSuccess("✅ Version bumped to %s", next)
+	return nil
+}
+
+// currentProjectVersion reads the version from the first manifest that has one
+func currentProjectVersion(projectRoot string) (string, error) {
+	for _, adapter := range versionAdapters {
+		data, err := os.ReadFile(filepath.Join(projectRoot, adapter.file))
+		if err != nil {
+			continue
+		}
+		if m := adapter.pattern.FindStringSubmatch(string(data)); m != nil {
+			return strings.TrimSpace(m[1]), nil
+		}
+	}
+	return "", fmt.Errorf("no version found in pom.xml, package.json or Cargo.toml")
+}
+
+// bumpSemver increments one part of a major.minor.patch version. A -SNAPSHOT
+// or pre-release suffix is dropped from the result.
+func bumpSemver(version, part string) (string, error) {
+	core := version
+	if idx := strings.IndexAny(core, "-+"); idx != -1 {
+		core = core[:idx]
+	}
+
+	parts := strings.Split(core, ".")
+	if len(parts) != 3 {
+		return "", fmt.Errorf("version %q is not a major.minor.patch version", version)
+	}
+	numbers := make([]int, 3)
+	for i, p := range parts {
+		n, err := strconv.Atoi(p)
+		if err != nil {
+			return "", fmt.Errorf("version %q is not a major.minor.patch version", version)
+		}
+		numbers[i] = n
+	}
+
+	switch part {
+	case "major":
+		numbers[0], numbers[1], numbers[2] = numbers[0]+1, 0, 0
+	case "minor":
+		numbers[1], numbers[2] = numbers[1]+1, 0
+	case "patch":
+		numbers[2]++
+	default:
+		return "", fmt.Errorf("unknown version part %q (use major, minor or patch)", part)
+	}
+
+	return fmt.Sprintf("%d.%d.%d", numbers[0], numbers[1], numbers[2]), nil
+}
+
+// replaceFirstSubmatch rewrites the first submatch of the first pattern match
+func replaceFirstSubmatch(content string, pattern *regexp.Regexp, replacement string, changed *bool) string {
+	loc := pattern.FindStringSubmatchIndex(content)
+	if loc == nil || loc[2] == -1 {
+		return content
+	}
+	*changed = true
+	return content[:loc[2]] + replacement + content[loc[3]:]
+}
+
+// runGit runs a git command in the project root, surfacing its output on error
+func runGit(projectRoot string, args ...string) error {
+	cmd := exec.Command("git", args...)
+	cmd.Dir = projectRoot
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("git %s failed: %v\n%s", strings.Join(args, " "), err, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
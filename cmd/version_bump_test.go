@@ -0,0 +1,51 @@
+package cmd
+
+import "testing"
+
+func TestBumpSemver(t *testing.T) {
+	cases := []struct {
+		version string
+		part    string
+		want    string
+		wantErr bool
+	}{
+		{"1.2.3", "patch", "1.2.4", false},
+		{"1.2.3", "minor", "1.3.0", false},
+		{"1.2.3", "major", "2.0.0", false},
+		{"1.2.3-SNAPSHOT", "patch", "1.2.4", false},
+		{"1.2.3+build7", "minor", "1.3.0", false},
+		{"1.2", "patch", "", true},
+		{"a.b.c", "patch", "", true},
+		{"1.2.3", "nano", "", true},
+	}
+
+	for _, tc := range cases {
+		got, err := bumpSemver(tc.version, tc.part)
+		if tc.wantErr {
+			if err == nil {
+				t.Errorf("bumpSemver(%q, %q): expected error, got %q", tc.version, tc.part, got)
+			}
+			continue
+		}
+		if err != nil {
+			t.Errorf("bumpSemver(%q, %q): unexpected error: %v", tc.version, tc.part, err)
+			continue
+		}
+		if got != tc.want {
+			t.Errorf("bumpSemver(%q, %q) = %q, want %q", tc.version, tc.part, got, tc.want)
+		}
+	}
+}
+
+func TestReplaceFirstSubmatch(t *testing.T) {
+	content := `{"name": "demo", "version": "1.0.0", "dependencies": {"x": {"version": "9.9.9"}}}`
+	changed := false
+	result := replaceFirstSubmatch(content, versionAdapters[1].pattern, "2.0.0", &changed)
+	if !changed {
+		t.Fatal("expected a replacement to happen")
+	}
+	expected := `{"name": "demo", "version": "2.0.0", "dependencies": {"x": {"version": "9.9.9"}}}`
+	if result != expected {
+		t.Errorf("unexpected result: %s", result)
+	}
+}
@@ -0,0 +1,134 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+	"github.com/spf13/cobra"
+)
+
+// whichCmd prints where a binary resolves inside the mvx-managed
+// environment, so scripts and CI snippets can locate tools without parsing
+// setup output.
+var whichCmd = &cobra.Command{
+	Use:   "which <binary>",
+	Short: "Print the resolved path of a binary in the mvx environment",
+	Long: `Print the absolute path a binary resolves to with the mvx-managed
+environment active.
+
+Examples:
+  mvx which mvn        # .../.mvx/tools/maven/3.9.9/bin/mvn
+  mvx which java       # the pinned JDK's java binary`,
+
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runWhich(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// homeCmd prints the installation root of a managed tool
+var homeCmd = &cobra.Command{
+	Use:   "home <tool>",
+	Short: "Print the installation root of a managed tool",
+	Long: `Print the installation root of a tool managed by mvx (what JAVA_HOME,
+MAVEN_HOME, etc. point at).
+
+Examples:
+  mvx home java        # use as JAVA_HOME in scripts
+  mvx home maven       # the unpacked Maven distribution`,
+
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		if err := runHome(args[0]); err != nil {
+			printError("%v", err)
+			os.Exit(1)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(whichCmd)
+	rootCmd.AddCommand(homeCmd)
+}
+
+// managedProject loads the project configuration and a manager wired to it
+func managedProject() (string, *config.Config, *tools.Manager, error) {
+	projectRoot, err := findProjectRoot()
+	if err != nil {
+		return "", nil, nil, fmt.Errorf("no mvx project found in the current directory")
+	}
+	cfg, err := config.LoadConfig(projectRoot)
+	if err != nil {
+		return "", nil, nil, fmt.Errorf("failed to load configuration: %w", err)
+	}
+	manager, err := tools.NewManager()
+	if err != nil {
+		return "", nil, nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(projectRoot, cfg)
+	manager.LoadProjectLock(projectRoot)
+	manager.ConfigureRegistries(cfg)
+	return projectRoot, cfg, manager, nil
+}
+
+// runWhich resolves a binary on the managed PATH and prints its path
+func runWhich(binary string) error {
+	projectRoot, cfg, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+	env, err := setupShellEnvironment(cfg, manager, projectRoot)
+	if err != nil {
+		return fmt.Errorf("failed to setup environment: %w", err)
+	}
+
+	binaryPath, err := lookPathIn(binary, pathFromEnv(env))
+	if err != nil {
+		return fmt.Errorf("%s: not found in the mvx environment", binary)
+	}
+	fmt.Println(binaryPath)
+	return nil
+}
+
+// runHome prints the installation root of a configured tool
+func runHome(toolName string) error {
+	_, cfg, manager, err := managedProject()
+	if err != nil {
+		return err
+	}
+
+	toolConfig, configured := cfg.Tools[toolName]
+	if !configured {
+		return fmt.Errorf("tool %s is not configured in this project", toolName)
+	}
+	tool, err := manager.GetTool(toolName)
+	if err != nil {
+		return fmt.Errorf("unknown tool: %s", toolName)
+	}
+	version, err := manager.ResolveVersion(toolName, toolConfig)
+	if err != nil {
+		return fmt.Errorf("failed to resolve %s version: %w", toolName, err)
+	}
+	resolvedConfig := toolConfig
+	resolvedConfig.Version = version
+
+	binPath, err := tool.GetPath(version, resolvedConfig)
+	if err != nil {
+		return fmt.Errorf("%s %s is not installed (run 'mvx setup')", toolName, version)
+	}
+
+	// GetPath returns the bin directory; the home is its parent
+	home := binPath
+	if filepath.Base(home) == "bin" {
+		home = filepath.Dir(home)
+	}
+	fmt.Println(home)
+	return nil
+}
@@ -29,8 +29,14 @@ if "%MVX_VERSION_TO_USE%"=="" (
 rem Remove any whitespace
 set MVX_VERSION_TO_USE=%MVX_VERSION_TO_USE: =%
 
-rem Determine download URL
+rem Determine download URL: environment variable, then the mvxDownloadUrl
+rem property (internal mirrors), then the public GitHub releases
 set DOWNLOAD_URL_TO_USE=%MVX_DOWNLOAD_URL%
+if "%DOWNLOAD_URL_TO_USE%"=="" (
+    if exist ".mvx\mvx.properties" (
+        for /f "tokens=2* delims==" %%i in ('findstr "^mvxDownloadUrl=" ".mvx\mvx.properties" 2^>nul') do set DOWNLOAD_URL_TO_USE=%%i
+    )
+)
 if "%DOWNLOAD_URL_TO_USE%"=="" (
     set DOWNLOAD_URL_TO_USE=%DEFAULT_DOWNLOAD_URL%
 )
@@ -40,6 +46,10 @@ set HOME_DIR=%USERPROFILE%
 if "%HOME_DIR%"=="" set HOME_DIR=%HOMEDRIVE%%HOMEPATH%
 if "%HOME_DIR%"=="" set HOME_DIR=.
 
+rem Detect architecture (Windows on ARM runs this script too)
+set MVX_PLATFORM=windows-amd64
+if /i "%PROCESSOR_ARCHITECTURE%"=="ARM64" set MVX_PLATFORM=windows-arm64
+
 rem Check verbosity level
 set VERBOSITY=normal
 :check_args
@@ -61,7 +71,7 @@ if "%1"=="update-bootstrap" (
 rem Only show bootstrap info in verbose mode
 if "%VERBOSITY%"=="verbose" (
     echo mvx
-    echo Platform: windows-amd64
+    echo Platform: %MVX_PLATFORM%
     echo Requested version: %MVX_VERSION_TO_USE%
 )
 
@@ -100,11 +110,27 @@ if "%MVX_VERSION_TO_USE%"=="latest" (
     echo Latest version: !RESOLVED_VERSION!
 )
 
+rem Check for a vendored binary committed with the project, so the
+rem bootstrap phase needs no network at all. Like any other binary it must
+rem match the checksum pinned in .mvx\mvx.properties before it runs.
+if exist ".mvx\dist\mvx-%MVX_PLATFORM%.exe" (
+    call :verify_checksum ".mvx\dist\mvx-%MVX_PLATFORM%.exe"
+    if errorlevel 1 exit /b 1
+    if "%VERBOSITY%"=="verbose" (
+        echo Using vendored mvx binary: .mvx\dist\mvx-%MVX_PLATFORM%.exe
+        echo.
+    )
+    ".mvx\dist\mvx-%MVX_PLATFORM%.exe" %*
+    goto :eof
+)
+
 rem Check cached version
 set CACHE_DIR=%HOME_DIR%\.mvx\versions\%RESOLVED_VERSION%
 set CACHED_BINARY=%CACHE_DIR%\mvx.exe
 
 if exist "%CACHED_BINARY%" (
+    call :verify_checksum "%CACHED_BINARY%"
+    if errorlevel 1 exit /b 1
     echo Using cached mvx binary: %CACHED_BINARY%
     echo.
     "%CACHED_BINARY%" %*
@@ -118,10 +144,10 @@ rem Create cache directory
 if not exist "%CACHE_DIR%" mkdir "%CACHE_DIR%"
 
 rem Construct download URL
-set BINARY_NAME=mvx-windows-amd64.exe
+set BINARY_NAME=mvx-%MVX_PLATFORM%.exe
 set DOWNLOAD_URL_FULL=%DOWNLOAD_URL_TO_USE%/download/v%RESOLVED_VERSION%/%BINARY_NAME%
 
-echo Downloading mvx %RESOLVED_VERSION% for windows-amd64...
+echo Downloading mvx %RESOLVED_VERSION% for %MVX_PLATFORM%...
 echo Downloading from: %DOWNLOAD_URL_FULL%
 
 rem Download using PowerShell (available on Windows 7+ with .NET 4.0+)
@@ -132,6 +158,13 @@ if not exist "%CACHED_BINARY%" (
     exit /b 1
 )
 
+rem Verify against the checksum pinned in .mvx\mvx.properties (if any)
+call :verify_checksum "%CACHED_BINARY%"
+if errorlevel 1 (
+    del "%CACHED_BINARY%" >nul 2>&1
+    exit /b 1
+)
+
 if "%VERBOSITY%"=="verbose" (
     echo Using mvx binary: %CACHED_BINARY%
     echo.
@@ -141,6 +174,28 @@ rem Execute mvx with all arguments
 "%CACHED_BINARY%" %*
 goto :eof
 
+rem Function to verify a binary against the checksum pinned in
+rem .mvx\mvx.properties (mvxChecksum.^<platform^>); succeeds when no
+rem checksum is pinned
+:verify_checksum
+set BINARY_TO_VERIFY=%~1
+set EXPECTED_CHECKSUM=
+if exist ".mvx\mvx.properties" (
+    for /f "tokens=2 delims==" %%i in ('findstr "^mvxChecksum.%MVX_PLATFORM%=" ".mvx\mvx.properties" 2^>nul') do set EXPECTED_CHECKSUM=%%i
+)
+if "!EXPECTED_CHECKSUM!"=="" exit /b 0
+
+set ACTUAL_CHECKSUM=
+for /f "delims=" %%i in ('powershell -Command "(Get-FileHash -Algorithm SHA256 '%BINARY_TO_VERIFY%').Hash.ToLower()"') do set ACTUAL_CHECKSUM=%%i
+if /i not "!ACTUAL_CHECKSUM!"=="!EXPECTED_CHECKSUM!" (
+    echo Error: checksum mismatch for %BINARY_TO_VERIFY%
+    echo   expected: !EXPECTED_CHECKSUM!
+    echo   actual:   !ACTUAL_CHECKSUM!
+    echo The binary may be corrupted or tampered with. Refusing to execute it.
+    exit /b 1
+)
+exit /b 0
+
 rem Function to get latest version from GitHub API
 :get_latest_version
 set "result_var=%~1"
@@ -0,0 +1,46 @@
+package config
+
+import "testing"
+
+func TestNormalizeChecksumShorthand(t *testing.T) {
+	tool := ToolConfig{Version: "3.9.9", Sha256: "abc123"}
+	expanded, err := tool.normalizeChecksum()
+	if err != nil {
+		t.Fatalf("normalizeChecksum failed: %v", err)
+	}
+	if expanded.Checksum == nil {
+		t.Fatal("expected a checksum block")
+	}
+	if expanded.Checksum.Type != "sha256" || expanded.Checksum.Value != "abc123" || !expanded.Checksum.Required {
+		t.Errorf("unexpected checksum block: %+v", expanded.Checksum)
+	}
+
+	tool = ToolConfig{Version: "3.9.9", Sha512: "def456"}
+	expanded, err = tool.normalizeChecksum()
+	if err != nil {
+		t.Fatalf("normalizeChecksum failed: %v", err)
+	}
+	if expanded.Checksum.Type != "sha512" || expanded.Checksum.Value != "def456" {
+		t.Errorf("unexpected checksum block: %+v", expanded.Checksum)
+	}
+}
+
+func TestNormalizeChecksumShorthandConflicts(t *testing.T) {
+	if _, err := (ToolConfig{Sha256: "a", Sha512: "b"}).normalizeChecksum(); err == nil {
+		t.Error("expected error when both sha256 and sha512 are set")
+	}
+	if _, err := (ToolConfig{Sha256: "a", Checksum: &ChecksumConfig{Value: "b"}}).normalizeChecksum(); err == nil {
+		t.Error("expected error when shorthand is combined with a checksum block")
+	}
+}
+
+func TestNormalizeChecksumNoShorthand(t *testing.T) {
+	tool := ToolConfig{Version: "21"}
+	expanded, err := tool.normalizeChecksum()
+	if err != nil {
+		t.Fatalf("normalizeChecksum failed: %v", err)
+	}
+	if expanded.Checksum != nil {
+		t.Errorf("expected no checksum block, got %+v", expanded.Checksum)
+	}
+}
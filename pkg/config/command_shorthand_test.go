@@ -0,0 +1,114 @@
+package config
+
+import "testing"
+
+func TestNormalizeCommandShorthands(t *testing.T) {
+	cmd := CommandConfig{
+		Script:          "mvn test",
+		Env:             map[string]EnvValue{"MAVEN_OPTS": "-Xmx1g"},
+		WorkingDirAlias: "modules/core",
+	}
+	normalized, err := cmd.normalizeShorthands()
+	if err != nil {
+		t.Fatalf("normalizeShorthands failed: %v", err)
+	}
+	if normalized.Environment["MAVEN_OPTS"] != "-Xmx1g" {
+		t.Errorf("unexpected environment: %+v", normalized.Environment)
+	}
+	if normalized.Env != nil {
+		t.Error("env shorthand should be cleared after folding")
+	}
+	if normalized.WorkingDir != "modules/core" || normalized.WorkingDirAlias != "" {
+		t.Errorf("unexpected working dir: %q / %q", normalized.WorkingDir, normalized.WorkingDirAlias)
+	}
+}
+
+func TestNormalizeCommandShorthandConflicts(t *testing.T) {
+	cmd := CommandConfig{
+		Environment: map[string]EnvValue{"A": "1"},
+		Env:         map[string]EnvValue{"B": "2"},
+	}
+	if _, err := cmd.normalizeShorthands(); err == nil {
+		t.Error("expected error when both env and environment are set")
+	}
+
+	cmd = CommandConfig{WorkingDir: "a", WorkingDirAlias: "b"}
+	if _, err := cmd.normalizeShorthands(); err == nil {
+		t.Error("expected error when both workingDir and working_dir are set")
+	}
+}
+
+func TestHasValidScriptStepArrays(t *testing.T) {
+	valid := []interface{}{
+		"mvn -q clean",
+		map[string]interface{}{"run": "notify.sh", "continueOnError": true},
+	}
+	if !HasValidScript(valid) {
+		t.Error("step array with script strings and run objects should be valid")
+	}
+	if HasValidScript([]interface{}{}) {
+		t.Error("empty step array should be invalid")
+	}
+	if HasValidScript([]interface{}{map[string]interface{}{"continueOnError": true}}) {
+		t.Error("step object without run should be invalid")
+	}
+}
+
+func TestApplyToolOverrides(t *testing.T) {
+	cfg := &Config{Tools: map[string]ToolConfig{
+		"java": {Version: "17", Distribution: "temurin"},
+	}}
+
+	if err := cfg.ApplyToolOverrides([]string{"java@21", "maven@4.0.0-rc-1"}); err != nil {
+		t.Fatalf("ApplyToolOverrides failed: %v", err)
+	}
+	if cfg.Tools["java"].Version != "21" || cfg.Tools["java"].Distribution != "temurin" {
+		t.Errorf("java override = %+v, want version 21 with distribution kept", cfg.Tools["java"])
+	}
+	if cfg.Tools["maven"].Version != "4.0.0-rc-1" {
+		t.Errorf("maven override = %+v", cfg.Tools["maven"])
+	}
+
+	if err := cfg.ApplyToolOverrides([]string{"java"}); err == nil {
+		t.Error("expected error for override without @version")
+	}
+}
+
+func TestValidateCommandRequires(t *testing.T) {
+	cfg := &Config{
+		Project: ProjectConfig{Name: "demo"},
+		Tools:   map[string]ToolConfig{"node": {Version: "22"}},
+		Commands: map[string]CommandConfig{
+			"docs": {Description: "d", Script: "mkdocs build", Requires: []string{"protoc"}},
+		},
+	}
+	if err := cfg.Validate(); err == nil {
+		t.Error("expected error for requires naming an unconfigured tool")
+	}
+
+	cfg.Commands["docs"] = CommandConfig{Description: "d", Script: "mkdocs build", Requires: []string{"node"}}
+	if err := cfg.Validate(); err != nil {
+		t.Errorf("Validate() error = %v", err)
+	}
+}
+
+func TestGetRequiredTools(t *testing.T) {
+	cfg := &Config{
+		Tools: map[string]ToolConfig{"java": {Version: "21"}, "node": {Version: "22"}},
+		Commands: map[string]CommandConfig{
+			"build": {Script: "mvn install"},
+			"fmt":   {Script: "prettier -w .", Requires: []string{"node"}},
+			"docs":  {Script: "mkdocs build", Requires: []string{}},
+		},
+	}
+
+	if tools := cfg.GetRequiredTools("build"); len(tools) != 2 {
+		t.Errorf("build should require all tools, got %v", tools)
+	}
+	if tools := cfg.GetRequiredTools("fmt"); len(tools) != 1 || tools[0] != "node" {
+		t.Errorf("fmt should require only node, got %v", tools)
+	}
+	if tools := cfg.GetRequiredTools("docs"); len(tools) != 0 {
+		t.Errorf("docs should require no tools, got %v", tools)
+	}
+}
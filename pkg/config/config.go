@@ -1,21 +1,181 @@
 package config
 
 import (
+	"encoding/json"
 	"fmt"
 	"os"
 	"path/filepath"
 	"runtime"
 	"strings"
+	"time"
 
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/gnodet/mvx/pkg/version"
 	"gopkg.in/yaml.v3"
 )
 
+// CurrentFormatVersion is the config schema version this build of mvx writes
+// and understands. Files without format_version are treated as version "1".
+const CurrentFormatVersion = "1"
+
 // Config represents the mvx project configuration
 type Config struct {
-	Project     ProjectConfig            `json:"project" yaml:"project"`
-	Tools       map[string]ToolConfig    `json:"tools" yaml:"tools"`
-	Environment map[string]string        `json:"environment" yaml:"environment"`
-	Commands    map[string]CommandConfig `json:"commands" yaml:"commands"`
+	FormatVersion string                      `json:"format_version,omitempty" yaml:"format_version,omitempty"` // config schema version (see CurrentFormatVersion)
+	MvxVersion    string                      `json:"mvxVersion,omitempty" yaml:"mvxVersion,omitempty"` // minimum mvx version required (e.g. ">=0.8.0")
+	Extends       string                      `json:"extends,omitempty" yaml:"extends,omitempty"` // parent config (relative path or URL) merged underneath this file
+	Project       ProjectConfig               `json:"project" yaml:"project"`
+	Tools         map[string]ToolConfig       `json:"tools" yaml:"tools"`
+	Environment   map[string]EnvValue         `json:"environment" yaml:"environment"`
+	Commands      map[string]CommandConfig    `json:"commands" yaml:"commands"`
+	JvmProfiles   map[string]JvmProfileConfig `json:"jvm_profiles,omitempty" yaml:"jvm_profiles,omitempty"`
+	Plugins       []string                    `json:"plugins,omitempty" yaml:"plugins,omitempty"`       // plugin manifest paths (relative to project root)
+	Registries    map[string]RegistryConfig   `json:"registries,omitempty" yaml:"registries,omitempty"` // internal registries keyed by tool name ("*" = all tools)
+	Mirrors       map[string]string           `json:"mirrors,omitempty" yaml:"mirrors,omitempty"`       // upstream URL prefix -> mirror prefix, applied before downloads
+	Sensitive     []string                    `json:"sensitive,omitempty" yaml:"sensitive,omitempty"`   // env var names whose values are redacted from logs and reports
+	AllowedHosts  []string                    `json:"allowed_hosts,omitempty" yaml:"allowed_hosts,omitempty"` // deprecated spelling of security.allowedHosts (still honored)
+	Security      *SecurityConfig             `json:"security,omitempty" yaml:"security,omitempty"`     // supply-chain hardening (signature verification)
+	Profiles      map[string]ProfileConfig    `json:"profiles,omitempty" yaml:"profiles,omitempty"`     // named overrides activated via --profile or MVX_PROFILE
+	Maintenance   *MaintenanceConfig          `json:"maintenance,omitempty" yaml:"maintenance,omitempty"`
+	Setup         *SetupConfig                `json:"setup,omitempty" yaml:"setup,omitempty"`       // setup behavior (lazy on-demand installation)
+	Generate      map[string]string           `json:"generate,omitempty" yaml:"generate,omitempty"` // template file -> output path, rendered by mvx generate and after setup
+	Hooks         *HooksConfig                `json:"hooks,omitempty" yaml:"hooks,omitempty"`       // scripts run at lifecycle points (setup, command execution)
+	DotEnv        *bool                       `json:"dotenv,omitempty" yaml:"dotenv,omitempty"`     // load .env / .mvx/.env files (default true; see dotenv.go for precedence)
+	Shell         string                      `json:"shell,omitempty" yaml:"shell,omitempty"`       // default shell for native scripts ("bash", "pwsh", "powershell", "cmd", ...)
+	Maven         *MavenConfig                `json:"maven,omitempty" yaml:"maven,omitempty"`       // Maven-specific integration (generated settings.xml)
+	Java          *JavaConfig                 `json:"java,omitempty" yaml:"java,omitempty"`         // Java-specific integration (declarative JVM options)
+}
+
+// SetupConfig tunes how and when tools are installed
+type SetupConfig struct {
+	// Lazy defers tool installation to the first command that actually needs
+	// the tool, instead of installing everything up front. Contributors who
+	// only run 'mvx run docs' never wait for a JDK download.
+	Lazy bool `json:"lazy,omitempty" yaml:"lazy,omitempty"`
+}
+
+// LazySetup reports whether on-demand installation is active, from the
+// setup.lazy config field or the MVX_SETUP_LAZY environment override
+func (c *Config) LazySetup() bool {
+	if lazy := os.Getenv("MVX_SETUP_LAZY"); lazy != "" {
+		return lazy == "1" || lazy == "true"
+	}
+	return c.Setup != nil && c.Setup.Lazy
+}
+
+// JavaConfig carries Java-specific project settings. Options are injected
+// into MAVEN_OPTS, GRADLE_OPTS and JAVA_TOOL_OPTIONS before any JVM profile
+// applies, replacing per-developer shell exports; use jvm_profiles for
+// environment-specific overrides (e.g. more heap in CI).
+type JavaConfig struct {
+	Options []string `json:"options,omitempty" yaml:"options,omitempty"`
+}
+
+// MavenConfig carries Maven-specific project integration settings
+type MavenConfig struct {
+	Settings   *MavenSettingsConfig   `json:"settings,omitempty" yaml:"settings,omitempty"`
+	Extensions []MavenExtensionConfig `json:"extensions,omitempty" yaml:"extensions,omitempty"` // materialized into .mvn/extensions.xml during setup
+	UseDaemon  bool                   `json:"useDaemon,omitempty" yaml:"useDaemon,omitempty"`   // route 'mvx mvn' through the pinned mvnd when available
+	JvmArgs    []string               `json:"jvmArgs,omitempty" yaml:"jvmArgs,omitempty"`       // JVM options injected into MAVEN_OPTS for every invocation
+}
+
+// MavenExtensionConfig declares one Maven core extension
+// (e.g. the build-cache extension), written to .mvn/extensions.xml
+type MavenExtensionConfig struct {
+	GroupID    string `json:"groupId" yaml:"groupId"`
+	ArtifactID string `json:"artifactId" yaml:"artifactId"`
+	Version    string `json:"version" yaml:"version"`
+}
+
+// MavenSettingsConfig declares mirrors, servers and proxies from which mvx
+// generates a settings.xml and passes it to 'mvx mvn' via -s, so nobody has
+// to hand-edit ~/.m2/settings.xml. Credential fields support ${env.NAME}
+// and ${secret.NAME} placeholders; resolved values only ever reach a
+// per-invocation temporary file, never the config.
+type MavenSettingsConfig struct {
+	Mirrors []MavenMirrorConfig `json:"mirrors,omitempty" yaml:"mirrors,omitempty"`
+	Servers []MavenServerConfig `json:"servers,omitempty" yaml:"servers,omitempty"`
+	Proxies []MavenProxyConfig  `json:"proxies,omitempty" yaml:"proxies,omitempty"`
+}
+
+// MavenMirrorConfig maps to a <mirror> entry in settings.xml
+type MavenMirrorConfig struct {
+	ID       string `json:"id" yaml:"id"`
+	MirrorOf string `json:"mirrorOf" yaml:"mirrorOf"`
+	URL      string `json:"url" yaml:"url"`
+}
+
+// MavenServerConfig maps to a <server> entry (repository credentials)
+type MavenServerConfig struct {
+	ID       string `json:"id" yaml:"id"`
+	Username string `json:"username,omitempty" yaml:"username,omitempty"`
+	Password string `json:"password,omitempty" yaml:"password,omitempty"`
+}
+
+// MavenProxyConfig maps to a <proxy> entry
+type MavenProxyConfig struct {
+	ID            string `json:"id" yaml:"id"`
+	Protocol      string `json:"protocol,omitempty" yaml:"protocol,omitempty"` // defaults to http
+	Host          string `json:"host" yaml:"host"`
+	Port          int    `json:"port,omitempty" yaml:"port,omitempty"`
+	Username      string `json:"username,omitempty" yaml:"username,omitempty"`
+	Password      string `json:"password,omitempty" yaml:"password,omitempty"`
+	NonProxyHosts string `json:"nonProxyHosts,omitempty" yaml:"nonProxyHosts,omitempty"`
+}
+
+// HooksConfig runs project scripts at well-defined lifecycle points. Each
+// hook resolves like a command script (plain string or platform map) and runs
+// with the resolved tool environment, so post-setup steps such as `npm ci` or
+// git hook installation happen automatically.
+type HooksConfig struct {
+	PreSetup    interface{} `json:"preSetup,omitempty" yaml:"preSetup,omitempty"`       // before mvx setup installs tools
+	PostSetup   interface{} `json:"postSetup,omitempty" yaml:"postSetup,omitempty"`     // after all tools are installed
+	PreCommand  interface{} `json:"preCommand,omitempty" yaml:"preCommand,omitempty"`   // before any configured command (MVX_COMMAND in env)
+	PostCommand interface{} `json:"postCommand,omitempty" yaml:"postCommand,omitempty"` // after a configured command succeeds (MVX_COMMAND in env)
+	OnFailure   interface{} `json:"onFailure,omitempty" yaml:"onFailure,omitempty"`     // after a configured command fails (MVX_COMMAND and MVX_ERROR in env)
+}
+
+// MaintenanceConfig opts into the lightweight maintenance scheduler, which
+// runs due tasks (cache pruning, registry refresh, toolchain audit) on
+// invocation — no background daemon involved.
+type MaintenanceConfig struct {
+	Enabled  bool   `json:"enabled,omitempty" yaml:"enabled,omitempty"`
+	Interval string `json:"interval,omitempty" yaml:"interval,omitempty"` // Go duration (default "168h")
+}
+
+// SecurityConfig opts into supply-chain hardening. Signature verification
+// checks PGP signatures (Apache .asc files) and Sigstore/cosign signatures
+// for tools that publish them, shelling out to gpg and cosign.
+type SecurityConfig struct {
+	VerifySignatures  bool     `json:"verifySignatures,omitempty" yaml:"verifySignatures,omitempty"`
+	RequireProvenance bool     `json:"requireProvenance,omitempty" yaml:"requireProvenance,omitempty"` // verify SLSA/GitHub attestations for tools that publish them
+	AllowedHosts      []string `json:"allowedHosts,omitempty" yaml:"allowedHosts,omitempty"`           // hosts mvx may download from ("*.example.com" wildcards allowed)
+}
+
+// ProfileConfig overrides parts of the configuration for a named profile
+// (e.g. "ci", "dev", "release"), so CI and laptops can share one config file.
+type ProfileConfig struct {
+	Tools       map[string]ToolConfig    `json:"tools,omitempty" yaml:"tools,omitempty"`
+	Environment map[string]EnvValue      `json:"environment,omitempty" yaml:"environment,omitempty"`
+	Commands    map[string]CommandConfig `json:"commands,omitempty" yaml:"commands,omitempty"`
+}
+
+// RegistryConfig points a tool at an internal HTTP registry serving tool
+// metadata (versions, URLs, checksums) and artifacts, so enterprises can keep
+// all discovery and downloads off the public internet.
+type RegistryConfig struct {
+	URL       string `json:"url" yaml:"url"`                                 // registry base URL
+	AuthEnv   string `json:"authEnv,omitempty" yaml:"authEnv,omitempty"`     // env var holding a bearer token (falls back to ~/.netrc)
+	Exclusive bool   `json:"exclusive,omitempty" yaml:"exclusive,omitempty"` // fail rather than fall back to public sources
+}
+
+// JvmProfileConfig defines JVM options (memory, GC, proxy, ...) applied when
+// running managed Java tools. Options are injected into MAVEN_OPTS, GRADLE_OPTS
+// and JAVA_TOOL_OPTIONS; the per-variable lists only affect a single variable.
+type JvmProfileConfig struct {
+	Options         []string `json:"options,omitempty" yaml:"options,omitempty"`
+	MavenOpts       []string `json:"maven_opts,omitempty" yaml:"maven_opts,omitempty"`
+	GradleOpts      []string `json:"gradle_opts,omitempty" yaml:"gradle_opts,omitempty"`
+	JavaToolOptions []string `json:"java_tool_options,omitempty" yaml:"java_tool_options,omitempty"`
 }
 
 // ProjectConfig contains project metadata
@@ -26,11 +186,145 @@ type ProjectConfig struct {
 
 // ToolConfig represents a tool requirement
 type ToolConfig struct {
-	Version      string            `json:"version" yaml:"version"`
-	Distribution string            `json:"distribution,omitempty" yaml:"distribution,omitempty"`
-	RequiredFor  []string          `json:"required_for,omitempty" yaml:"required_for,omitempty"`
-	Options      map[string]string `json:"options,omitempty" yaml:"options,omitempty"`
-	Checksum     *ChecksumConfig   `json:"checksum,omitempty" yaml:"checksum,omitempty"`
+	Version         string            `json:"version" yaml:"version"`
+	Distribution    string            `json:"distribution,omitempty" yaml:"distribution,omitempty"`
+	LtsOnly         bool              `json:"ltsOnly,omitempty" yaml:"ltsOnly,omitempty"`                 // "latest" resolves to the newest LTS release instead of the newest release
+	AllowPrerelease bool              `json:"allowPrerelease,omitempty" yaml:"allowPrerelease,omitempty"` // version resolution may land on RCs, betas and milestone builds
+	Source          string            `json:"source,omitempty" yaml:"source,omitempty"` // Java EA build source: "jdk.java.net" or a project page ("loom", "valhalla", ...)
+	RequiredFor     []string          `json:"required_for,omitempty" yaml:"required_for,omitempty"`
+	Options         map[string]string `json:"options,omitempty" yaml:"options,omitempty"`
+	Packages        []string          `json:"packages,omitempty" yaml:"packages,omitempty"`     // SDK packages (e.g. Android "platforms;android-34")
+	Components      []string          `json:"components,omitempty" yaml:"components,omitempty"` // GraalVM components (e.g. "native-image", "js", "espresso")
+	OS              []string          `json:"os,omitempty" yaml:"os,omitempty"`                 // restrict to operating systems (e.g. ["linux", "darwin"])
+	Arch            []string          `json:"arch,omitempty" yaml:"arch,omitempty"`             // restrict to architectures (e.g. ["amd64"])
+	Checksum        *ChecksumConfig   `json:"checksum,omitempty" yaml:"checksum,omitempty"`
+	Sha256          string            `json:"sha256,omitempty" yaml:"sha256,omitempty"` // shorthand: pinned SHA-256 of the download (verification becomes required)
+	Sha512          string            `json:"sha512,omitempty" yaml:"sha512,omitempty"` // shorthand: pinned SHA-512 of the download (verification becomes required)
+
+	// Custom (URL-based) tool declaration
+	Type            string            `json:"type,omitempty" yaml:"type,omitempty"`                 // "custom" for URL-based tools
+	URL             string            `json:"url,omitempty" yaml:"url,omitempty"`                   // URL template ({version}, {os}, {arch} placeholders)
+	URLs            map[string]string `json:"urls,omitempty" yaml:"urls,omitempty"`                 // per-platform URLs keyed by os-arch (e.g. "linux-amd64")
+	Repo            string            `json:"repo,omitempty" yaml:"repo,omitempty"`                 // GitHub "owner/name" repository serving releases
+	AssetPattern    string            `json:"assetPattern,omitempty" yaml:"assetPattern,omitempty"` // release asset regex ({version}, {os}, {arch} placeholders)
+	BinaryPath      string            `json:"binaryPath,omitempty" yaml:"binaryPath,omitempty"`     // relative path from install dir to the binary directory
+	BinaryName      string            `json:"binaryName,omitempty" yaml:"binaryName,omitempty"`     // binary file name (defaults to tool name)
+}
+
+// normalizeChecksum expands the sha256/sha512 shorthand fields into an
+// equivalent required ChecksumConfig, so high-security projects can pin an
+// artifact with a single line. The shorthands cannot be combined with each
+// other or with an explicit checksum block.
+func (t ToolConfig) normalizeChecksum() (ToolConfig, error) {
+	if t.Sha256 == "" && t.Sha512 == "" {
+		return t, nil
+	}
+	if t.Sha256 != "" && t.Sha512 != "" {
+		return t, fmt.Errorf("sha256 and sha512 cannot both be set")
+	}
+	if t.Checksum != nil {
+		return t, fmt.Errorf("sha256/sha512 shorthand cannot be combined with a checksum block")
+	}
+
+	checksumType, value := "sha256", t.Sha256
+	if t.Sha512 != "" {
+		checksumType, value = "sha512", t.Sha512
+	}
+	t.Checksum = &ChecksumConfig{
+		Type:     checksumType,
+		Value:    value,
+		Required: true,
+	}
+	return t, nil
+}
+
+// MatchesPlatform reports whether the tool applies to the current OS and
+// architecture. Empty os/arch lists match everything, so tools without
+// conditions keep installing everywhere.
+func (t ToolConfig) MatchesPlatform() bool {
+	return matchesPlatformValue(t.OS, runtime.GOOS) && matchesPlatformValue(t.Arch, runtime.GOARCH)
+}
+
+// matchesPlatformValue reports whether value is in list (empty list matches all)
+func matchesPlatformValue(list []string, value string) bool {
+	if len(list) == 0 {
+		return true
+	}
+	for _, entry := range list {
+		if strings.EqualFold(entry, value) {
+			return true
+		}
+	}
+	return false
+}
+
+// EnvValue is an environment variable value. It can be declared as a plain
+// string or as a per-OS object (e.g. { windows: ";", unix: ":" }), which is
+// resolved to the current platform's value at parse time.
+type EnvValue string
+
+// UnmarshalJSON accepts a string or a platform object
+func (e *EnvValue) UnmarshalJSON(data []byte) error {
+	var plain string
+	if err := json.Unmarshal(data, &plain); err == nil {
+		*e = EnvValue(plain)
+		return nil
+	}
+
+	var byPlatform map[string]string
+	if err := json.Unmarshal(data, &byPlatform); err != nil {
+		return fmt.Errorf("environment value must be a string or a platform object: %s", string(data))
+	}
+	resolved, err := resolvePlatformEnvValue(byPlatform)
+	if err != nil {
+		return err
+	}
+	*e = EnvValue(resolved)
+	return nil
+}
+
+// UnmarshalYAML accepts a string or a platform object
+func (e *EnvValue) UnmarshalYAML(node *yaml.Node) error {
+	var plain string
+	if err := node.Decode(&plain); err == nil {
+		*e = EnvValue(plain)
+		return nil
+	}
+
+	var byPlatform map[string]string
+	if err := node.Decode(&byPlatform); err != nil {
+		return fmt.Errorf("environment value must be a string or a platform object")
+	}
+	resolved, err := resolvePlatformEnvValue(byPlatform)
+	if err != nil {
+		return err
+	}
+	*e = EnvValue(resolved)
+	return nil
+}
+
+// resolvePlatformEnvValue picks the value for the current OS, with the same
+// fallbacks as platform scripts: linux/darwin fall back to unix, and default
+// applies everywhere.
+func resolvePlatformEnvValue(byPlatform map[string]string) (string, error) {
+	keys := []string{"default"}
+	switch runtime.GOOS {
+	case "windows":
+		keys = []string{"windows", "default"}
+	case "linux":
+		keys = []string{"linux", "unix", "default"}
+	case "darwin":
+		keys = []string{"macos", "darwin", "unix", "default"}
+	default:
+		keys = []string{"unix", "default"}
+	}
+
+	for _, key := range keys {
+		if value, exists := byPlatform[key]; exists {
+			return value, nil
+		}
+	}
+	return "", fmt.Errorf("no environment value for platform %s (and no unix/default fallback)", runtime.GOOS)
 }
 
 // ChecksumConfig represents checksum verification configuration
@@ -44,14 +338,64 @@ type ChecksumConfig struct {
 
 // CommandConfig represents a command definition
 type CommandConfig struct {
-	Description string             `json:"description" yaml:"description"`
-	Script      interface{}        `json:"script" yaml:"script"` // Can be string or PlatformScript
-	WorkingDir  string             `json:"working_dir,omitempty" yaml:"working_dir,omitempty"`
-	Requires    []string           `json:"requires,omitempty" yaml:"requires,omitempty"`
-	Args        []CommandArgConfig `json:"args,omitempty" yaml:"args,omitempty"`
-	Environment map[string]string  `json:"environment,omitempty" yaml:"environment,omitempty"`
-	Interpreter string             `json:"interpreter,omitempty" yaml:"interpreter,omitempty"` // "native" (default), "mvx-shell"
+	Description     string                `json:"description" yaml:"description"`
+	Group           string                `json:"group,omitempty" yaml:"group,omitempty"` // help-output section (e.g. "build", "release"); also enables group:name invocation
+	Script          interface{}           `json:"script" yaml:"script"` // Can be string or PlatformScript
+	DependsOn       []string              `json:"dependsOn,omitempty" yaml:"dependsOn,omitempty"`         // prerequisite commands run once before this one
+	Pre             interface{}           `json:"pre,omitempty" yaml:"pre,omitempty"`                     // script run before the main script (string or PlatformScript)
+	Post            interface{}           `json:"post,omitempty" yaml:"post,omitempty"`                   // script run after the main script succeeds
+	WorkingDir      string                `json:"working_dir,omitempty" yaml:"working_dir,omitempty"`
+	WorkingDirAlias string                `json:"workingDir,omitempty" yaml:"workingDir,omitempty"` // camelCase spelling of working_dir
+	Requires        []string              `json:"requires,omitempty" yaml:"requires,omitempty"`
+	Args            []CommandArgConfig    `json:"args,omitempty" yaml:"args,omitempty"`
+	Prompts         []CommandPromptConfig `json:"prompts,omitempty" yaml:"prompts,omitempty"` // values collected before the script runs (flags, env, or interactive)
+	Environment     map[string]EnvValue   `json:"environment,omitempty" yaml:"environment,omitempty"`
+	Env             map[string]EnvValue   `json:"env,omitempty" yaml:"env,omitempty"`                     // shorthand spelling of environment
+	Interpreter     string                `json:"interpreter,omitempty" yaml:"interpreter,omitempty"`     // "native" (default), "mvx-shell", "mvx-script"
+	Shell           string                `json:"shell,omitempty" yaml:"shell,omitempty"`                 // shell for native scripts, overriding the top-level default
+	Parallel        bool                  `json:"parallel,omitempty" yaml:"parallel,omitempty"`           // run script array steps concurrently instead of sequentially
+	Timeout         string                `json:"timeout,omitempty" yaml:"timeout,omitempty"`             // kill the script after this duration (e.g. "10m"); applies to each step of a script array
+	Retries         int                   `json:"retries,omitempty" yaml:"retries,omitempty"`             // re-run a failed script up to this many extra times
+	Inputs          []string              `json:"inputs,omitempty" yaml:"inputs,omitempty"`               // artifact globs the command consumes (checked before execution)
+	Outputs         []string              `json:"outputs,omitempty" yaml:"outputs,omitempty"`             // artifact globs the command produces (checked after execution)
+	Locale          string                `json:"locale,omitempty" yaml:"locale,omitempty"`               // pin LANG/LC_ALL (e.g. "C.UTF-8") for reproducible output
+	Timezone        string                `json:"timezone,omitempty" yaml:"timezone,omitempty"`           // pin TZ (e.g. "UTC") for reproducible output
+	Sandbox         bool                  `json:"sandbox,omitempty" yaml:"sandbox,omitempty"`             // restrict writes to project dir, mvx cache and sandbox_paths
+	SandboxPaths    []string              `json:"sandbox_paths,omitempty" yaml:"sandbox_paths,omitempty"` // extra writable paths in sandbox mode
+	Hermetic        bool                  `json:"hermetic,omitempty" yaml:"hermetic,omitempty"`           // strip the inherited environment so only mvx-managed tools are visible
+	HermeticEnv     []string              `json:"hermeticEnv,omitempty" yaml:"hermeticEnv,omitempty"`     // extra env vars passed through in hermetic mode
+}
+
+// CommandPromptConfig declares a value a command collects before running:
+// from a --<name> flag, the MVX_INPUT_<NAME> environment variable, or an
+// interactive prompt when stdin is a terminal. Resolved values are exposed
+// to scripts as ${args.<name>} placeholders, like declared arguments.
+type CommandPromptConfig struct {
+	Name        string   `json:"name" yaml:"name"`
+	Description string   `json:"description,omitempty" yaml:"description,omitempty"`
+	Type        string   `json:"type,omitempty" yaml:"type,omitempty"`       // "string" (default), "choice", "confirm", "password"
+	Choices     []string `json:"choices,omitempty" yaml:"choices,omitempty"` // allowed values for type "choice"
+	Default     string   `json:"default,omitempty" yaml:"default,omitempty"` // used when nothing is provided and stdin is not a terminal
+}
 
+// normalizeShorthands folds the env / workingDir shorthand spellings into
+// the canonical environment / working_dir fields
+func (cmd CommandConfig) normalizeShorthands() (CommandConfig, error) {
+	if len(cmd.Env) > 0 {
+		if len(cmd.Environment) > 0 {
+			return cmd, fmt.Errorf("declare either env or environment, not both")
+		}
+		cmd.Environment = cmd.Env
+		cmd.Env = nil
+	}
+	if cmd.WorkingDirAlias != "" {
+		if cmd.WorkingDir != "" {
+			return cmd, fmt.Errorf("declare either workingDir or working_dir, not both")
+		}
+		cmd.WorkingDir = cmd.WorkingDirAlias
+		cmd.WorkingDirAlias = ""
+	}
+	return cmd, nil
 }
 
 // PlatformScript represents platform-specific script definitions
@@ -70,56 +414,182 @@ type CommandArgConfig struct {
 	Description string `json:"description" yaml:"description"`
 	Default     string `json:"default,omitempty" yaml:"default,omitempty"`
 	Required    bool   `json:"required,omitempty" yaml:"required,omitempty"`
+	Type        string `json:"type,omitempty" yaml:"type,omitempty"` // "string" (default) or "bool"
 }
 
-// LoadConfig loads configuration from the project directory
+// projectConfigNames are the project config file names tried in order of
+// preference
+var projectConfigNames = []string{
+	"config.json5",
+	"config.yml",
+	"config.yaml",
+	"config.json",
+	"mvx.yaml", // alternative YAML name, friendlier to tooling like Renovate
+	"mvx.yml",
+	"mvx.json5",
+	"mvx.toml",
+}
+
+// LoadConfig loads configuration from the project directory, stacking any
+// extra sources around it (MVX_CONFIG_PATH baselines underneath, local
+// override files on top). See ConfigSources for the merge order.
 func LoadConfig(projectRoot string) (*Config, error) {
-	mvxDir := filepath.Join(projectRoot, ".mvx")
+	sources, err := ConfigSources(projectRoot)
+	if err != nil {
+		return nil, err
+	}
+
+	var merged *Config
+	for _, path := range sources {
+		layer, err := loadConfigLayer(path)
+		if err != nil {
+			return nil, err
+		}
+		if merged == nil {
+			merged = layer
+		} else {
+			merged = mergeConfigs(merged, layer)
+		}
+	}
+
+	// Fold .env / .mvx/.env files into the environment section (declared
+	// environment entries win); dotenv: false opts out
+	if err := loadDotEnvFiles(merged, projectRoot); err != nil {
+		return nil, err
+	}
+
+	return finalizeConfig(merged)
+}
+
+// ConfigSources returns the ordered list of config files merged into the
+// effective configuration (earlier entries are overridden by later ones):
+// MVX_CONFIG_PATH entries first (org/team baselines), then the project
+// config, then untracked local override files.
+func ConfigSources(projectRoot string) ([]string, error) {
+	var sources []string
+
+	// Org/team baselines from MVX_CONFIG_PATH (path-list separated)
+	if stack := os.Getenv("MVX_CONFIG_PATH"); stack != "" {
+		for _, entry := range filepath.SplitList(stack) {
+			if entry == "" {
+				continue
+			}
+			if _, err := os.Stat(entry); err != nil {
+				return nil, fmt.Errorf("MVX_CONFIG_PATH entry %s does not exist", entry)
+			}
+			sources = append(sources, entry)
+		}
+	}
 
-	// Try different config file names in order of preference
-	configFiles := []string{
-		"config.json5",
-		"config.yml",
-		"config.yaml",
-		"config.json",
+	// Ancestor project configs (monorepo roots), outermost first, so a nested
+	// subproject inherits the shared baseline and overrides what it needs
+	for _, ancestor := range ancestorConfigFiles(projectRoot) {
+		sources = append(sources, ancestor)
 	}
 
-	for _, filename := range configFiles {
+	// The project config file itself
+	mvxDir := filepath.Join(projectRoot, ".mvx")
+	found := false
+	for _, filename := range projectConfigNames {
 		configPath := filepath.Join(mvxDir, filename)
 		if _, err := os.Stat(configPath); err == nil {
-			return loadConfigFile(configPath)
+			sources = append(sources, configPath)
+			found = true
+			break
+		}
+	}
+	if !found {
+		return nil, fmt.Errorf("no configuration file found in %s (tried: %s)",
+			mvxDir, strings.Join(projectConfigNames, ", "))
+	}
+
+	// Untracked (gitignored) local overrides, merged on top of everything so
+	// individuals can tweak env vars or tool versions without dirtying the
+	// shared file
+	for _, filename := range []string{"config.local.json5", "config.local.yml", "mvx.local.json5", "mvx.local.yml"} {
+		localPath := filepath.Join(mvxDir, filename)
+		if _, err := os.Stat(localPath); err == nil {
+			sources = append(sources, localPath)
+		}
+	}
+
+	return sources, nil
+}
+
+// ancestorConfigFiles walks up from the project root collecting config files
+// of enclosing .mvx directories, returned outermost first. This lets monorepo
+// modules carry their own .mvx directory while inheriting the repository
+// root's tools and commands.
+func ancestorConfigFiles(projectRoot string) []string {
+	var found []string
+
+	dir := filepath.Dir(projectRoot)
+	for {
+		for _, filename := range projectConfigNames {
+			configPath := filepath.Join(dir, ".mvx", filename)
+			if _, err := os.Stat(configPath); err == nil {
+				found = append(found, configPath)
+				break
+			}
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			break
 		}
+		dir = parent
 	}
 
-	return nil, fmt.Errorf("no configuration file found in %s (tried: %s)",
-		mvxDir, strings.Join(configFiles, ", "))
+	// Reverse so the outermost config comes first (overridden by inner ones)
+	for i, j := 0, len(found)-1; i < j; i, j = i+1, j-1 {
+		found[i], found[j] = found[j], found[i]
+	}
+	return found
 }
 
-// loadConfigFile loads configuration from a specific file
-func loadConfigFile(path string) (*Config, error) {
+// loadConfigLayer loads one config file and resolves its extends chain,
+// without applying profiles or validation (those run on the merged result)
+func loadConfigLayer(path string) (*Config, error) {
 	data, err := os.ReadFile(path)
 	if err != nil {
 		return nil, fmt.Errorf("failed to read config file %s: %w", path, err)
 	}
 
-	var config Config
+	cfg, err := parseConfigData(data, strings.ToLower(filepath.Ext(path)))
+	if err != nil {
+		return nil, fmt.Errorf("failed to parse config file %s: %w", path, err)
+	}
+	config := *cfg
 
-	// Determine format by file extension
-	ext := strings.ToLower(filepath.Ext(path))
-	switch ext {
-	case ".json5":
-		err = ParseJSON5(data, &config)
-	case ".yml", ".yaml":
-		err = yaml.Unmarshal(data, &config)
-	case ".json":
-		// Use JSON5 preprocessor for .json files too (allows comments)
-		err = ParseJSON5(data, &config)
-	default:
-		return nil, fmt.Errorf("unsupported config file format: %s", ext)
+	// Merge parent configs referenced via "extends" (local file wins)
+	if config.Extends != "" {
+		merged, err := resolveExtends(&config, filepath.Dir(path), 0)
+		if err != nil {
+			return nil, fmt.Errorf("failed to resolve extends in %s: %w", path, err)
+		}
+		config = *merged
 	}
 
-	if err != nil {
-		return nil, fmt.Errorf("failed to parse config file %s: %w", path, err)
+	return &config, nil
+}
+
+// finalizeConfig applies the active profile, validates the merged config and
+// registers sensitive values
+func finalizeConfig(config *Config) (*Config, error) {
+	// Apply the active profile (--profile flag or MVX_PROFILE env var)
+	if profile := os.Getenv("MVX_PROFILE"); profile != "" {
+		if err := config.ApplyProfile(profile); err != nil {
+			return nil, err
+		}
+	}
+
+	// Apply per-invocation tool version overrides (--with tool@version or
+	// MVX_WITH env var), so "does it pass on JDK 21?" checks never touch the
+	// config file
+	if overrides := os.Getenv("MVX_WITH"); overrides != "" {
+		if err := config.ApplyToolOverrides(strings.Split(overrides, ",")); err != nil {
+			return nil, err
+		}
 	}
 
 	// Validate configuration
@@ -127,7 +597,61 @@ func loadConfigFile(path string) (*Config, error) {
 		return nil, fmt.Errorf("invalid configuration: %w", err)
 	}
 
-	return &config, nil
+	// Expand the sha256/sha512 shorthands into required checksum blocks
+	for name, tool := range config.Tools {
+		expanded, err := tool.normalizeChecksum()
+		if err != nil {
+			return nil, fmt.Errorf("tools.%s: %w", name, err)
+		}
+		config.Tools[name] = expanded
+	}
+
+	// Fold command shorthand spellings (env, workingDir) into canonical fields
+	for name, command := range config.Commands {
+		normalized, err := command.normalizeShorthands()
+		if err != nil {
+			return nil, fmt.Errorf("commands.%s: %w", name, err)
+		}
+		config.Commands[name] = normalized
+	}
+
+	// Register sensitive env vars so their values never reach logs or reports
+	util.MarkSensitive(config.Sensitive...)
+	for _, name := range config.Sensitive {
+		if value := os.Getenv(name); value != "" {
+			util.RegisterSensitiveValue(value)
+		}
+	}
+
+	return config, nil
+}
+
+// CheckMvxVersion verifies the running mvx binary satisfies the config's
+// mvxVersion requirement ("0.8.0" or ">=0.8.0" both mean at-least), so older
+// binaries fail fast instead of mis-parsing newer config features.
+// Development builds ("dev") skip the check.
+func (c *Config) CheckMvxVersion(current string) error {
+	if c.MvxVersion == "" || current == "" || current == "dev" {
+		return nil
+	}
+
+	required := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(c.MvxVersion), ">="))
+	requiredVersion, err := version.ParseVersion(required)
+	if err != nil {
+		return fmt.Errorf("invalid mvxVersion requirement %q: %w", c.MvxVersion, err)
+	}
+
+	currentVersion, err := version.ParseVersion(current)
+	if err != nil {
+		// Unrecognized build versions (snapshots, ...) are not blocked
+		return nil
+	}
+
+	if currentVersion.Compare(requiredVersion) < 0 {
+		return fmt.Errorf("this project requires mvx %s but %s is running; upgrade mvx or use the project wrapper (./mvx)",
+			c.MvxVersion, current)
+	}
+	return nil
 }
 
 // SaveConfig saves configuration to the project directory in JSON5 format
@@ -158,6 +682,13 @@ func SaveConfig(cfg *Config, projectRoot string) error {
 
 // Validate checks if the configuration is valid
 func (c *Config) Validate() error {
+	// An unknown (newer) format version means the file uses structures this
+	// build does not understand — fail loudly instead of misreading it
+	if c.FormatVersion != "" && c.FormatVersion != CurrentFormatVersion {
+		return fmt.Errorf("config format_version %q is not supported by this mvx build (supported: %s); upgrade mvx or run 'mvx config migrate'",
+			c.FormatVersion, CurrentFormatVersion)
+	}
+
 	if c.Project.Name == "" {
 		return fmt.Errorf("project.name is required")
 	}
@@ -167,6 +698,21 @@ func (c *Config) Validate() error {
 		if toolConfig.Version == "" {
 			return fmt.Errorf("tool %s: version is required", toolName)
 		}
+
+		// Custom tools must declare where to download from
+		if toolConfig.Type == "custom" && toolConfig.URL == "" && len(toolConfig.URLs) == 0 && toolConfig.Repo == "" {
+			return fmt.Errorf("tool %s: custom tools require url, urls or repo", toolName)
+		}
+		if toolConfig.Repo != "" && toolConfig.AssetPattern == "" {
+			return fmt.Errorf("tool %s: repo requires an assetPattern to select release assets", toolName)
+		}
+		if toolConfig.Type != "" && toolConfig.Type != "custom" {
+			return fmt.Errorf("tool %s: invalid type '%s', only 'custom' is supported", toolName, toolConfig.Type)
+		}
+	}
+
+	if err := validateShellName(c.Shell); err != nil {
+		return err
 	}
 
 	// Validate command configurations
@@ -176,18 +722,119 @@ func (c *Config) Validate() error {
 			return fmt.Errorf("command %s: script is required", cmdName)
 		}
 
+		// Requires entries must reference configured tools
+		for _, toolName := range cmdConfig.Requires {
+			if _, exists := c.Tools[toolName]; !exists {
+				return fmt.Errorf("command %s: requires unknown tool '%s'", cmdName, toolName)
+			}
+		}
+
+		// Validate shell selection (native interpreter only)
+		if err := validateShellName(cmdConfig.Shell); err != nil {
+			return fmt.Errorf("command %s: %w", cmdName, err)
+		}
+
 		// Validate interpreter field
-		if cmdConfig.Interpreter != "" && cmdConfig.Interpreter != "native" && cmdConfig.Interpreter != "mvx-shell" {
-			return fmt.Errorf("command %s: invalid interpreter '%s', must be 'native' or 'mvx-shell'", cmdName, cmdConfig.Interpreter)
+		if cmdConfig.Interpreter != "" && cmdConfig.Interpreter != "native" && cmdConfig.Interpreter != "mvx-shell" && cmdConfig.Interpreter != "mvx-script" {
+			return fmt.Errorf("command %s: invalid interpreter '%s', must be 'native', 'mvx-shell' or 'mvx-script'", cmdName, cmdConfig.Interpreter)
+		}
+
+		// Validate timeout and retry policy
+		if cmdConfig.Timeout != "" {
+			if _, err := time.ParseDuration(cmdConfig.Timeout); err != nil {
+				return fmt.Errorf("command %s: invalid timeout %q (use Go duration syntax, e.g. \"10m\")", cmdName, cmdConfig.Timeout)
+			}
+		}
+		if cmdConfig.Retries < 0 {
+			return fmt.Errorf("command %s: retries must not be negative", cmdName)
+		}
+
+		// Validate prompt declarations
+		for _, prompt := range cmdConfig.Prompts {
+			if prompt.Name == "" {
+				return fmt.Errorf("command %s: prompts require a name", cmdName)
+			}
+			switch prompt.Type {
+			case "", "string", "confirm", "password":
+			case "choice":
+				if len(prompt.Choices) == 0 {
+					return fmt.Errorf("command %s: prompt %s of type choice requires choices", cmdName, prompt.Name)
+				}
+			default:
+				return fmt.Errorf("command %s: prompt %s has invalid type %q (string, choice, confirm or password)", cmdName, prompt.Name, prompt.Type)
+			}
+		}
+	}
+
+	// Validate the generated Maven settings declaration
+	if c.Maven != nil && c.Maven.Settings != nil {
+		for i, mirror := range c.Maven.Settings.Mirrors {
+			if mirror.ID == "" || mirror.URL == "" || mirror.MirrorOf == "" {
+				return fmt.Errorf("maven.settings.mirrors[%d]: id, mirrorOf and url are required", i)
+			}
+		}
+		for i, server := range c.Maven.Settings.Servers {
+			if server.ID == "" {
+				return fmt.Errorf("maven.settings.servers[%d]: id is required", i)
+			}
+		}
+		for i, proxy := range c.Maven.Settings.Proxies {
+			if proxy.ID == "" || proxy.Host == "" {
+				return fmt.Errorf("maven.settings.proxies[%d]: id and host are required", i)
+			}
+		}
+	}
+	if c.Maven != nil {
+		for i, ext := range c.Maven.Extensions {
+			if ext.GroupID == "" || ext.ArtifactID == "" || ext.Version == "" {
+				return fmt.Errorf("maven.extensions[%d]: groupId, artifactId and version are required", i)
+			}
 		}
 	}
 
 	return nil
 }
 
-// GetRequiredTools returns a list of tools required for a specific command
+// ApplyToolOverrides overrides pinned tool versions for this invocation
+// (--with tool@version), adding the tool when the config does not pin it
+func (c *Config) ApplyToolOverrides(specs []string) error {
+	for _, spec := range specs {
+		spec = strings.TrimSpace(spec)
+		if spec == "" {
+			continue
+		}
+		name, version, found := strings.Cut(spec, "@")
+		if !found || name == "" || version == "" {
+			return fmt.Errorf("invalid tool override %q (expected tool@version)", spec)
+		}
+		if c.Tools == nil {
+			c.Tools = make(map[string]ToolConfig)
+		}
+		tool := c.Tools[name]
+		tool.Version = version
+		c.Tools[name] = tool
+	}
+	return nil
+}
+
+// validateShellName checks a shell selection for native scripts. Known
+// names are accepted, as is an explicit path to a shell binary.
+func validateShellName(shell string) error {
+	switch shell {
+	case "", "bash", "sh", "zsh", "fish", "cmd", "powershell", "pwsh":
+		return nil
+	}
+	if strings.ContainsAny(shell, `/\`) {
+		return nil // explicit path to a shell binary
+	}
+	return fmt.Errorf("invalid shell '%s', must be bash, sh, zsh, fish, cmd, powershell, pwsh or a path", shell)
+}
+
+// GetRequiredTools returns a list of tools required for a specific command.
+// A nil requires list means all configured tools; an explicit empty list
+// means the command needs none of them.
 func (c *Config) GetRequiredTools(commandName string) []string {
-	if cmd, exists := c.Commands[commandName]; exists {
+	if cmd, exists := c.Commands[commandName]; exists && cmd.Requires != nil {
 		return cmd.Requires
 	}
 
@@ -211,6 +858,51 @@ func (c *Config) GetCommandConfig(commandName string) (CommandConfig, bool) {
 	return config, exists
 }
 
+// resolveScriptFile picks the script file for the current platform from a
+// file-style script map, falling back to the default file key
+func resolveScriptFile(s map[string]interface{}, platform string) (string, error) {
+	var keys []string
+	switch platform {
+	case "windows":
+		keys = []string{"windows", "file"}
+	case "linux":
+		keys = []string{"linux", "unix", "file"}
+	case "darwin":
+		keys = []string{"macos", "darwin", "unix", "file"}
+	default:
+		keys = []string{"unix", "file"}
+	}
+	for _, key := range keys {
+		if value, found := s[key]; found {
+			path, ok := value.(string)
+			if !ok || path == "" {
+				return "", fmt.Errorf("script file for %s must be a non-empty path", key)
+			}
+			return path, nil
+		}
+	}
+	return "", fmt.Errorf("no script file defined for platform %s", platform)
+}
+
+// scriptFileInvocation turns a declared script file into a native shell
+// invocation, picking the runner from the file extension. Relative paths
+// resolve against the project root via the ${project.dir} placeholder, so
+// commands with a working_dir still find their scripts.
+func scriptFileInvocation(path string) string {
+	ref := path
+	if !filepath.IsAbs(path) {
+		ref = "${project.dir}/" + filepath.ToSlash(path)
+	}
+	switch strings.ToLower(filepath.Ext(path)) {
+	case ".ps1":
+		return fmt.Sprintf(`powershell -NoProfile -ExecutionPolicy Bypass -File "%s"`, ref)
+	case ".bat", ".cmd":
+		return fmt.Sprintf(`call "%s"`, ref)
+	default:
+		return fmt.Sprintf(`bash "%s"`, ref)
+	}
+}
+
 // ResolvePlatformScript resolves a script based on the current platform
 func ResolvePlatformScript(script interface{}) (string, error) {
 	switch s := script.(type) {
@@ -314,6 +1006,21 @@ func ResolvePlatformScriptWithInterpreter(script interface{}, defaultInterpreter
 	case map[string]interface{}:
 		platform := runtime.GOOS
 
+		// A file key turns the map into a script-file declaration, so long
+		// scripts can live in real files (syntax highlighting, shellcheck)
+		// instead of JSON5 strings; platform keys may point to other files
+		if _, isFile := s["file"]; isFile {
+			path, err := resolveScriptFile(s, platform)
+			if err != nil {
+				return "", "", err
+			}
+			interpreter := defaultInterpreter
+			if interpreter == "" {
+				interpreter = "native"
+			}
+			return scriptFileInvocation(path), interpreter, nil
+		}
+
 		// Try to find platform-specific configuration
 		var platformValue interface{}
 		var found bool
@@ -431,6 +1138,26 @@ func HasValidScript(script interface{}) bool {
 		return false
 	case PlatformScript:
 		return s.Windows != "" || s.Unix != "" || s.Linux != "" || s.MacOS != "" || s.Darwin != "" || s.Default != ""
+	case []interface{}:
+		// Step arrays are valid when every step is a script or a
+		// { run: ... } step object
+		if len(s) == 0 {
+			return false
+		}
+		for _, step := range s {
+			if m, ok := step.(map[string]interface{}); ok {
+				if run, isStep := m["run"]; isStep {
+					if !HasValidScript(run) {
+						return false
+					}
+					continue
+				}
+			}
+			if !HasValidScript(step) {
+				return false
+			}
+		}
+		return true
 	default:
 		return false
 	}
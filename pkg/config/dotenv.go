@@ -0,0 +1,81 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// Dotenv support: mvx loads .env and .mvx/.env from the project root into
+// the configuration's environment section, so values reach commands and tool
+// invocations like declared environment entries (including overriding the
+// OS environment). Precedence, lowest to highest: .env, .mvx/.env, the
+// environment section itself. Set dotenv: false in the config to opt out.
+
+// loadDotEnvFiles merges dotenv files into the config's environment section
+func loadDotEnvFiles(config *Config, projectRoot string) error {
+	if config.DotEnv != nil && !*config.DotEnv {
+		return nil
+	}
+
+	merged := make(map[string]string)
+	for _, name := range []string{".env", filepath.Join(".mvx", ".env")} {
+		path := filepath.Join(projectRoot, name)
+		data, err := os.ReadFile(path)
+		if os.IsNotExist(err) {
+			continue
+		}
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		values, err := parseDotEnv(string(data))
+		if err != nil {
+			return fmt.Errorf("failed to parse %s: %w", path, err)
+		}
+		for key, value := range values {
+			merged[key] = value
+		}
+	}
+	if len(merged) == 0 {
+		return nil
+	}
+
+	if config.Environment == nil {
+		config.Environment = make(map[string]EnvValue)
+	}
+	for key, value := range merged {
+		if _, declared := config.Environment[key]; !declared {
+			config.Environment[key] = EnvValue(value)
+		}
+	}
+	return nil
+}
+
+// parseDotEnv parses dotenv content: KEY=VALUE lines, blank lines and #
+// comments, an optional "export " prefix, and single or double quoted values
+func parseDotEnv(content string) (map[string]string, error) {
+	values := make(map[string]string)
+	for i, line := range strings.Split(content, "\n") {
+		line = strings.TrimSpace(line)
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		line = strings.TrimPrefix(line, "export ")
+
+		key, value, found := strings.Cut(line, "=")
+		key = strings.TrimSpace(key)
+		if !found || key == "" {
+			return nil, fmt.Errorf("line %d: expected KEY=VALUE, got %q", i+1, line)
+		}
+
+		value = strings.TrimSpace(value)
+		if len(value) >= 2 {
+			if (value[0] == '"' && value[len(value)-1] == '"') || (value[0] == '\'' && value[len(value)-1] == '\'') {
+				value = value[1 : len(value)-1]
+			}
+		}
+		values[key] = value
+	}
+	return values, nil
+}
@@ -0,0 +1,76 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestParseDotEnv(t *testing.T) {
+	values, err := parseDotEnv(`# comment
+FOO=bar
+export QUOTED="hello world"
+SINGLE='a b'
+EMPTY=
+
+SPACED = trimmed
+`)
+	if err != nil {
+		t.Fatalf("parseDotEnv() error = %v", err)
+	}
+	want := map[string]string{
+		"FOO":    "bar",
+		"QUOTED": "hello world",
+		"SINGLE": "a b",
+		"EMPTY":  "",
+		"SPACED": "trimmed",
+	}
+	for key, expected := range want {
+		if values[key] != expected {
+			t.Errorf("values[%q] = %q, want %q", key, values[key], expected)
+		}
+	}
+
+	if _, err := parseDotEnv("not a pair"); err == nil {
+		t.Error("expected error for malformed line")
+	}
+}
+
+func TestLoadDotEnvFiles(t *testing.T) {
+	tempDir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(tempDir, ".mvx"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(tempDir, ".env"), []byte("SHARED=from-dotenv\nONLY_ROOT=1\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(tempDir, ".mvx", ".env"), []byte("SHARED=from-mvx-dotenv\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg := &Config{Environment: map[string]EnvValue{"DECLARED": "config-wins"}}
+	if err := loadDotEnvFiles(cfg, tempDir); err != nil {
+		t.Fatalf("loadDotEnvFiles() error = %v", err)
+	}
+
+	// .mvx/.env wins over .env; the environment section wins over both
+	if cfg.Environment["SHARED"] != "from-mvx-dotenv" {
+		t.Errorf("SHARED = %q, want from-mvx-dotenv", cfg.Environment["SHARED"])
+	}
+	if cfg.Environment["ONLY_ROOT"] != "1" {
+		t.Errorf("ONLY_ROOT = %q, want 1", cfg.Environment["ONLY_ROOT"])
+	}
+	if cfg.Environment["DECLARED"] != "config-wins" {
+		t.Errorf("DECLARED = %q, want config-wins", cfg.Environment["DECLARED"])
+	}
+
+	// dotenv: false opts out entirely
+	disabled := false
+	cfg = &Config{DotEnv: &disabled}
+	if err := loadDotEnvFiles(cfg, tempDir); err != nil {
+		t.Fatalf("loadDotEnvFiles() error = %v", err)
+	}
+	if len(cfg.Environment) != 0 {
+		t.Errorf("environment should stay empty with dotenv disabled, got %v", cfg.Environment)
+	}
+}
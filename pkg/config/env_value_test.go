@@ -0,0 +1,53 @@
+package config
+
+import (
+	"encoding/json"
+	"runtime"
+	"testing"
+)
+
+func TestEnvValuePlainString(t *testing.T) {
+	var cfg Config
+	data := []byte(`{"environment": {"MAVEN_OPTS": "-Xmx2g"}}`)
+	if err := json.Unmarshal(data, &cfg); err != nil {
+		t.Fatalf("Unmarshal() error = %v", err)
+	}
+	if cfg.Environment["MAVEN_OPTS"] != "-Xmx2g" {
+		t.Errorf("Expected -Xmx2g, got %s", cfg.Environment["MAVEN_OPTS"])
+	}
+}
+
+func TestEnvValuePerPlatform(t *testing.T) {
+	var cfg Config
+	data := []byte(`{"environment": {"PATH_SEP": {"windows": ";", "unix": ":"}}}`)
+	if err := json.Unmarshal(data, &cfg); err != nil {
+		t.Fatalf("Unmarshal() error = %v", err)
+	}
+
+	expected := ":"
+	if runtime.GOOS == "windows" {
+		expected = ";"
+	}
+	if string(cfg.Environment["PATH_SEP"]) != expected {
+		t.Errorf("Expected %q for %s, got %q", expected, runtime.GOOS, cfg.Environment["PATH_SEP"])
+	}
+}
+
+func TestEnvValueDefaultFallback(t *testing.T) {
+	var cfg Config
+	data := []byte(`{"environment": {"TMP_DIR": {"windows": "C:\\Temp", "default": "/tmp"}}}`)
+	if err := json.Unmarshal(data, &cfg); err != nil {
+		t.Fatalf("Unmarshal() error = %v", err)
+	}
+	if runtime.GOOS != "windows" && cfg.Environment["TMP_DIR"] != "/tmp" {
+		t.Errorf("Expected default fallback /tmp, got %s", cfg.Environment["TMP_DIR"])
+	}
+}
+
+func TestEnvValueNoPlatformMatch(t *testing.T) {
+	var cfg Config
+	data := []byte(`{"environment": {"ONLY_ELSEWHERE": {"plan9": "x"}}}`)
+	if err := json.Unmarshal(data, &cfg); err == nil {
+		t.Error("Expected error for value with no matching platform, got nil")
+	}
+}
@@ -0,0 +1,300 @@
+package config
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+	"gopkg.in/yaml.v3"
+)
+
+// maxExtendsDepth guards against extends cycles across files and URLs
+const maxExtendsDepth = 10
+
+// parseConfigData parses raw config bytes based on the file extension
+func parseConfigData(data []byte, ext string) (*Config, error) {
+	var config Config
+	var err error
+
+	switch ext {
+	case ".json5", ".json", "":
+		// JSON5 preprocessor also handles plain JSON (allows comments)
+		err = ParseJSON5(data, &config)
+	case ".yml", ".yaml":
+		err = yaml.Unmarshal(data, &config)
+	case ".toml":
+		var parsed *Config
+		if parsed, err = ParseTOML(data); err == nil {
+			config = *parsed
+		}
+	default:
+		return nil, fmt.Errorf("unsupported config file format: %s", ext)
+	}
+
+	if err != nil {
+		return nil, err
+	}
+	return &config, nil
+}
+
+// resolveExtends loads the parent config referenced by cfg.Extends (and its
+// own parents, recursively) and merges cfg on top of it.
+func resolveExtends(cfg *Config, baseDir string, depth int) (*Config, error) {
+	if depth >= maxExtendsDepth {
+		return nil, fmt.Errorf("extends chain deeper than %d levels (cycle?)", maxExtendsDepth)
+	}
+
+	parent, parentDir, err := loadParentConfig(cfg.Extends, baseDir)
+	if err != nil {
+		return nil, err
+	}
+
+	if parent.Extends != "" {
+		parent, err = resolveExtends(parent, parentDir, depth+1)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return mergeConfigs(parent, cfg), nil
+}
+
+// loadParentConfig loads a parent config from a relative path or an http(s)
+// URL, returning the config and the directory for resolving its own extends.
+func loadParentConfig(ref, baseDir string) (*Config, string, error) {
+	if strings.HasPrefix(ref, "http://") || strings.HasPrefix(ref, "https://") {
+		url, pin := splitChecksumPin(ref)
+		data, err := fetchRemoteConfig(url, pin)
+		if err != nil {
+			return nil, "", err
+		}
+		cfg, err := parseConfigData(data, strings.ToLower(filepath.Ext(url)))
+		if err != nil {
+			return nil, "", fmt.Errorf("failed to parse parent config %s: %w", url, err)
+		}
+		return cfg, baseDir, nil
+	}
+
+	path := ref
+	if !filepath.IsAbs(path) {
+		path = filepath.Join(baseDir, path)
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to read parent config %s: %w", path, err)
+	}
+	cfg, err := parseConfigData(data, strings.ToLower(filepath.Ext(path)))
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to parse parent config %s: %w", path, err)
+	}
+	return cfg, filepath.Dir(path), nil
+}
+
+// splitChecksumPin splits a "#sha256=<hex>" fragment off a remote ref, so
+// platform teams can publish integrity-pinned baselines:
+//
+//	extends: "https://git.company.com/mvx/defaults.json5#sha256=ab12..."
+func splitChecksumPin(ref string) (url, pin string) {
+	if idx := strings.Index(ref, "#sha256="); idx != -1 {
+		return ref[:idx], strings.ToLower(ref[idx+len("#sha256="):])
+	}
+	return ref, ""
+}
+
+// fetchRemoteConfig downloads a remote parent config, verifying the sha256
+// pin when present. Verified responses are cached under ~/.mvx/cache/extends
+// so pinned configs keep working offline; on network failure the cached copy
+// is used as a fallback.
+func fetchRemoteConfig(url, pin string) ([]byte, error) {
+	cachePath := remoteConfigCachePath(url, pin)
+
+	// Pinned configs are immutable: a valid cached copy never needs the network
+	if pin != "" && cachePath != "" {
+		if data, err := os.ReadFile(cachePath); err == nil && checksumSHA256(data) == pin {
+			return data, nil
+		}
+	}
+
+	// Offline mode: the cached copy is the only option
+	if util.IsOffline() {
+		if cachePath != "" {
+			if data, err := os.ReadFile(cachePath); err == nil {
+				if pin == "" || checksumSHA256(data) == pin {
+					return data, nil
+				}
+			}
+		}
+		return nil, fmt.Errorf("offline mode: parent config %s is not cached", url)
+	}
+
+	client := util.HTTPClient(30 * time.Second)
+	resp, err := client.Get(url)
+	if err != nil {
+		// Offline fallback: reuse the cached copy if we have one
+		if cachePath != "" {
+			if data, cacheErr := os.ReadFile(cachePath); cacheErr == nil {
+				if pin == "" || checksumSHA256(data) == pin {
+					return data, nil
+				}
+			}
+		}
+		return nil, fmt.Errorf("failed to fetch parent config %s: %w", url, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("parent config %s returned status %d", url, resp.StatusCode)
+	}
+	data, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read parent config %s: %w", url, err)
+	}
+
+	if pin != "" {
+		if actual := checksumSHA256(data); actual != pin {
+			return nil, fmt.Errorf("parent config %s checksum mismatch: expected sha256 %s, got %s", url, pin, actual)
+		}
+	}
+
+	// Best-effort cache write; failures only cost the offline fallback
+	if cachePath != "" {
+		if err := os.MkdirAll(filepath.Dir(cachePath), 0755); err == nil {
+			_ = os.WriteFile(cachePath, data, 0644)
+		}
+	}
+
+	return data, nil
+}
+
+// remoteConfigCachePath returns the cache file for a remote config URL,
+// keyed by the URL hash and keeping the original extension for parsing
+func remoteConfigCachePath(url, pin string) string {
+	cacheDir, err := util.MvxCacheDir()
+	if err != nil {
+		return ""
+	}
+	key := pin
+	if key == "" {
+		key = checksumSHA256([]byte(url))
+	}
+	return filepath.Join(cacheDir, "extends", key+filepath.Ext(url))
+}
+
+// checksumSHA256 returns the lowercase hex sha256 of data
+func checksumSHA256(data []byte) string {
+	sum := sha256.Sum256(data)
+	return hex.EncodeToString(sum[:])
+}
+
+// mergeConfigs layers child on top of parent: map entries merge per key and
+// scalar/slice fields from the child replace the parent's when set.
+func mergeConfigs(parent, child *Config) *Config {
+	merged := *parent
+	merged.Extends = "" // fully resolved
+
+	if child.Project.Name != "" {
+		merged.Project.Name = child.Project.Name
+	}
+	if child.Project.Description != "" {
+		merged.Project.Description = child.Project.Description
+	}
+
+	merged.Tools = mergeMap(parent.Tools, child.Tools)
+	merged.Environment = mergeMap(parent.Environment, child.Environment)
+	merged.Commands = mergeMap(parent.Commands, child.Commands)
+	merged.JvmProfiles = mergeMap(parent.JvmProfiles, child.JvmProfiles)
+	merged.Registries = mergeMap(parent.Registries, child.Registries)
+	merged.Mirrors = mergeMap(parent.Mirrors, child.Mirrors)
+	merged.Profiles = mergeMap(parent.Profiles, child.Profiles)
+
+	if len(child.Plugins) > 0 {
+		merged.Plugins = child.Plugins
+	}
+	if len(child.Sensitive) > 0 {
+		merged.Sensitive = append(append([]string{}, parent.Sensitive...), child.Sensitive...)
+	}
+	if len(child.AllowedHosts) > 0 {
+		merged.AllowedHosts = child.AllowedHosts
+	}
+	if child.MvxVersion != "" {
+		merged.MvxVersion = child.MvxVersion
+	}
+	merged.Security = mergeSecurity(parent.Security, child.Security)
+	if child.Maven != nil {
+		merged.Maven = child.Maven
+	}
+	if child.Java != nil {
+		merged.Java = child.Java
+	}
+	if child.Setup != nil {
+		merged.Setup = child.Setup
+	}
+
+	return &merged
+}
+
+// mergeSecurity merges security blocks field-wise. A parent's allowedHosts is
+// a policy, not a default: an org-level extended config stays enforceable
+// because a child cannot replace, drop or widen the parent's list.
+func mergeSecurity(parent, child *SecurityConfig) *SecurityConfig {
+	if child == nil {
+		return parent
+	}
+	if parent == nil {
+		return child
+	}
+	merged := *parent
+	if child.VerifySignatures {
+		merged.VerifySignatures = true
+	}
+	if child.RequireProvenance {
+		merged.RequireProvenance = true
+	}
+	if len(parent.AllowedHosts) == 0 {
+		merged.AllowedHosts = child.AllowedHosts
+	}
+	return &merged
+}
+
+// ApplyProfile overlays a named profile's tools, environment and commands
+// onto the configuration. Unknown profile names are an error so typos in
+// --profile or MVX_PROFILE fail loudly.
+func (c *Config) ApplyProfile(name string) error {
+	profile, exists := c.Profiles[name]
+	if !exists {
+		var known []string
+		for profileName := range c.Profiles {
+			known = append(known, profileName)
+		}
+		if len(known) == 0 {
+			return fmt.Errorf("profile %s requested but no profiles are defined", name)
+		}
+		return fmt.Errorf("unknown profile %s (defined: %s)", name, strings.Join(known, ", "))
+	}
+
+	c.Tools = mergeMap(c.Tools, profile.Tools)
+	c.Environment = mergeMap(c.Environment, profile.Environment)
+	c.Commands = mergeMap(c.Commands, profile.Commands)
+	return nil
+}
+
+// mergeMap merges child entries over parent entries into a fresh map
+func mergeMap[V any](parent, child map[string]V) map[string]V {
+	if len(parent) == 0 {
+		return child
+	}
+	merged := make(map[string]V, len(parent)+len(child))
+	for key, value := range parent {
+		merged[key] = value
+	}
+	for key, value := range child {
+		merged[key] = value
+	}
+	return merged
+}
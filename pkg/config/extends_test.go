@@ -0,0 +1,277 @@
+package config
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestMergeConfigs(t *testing.T) {
+	parent := &Config{
+		Project:     ProjectConfig{Name: "org-defaults", Description: "shared"},
+		Tools:       map[string]ToolConfig{"java": {Version: "17"}, "maven": {Version: "3.9.6"}},
+		Environment: map[string]EnvValue{"ORG": "acme", "LEVEL": "parent"},
+		Commands:    map[string]CommandConfig{"build": {Script: "mvn install"}},
+	}
+	child := &Config{
+		Project:     ProjectConfig{Name: "my-repo"},
+		Tools:       map[string]ToolConfig{"java": {Version: "21"}},
+		Environment: map[string]EnvValue{"LEVEL": "child"},
+	}
+
+	merged := mergeConfigs(parent, child)
+
+	if merged.Project.Name != "my-repo" {
+		t.Errorf("expected child project name, got %s", merged.Project.Name)
+	}
+	if merged.Project.Description != "shared" {
+		t.Errorf("expected inherited description, got %s", merged.Project.Description)
+	}
+	if merged.Tools["java"].Version != "21" {
+		t.Errorf("expected child java version 21, got %s", merged.Tools["java"].Version)
+	}
+	if merged.Tools["maven"].Version != "3.9.6" {
+		t.Errorf("expected inherited maven version, got %s", merged.Tools["maven"].Version)
+	}
+	if merged.Environment["LEVEL"] != "child" {
+		t.Errorf("expected child env to win, got %s", merged.Environment["LEVEL"])
+	}
+	if merged.Environment["ORG"] != "acme" {
+		t.Errorf("expected inherited env, got %s", merged.Environment["ORG"])
+	}
+	if _, exists := merged.Commands["build"]; !exists {
+		t.Error("expected inherited build command")
+	}
+}
+
+func TestLoadConfigWithExtends(t *testing.T) {
+	dir := t.TempDir()
+	mvxDir := filepath.Join(dir, ".mvx")
+	if err := os.MkdirAll(mvxDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	parent := `{
+		tools: {
+			java: { version: "17" },
+			maven: { version: "3.9.6" },
+		},
+	}`
+	if err := os.WriteFile(filepath.Join(mvxDir, "parent.json5"), []byte(parent), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	childConfig := `{
+		extends: "parent.json5",
+		project: { name: "child" },
+		tools: {
+			java: { version: "21" },
+		},
+	}`
+	if err := os.WriteFile(filepath.Join(mvxDir, "config.json5"), []byte(childConfig), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg, err := LoadConfig(dir)
+	if err != nil {
+		t.Fatalf("LoadConfig failed: %v", err)
+	}
+
+	if cfg.Tools["java"].Version != "21" {
+		t.Errorf("expected java 21 from child, got %s", cfg.Tools["java"].Version)
+	}
+	if cfg.Tools["maven"].Version != "3.9.6" {
+		t.Errorf("expected maven 3.9.6 from parent, got %s", cfg.Tools["maven"].Version)
+	}
+	if cfg.Extends != "" {
+		t.Errorf("expected extends to be cleared after resolution, got %s", cfg.Extends)
+	}
+}
+
+func TestLoadConfigStacking(t *testing.T) {
+	tempDir := t.TempDir()
+	mvxDir := filepath.Join(tempDir, ".mvx")
+	if err := os.MkdirAll(mvxDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	// Org baseline supplied via MVX_CONFIG_PATH
+	baseline := filepath.Join(tempDir, "org.json5")
+	if err := os.WriteFile(baseline, []byte(`{
+		tools: { java: { version: "17" }, maven: { version: "3.9.6" } },
+		environment: { ORG: "acme" },
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Project config overrides the baseline
+	if err := os.WriteFile(filepath.Join(mvxDir, "config.json5"), []byte(`{
+		project: { name: "stacked" },
+		tools: { java: { version: "21" } },
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Untracked local override wins over everything
+	if err := os.WriteFile(filepath.Join(mvxDir, "config.local.json5"), []byte(`{
+		environment: { ORG: "personal" },
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	t.Setenv("MVX_CONFIG_PATH", baseline)
+
+	cfg, err := LoadConfig(tempDir)
+	if err != nil {
+		t.Fatalf("LoadConfig() error = %v", err)
+	}
+
+	if cfg.Project.Name != "stacked" {
+		t.Errorf("expected project name stacked, got %s", cfg.Project.Name)
+	}
+	if cfg.Tools["java"].Version != "21" {
+		t.Errorf("expected project java version 21, got %s", cfg.Tools["java"].Version)
+	}
+	if cfg.Tools["maven"].Version != "3.9.6" {
+		t.Errorf("expected baseline maven version, got %s", cfg.Tools["maven"].Version)
+	}
+	if cfg.Environment["ORG"] != "personal" {
+		t.Errorf("expected local override to win, got %s", cfg.Environment["ORG"])
+	}
+}
+
+func TestLoadConfigNestedProjects(t *testing.T) {
+	repoRoot := t.TempDir()
+	rootMvx := filepath.Join(repoRoot, ".mvx")
+	moduleMvx := filepath.Join(repoRoot, "modules", "frontend", ".mvx")
+	for _, dir := range []string{rootMvx, moduleMvx} {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	// Repository root declares the shared Java/Maven baseline
+	if err := os.WriteFile(filepath.Join(rootMvx, "config.json5"), []byte(`{
+		project: { name: "monorepo" },
+		tools: { java: { version: "21" }, node: { version: "20" } },
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Nested module only overrides what it needs
+	if err := os.WriteFile(filepath.Join(moduleMvx, "config.json5"), []byte(`{
+		project: { name: "frontend" },
+		tools: { node: { version: "22" } },
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg, err := LoadConfig(filepath.Join(repoRoot, "modules", "frontend"))
+	if err != nil {
+		t.Fatalf("LoadConfig() error = %v", err)
+	}
+
+	if cfg.Project.Name != "frontend" {
+		t.Errorf("expected nested project name frontend, got %s", cfg.Project.Name)
+	}
+	if cfg.Tools["node"].Version != "22" {
+		t.Errorf("expected nested node version 22, got %s", cfg.Tools["node"].Version)
+	}
+	if cfg.Tools["java"].Version != "21" {
+		t.Errorf("expected inherited java version 21, got %s", cfg.Tools["java"].Version)
+	}
+
+	// The root project itself is unaffected by the nested module
+	rootCfg, err := LoadConfig(repoRoot)
+	if err != nil {
+		t.Fatalf("LoadConfig(root) error = %v", err)
+	}
+	if rootCfg.Tools["node"].Version != "20" {
+		t.Errorf("expected root node version 20, got %s", rootCfg.Tools["node"].Version)
+	}
+}
+
+func TestRemoteExtendsChecksumPin(t *testing.T) {
+	parentBody := []byte(`{ tools: { java: { version: "21" } } }`)
+
+	requests := 0
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		requests++
+		w.Write(parentBody)
+	}))
+	defer server.Close()
+
+	// Keep the extends cache inside the test sandbox
+	home := t.TempDir()
+	t.Setenv("HOME", home)
+	t.Setenv("USERPROFILE", home)
+
+	pin := checksumSHA256(parentBody)
+	url := server.URL + "/defaults.json5"
+
+	cfg := &Config{
+		Project: ProjectConfig{Name: "pinned"},
+		Extends: url + "#sha256=" + pin,
+	}
+	merged, err := resolveExtends(cfg, t.TempDir(), 0)
+	if err != nil {
+		t.Fatalf("resolveExtends() error = %v", err)
+	}
+	if merged.Tools["java"].Version != "21" {
+		t.Errorf("expected inherited java version 21, got %s", merged.Tools["java"].Version)
+	}
+
+	// Second resolution must come from the cache, not the network
+	fetched := requests
+	if _, err := resolveExtends(cfg, t.TempDir(), 0); err != nil {
+		t.Fatalf("resolveExtends() from cache error = %v", err)
+	}
+	if requests != fetched {
+		t.Errorf("expected pinned config to be served from cache, got %d extra fetch(es)", requests-fetched)
+	}
+
+	// A wrong pin must be rejected
+	bad := &Config{
+		Project: ProjectConfig{Name: "bad-pin"},
+		Extends: url + "#sha256=" + checksumSHA256([]byte("something else")),
+	}
+	if _, err := resolveExtends(bad, t.TempDir(), 0); err == nil {
+		t.Error("expected checksum mismatch error, got nil")
+	}
+}
+
+func TestSplitChecksumPin(t *testing.T) {
+	url, pin := splitChecksumPin("https://example.com/defaults.json5#sha256=ABCDEF")
+	if url != "https://example.com/defaults.json5" || pin != "abcdef" {
+		t.Errorf("unexpected split: url=%s pin=%s", url, pin)
+	}
+	url, pin = splitChecksumPin("https://example.com/defaults.json5")
+	if url != "https://example.com/defaults.json5" || pin != "" {
+		t.Errorf("unexpected split without pin: url=%s pin=%s", url, pin)
+	}
+}
+
+func TestMergeSecurity(t *testing.T) {
+	org := &SecurityConfig{AllowedHosts: []string{"*.company.com"}}
+	child := &SecurityConfig{VerifySignatures: true, AllowedHosts: []string{"evil.example.com"}}
+
+	merged := mergeSecurity(org, child)
+	if !merged.VerifySignatures {
+		t.Error("expected child to be able to enable signature verification")
+	}
+	if len(merged.AllowedHosts) != 1 || merged.AllowedHosts[0] != "*.company.com" {
+		t.Errorf("expected org allowedHosts to survive the merge, got %v", merged.AllowedHosts)
+	}
+
+	// Child can set an allowlist when the parent has none
+	merged = mergeSecurity(&SecurityConfig{VerifySignatures: true}, child)
+	if len(merged.AllowedHosts) != 1 || merged.AllowedHosts[0] != "evil.example.com" {
+		t.Errorf("expected child allowedHosts when parent sets none, got %v", merged.AllowedHosts)
+	}
+
+	if mergeSecurity(nil, child) != child || mergeSecurity(org, nil) != org {
+		t.Error("expected nil blocks to pass through the other side")
+	}
+}
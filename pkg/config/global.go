@@ -4,8 +4,9 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
-	"runtime"
 	"strings"
+
+	"github.com/gnodet/mvx/pkg/util"
 )
 
 // GlobalConfig represents the global mvx configuration
@@ -21,28 +22,10 @@ func getGlobalConfigDir() (string, error) {
 	return globalConfigDirFunc()
 }
 
-// getGlobalConfigDirImpl is the actual implementation
+// getGlobalConfigDirImpl is the actual implementation: the global config
+// lives in the mvx home (~/.mvx, MVX_HOME or the XDG data directory)
 func getGlobalConfigDirImpl() (string, error) {
-	var homeDir string
-	var err error
-
-	if runtime.GOOS == "windows" {
-		homeDir = os.Getenv("USERPROFILE")
-		if homeDir == "" {
-			homeDir = os.Getenv("HOMEDRIVE") + os.Getenv("HOMEPATH")
-		}
-	} else {
-		homeDir, err = os.UserHomeDir()
-		if err != nil {
-			return "", fmt.Errorf("failed to get user home directory: %w", err)
-		}
-	}
-
-	if homeDir == "" {
-		return "", fmt.Errorf("unable to determine user home directory")
-	}
-
-	return filepath.Join(homeDir, ".mvx"), nil
+	return util.MvxHome()
 }
 
 // LoadGlobalConfig loads the global configuration
@@ -0,0 +1,356 @@
+package config
+
+import (
+	"fmt"
+	"strings"
+)
+
+// Format-preserving JSON5 editing. SetJSON5Value and RemoveJSON5Key splice a
+// single entry in or out of a JSON5 document textually, so comments, key
+// order and formatting written by humans survive automated edits
+// (mvx tools add/remove, version bumps by bots, ...).
+
+// json5Entry is one key/value pair found while scanning an object
+type json5Entry struct {
+	keyStart   int // index of the key token
+	valueStart int // index of the first value character
+	valueEnd   int // index just past the last value character
+	name       string
+}
+
+// SetJSON5Value sets the value at a key path in a JSON5 document, creating
+// intermediate objects as needed. value is inserted verbatim, so callers
+// quote strings themselves (e.g. `"22"`).
+func SetJSON5Value(content string, path []string, value string) (string, error) {
+	if len(path) == 0 {
+		return "", fmt.Errorf("empty key path")
+	}
+
+	open := skipJSON5Junk(content, 0)
+	if open >= len(content) || content[open] != '{' {
+		return "", fmt.Errorf("document does not start with an object")
+	}
+
+	return setJSON5InObject(content, open, path, value)
+}
+
+// setJSON5InObject sets path relative to the object opening at open
+func setJSON5InObject(content string, open int, path []string, value string) (string, error) {
+	entries, close, err := scanJSON5Object(content, open)
+	if err != nil {
+		return "", err
+	}
+
+	for _, entry := range entries {
+		if entry.name != path[0] {
+			continue
+		}
+		if len(path) == 1 {
+			return content[:entry.valueStart] + value + content[entry.valueEnd:], nil
+		}
+		inner := skipJSON5Junk(content, entry.valueStart)
+		if inner >= len(content) || content[inner] != '{' {
+			return "", fmt.Errorf("key %s is not an object", entry.name)
+		}
+		return setJSON5InObject(content, inner, path[1:], value)
+	}
+
+	// Key missing: build the remaining path as a nested literal and insert it
+	entryText := path[len(path)-1] + ": " + value
+	for i := len(path) - 2; i >= 0; i-- {
+		entryText = path[i] + ": { " + entryText + " }"
+	}
+	return insertJSON5Entry(content, open, close, entries, entryText), nil
+}
+
+// insertJSON5Entry splices a new "key: value" entry before the closing brace
+// of an object, matching the indentation of its existing entries
+func insertJSON5Entry(content string, open, close int, entries []json5Entry, entryText string) string {
+	indent := "  "
+	closeIndent := lineIndent(content, close)
+	if len(entries) > 0 {
+		indent = lineIndent(content, entries[len(entries)-1].keyStart)
+	} else {
+		indent = closeIndent + "  "
+	}
+
+	if len(entries) == 0 {
+		return content[:open+1] + "\n" + indent + entryText + ",\n" + closeIndent + content[close:]
+	}
+
+	last := entries[len(entries)-1]
+	// Insert after the last entry, reusing its trailing comma when present
+	pos := last.valueEnd
+	prefix := ",\n" + indent
+	if comma := nextJSON5Comma(content, last.valueEnd, close); comma != -1 {
+		pos = comma + 1
+		prefix = "\n" + indent
+	}
+	suffix := ","
+	if prefix == ",\n"+indent {
+		suffix = ""
+	}
+	return content[:pos] + prefix + entryText + suffix + content[pos:]
+}
+
+// RemoveJSON5Key removes the entry at a key path from a JSON5 document,
+// including its trailing comma (or the preceding one for a last entry)
+func RemoveJSON5Key(content string, path []string) (string, error) {
+	if len(path) == 0 {
+		return "", fmt.Errorf("empty key path")
+	}
+
+	open := skipJSON5Junk(content, 0)
+	if open >= len(content) || content[open] != '{' {
+		return "", fmt.Errorf("document does not start with an object")
+	}
+
+	for depth := 0; depth < len(path)-1; depth++ {
+		entries, _, err := scanJSON5Object(content, open)
+		if err != nil {
+			return "", err
+		}
+		found := false
+		for _, entry := range entries {
+			if entry.name == path[depth] {
+				inner := skipJSON5Junk(content, entry.valueStart)
+				if inner >= len(content) || content[inner] != '{' {
+					return "", fmt.Errorf("key %s is not an object", entry.name)
+				}
+				open = inner
+				found = true
+				break
+			}
+		}
+		if !found {
+			return "", fmt.Errorf("key %s not found", strings.Join(path[:depth+1], "."))
+		}
+	}
+
+	entries, close, err := scanJSON5Object(content, open)
+	if err != nil {
+		return "", err
+	}
+	for i, entry := range entries {
+		if entry.name != path[len(path)-1] {
+			continue
+		}
+
+		start := entry.keyStart
+		// Take the whole line when the key starts it
+		if indent := lineIndent(content, start); strings.TrimSpace(content[start-len(indent):start]) == "" {
+			start -= len(indent)
+		}
+
+		end := entry.valueEnd
+		if comma := nextJSON5Comma(content, end, close); comma != -1 {
+			end = comma + 1
+		} else if i > 0 {
+			// Last entry without trailing comma: drop the comma after the
+			// previous entry instead
+			if comma := nextJSON5Comma(content, entries[i-1].valueEnd, entry.keyStart); comma != -1 {
+				content = content[:comma] + content[comma+1:]
+				start--
+				end--
+			}
+		}
+		// Swallow the rest of the line when nothing but whitespace follows
+		lineEnd := end
+		for lineEnd < len(content) && content[lineEnd] != '\n' {
+			if !isJSON5Space(content[lineEnd]) {
+				lineEnd = end
+				break
+			}
+			lineEnd++
+		}
+		if lineEnd < len(content) && content[lineEnd] == '\n' && lineEnd > end {
+			end = lineEnd
+		}
+		if end < len(content) && content[end] == '\n' && start > 0 && content[start-1] == '\n' {
+			end++
+		}
+
+		return content[:start] + content[end:], nil
+	}
+
+	return "", fmt.Errorf("key %s not found", strings.Join(path, "."))
+}
+
+// scanJSON5Object parses the entries of the object opening at open, returning
+// them together with the index of the closing brace
+func scanJSON5Object(content string, open int) ([]json5Entry, int, error) {
+	var entries []json5Entry
+	i := open + 1
+
+	for {
+		i = skipJSON5Junk(content, i)
+		if i >= len(content) {
+			return nil, 0, fmt.Errorf("unterminated object")
+		}
+		if content[i] == '}' {
+			return entries, i, nil
+		}
+
+		entry := json5Entry{keyStart: i}
+		name, next, err := parseJSON5Key(content, i)
+		if err != nil {
+			return nil, 0, err
+		}
+		entry.name = name
+
+		i = skipJSON5Junk(content, next)
+		if i >= len(content) || content[i] != ':' {
+			return nil, 0, fmt.Errorf("expected ':' after key %s", name)
+		}
+		i = skipJSON5Junk(content, i+1)
+		entry.valueStart = i
+
+		end, err := json5ValueEnd(content, i)
+		if err != nil {
+			return nil, 0, err
+		}
+		entry.valueEnd = end
+		entries = append(entries, entry)
+
+		i = skipJSON5Junk(content, end)
+		if i < len(content) && content[i] == ',' {
+			i++
+		}
+	}
+}
+
+// parseJSON5Key parses a quoted or bare object key starting at i
+func parseJSON5Key(content string, i int) (string, int, error) {
+	if content[i] == '"' || content[i] == '\'' {
+		end, err := json5StringEnd(content, i)
+		if err != nil {
+			return "", 0, err
+		}
+		return content[i+1 : end-1], end, nil
+	}
+
+	start := i
+	for i < len(content) && !isJSON5Space(content[i]) && content[i] != ':' && content[i] != '\n' {
+		i++
+	}
+	if start == i {
+		return "", 0, fmt.Errorf("expected object key at offset %d", start)
+	}
+	return content[start:i], i, nil
+}
+
+// json5ValueEnd returns the index just past the value starting at i
+func json5ValueEnd(content string, i int) (int, error) {
+	if i >= len(content) {
+		return 0, fmt.Errorf("unexpected end of document")
+	}
+
+	switch content[i] {
+	case '{', '[':
+		openChar, closeChar := content[i], byte('}')
+		if openChar == '[' {
+			closeChar = ']'
+		}
+		depth := 0
+		for i < len(content) {
+			switch content[i] {
+			case '"', '\'':
+				end, err := json5StringEnd(content, i)
+				if err != nil {
+					return 0, err
+				}
+				i = end
+				continue
+			case '/':
+				if next := skipJSON5Junk(content, i); next != i {
+					i = next
+					continue
+				}
+			case openChar:
+				depth++
+			case closeChar:
+				depth--
+				if depth == 0 {
+					return i + 1, nil
+				}
+			}
+			i++
+		}
+		return 0, fmt.Errorf("unterminated %c", openChar)
+	case '"', '\'':
+		return json5StringEnd(content, i)
+	default:
+		// Bare value (number, boolean, null): runs to a separator
+		for i < len(content) && content[i] != ',' && content[i] != '}' && content[i] != ']' && content[i] != '\n' {
+			i++
+		}
+		end := i
+		for end > 0 && isJSON5Space(content[end-1]) {
+			end--
+		}
+		return end, nil
+	}
+}
+
+// json5StringEnd returns the index just past the string starting at i
+func json5StringEnd(content string, i int) (int, error) {
+	quote := content[i]
+	for j := i + 1; j < len(content); j++ {
+		switch content[j] {
+		case '\\':
+			j++
+		case quote:
+			return j + 1, nil
+		}
+	}
+	return 0, fmt.Errorf("unterminated string at offset %d", i)
+}
+
+// skipJSON5Junk advances past whitespace and // and /* */ comments
+func skipJSON5Junk(content string, i int) int {
+	for i < len(content) {
+		c := content[i]
+		switch {
+		case isJSON5Space(c) || c == '\n' || c == '\r':
+			i++
+		case c == '/' && i+1 < len(content) && content[i+1] == '/':
+			for i < len(content) && content[i] != '\n' {
+				i++
+			}
+		case c == '/' && i+1 < len(content) && content[i+1] == '*':
+			end := strings.Index(content[i+2:], "*/")
+			if end == -1 {
+				return len(content)
+			}
+			i += 2 + end + 2
+		default:
+			return i
+		}
+	}
+	return i
+}
+
+// nextJSON5Comma finds the next comma between from and until, skipping
+// comments; -1 when none is found
+func nextJSON5Comma(content string, from, until int) int {
+	i := skipJSON5Junk(content, from)
+	if i < until && i < len(content) && content[i] == ',' {
+		return i
+	}
+	return -1
+}
+
+// lineIndent returns the whitespace at the start of the line containing pos
+func lineIndent(content string, pos int) string {
+	start := strings.LastIndexByte(content[:pos], '\n') + 1
+	end := start
+	for end < len(content) && isJSON5Space(content[end]) {
+		end++
+	}
+	return content[start:end]
+}
+
+// isJSON5Space reports whether c is a space or tab
+func isJSON5Space(c byte) bool {
+	return c == ' ' || c == '\t'
+}
@@ -0,0 +1,113 @@
+package config
+
+import (
+	"strings"
+	"testing"
+)
+
+const editFixture = `{
+  // Project metadata
+  project: {
+    name: "demo", // keep this name
+  },
+  tools: {
+    /* shared baseline */
+    java: {
+      version: "21",
+      distribution: "temurin",
+    },
+    maven: { version: "3.9.9" },
+  },
+}
+`
+
+func TestSetJSON5ValueReplacesExisting(t *testing.T) {
+	result, err := SetJSON5Value(editFixture, []string{"tools", "java", "version"}, `"25"`)
+	if err != nil {
+		t.Fatalf("SetJSON5Value() error = %v", err)
+	}
+	if !strings.Contains(result, `version: "25",`) {
+		t.Errorf("expected updated version, got:\n%s", result)
+	}
+	for _, preserved := range []string{"// Project metadata", "// keep this name", "/* shared baseline */", `distribution: "temurin",`} {
+		if !strings.Contains(result, preserved) {
+			t.Errorf("expected %q to be preserved, got:\n%s", preserved, result)
+		}
+	}
+
+	// The rest of the document is untouched
+	var cfg Config
+	if err := ParseJSON5([]byte(result), &cfg); err != nil {
+		t.Fatalf("edited document no longer parses: %v", err)
+	}
+	if cfg.Tools["maven"].Version != "3.9.9" {
+		t.Errorf("expected maven untouched, got %s", cfg.Tools["maven"].Version)
+	}
+}
+
+func TestSetJSON5ValueInsertsNewTool(t *testing.T) {
+	result, err := SetJSON5Value(editFixture, []string{"tools", "node", "version"}, `"22"`)
+	if err != nil {
+		t.Fatalf("SetJSON5Value() error = %v", err)
+	}
+
+	var cfg Config
+	if err := ParseJSON5([]byte(result), &cfg); err != nil {
+		t.Fatalf("edited document no longer parses: %v\n%s", err, result)
+	}
+	if cfg.Tools["node"].Version != "22" {
+		t.Errorf("expected node 22 to be inserted, got %q", cfg.Tools["node"].Version)
+	}
+	if cfg.Tools["java"].Version != "21" {
+		t.Errorf("expected java untouched, got %s", cfg.Tools["java"].Version)
+	}
+	if !strings.Contains(result, "/* shared baseline */") {
+		t.Errorf("expected comments preserved, got:\n%s", result)
+	}
+}
+
+func TestSetJSON5ValueCreatesSection(t *testing.T) {
+	doc := "{\n  project: { name: \"demo\" },\n}\n"
+	result, err := SetJSON5Value(doc, []string{"tools", "go", "version"}, `"1.23.1"`)
+	if err != nil {
+		t.Fatalf("SetJSON5Value() error = %v", err)
+	}
+
+	var cfg Config
+	if err := ParseJSON5([]byte(result), &cfg); err != nil {
+		t.Fatalf("edited document no longer parses: %v\n%s", err, result)
+	}
+	if cfg.Tools["go"].Version != "1.23.1" {
+		t.Errorf("expected go tool section to be created, got %q", cfg.Tools["go"].Version)
+	}
+}
+
+func TestRemoveJSON5Key(t *testing.T) {
+	result, err := RemoveJSON5Key(editFixture, []string{"tools", "java"})
+	if err != nil {
+		t.Fatalf("RemoveJSON5Key() error = %v", err)
+	}
+	if strings.Contains(result, "temurin") {
+		t.Errorf("expected java entry removed, got:\n%s", result)
+	}
+
+	var cfg Config
+	if err := ParseJSON5([]byte(result), &cfg); err != nil {
+		t.Fatalf("edited document no longer parses: %v\n%s", err, result)
+	}
+	if _, exists := cfg.Tools["java"]; exists {
+		t.Error("expected java to be gone from parsed config")
+	}
+	if cfg.Tools["maven"].Version != "3.9.9" {
+		t.Errorf("expected maven preserved, got %s", cfg.Tools["maven"].Version)
+	}
+	if !strings.Contains(result, "// Project metadata") {
+		t.Errorf("expected comments preserved, got:\n%s", result)
+	}
+}
+
+func TestRemoveJSON5KeyMissing(t *testing.T) {
+	if _, err := RemoveJSON5Key(editFixture, []string{"tools", "gradle"}); err == nil {
+		t.Error("expected error for missing key, got nil")
+	}
+}
@@ -0,0 +1,31 @@
+package config
+
+import "testing"
+
+func TestCheckMvxVersion(t *testing.T) {
+	cases := []struct {
+		required string
+		current  string
+		wantErr  bool
+	}{
+		{"", "0.5.0", false}, // no requirement
+		{">=0.8.0", "0.9.1", false}, // newer binary
+		{">=0.8.0", "0.8.0", false}, // exact match
+		{">=0.8.0", "0.7.3", true}, // too old
+		{"0.8.0", "0.7.3", true}, // bare version means at-least
+		{">=0.8.0", "dev", false}, // development builds skip the check
+		{">=0.8.0", "snapshot-x", false}, // unparseable build versions pass
+		{"not-a-version", "0.9.0", true}, // invalid requirement is an error
+	}
+
+	for _, tc := range cases {
+		cfg := &Config{MvxVersion: tc.required}
+		err := cfg.CheckMvxVersion(tc.current)
+		if tc.wantErr && err == nil {
+			t.Errorf("CheckMvxVersion(%q) with requirement %q: expected error, got nil", tc.current, tc.required)
+		}
+		if !tc.wantErr && err != nil {
+			t.Errorf("CheckMvxVersion(%q) with requirement %q: unexpected error: %v", tc.current, tc.required, err)
+		}
+	}
+}
@@ -2,6 +2,7 @@ package config
 
 import (
 	"runtime"
+	"strings"
 	"testing"
 )
 
@@ -176,3 +177,37 @@ func getUnixExpected() string {
 		return "echo unix"
 	}
 }
+
+func TestResolveScriptFileDeclaration(t *testing.T) {
+	script := map[string]interface{}{
+		"file":    ".mvx/scripts/release.sh",
+		"windows": ".mvx/scripts/release.ps1",
+	}
+
+	resolved, interpreter, err := ResolvePlatformScriptWithInterpreter(script, "")
+	if err != nil {
+		t.Fatalf("ResolvePlatformScriptWithInterpreter() error = %v", err)
+	}
+	if interpreter != "native" {
+		t.Errorf("interpreter = %q, want native", interpreter)
+	}
+	if runtime.GOOS == "windows" {
+		if !strings.Contains(resolved, "release.ps1") || !strings.Contains(resolved, "powershell") {
+			t.Errorf("resolved = %q, want a powershell invocation of release.ps1", resolved)
+		}
+	} else {
+		want := `bash "${project.dir}/.mvx/scripts/release.sh"`
+		if resolved != want {
+			t.Errorf("resolved = %q, want %q", resolved, want)
+		}
+	}
+}
+
+func TestScriptFileInvocation(t *testing.T) {
+	if got := scriptFileInvocation(".mvx/scripts/release.ps1"); got != `powershell -NoProfile -ExecutionPolicy Bypass -File "${project.dir}/.mvx/scripts/release.ps1"` {
+		t.Errorf("scriptFileInvocation() = %q", got)
+	}
+	if got := scriptFileInvocation(".mvx/scripts/build.sh"); got != `bash "${project.dir}/.mvx/scripts/build.sh"` {
+		t.Errorf("scriptFileInvocation() = %q", got)
+	}
+}
@@ -0,0 +1,205 @@
+package config
+
+import (
+	"encoding/json"
+	"fmt"
+	"reflect"
+	"sort"
+	"strings"
+
+	"gopkg.in/yaml.v3"
+)
+
+// ConfigJSONSchema generates a JSON Schema (draft-07) for the mvx config
+// format from the Config struct tags, so editors can offer completion and
+// CI can validate config files.
+func ConfigJSONSchema() (string, error) {
+	schema := schemaForType(reflect.TypeOf(Config{}))
+	schema["$schema"] = "http://json-schema.org/draft-07/schema#"
+	schema["title"] = "mvx configuration"
+
+	data, err := json.MarshalIndent(schema, "", "  ")
+	if err != nil {
+		return "", err
+	}
+	return string(data), nil
+}
+
+// schemaForType builds a JSON Schema fragment for a Go type
+func schemaForType(t reflect.Type) map[string]interface{} {
+	switch t.Kind() {
+	case reflect.Ptr:
+		return schemaForType(t.Elem())
+	case reflect.String:
+		return map[string]interface{}{"type": "string"}
+	case reflect.Bool:
+		return map[string]interface{}{"type": "boolean"}
+	case reflect.Int, reflect.Int64, reflect.Float64:
+		return map[string]interface{}{"type": "number"}
+	case reflect.Slice:
+		return map[string]interface{}{
+			"type":  "array",
+			"items": schemaForType(t.Elem()),
+		}
+	case reflect.Map:
+		return map[string]interface{}{
+			"type":                 "object",
+			"additionalProperties": schemaForType(t.Elem()),
+		}
+	case reflect.Struct:
+		properties := make(map[string]interface{})
+		for name, field := range structFields(t) {
+			properties[name] = schemaForType(field.Type)
+		}
+		return map[string]interface{}{
+			"type":                 "object",
+			"properties":           properties,
+			"additionalProperties": false,
+		}
+	default:
+		// interface{} fields (e.g. script: string or platform map)
+		return map[string]interface{}{}
+	}
+}
+
+// structFields returns the JSON-visible fields of a struct keyed by tag name
+func structFields(t reflect.Type) map[string]reflect.StructField {
+	fields := make(map[string]reflect.StructField)
+	for i := 0; i < t.NumField(); i++ {
+		field := t.Field(i)
+		tag := strings.Split(field.Tag.Get("json"), ",")[0]
+		if tag == "" || tag == "-" {
+			continue
+		}
+		fields[tag] = field
+	}
+	return fields
+}
+
+// ValidateStructure checks a raw (generically parsed) config document against
+// the Config struct: unknown keys and mistyped values are reported with their
+// dotted path. The Validate() method covers semantic checks on top of this.
+func ValidateStructure(raw map[string]interface{}) []string {
+	var issues []string
+	validateValue(reflect.TypeOf(Config{}), raw, "", &issues)
+	sort.Strings(issues)
+	return issues
+}
+
+// validateValue recursively checks a parsed value against a Go type
+func validateValue(t reflect.Type, value interface{}, path string, issues *[]string) {
+	if t.Kind() == reflect.Ptr {
+		t = t.Elem()
+	}
+	if value == nil {
+		return
+	}
+
+	switch t.Kind() {
+	case reflect.String:
+		if _, ok := value.(string); !ok {
+			*issues = append(*issues, fmt.Sprintf("%s: expected a string, got %s", path, describeValue(value)))
+		}
+	case reflect.Bool:
+		if _, ok := value.(bool); !ok {
+			*issues = append(*issues, fmt.Sprintf("%s: expected a boolean, got %s", path, describeValue(value)))
+		}
+	case reflect.Slice:
+		list, ok := value.([]interface{})
+		if !ok {
+			*issues = append(*issues, fmt.Sprintf("%s: expected an array, got %s", path, describeValue(value)))
+			return
+		}
+		for i, element := range list {
+			validateValue(t.Elem(), element, fmt.Sprintf("%s[%d]", path, i), issues)
+		}
+	case reflect.Map:
+		table, ok := toStringMap(value)
+		if !ok {
+			*issues = append(*issues, fmt.Sprintf("%s: expected an object, got %s", path, describeValue(value)))
+			return
+		}
+		for key, element := range table {
+			validateValue(t.Elem(), element, joinPath(path, key), issues)
+		}
+	case reflect.Struct:
+		table, ok := toStringMap(value)
+		if !ok {
+			*issues = append(*issues, fmt.Sprintf("%s: expected an object, got %s", path, describeValue(value)))
+			return
+		}
+		fields := structFields(t)
+		for key, element := range table {
+			field, known := fields[key]
+			if !known {
+				*issues = append(*issues, fmt.Sprintf("%s: unknown key", joinPath(path, key)))
+				continue
+			}
+			validateValue(field.Type, element, joinPath(path, key), issues)
+		}
+	default:
+		// interface{} fields accept anything
+	}
+}
+
+// toStringMap normalizes JSON and YAML map representations
+func toStringMap(value interface{}) (map[string]interface{}, bool) {
+	switch table := value.(type) {
+	case map[string]interface{}:
+		return table, true
+	case map[interface{}]interface{}:
+		normalized := make(map[string]interface{}, len(table))
+		for key, element := range table {
+			normalized[fmt.Sprintf("%v", key)] = element
+		}
+		return normalized, true
+	}
+	return nil, false
+}
+
+// describeValue names a parsed value's type for error messages
+func describeValue(value interface{}) string {
+	switch value.(type) {
+	case string:
+		return "a string"
+	case bool:
+		return "a boolean"
+	case float64, int, int64:
+		return "a number"
+	case []interface{}:
+		return "an array"
+	case map[string]interface{}, map[interface{}]interface{}:
+		return "an object"
+	}
+	return fmt.Sprintf("%T", value)
+}
+
+// joinPath appends a key to a dotted path
+func joinPath(path, key string) string {
+	if path == "" {
+		return key
+	}
+	return path + "." + key
+}
+
+// ParseRawConfig parses config bytes into a generic document for structural
+// validation, using the same format detection as parseConfigData.
+func ParseRawConfig(data []byte, ext string) (map[string]interface{}, error) {
+	switch ext {
+	case ".json5", ".json", "":
+		var raw map[string]interface{}
+		if err := ParseJSON5(data, &raw); err != nil {
+			return nil, err
+		}
+		return raw, nil
+	case ".yml", ".yaml":
+		var raw map[string]interface{}
+		if err := yaml.Unmarshal(data, &raw); err != nil {
+			return nil, err
+		}
+		return raw, nil
+	case ".toml":
+		return tomlToMap(string(data))
+	}
+	return nil, fmt.Errorf("unsupported config file format: %s", ext)
+}
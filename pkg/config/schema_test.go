@@ -0,0 +1,80 @@
+package config
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+)
+
+func TestConfigJSONSchema(t *testing.T) {
+	schema, err := ConfigJSONSchema()
+	if err != nil {
+		t.Fatalf("ConfigJSONSchema() error = %v", err)
+	}
+
+	var parsed map[string]interface{}
+	if err := json.Unmarshal([]byte(schema), &parsed); err != nil {
+		t.Fatalf("Schema is not valid JSON: %v", err)
+	}
+
+	properties, ok := parsed["properties"].(map[string]interface{})
+	if !ok {
+		t.Fatal("Schema has no properties")
+	}
+	for _, key := range []string{"project", "tools", "commands", "environment"} {
+		if _, exists := properties[key]; !exists {
+			t.Errorf("Schema is missing top-level property %s", key)
+		}
+	}
+}
+
+func TestValidateStructure(t *testing.T) {
+	raw := map[string]interface{}{
+		"project": map[string]interface{}{
+			"name":    "test",
+			"unknown": "key",
+		},
+		"tools": map[string]interface{}{
+			"java": map[string]interface{}{
+				"version": 21, // should be a string
+			},
+		},
+		"typo_section": map[string]interface{}{},
+	}
+
+	issues := ValidateStructure(raw)
+	if len(issues) != 3 {
+		t.Fatalf("Expected 3 issues, got %d: %v", len(issues), issues)
+	}
+
+	joined := strings.Join(issues, "\n")
+	for _, expected := range []string{
+		"project.unknown: unknown key",
+		"tools.java.version: expected a string",
+		"typo_section: unknown key",
+	} {
+		if !strings.Contains(joined, expected) {
+			t.Errorf("Expected issue %q in %v", expected, issues)
+		}
+	}
+}
+
+func TestValidateStructureValid(t *testing.T) {
+	raw := map[string]interface{}{
+		"project": map[string]interface{}{"name": "test"},
+		"tools": map[string]interface{}{
+			"java": map[string]interface{}{"version": "21", "distribution": "temurin"},
+		},
+		"commands": map[string]interface{}{
+			"build": map[string]interface{}{
+				"description": "Build",
+				"script":      "mvn install",
+				"requires":    []interface{}{"java"},
+			},
+		},
+	}
+
+	if issues := ValidateStructure(raw); len(issues) != 0 {
+		t.Errorf("Expected no issues, got %v", issues)
+	}
+}
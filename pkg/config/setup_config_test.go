@@ -0,0 +1,28 @@
+package config
+
+import "testing"
+
+func TestLazySetup(t *testing.T) {
+	t.Setenv("MVX_SETUP_LAZY", "")
+
+	cfg := &Config{}
+	if cfg.LazySetup() {
+		t.Error("lazy setup should be off by default")
+	}
+
+	cfg.Setup = &SetupConfig{Lazy: true}
+	if !cfg.LazySetup() {
+		t.Error("setup.lazy should enable lazy setup")
+	}
+
+	// The environment override wins in both directions
+	t.Setenv("MVX_SETUP_LAZY", "false")
+	if cfg.LazySetup() {
+		t.Error("MVX_SETUP_LAZY=false should override setup.lazy")
+	}
+	cfg.Setup = nil
+	t.Setenv("MVX_SETUP_LAZY", "true")
+	if !cfg.LazySetup() {
+		t.Error("MVX_SETUP_LAZY=true should enable lazy setup")
+	}
+}
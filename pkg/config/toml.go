@@ -0,0 +1,264 @@
+package config
+
+import (
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+// ParseTOML parses a TOML document into a Config. Only the subset of TOML
+// the mvx schema needs is supported: tables, dotted table headers, basic and
+// literal strings, integers, floats, booleans, arrays and inline tables.
+// Arrays of tables ([[...]]) are not part of the schema and are rejected.
+func ParseTOML(data []byte) (*Config, error) {
+	root, err := tomlToMap(string(data))
+	if err != nil {
+		return nil, err
+	}
+
+	// Round-trip through JSON so the existing struct tags drive the mapping
+	jsonData, err := json.Marshal(root)
+	if err != nil {
+		return nil, err
+	}
+	var config Config
+	if err := json.Unmarshal(jsonData, &config); err != nil {
+		return nil, fmt.Errorf("TOML does not match the mvx config schema: %w", err)
+	}
+	return &config, nil
+}
+
+// tomlToMap parses TOML source into a nested map
+func tomlToMap(source string) (map[string]interface{}, error) {
+	root := make(map[string]interface{})
+	current := root
+
+	lines := strings.Split(source, "\n")
+	for lineNo := 0; lineNo < len(lines); lineNo++ {
+		line := strings.TrimSpace(stripTomlComment(lines[lineNo]))
+		if line == "" {
+			continue
+		}
+
+		// Table headers: [tools.java]
+		if strings.HasPrefix(line, "[") {
+			if strings.HasPrefix(line, "[[") {
+				return nil, fmt.Errorf("line %d: arrays of tables are not supported in mvx config", lineNo+1)
+			}
+			if !strings.HasSuffix(line, "]") {
+				return nil, fmt.Errorf("line %d: malformed table header %s", lineNo+1, line)
+			}
+			path := strings.TrimSpace(line[1 : len(line)-1])
+			table, err := tomlDescend(root, path)
+			if err != nil {
+				return nil, fmt.Errorf("line %d: %w", lineNo+1, err)
+			}
+			current = table
+			continue
+		}
+
+		// Key/value pairs: key = value
+		key, rawValue, found := strings.Cut(line, "=")
+		if !found {
+			return nil, fmt.Errorf("line %d: expected key = value, got %s", lineNo+1, line)
+		}
+		key = tomlUnquoteKey(strings.TrimSpace(key))
+		rawValue = strings.TrimSpace(rawValue)
+
+		// Multiline arrays: keep consuming lines until brackets balance
+		for strings.HasPrefix(rawValue, "[") && !tomlBalanced(rawValue) && lineNo+1 < len(lines) {
+			lineNo++
+			rawValue += strings.TrimSpace(stripTomlComment(lines[lineNo]))
+		}
+
+		value, err := tomlValue(rawValue)
+		if err != nil {
+			return nil, fmt.Errorf("line %d: %w", lineNo+1, err)
+		}
+		current[key] = value
+	}
+
+	return root, nil
+}
+
+// tomlDescend walks (creating as needed) to the table named by a dotted path
+func tomlDescend(root map[string]interface{}, path string) (map[string]interface{}, error) {
+	current := root
+	for _, part := range strings.Split(path, ".") {
+		part = tomlUnquoteKey(strings.TrimSpace(part))
+		child, exists := current[part]
+		if !exists {
+			table := make(map[string]interface{})
+			current[part] = table
+			current = table
+			continue
+		}
+		table, ok := child.(map[string]interface{})
+		if !ok {
+			return nil, fmt.Errorf("key %s is already defined as a value", part)
+		}
+		current = table
+	}
+	return current, nil
+}
+
+// tomlValue parses a single TOML value
+func tomlValue(raw string) (interface{}, error) {
+	switch {
+	case raw == "true":
+		return true, nil
+	case raw == "false":
+		return false, nil
+
+	case strings.HasPrefix(raw, "\""):
+		return tomlUnquoteBasic(raw)
+	case strings.HasPrefix(raw, "'"):
+		if !strings.HasSuffix(raw, "'") || len(raw) < 2 {
+			return nil, fmt.Errorf("unterminated literal string %s", raw)
+		}
+		return raw[1 : len(raw)-1], nil
+
+	case strings.HasPrefix(raw, "["):
+		return tomlArray(raw)
+	case strings.HasPrefix(raw, "{"):
+		return tomlInlineTable(raw)
+	}
+
+	if value, err := strconv.ParseInt(raw, 10, 64); err == nil {
+		return value, nil
+	}
+	if value, err := strconv.ParseFloat(raw, 64); err == nil {
+		return value, nil
+	}
+	return nil, fmt.Errorf("cannot parse value %s", raw)
+}
+
+// tomlArray parses an array value like ["a", "b"]
+func tomlArray(raw string) (interface{}, error) {
+	if !strings.HasSuffix(raw, "]") {
+		return nil, fmt.Errorf("unterminated array %s", raw)
+	}
+	items := []interface{}{}
+	for _, element := range tomlSplitTop(raw[1 : len(raw)-1]) {
+		value, err := tomlValue(element)
+		if err != nil {
+			return nil, err
+		}
+		items = append(items, value)
+	}
+	return items, nil
+}
+
+// tomlInlineTable parses an inline table like { version = "21", os = ["linux"] }
+func tomlInlineTable(raw string) (interface{}, error) {
+	if !strings.HasSuffix(raw, "}") {
+		return nil, fmt.Errorf("unterminated inline table %s", raw)
+	}
+	table := make(map[string]interface{})
+	for _, pair := range tomlSplitTop(raw[1 : len(raw)-1]) {
+		key, rawValue, found := strings.Cut(pair, "=")
+		if !found {
+			return nil, fmt.Errorf("expected key = value in inline table, got %s", pair)
+		}
+		value, err := tomlValue(strings.TrimSpace(rawValue))
+		if err != nil {
+			return nil, err
+		}
+		table[tomlUnquoteKey(strings.TrimSpace(key))] = value
+	}
+	return table, nil
+}
+
+// tomlSplitTop splits on commas at the top nesting level, respecting quotes
+func tomlSplitTop(raw string) []string {
+	var parts []string
+	var current strings.Builder
+	depth := 0
+	inString := byte(0)
+
+	for i := 0; i < len(raw); i++ {
+		char := raw[i]
+		switch {
+		case inString != 0:
+			if char == inString && (inString == '\'' || raw[i-1] != '\\') {
+				inString = 0
+			}
+		case char == '"' || char == '\'':
+			inString = char
+		case char == '[' || char == '{':
+			depth++
+		case char == ']' || char == '}':
+			depth--
+		case char == ',' && depth == 0:
+			if part := strings.TrimSpace(current.String()); part != "" {
+				parts = append(parts, part)
+			}
+			current.Reset()
+			continue
+		}
+		current.WriteByte(char)
+	}
+	if part := strings.TrimSpace(current.String()); part != "" {
+		parts = append(parts, part)
+	}
+	return parts
+}
+
+// tomlUnquoteBasic unquotes a basic string with escape sequences
+func tomlUnquoteBasic(raw string) (string, error) {
+	value, err := strconv.Unquote(raw)
+	if err != nil {
+		return "", fmt.Errorf("malformed string %s", raw)
+	}
+	return value, nil
+}
+
+// tomlUnquoteKey strips optional quotes from a key
+func tomlUnquoteKey(key string) string {
+	if len(key) >= 2 && (key[0] == '"' || key[0] == '\'') && key[len(key)-1] == key[0] {
+		return key[1 : len(key)-1]
+	}
+	return key
+}
+
+// tomlBalanced reports whether brackets balance outside of strings
+func tomlBalanced(raw string) bool {
+	depth := 0
+	inString := byte(0)
+	for i := 0; i < len(raw); i++ {
+		char := raw[i]
+		switch {
+		case inString != 0:
+			if char == inString && (inString == '\'' || raw[i-1] != '\\') {
+				inString = 0
+			}
+		case char == '"' || char == '\'':
+			inString = char
+		case char == '[':
+			depth++
+		case char == ']':
+			depth--
+		}
+	}
+	return depth == 0
+}
+
+// stripTomlComment removes a trailing # comment, respecting strings
+func stripTomlComment(line string) string {
+	inString := byte(0)
+	for i := 0; i < len(line); i++ {
+		char := line[i]
+		switch {
+		case inString != 0:
+			if char == inString && (inString == '\'' || line[i-1] != '\\') {
+				inString = 0
+			}
+		case char == '"' || char == '\'':
+			inString = char
+		case char == '#':
+			return line[:i]
+		}
+	}
+	return line
+}
@@ -0,0 +1,83 @@
+package config
+
+import (
+	"testing"
+)
+
+func TestParseTOML(t *testing.T) {
+	data := []byte(`
+# mvx configuration
+[project]
+name = "test-project"
+description = "A test project"
+
+[tools.java]
+version = "21"
+distribution = "temurin"
+
+[tools.maven]
+version = "3.9.6"
+
+[environment]
+MAVEN_OPTS = "-Xmx2g"
+
+[commands.build]
+description = "Build the project"
+script = "mvn clean install"
+requires = ["java", "maven"]
+
+[commands.build.environment]
+BUILD_ENV = "toml"
+`)
+
+	cfg, err := ParseTOML(data)
+	if err != nil {
+		t.Fatalf("ParseTOML() error = %v", err)
+	}
+
+	if cfg.Project.Name != "test-project" {
+		t.Errorf("Expected project name test-project, got %s", cfg.Project.Name)
+	}
+	if cfg.Tools["java"].Version != "21" {
+		t.Errorf("Expected java version 21, got %s", cfg.Tools["java"].Version)
+	}
+	if cfg.Tools["java"].Distribution != "temurin" {
+		t.Errorf("Expected java distribution temurin, got %s", cfg.Tools["java"].Distribution)
+	}
+	if cfg.Environment["MAVEN_OPTS"] != "-Xmx2g" {
+		t.Errorf("Expected MAVEN_OPTS -Xmx2g, got %s", cfg.Environment["MAVEN_OPTS"])
+	}
+
+	build, exists := cfg.Commands["build"]
+	if !exists {
+		t.Fatal("Expected build command to exist")
+	}
+	if build.Script != "mvn clean install" {
+		t.Errorf("Expected build script, got %v", build.Script)
+	}
+	if len(build.Requires) != 2 || build.Requires[0] != "java" {
+		t.Errorf("Expected requires [java maven], got %v", build.Requires)
+	}
+	if build.Environment["BUILD_ENV"] != "toml" {
+		t.Errorf("Expected BUILD_ENV toml, got %s", build.Environment["BUILD_ENV"])
+	}
+}
+
+func TestParseTOMLErrors(t *testing.T) {
+	tests := []struct {
+		name string
+		data string
+	}{
+		{"array of tables", "[[tools]]\nversion = \"1\""},
+		{"missing equals", "[project]\nname"},
+		{"unterminated string", "[project]\nname = \"oops"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := ParseTOML([]byte(tt.data)); err == nil {
+				t.Errorf("Expected error for %s, got nil", tt.name)
+			}
+		})
+	}
+}
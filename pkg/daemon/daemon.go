@@ -0,0 +1,213 @@
+// Package daemon implements the optional mvx background daemon. The daemon
+// keeps the resolved configuration and computed environment for one project
+// in memory behind a unix socket, so hot-path invocations like
+// 'mvx mvn -q ...' skip config parsing and version resolution entirely.
+// It is opt-in (MVX_DAEMON=true), started lazily by the first invocation
+// that misses it, and exits on its own after an idle timeout.
+package daemon
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sync"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// DefaultIdleTimeout is how long the daemon waits without requests before
+// exiting (override with MVX_DAEMON_IDLE_TIMEOUT, a Go duration)
+const DefaultIdleTimeout = 10 * time.Minute
+
+// Enabled reports whether daemon mode is opted into
+func Enabled() bool {
+	value := os.Getenv("MVX_DAEMON")
+	return value == "1" || value == "true"
+}
+
+// IdleTimeout returns the configured idle timeout
+func IdleTimeout() time.Duration {
+	if value := os.Getenv("MVX_DAEMON_IDLE_TIMEOUT"); value != "" {
+		if d, err := time.ParseDuration(value); err == nil && d > 0 {
+			return d
+		}
+	}
+	return DefaultIdleTimeout
+}
+
+// SocketPath returns the per-project socket, under the mvx home so multiple
+// checkouts get independent daemons
+func SocketPath(projectRoot string) (string, error) {
+	home, err := util.MvxHome()
+	if err != nil {
+		return "", err
+	}
+	sum := sha256.Sum256([]byte(projectRoot))
+	return filepath.Join(home, "daemon", hex.EncodeToString(sum[:8])+".sock"), nil
+}
+
+// request is one client message; the protocol is one JSON request and one
+// JSON response per connection
+type request struct {
+	Op string `json:"op"` // "env", "ping" or "stop"
+}
+
+// response is the daemon's answer
+type response struct {
+	OK    bool              `json:"ok"`
+	Error string            `json:"error,omitempty"`
+	Env   map[string]string `json:"env,omitempty"`
+	Pid   int               `json:"pid,omitempty"`
+}
+
+// Serve runs the daemon loop in the foreground until it is stopped or the
+// idle timeout expires. compute produces the project environment;
+// fingerprint identifies the config state, and a change invalidates the
+// cached environment.
+func Serve(projectRoot string, idleTimeout time.Duration, compute func() (map[string]string, error), fingerprint func() string) error {
+	socket, err := SocketPath(projectRoot)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(socket), 0700); err != nil {
+		return err
+	}
+	// A leftover socket from a dead daemon would block the listener
+	_ = os.Remove(socket)
+
+	listener, err := net.Listen("unix", socket)
+	if err != nil {
+		return fmt.Errorf("failed to listen on %s: %w", socket, err)
+	}
+	defer func() {
+		listener.Close()
+		os.Remove(socket)
+	}()
+
+	// The idle timer closes the listener, which ends the accept loop
+	var mu sync.Mutex
+	idle := time.AfterFunc(idleTimeout, func() {
+		util.LogVerbose("mvx daemon idle for %s, exiting", idleTimeout)
+		listener.Close()
+	})
+	defer idle.Stop()
+
+	var cachedEnv map[string]string
+	cachedFingerprint := ""
+
+	util.LogVerbose("mvx daemon serving %s on %s (pid %d)", projectRoot, socket, os.Getpid())
+	for {
+		conn, err := listener.Accept()
+		if err != nil {
+			// Listener closed by the idle timer or a stop request
+			return nil
+		}
+		mu.Lock()
+		idle.Reset(idleTimeout)
+		mu.Unlock()
+
+		var req request
+		resp := response{OK: true, Pid: os.Getpid()}
+		if err := json.NewDecoder(conn).Decode(&req); err != nil {
+			resp = response{Error: fmt.Sprintf("bad request: %v", err)}
+		} else {
+			switch req.Op {
+			case "env":
+				if fp := fingerprint(); fp != cachedFingerprint || cachedEnv == nil {
+					env, err := compute()
+					if err != nil {
+						resp = response{Error: err.Error()}
+						break
+					}
+					cachedEnv = env
+					cachedFingerprint = fp
+					util.LogVerbose("mvx daemon recomputed environment (fingerprint %s)", fp)
+				}
+				resp.Env = cachedEnv
+			case "ping":
+				// Pid already set
+			case "stop":
+				json.NewEncoder(conn).Encode(resp)
+				conn.Close()
+				return nil
+			default:
+				resp = response{Error: fmt.Sprintf("unknown op %q", req.Op)}
+			}
+		}
+		json.NewEncoder(conn).Encode(resp)
+		conn.Close()
+	}
+}
+
+// roundTrip sends one request to the project's daemon
+func roundTrip(projectRoot string, req request) (*response, error) {
+	socket, err := SocketPath(projectRoot)
+	if err != nil {
+		return nil, err
+	}
+	conn, err := net.DialTimeout("unix", socket, 500*time.Millisecond)
+	if err != nil {
+		return nil, err
+	}
+	defer conn.Close()
+	conn.SetDeadline(time.Now().Add(5 * time.Second))
+
+	if err := json.NewEncoder(conn).Encode(req); err != nil {
+		return nil, err
+	}
+	var resp response
+	if err := json.NewDecoder(conn).Decode(&resp); err != nil {
+		return nil, err
+	}
+	if resp.Error != "" {
+		return nil, fmt.Errorf("daemon: %s", resp.Error)
+	}
+	return &resp, nil
+}
+
+// RequestEnv fetches the cached project environment from the daemon
+func RequestEnv(projectRoot string) (map[string]string, error) {
+	resp, err := roundTrip(projectRoot, request{Op: "env"})
+	if err != nil {
+		return nil, err
+	}
+	return resp.Env, nil
+}
+
+// Ping returns the daemon's pid, or an error when none is running
+func Ping(projectRoot string) (int, error) {
+	resp, err := roundTrip(projectRoot, request{Op: "ping"})
+	if err != nil {
+		return 0, err
+	}
+	return resp.Pid, nil
+}
+
+// Stop asks the project's daemon to exit
+func Stop(projectRoot string) error {
+	_, err := roundTrip(projectRoot, request{Op: "stop"})
+	return err
+}
+
+// Start launches 'mvx daemon serve' detached, so the next invocation finds
+// a warm daemon. Best effort: failures only cost the caller the fast path.
+func Start(projectRoot string) error {
+	exe, err := os.Executable()
+	if err != nil {
+		return err
+	}
+	cmd := exec.Command(exe, "daemon", "serve")
+	cmd.Dir = projectRoot
+	cmd.Stdout = nil
+	cmd.Stderr = nil
+	if err := cmd.Start(); err != nil {
+		return err
+	}
+	return cmd.Process.Release()
+}
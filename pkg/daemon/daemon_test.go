@@ -0,0 +1,87 @@
+package daemon
+
+import (
+	"runtime"
+	"testing"
+	"time"
+)
+
+func TestDaemonRoundTrip(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("unix socket test")
+	}
+	t.Setenv("MVX_HOME", t.TempDir())
+	projectRoot := t.TempDir()
+
+	computations := 0
+	fingerprint := "v1"
+	done := make(chan error, 1)
+	go func() {
+		done <- Serve(projectRoot, time.Minute, func() (map[string]string, error) {
+			computations++
+			return map[string]string{"JAVA_HOME": "/tmp/java"}, nil
+		}, func() string { return fingerprint })
+	}()
+
+	// Wait for the socket to come up
+	var pid int
+	var err error
+	for i := 0; i < 50; i++ {
+		if pid, err = Ping(projectRoot); err == nil {
+			break
+		}
+		time.Sleep(20 * time.Millisecond)
+	}
+	if err != nil {
+		t.Fatalf("daemon never came up: %v", err)
+	}
+	if pid == 0 {
+		t.Error("expected a pid from ping")
+	}
+
+	// Two requests with the same fingerprint compute once
+	for i := 0; i < 2; i++ {
+		env, err := RequestEnv(projectRoot)
+		if err != nil {
+			t.Fatalf("RequestEnv failed: %v", err)
+		}
+		if env["JAVA_HOME"] != "/tmp/java" {
+			t.Errorf("unexpected env: %v", env)
+		}
+	}
+	if computations != 1 {
+		t.Errorf("expected 1 computation for an unchanged fingerprint, got %d", computations)
+	}
+
+	// A fingerprint change invalidates the cache
+	fingerprint = "v2"
+	if _, err := RequestEnv(projectRoot); err != nil {
+		t.Fatalf("RequestEnv failed: %v", err)
+	}
+	if computations != 2 {
+		t.Errorf("expected recomputation after fingerprint change, got %d", computations)
+	}
+
+	if err := Stop(projectRoot); err != nil {
+		t.Fatalf("Stop failed: %v", err)
+	}
+	select {
+	case err := <-done:
+		if err != nil {
+			t.Errorf("Serve returned error: %v", err)
+		}
+	case <-time.After(2 * time.Second):
+		t.Error("daemon did not exit after stop")
+	}
+}
+
+func TestEnabled(t *testing.T) {
+	t.Setenv("MVX_DAEMON", "")
+	if Enabled() {
+		t.Error("daemon mode should be off by default")
+	}
+	t.Setenv("MVX_DAEMON", "true")
+	if !Enabled() {
+		t.Error("MVX_DAEMON=true should enable daemon mode")
+	}
+}
@@ -0,0 +1,95 @@
+package executor
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// Named command arguments: commands can declare parameters (see
+// CommandArgConfig) that callers pass as flags, e.g.
+// `mvx run build --module api --skipTests`. Declared arguments are collected
+// into ${args.<name>} placeholder values; anything undeclared stays in the
+// positional passthrough appended to the script.
+
+// argPlaceholderPattern matches ${args.<name>} placeholders in scripts
+var argPlaceholderPattern = regexp.MustCompile(`\$\{args\.([a-zA-Z][a-zA-Z0-9_-]*)\}`)
+
+// parseCommandArgs splits raw arguments into declared named argument values
+// and positional passthrough, applying defaults and validating required and
+// bool arguments
+func parseCommandArgs(declared []config.CommandArgConfig, raw []string) (map[string]string, []string, error) {
+	byName := make(map[string]config.CommandArgConfig, len(declared))
+	for _, arg := range declared {
+		byName[arg.Name] = arg
+	}
+
+	values := make(map[string]string)
+	var positional []string
+
+	for i := 0; i < len(raw); i++ {
+		token := raw[i]
+
+		// Everything after -- is positional passthrough by convention
+		if token == "--" {
+			positional = append(positional, raw[i+1:]...)
+			break
+		}
+
+		name, inline, hasInline := strings.Cut(strings.TrimPrefix(token, "--"), "=")
+		arg, isDeclared := byName[name]
+		if !strings.HasPrefix(token, "--") || !isDeclared {
+			positional = append(positional, token)
+			continue
+		}
+
+		switch {
+		case hasInline:
+			values[name] = inline
+		case arg.Type == "bool":
+			values[name] = "true"
+		case i+1 < len(raw):
+			i++
+			values[name] = raw[i]
+		default:
+			return nil, nil, fmt.Errorf("argument --%s requires a value", name)
+		}
+
+		if arg.Type == "bool" && values[name] != "true" && values[name] != "false" {
+			return nil, nil, fmt.Errorf("argument --%s must be true or false, got %q", name, values[name])
+		}
+	}
+
+	// Apply defaults and enforce required arguments
+	for _, arg := range declared {
+		if _, set := values[arg.Name]; set {
+			continue
+		}
+		switch {
+		case arg.Default != "":
+			values[arg.Name] = arg.Default
+		case arg.Type == "bool":
+			values[arg.Name] = "false"
+		case arg.Required:
+			return nil, nil, fmt.Errorf("missing required argument --%s", arg.Name)
+		default:
+			values[arg.Name] = ""
+		}
+	}
+
+	return values, positional, nil
+}
+
+// expandArgPlaceholders substitutes ${args.<name>} placeholders with parsed
+// argument values, leaving unknown names untouched
+func expandArgPlaceholders(script string, values map[string]string) string {
+	return argPlaceholderPattern.ReplaceAllStringFunc(script, func(match string) string {
+		name := argPlaceholderPattern.FindStringSubmatch(match)[1]
+		if value, ok := values[name]; ok {
+			return value
+		}
+		return match
+	})
+}
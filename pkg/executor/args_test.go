@@ -0,0 +1,70 @@
+package executor
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestParseCommandArgs(t *testing.T) {
+	declared := []config.CommandArgConfig{
+		{Name: "module", Default: "core"},
+		{Name: "skipTests", Type: "bool"},
+		{Name: "target", Required: true},
+	}
+
+	// Named values, bool flag, and positional passthrough
+	values, positional, err := parseCommandArgs(declared, []string{"--module", "api", "--skipTests", "--target=dist", "extra"})
+	if err != nil {
+		t.Fatalf("parseCommandArgs() error = %v", err)
+	}
+	if values["module"] != "api" || values["skipTests"] != "true" || values["target"] != "dist" {
+		t.Errorf("parsed values = %v", values)
+	}
+	if len(positional) != 1 || positional[0] != "extra" {
+		t.Errorf("positional = %v, want [extra]", positional)
+	}
+
+	// Defaults apply when flags are omitted
+	values, _, err = parseCommandArgs(declared, []string{"--target", "dist"})
+	if err != nil {
+		t.Fatalf("parseCommandArgs() error = %v", err)
+	}
+	if values["module"] != "core" || values["skipTests"] != "false" {
+		t.Errorf("defaulted values = %v", values)
+	}
+
+	// Missing required argument is an error
+	if _, _, err := parseCommandArgs(declared, nil); err == nil || !strings.Contains(err.Error(), "--target") {
+		t.Errorf("expected missing required argument error, got %v", err)
+	}
+
+	// Undeclared flags pass through untouched
+	_, positional, err = parseCommandArgs(declared, []string{"--target", "dist", "--verbose"})
+	if err != nil {
+		t.Fatalf("parseCommandArgs() error = %v", err)
+	}
+	if len(positional) != 1 || positional[0] != "--verbose" {
+		t.Errorf("positional = %v, want [--verbose]", positional)
+	}
+
+	// Everything after -- is positional even when it matches a declared name
+	_, positional, err = parseCommandArgs(declared, []string{"--target", "dist", "--", "--module", "x"})
+	if err != nil {
+		t.Fatalf("parseCommandArgs() error = %v", err)
+	}
+	if strings.Join(positional, " ") != "--module x" {
+		t.Errorf("positional = %v, want [--module x]", positional)
+	}
+}
+
+func TestExpandArgPlaceholders(t *testing.T) {
+	values := map[string]string{"module": "api", "skipTests": "true"}
+
+	script := expandArgPlaceholders("mvn -pl ${args.module} -DskipTests=${args.skipTests} ${args.unknown}", values)
+	want := "mvn -pl api -DskipTests=true ${args.unknown}"
+	if script != want {
+		t.Errorf("expandArgPlaceholders() = %q, want %q", script, want)
+	}
+}
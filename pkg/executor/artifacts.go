@@ -0,0 +1,114 @@
+package executor
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// expandArtifactGlobs resolves artifact glob patterns relative to the working
+// directory and returns the matching paths. Patterns without wildcards are
+// treated as plain paths so "target/app.jar" works whether or not it exists.
+func expandArtifactGlobs(workDir string, patterns []string) ([]string, error) {
+	var paths []string
+	for _, pattern := range patterns {
+		matches, err := filepath.Glob(filepath.Join(workDir, pattern))
+		if err != nil {
+			return nil, fmt.Errorf("invalid artifact pattern %s: %w", pattern, err)
+		}
+		paths = append(paths, matches...)
+	}
+	return paths, nil
+}
+
+// validateInputs checks that every declared input artifact exists before the
+// command runs, suggesting the producing command when one is known.
+func (e *Executor) validateInputs(commandName, workDir string, cmdConfig config.CommandConfig) error {
+	for _, pattern := range cmdConfig.Inputs {
+		matches, err := filepath.Glob(filepath.Join(workDir, pattern))
+		if err != nil {
+			return fmt.Errorf("invalid input pattern %s: %w", pattern, err)
+		}
+		if len(matches) == 0 {
+			if producer := e.findProducer(pattern); producer != "" {
+				return fmt.Errorf("command %s requires input %s which does not exist — run 'mvx %s' first", commandName, pattern, producer)
+			}
+			return fmt.Errorf("command %s requires input %s which does not exist", commandName, pattern)
+		}
+	}
+	return nil
+}
+
+// validateOutputs checks that every declared output artifact exists after the
+// command ran, catching scripts that silently stopped producing what other
+// commands consume.
+func validateOutputs(commandName, workDir string, cmdConfig config.CommandConfig) error {
+	for _, pattern := range cmdConfig.Outputs {
+		matches, err := filepath.Glob(filepath.Join(workDir, pattern))
+		if err != nil {
+			return fmt.Errorf("invalid output pattern %s: %w", pattern, err)
+		}
+		if len(matches) == 0 {
+			return fmt.Errorf("command %s declared output %s but nothing matched after execution", commandName, pattern)
+		}
+	}
+	return nil
+}
+
+// findProducer returns the name of a command whose outputs include the given
+// pattern, or "" when no producer is declared.
+func (e *Executor) findProducer(pattern string) string {
+	for name, cmd := range e.config.Commands {
+		for _, output := range cmd.Outputs {
+			if output == pattern {
+				return name
+			}
+		}
+	}
+	return ""
+}
+
+// isUpToDate reports whether a command with declared inputs and outputs can be
+// skipped because every output is newer than the newest input.
+func isUpToDate(workDir string, cmdConfig config.CommandConfig) bool {
+	if len(cmdConfig.Inputs) == 0 || len(cmdConfig.Outputs) == 0 {
+		return false
+	}
+
+	inputs, err := expandArtifactGlobs(workDir, cmdConfig.Inputs)
+	if err != nil || len(inputs) == 0 {
+		return false
+	}
+	outputs, err := expandArtifactGlobs(workDir, cmdConfig.Outputs)
+	if err != nil || len(outputs) == 0 {
+		return false
+	}
+
+	var newestInput time.Time
+	for _, path := range inputs {
+		info, err := os.Stat(path)
+		if err != nil {
+			return false
+		}
+		if info.ModTime().After(newestInput) {
+			newestInput = info.ModTime()
+		}
+	}
+
+	for _, path := range outputs {
+		info, err := os.Stat(path)
+		if err != nil {
+			return false
+		}
+		if info.ModTime().Before(newestInput) {
+			util.LogVerbose("Output %s is older than inputs, rerunning", path)
+			return false
+		}
+	}
+
+	return true
+}
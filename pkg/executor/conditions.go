@@ -0,0 +1,125 @@
+package executor
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"runtime"
+	"strings"
+)
+
+// Step conditions: a step object's if field is evaluated by mvx before the
+// step runs, so one command definition can cover platforms and local/CI
+// contexts. Supported forms:
+//
+//	os == 'windows'   / os != 'linux'    current operating system
+//	arch == 'arm64'   / arch != 'amd64'  current architecture
+//	env.CI            / !env.CI          env var set to a non-empty value
+//	                                     other than "false" or "0"
+//	exists('pom.xml') / !exists('...')   path exists (relative paths resolve
+//	                                     against the step's working directory)
+//
+// Conditions combine with && and ||; an optional ${...} wrapper is stripped.
+
+// existsPattern matches the exists('path') condition atom
+var existsPattern = regexp.MustCompile(`^exists\((['"])(.+)['"]\)$`)
+
+// evaluateCondition evaluates a step condition expression
+func (e *Executor) evaluateCondition(expr, workDir string) (bool, error) {
+	expr = strings.TrimSpace(expr)
+	if strings.HasPrefix(expr, "${") && strings.HasSuffix(expr, "}") {
+		expr = strings.TrimSpace(expr[2 : len(expr)-1])
+	}
+
+	// || binds loosest, then &&
+	if parts := strings.Split(expr, "||"); len(parts) > 1 {
+		for _, part := range parts {
+			ok, err := e.evaluateCondition(part, workDir)
+			if err != nil {
+				return false, err
+			}
+			if ok {
+				return true, nil
+			}
+		}
+		return false, nil
+	}
+	if parts := strings.Split(expr, "&&"); len(parts) > 1 {
+		for _, part := range parts {
+			ok, err := e.evaluateCondition(part, workDir)
+			if err != nil {
+				return false, err
+			}
+			if !ok {
+				return false, nil
+			}
+		}
+		return true, nil
+	}
+
+	negate := false
+	for strings.HasPrefix(expr, "!") {
+		negate = !negate
+		expr = strings.TrimSpace(expr[1:])
+	}
+
+	result, err := e.evaluateConditionAtom(expr, workDir)
+	if err != nil {
+		return false, err
+	}
+	return result != negate, nil
+}
+
+// evaluateConditionAtom evaluates a single condition without operators
+func (e *Executor) evaluateConditionAtom(expr, workDir string) (bool, error) {
+	if match := existsPattern.FindStringSubmatch(expr); match != nil {
+		path := match[2]
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(workDir, path)
+		}
+		_, err := os.Stat(path)
+		return err == nil, nil
+	}
+
+	for _, op := range []string{"==", "!="} {
+		if left, right, found := strings.Cut(expr, op); found {
+			equal := conditionTerm(left) == conditionTerm(right)
+			return equal == (op == "=="), nil
+		}
+	}
+
+	if name, found := strings.CutPrefix(expr, "env."); found {
+		value := strings.ToLower(os.Getenv(name))
+		return value != "" && value != "false" && value != "0", nil
+	}
+
+	switch expr {
+	case "true":
+		return true, nil
+	case "false":
+		return false, nil
+	}
+
+	return false, fmt.Errorf("unsupported condition %q (use os/arch comparisons, env.VAR or exists('path'))", expr)
+}
+
+// conditionTerm resolves one side of a comparison: the os and arch variables,
+// env.VAR references, quoted literals, or the bare text itself
+func conditionTerm(term string) string {
+	term = strings.TrimSpace(term)
+	switch {
+	case term == "os":
+		return runtime.GOOS
+	case term == "arch":
+		return runtime.GOARCH
+	case strings.HasPrefix(term, "env."):
+		return os.Getenv(strings.TrimPrefix(term, "env."))
+	}
+	if len(term) >= 2 {
+		if (term[0] == '\'' && term[len(term)-1] == '\'') || (term[0] == '"' && term[len(term)-1] == '"') {
+			return term[1 : len(term)-1]
+		}
+	}
+	return term
+}
@@ -0,0 +1,55 @@
+package executor
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+)
+
+func TestEvaluateCondition(t *testing.T) {
+	e := &Executor{}
+	tempDir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(tempDir, "pom.xml"), []byte("<project/>"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	t.Setenv("MVX_COND_SET", "1")
+	t.Setenv("MVX_COND_OFF", "false")
+
+	tests := []struct {
+		expr string
+		want bool
+	}{
+		{fmt.Sprintf("os == '%s'", runtime.GOOS), true},
+		{"os == 'plan9'", false},
+		{"os != 'plan9'", true},
+		{fmt.Sprintf("arch == '%s'", runtime.GOARCH), true},
+		{"env.MVX_COND_SET", true},
+		{"env.MVX_COND_OFF", false},
+		{"!env.MVX_COND_OFF", true},
+		{"env.MVX_COND_UNSET", false},
+		{"exists('pom.xml')", true},
+		{"!exists('missing.txt')", true},
+		{"${env.MVX_COND_SET}", true},
+		{"env.MVX_COND_SET && exists('pom.xml')", true},
+		{"env.MVX_COND_SET && os == 'plan9'", false},
+		{"os == 'plan9' || env.MVX_COND_SET", true},
+	}
+	for _, tt := range tests {
+		got, err := e.evaluateCondition(tt.expr, tempDir)
+		if err != nil {
+			t.Errorf("evaluateCondition(%q) error = %v", tt.expr, err)
+			continue
+		}
+		if got != tt.want {
+			t.Errorf("evaluateCondition(%q) = %v, want %v", tt.expr, got, tt.want)
+		}
+	}
+
+	// Unsupported expressions fail loudly instead of silently skipping steps
+	if _, err := e.evaluateCondition("frobnicate", tempDir); err == nil {
+		t.Error("expected error for unsupported condition")
+	}
+}
@@ -1,14 +1,18 @@
 package executor
 
 import (
+	"bytes"
 	"fmt"
+	"io"
 	"os"
 	"os/exec"
 	"path/filepath"
 	"runtime"
 	"strings"
+	"time"
 
 	"github.com/gnodet/mvx/pkg/config"
+	mvxscript "github.com/gnodet/mvx/pkg/script"
 	"github.com/gnodet/mvx/pkg/shell"
 	"github.com/gnodet/mvx/pkg/tools"
 	"github.com/gnodet/mvx/pkg/util"
@@ -19,6 +23,24 @@ type Executor struct {
 	config      *config.Config
 	toolManager *tools.Manager
 	projectRoot string
+	output      io.Writer // command output destination (defaults to os.Stdout; see withOutput)
+}
+
+// stdout returns the command output destination
+func (e *Executor) stdout() io.Writer {
+	if e.output != nil {
+		return e.output
+	}
+	return os.Stdout
+}
+
+// stderr returns the error output destination, following the configured
+// writer so parallel output stays attributable
+func (e *Executor) stderr() io.Writer {
+	if e.output != nil {
+		return e.output
+	}
+	return os.Stderr
 }
 
 // NewExecutor creates a new command executor
@@ -30,10 +52,72 @@ func NewExecutor(cfg *config.Config, toolManager *tools.Manager, projectRoot str
 	}
 }
 
-// ExecuteCommand executes a configured command with arguments
+// ExecuteCommand executes a configured command with arguments, running its
+// dependsOn chain first, wrapped in the project's lifecycle hooks
 func (e *Executor) ExecuteCommand(commandName string, args []string) error {
+	if err := e.RunLifecycleHook("preCommand", "MVX_COMMAND="+commandName); err != nil {
+		return fmt.Errorf("preCommand hook failed: %w", err)
+	}
+
+	if err := e.executeCommandWithDeps(commandName, args, make(map[string]bool), nil); err != nil {
+		if hookErr := e.RunLifecycleHook("onFailure", "MVX_COMMAND="+commandName, "MVX_ERROR="+err.Error()); hookErr != nil {
+			util.LogVerbose("onFailure hook failed: %v", hookErr)
+		}
+		return err
+	}
+
+	if err := e.RunLifecycleHook("postCommand", "MVX_COMMAND="+commandName); err != nil {
+		return fmt.Errorf("postCommand hook failed: %w", err)
+	}
+	return nil
+}
+
+// executeCommandWithDeps runs a command after its prerequisites, executing
+// each command at most once per invocation and rejecting dependency cycles
+func (e *Executor) executeCommandWithDeps(commandName string, args []string, done map[string]bool, stack []string) error {
+	if done[commandName] {
+		return nil
+	}
+	for _, ancestor := range stack {
+		if ancestor == commandName {
+			return fmt.Errorf("dependency cycle between commands: %s", strings.Join(append(stack, commandName), " -> "))
+		}
+	}
+
+	cmdConfig, exists := e.lookupCommand(commandName)
+	if !exists {
+		return fmt.Errorf("unknown command: %s", commandName)
+	}
+
+	// Prerequisites run first, with no extra arguments
+	for _, dep := range cmdConfig.DependsOn {
+		if err := e.executeCommandWithDeps(dep, nil, done, append(stack, commandName)); err != nil {
+			return fmt.Errorf("dependency %s of %s failed: %w", dep, commandName, err)
+		}
+	}
+	done[commandName] = true
+
+	return e.executeSingleCommand(commandName, args)
+}
+
+// lookupCommand resolves a command name, also accepting group-qualified
+// "group:name" spellings for commands that declare a group
+func (e *Executor) lookupCommand(commandName string) (config.CommandConfig, bool) {
+	if cmdConfig, exists := e.config.Commands[commandName]; exists {
+		return cmdConfig, true
+	}
+	if group, short, qualified := strings.Cut(commandName, ":"); qualified {
+		if cmdConfig, exists := e.config.Commands[short]; exists && cmdConfig.Group == group {
+			return cmdConfig, true
+		}
+	}
+	return config.CommandConfig{}, false
+}
+
+// executeSingleCommand executes one configured command with arguments
+func (e *Executor) executeSingleCommand(commandName string, args []string) error {
 	// Get command configuration
-	cmdConfig, exists := e.config.Commands[commandName]
+	cmdConfig, exists := e.lookupCommand(commandName)
 	if !exists {
 		return fmt.Errorf("unknown command: %s", commandName)
 	}
@@ -44,28 +128,102 @@ func (e *Executor) ExecuteCommand(commandName string, args []string) error {
 		return fmt.Errorf("failed to setup environment: %w", err)
 	}
 
-	// Determine working directory
+	// Determine working directory (relative paths are resolved against the
+	// project root, so scripts never need a leading cd)
 	workDir := e.projectRoot
 	if cmdConfig.WorkingDir != "" {
-		workDir = filepath.Join(e.projectRoot, cmdConfig.WorkingDir)
+		dir := e.interpolate(cmdConfig.WorkingDir)
+		if filepath.IsAbs(dir) {
+			workDir = dir
+		} else {
+			workDir = filepath.Join(e.projectRoot, dir)
+		}
 	}
 
-	// Process script and resolve interpreter (handle platform-specific scripts)
-	script, interpreter, err := config.ResolvePlatformScriptWithInterpreter(cmdConfig.Script, cmdConfig.Interpreter)
+	// Split declared named arguments from the positional passthrough; prompts
+	// parse like arguments so their values can be passed as flags
+	declaredArgs := append(append([]config.CommandArgConfig{}, cmdConfig.Args...), promptArgConfigs(cmdConfig.Prompts)...)
+	argValues, positional, err := parseCommandArgs(declaredArgs, args)
 	if err != nil {
-		return fmt.Errorf("failed to resolve script: %w", err)
+		return fmt.Errorf("invalid arguments for %s: %w", commandName, err)
 	}
 
-	// Process script arguments
-	processedScript := e.processScriptString(script, args)
+	// Resolve prompt values (flags, then MVX_INPUT_* env vars, then a TTY)
+	if err := e.resolvePrompts(cmdConfig.Prompts, argValues); err != nil {
+		return err
+	}
+
+	// Check declared input artifacts and skip when outputs are already current
+	if err := e.validateInputs(commandName, workDir, cmdConfig); err != nil {
+		return err
+	}
+	if isUpToDate(workDir, cmdConfig) {
+		if !util.IsQuiet() {
+			fmt.Fprintf(e.stdout(), "✅ Command %s is up to date, skipping (outputs newer than inputs)\n", commandName)
+		}
+		return nil
+	}
 
 	// Execute command
-	fmt.Printf("🔨 Running command: %s\n", commandName)
-	if cmdConfig.Description != "" {
-		fmt.Printf("   %s\n", cmdConfig.Description)
+	if !util.IsQuiet() {
+		fmt.Fprintf(e.stdout(), "🔨 Running command: %s\n", commandName)
+		if cmdConfig.Description != "" {
+			fmt.Fprintf(e.stdout(), "   %s\n", cmdConfig.Description)
+		}
 	}
 
-	return e.executeScriptWithInterpreter(processedScript, workDir, env, interpreter)
+	// Run the pre hook before the main script
+	if err := e.runHookScript("pre", cmdConfig.Pre, positional, argValues, workDir, env, cmdConfig); err != nil {
+		return fmt.Errorf("pre hook of %s failed: %w", commandName, err)
+	}
+
+	// Script arrays are step groups, run sequentially or (with parallel:
+	// true) concurrently; single scripts get the positional passthrough
+	if steps, isGroup := scriptStepList(cmdConfig.Script); isGroup {
+		if err := e.executeStepGroup(steps, argValues, workDir, env, cmdConfig); err != nil {
+			return err
+		}
+	} else {
+		// Process script and resolve interpreter (handle platform-specific scripts)
+		script, interpreter, err := config.ResolvePlatformScriptWithInterpreter(cmdConfig.Script, cmdConfig.Interpreter)
+		if err != nil {
+			return fmt.Errorf("failed to resolve script: %w", err)
+		}
+
+		// Process script arguments and expand ${...} config placeholders
+		processedScript := e.interpolate(expandArgPlaceholders(e.processScriptString(script, positional), argValues))
+
+		if err := e.executeScriptWithInterpreter(processedScript, workDir, env, interpreter, cmdConfig); err != nil {
+			return err
+		}
+	}
+
+	// The post hook only runs after the main script succeeded
+	if err := e.runHookScript("post", cmdConfig.Post, positional, argValues, workDir, env, cmdConfig); err != nil {
+		return fmt.Errorf("post hook of %s failed: %w", commandName, err)
+	}
+
+	// Verify declared output artifacts were actually produced
+	return validateOutputs(commandName, workDir, cmdConfig)
+}
+
+// runHookScript executes an optional pre/post hook script with the command's
+// environment, working directory and interpreter
+func (e *Executor) runHookScript(phase string, hook interface{}, args []string, argValues map[string]string, workDir string, env []string, cmdConfig config.CommandConfig) error {
+	if hook == nil {
+		return nil
+	}
+
+	script, interpreter, err := config.ResolvePlatformScriptWithInterpreter(hook, cmdConfig.Interpreter)
+	if err != nil {
+		return fmt.Errorf("failed to resolve %s script: %w", phase, err)
+	}
+	if script == "" {
+		return nil
+	}
+
+	processed := e.interpolate(expandArgPlaceholders(e.processScriptString(script, args), argValues))
+	return e.executeScriptWithInterpreter(processed, workDir, env, interpreter, cmdConfig)
 }
 
 // ExecuteTool executes a tool command with mvx-managed environment
@@ -116,7 +274,7 @@ func (e *Executor) ListCommands() map[string]string {
 
 // GetCommandInfo returns detailed information about a command
 func (e *Executor) GetCommandInfo(commandName string) (*config.CommandConfig, error) {
-	cmdConfig, exists := e.config.Commands[commandName]
+	cmdConfig, exists := e.lookupCommand(commandName)
 	if !exists {
 		return nil, fmt.Errorf("unknown command: %s", commandName)
 	}
@@ -125,9 +283,17 @@ func (e *Executor) GetCommandInfo(commandName string) (*config.CommandConfig, er
 
 // setupEnvironment prepares the environment for command execution
 func (e *Executor) setupEnvironment(cmdConfig config.CommandConfig) ([]string, error) {
-	// Create environment manager starting with current environment
+	// Hermetic commands start from a minimal base instead of the inherited
+	// environment, so system toolchains cannot leak into the build
+	hermetic := cmdConfig.Hermetic || util.IsHermetic()
+	baseEnviron := os.Environ()
+	if hermetic {
+		baseEnviron = tools.HermeticBaseEnvironment(cmdConfig.HermeticEnv)
+	}
+
+	// Create environment manager starting with the base environment
 	envManager := tools.NewEnvironmentManager()
-	for _, envVar := range os.Environ() {
+	for _, envVar := range baseEnviron {
 		parts := strings.SplitN(envVar, "=", 2)
 		if len(parts) == 2 {
 			if parts[0] == "PATH" {
@@ -143,8 +309,27 @@ func (e *Executor) setupEnvironment(cmdConfig config.CommandConfig) ([]string, e
 		}
 	}
 
-	// Add global environment variables from config (includes tool paths and environment)
-	globalEnv, err := e.toolManager.SetupEnvironment(e.config)
+	// Add global environment variables from config (includes tool paths and
+	// environment). Commands that declare requires only see those tools, so a
+	// docs-only command neither installs nor accidentally depends on the rest.
+	envConfig := e.config
+	if cmdConfig.Requires != nil {
+		limited := *e.config
+		limited.Tools = make(map[string]config.ToolConfig, len(cmdConfig.Requires))
+		for _, toolName := range cmdConfig.Requires {
+			if toolConfig, exists := e.config.Tools[toolName]; exists {
+				limited.Tools[toolName] = toolConfig
+			}
+		}
+		envConfig = &limited
+	}
+	var globalEnv map[string]string
+	var err error
+	if hermetic {
+		globalEnv, err = e.toolManager.SetupHermeticEnvironment(envConfig, cmdConfig.HermeticEnv)
+	} else {
+		globalEnv, err = e.toolManager.SetupEnvironment(envConfig)
+	}
 	if err != nil {
 		return nil, err
 	}
@@ -162,19 +347,31 @@ func (e *Executor) setupEnvironment(cmdConfig config.CommandConfig) ([]string, e
 				}
 			}
 		} else {
-			envManager.SetEnv(key, value)
+			envManager.SetEnv(key, e.interpolate(value))
 		}
 	}
 
-	// Add command-specific environment variables (these override global ones)
+	// Add command-specific environment variables (these override global ones).
+	// Values may reference ${env.VAR}, ${project.dir} and ${tools.<name>.home}.
 	for key, value := range cmdConfig.Environment {
-		envManager.SetEnv(key, value)
+		envManager.SetEnv(key, e.interpolate(string(value)))
 	}
 
-	// Ensure required tools are installed (auto-install if needed)
+	// Pin locale and timezone when the command declares them, so output
+	// (javadoc, test snapshots, ...) does not depend on the developer's machine
+	if cmdConfig.Locale != "" {
+		envManager.SetEnv("LANG", cmdConfig.Locale)
+		envManager.SetEnv("LC_ALL", cmdConfig.Locale)
+	}
+	if cmdConfig.Timezone != "" {
+		envManager.SetEnv("TZ", cmdConfig.Timezone)
+	}
+
+	// Ensure required tools are installed (auto-install if needed). A nil
+	// requires list means all configured tools; an explicit empty list means
+	// the command needs none of them.
 	requiredTools := cmdConfig.Requires
-	if len(requiredTools) == 0 {
-		// If no specific requirements, use all configured tools
+	if requiredTools == nil {
 		for toolName := range e.config.Tools {
 			requiredTools = append(requiredTools, toolName)
 		}
@@ -209,35 +406,96 @@ func (e *Executor) processScriptString(script string, args []string) string {
 	return script
 }
 
-// executeScriptWithInterpreter executes a script using the specified interpreter
-func (e *Executor) executeScriptWithInterpreter(script, workDir string, env []string, interpreter string) error {
+// executeScriptWithInterpreter executes a script using the specified
+// interpreter, applying the command's timeout and retry policy to each attempt
+func (e *Executor) executeScriptWithInterpreter(script, workDir string, env []string, interpreter string, cmdConfig config.CommandConfig) error {
+	var timeout time.Duration
+	if cmdConfig.Timeout != "" {
+		parsed, err := time.ParseDuration(cmdConfig.Timeout)
+		if err != nil {
+			return fmt.Errorf("invalid timeout %q: %w", cmdConfig.Timeout, err)
+		}
+		timeout = parsed
+	}
+
+	var lastErr error
+	for attempt := 0; attempt <= cmdConfig.Retries; attempt++ {
+		if attempt > 0 {
+			fmt.Fprintf(e.stdout(), "  🔁 Retry %d/%d after failure: %v\n", attempt, cmdConfig.Retries, lastErr)
+		}
+		lastErr = e.executeScriptOnce(script, workDir, env, interpreter, timeout, cmdConfig)
+		if lastErr == nil {
+			return nil
+		}
+	}
+	return lastErr
+}
+
+// executeScriptOnce runs a single attempt with the given interpreter
+func (e *Executor) executeScriptOnce(script, workDir string, env []string, interpreter string, timeout time.Duration, cmdConfig config.CommandConfig) error {
 	util.LogVerbose("executeScriptWithInterpreter called with interpreter: '%s', script: '%s'", interpreter, script)
 
 	// Default to native interpreter if not specified
 	if interpreter == "" || interpreter == "native" {
 		util.LogVerbose("Using native interpreter")
-		return e.executeNativeScript(script, workDir, env)
+		return e.executeNativeScript(script, workDir, env, timeout, cmdConfig)
 	}
 
 	// Use mvx-shell interpreter
 	if interpreter == "mvx-shell" {
+		if cmdConfig.Sandbox {
+			return fmt.Errorf("sandbox mode requires the native interpreter (mvx-shell runs in-process)")
+		}
 		mvxShell := shell.NewMVXShell(workDir, env)
-		return mvxShell.Execute(script)
+		if e.output != nil {
+			mvxShell.SetOutput(e.output)
+		}
+		if timeout <= 0 {
+			return mvxShell.Execute(script)
+		}
+		// Best effort for the in-process interpreter: report the timeout, but
+		// commands it already spawned cannot be killed as a group
+		done := make(chan error, 1)
+		go func() { done <- mvxShell.Execute(script) }()
+		select {
+		case err := <-done:
+			return err
+		case <-time.After(timeout):
+			return fmt.Errorf("timed out after %s", timeout)
+		}
+	}
+
+	// Use the embedded mvx-script interpreter
+	if interpreter == "mvx-script" {
+		if cmdConfig.Sandbox {
+			return fmt.Errorf("sandbox mode requires the native interpreter (mvx-script runs in-process)")
+		}
+		engine := mvxscript.NewEngine(workDir, env)
+		if e.output != nil {
+			engine.SetOutput(e.output)
+		}
+		if timeout <= 0 {
+			return engine.Run(script)
+		}
+		// Best effort for the in-process interpreter: report the timeout, but
+		// commands it already spawned cannot be killed as a group
+		done := make(chan error, 1)
+		go func() { done <- engine.Run(script) }()
+		select {
+		case err := <-done:
+			return err
+		case <-time.After(timeout):
+			return fmt.Errorf("timed out after %s", timeout)
+		}
 	}
 
 	return fmt.Errorf("unknown interpreter: %s", interpreter)
 }
 
 // executeNativeScript executes a script using the native system shell
-func (e *Executor) executeNativeScript(script, workDir string, env []string) error {
-	// Determine shell
-	shell := "/bin/bash"
-	shellArgs := []string{"-c"}
-
-	if runtime.GOOS == "windows" {
-		shell = "cmd"
-		shellArgs = []string{"/c"}
-	}
+func (e *Executor) executeNativeScript(script, workDir string, env []string, timeout time.Duration, cmdConfig config.CommandConfig) error {
+	// Determine shell (per-command shell beats the top-level default)
+	shell, shellArgs, script := e.nativeShell(cmdConfig, script)
 
 	util.LogVerbose("Executing native script: %s", script)
 	util.LogVerbose("Working directory: %s", workDir)
@@ -251,26 +509,112 @@ func (e *Executor) executeNativeScript(script, workDir string, env []string) err
 		}
 	}
 
+	// Opt-in sandbox: restrict writes to the project dir, mvx cache and
+	// declared sandbox_paths (bwrap on Linux, sandbox-exec on macOS)
+	if cmdConfig.Sandbox {
+		var err error
+		shell, shellArgs, err = e.wrapInSandbox(shell, append(shellArgs, script), cmdConfig)
+		if err != nil {
+			return err
+		}
+	} else {
+		shellArgs = append(shellArgs, script)
+	}
+
 	// Create command
-	cmd := exec.Command(shell, append(shellArgs, script)...)
+	cmd := exec.Command(shell, shellArgs...)
 	cmd.Dir = workDir
 	cmd.Env = env
-	cmd.Stdout = os.Stdout
-	cmd.Stderr = os.Stderr
 	cmd.Stdin = os.Stdin
 
+	// In quiet mode, buffer output and only show it (truncated) on failure,
+	// so successful CI steps stay short while failures remain debuggable.
+	// A configured writer (parallel prefixing, step output capture) takes
+	// precedence over the quiet buffering.
+	if util.IsQuiet() && e.output == nil {
+		var output bytes.Buffer
+		cmd.Stdout = &output
+		cmd.Stderr = &output
+		if err := runWithTimeout(cmd, timeout); err != nil {
+			fmt.Fprint(os.Stderr, util.RedactText(truncateOutput(output.String())))
+			return err
+		}
+		return nil
+	}
+
+	cmd.Stdout = e.stdout()
+	cmd.Stderr = e.stderr()
+
 	// Execute command
-	return cmd.Run()
+	return runWithTimeout(cmd, timeout)
+}
+
+// nativeShell resolves which shell runs a native script. Commands may pick
+// one with shell:, the top-level shell: sets the project default, and the
+// fallback is /bin/bash (cmd on Windows). PowerShell scripts are wrapped so
+// errors stop execution and the native exit code propagates — cmd's quoting
+// rules mangle anything non-trivial, which is why projects opt into pwsh.
+func (e *Executor) nativeShell(cmdConfig config.CommandConfig, script string) (string, []string, string) {
+	selected := cmdConfig.Shell
+	if selected == "" {
+		selected = e.config.Shell
+	}
+
+	switch selected {
+	case "":
+		if runtime.GOOS == "windows" {
+			return "cmd", []string{"/c"}, script
+		}
+		return "/bin/bash", []string{"-c"}, script
+	case "cmd":
+		return "cmd", []string{"/c"}, script
+	case "powershell", "pwsh":
+		wrapped := fmt.Sprintf("$ErrorActionPreference = 'Stop'; %s; if ($LASTEXITCODE) { exit $LASTEXITCODE }", script)
+		return selected, []string{"-NoProfile", "-NonInteractive", "-Command"}, wrapped
+	default:
+		// bash, sh, zsh, fish or an explicit path
+		return selected, []string{"-c"}, script
+	}
+}
+
+// runWithTimeout starts the command in its own process group and kills the
+// whole group if it outlives the timeout (zero means no limit), so hung
+// scripts cannot wedge CI jobs via orphaned children
+func runWithTimeout(cmd *exec.Cmd, timeout time.Duration) error {
+	setProcessGroup(cmd)
+	if err := cmd.Start(); err != nil {
+		return err
+	}
+	if timeout <= 0 {
+		return cmd.Wait()
+	}
+
+	done := make(chan error, 1)
+	go func() { done <- cmd.Wait() }()
+	select {
+	case err := <-done:
+		return err
+	case <-time.After(timeout):
+		killProcessGroup(cmd)
+		<-done
+		return fmt.Errorf("timed out after %s", timeout)
+	}
 }
 
 // ValidateCommand is deprecated - tools are now auto-installed via EnsureTool
 // This method is kept for backward compatibility but does nothing
 func (e *Executor) ValidateCommand(commandName string) error {
 	// Just check if command exists
-	_, exists := e.config.Commands[commandName]
+	cmdConfig, exists := e.lookupCommand(commandName)
 	if !exists {
 		return fmt.Errorf("unknown command: %s", commandName)
 	}
+	// Dependencies must reference defined commands
+	for _, dep := range cmdConfig.DependsOn {
+		if _, ok := e.config.Commands[dep]; !ok {
+			return fmt.Errorf("command %s depends on unknown command %s", commandName, dep)
+		}
+	}
 	// Note: Tool installation checks removed - EnsureTool handles automatic installation
 	return nil
 }
@@ -1,6 +1,9 @@
 package executor
 
 import (
+	"os"
+	"path/filepath"
+	"runtime"
 	"strings"
 	"testing"
 
@@ -26,14 +29,14 @@ func TestExecutor_SetupEnvironment(t *testing.T) {
 				Distribution: "zulu",
 			},
 		},
-		Environment: map[string]string{
+		Environment: map[string]config.EnvValue{
 			"TEST_VAR": "test_value",
 		},
 		Commands: map[string]config.CommandConfig{
 			"test-cmd": {
 				Description: "Test command",
 				Script:      "echo test",
-				Environment: map[string]string{
+				Environment: map[string]config.EnvValue{
 					"CMD_VAR": "cmd_value",
 				},
 			},
@@ -313,3 +316,217 @@ func TestExecutor_InterpreterSelection(t *testing.T) {
 		})
 	}
 }
+
+func TestExecutor_Interpolate(t *testing.T) {
+	tools.ResetManager()
+	tempDir := t.TempDir()
+
+	cfg := &config.Config{
+		Tools: map[string]config.ToolConfig{},
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		t.Fatalf("Failed to create tool manager: %v", err)
+	}
+
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	t.Setenv("MVX_TEST_INTERP", "interpolated")
+
+	tests := []struct {
+		input    string
+		expected string
+	}{
+		{"${project.dir}/target", tempDir + "/target"},
+		{"${env.MVX_TEST_INTERP}", "interpolated"},
+		{"prefix ${env.MVX_TEST_INTERP} suffix", "prefix interpolated suffix"},
+		// Unknown placeholders are left untouched for shell expansion
+		{"${UNKNOWN_VAR}", "${UNKNOWN_VAR}"},
+		{"${tools.nonexistent.home}", "${tools.nonexistent.home}"},
+		{"no placeholders", "no placeholders"},
+	}
+
+	for _, tt := range tests {
+		if result := executor.interpolate(tt.input); result != tt.expected {
+			t.Errorf("interpolate(%q) = %q, expected %q", tt.input, result, tt.expected)
+		}
+	}
+}
+
+func TestTruncateOutput(t *testing.T) {
+	t.Setenv("MVX_OUTPUT_HEAD", "2")
+	t.Setenv("MVX_OUTPUT_TAIL", "2")
+
+	short := "line1\nline2\nline3\n"
+	if got := truncateOutput(short); got != short {
+		t.Errorf("expected short output unchanged, got %q", got)
+	}
+
+	long := "a\nb\nc\nd\ne\nf\ng\n"
+	got := truncateOutput(long)
+	if !strings.HasPrefix(got, "a\nb\n") {
+		t.Errorf("expected head lines preserved, got %q", got)
+	}
+	if !strings.HasSuffix(got, "f\ng\n") {
+		t.Errorf("expected tail lines preserved, got %q", got)
+	}
+	if !strings.Contains(got, "3 lines omitted") {
+		t.Errorf("expected elision marker, got %q", got)
+	}
+}
+
+func TestExecutor_CommandDependencies(t *testing.T) {
+	// Reset manager for test isolation
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"codegen": {
+				Description: "Generate sources",
+				Script:      "echo codegen >> order.txt",
+			},
+			"compile": {
+				Description: "Compile",
+				Script:      "echo compile >> order.txt",
+				DependsOn:   []string{"codegen"},
+			},
+			"build": {
+				Description: "Build",
+				Script:      "echo build >> order.txt",
+				DependsOn:   []string{"codegen", "compile"},
+			},
+			"cyclic-a": {
+				Script:    "echo a",
+				DependsOn: []string{"cyclic-b"},
+			},
+			"cyclic-b": {
+				Script:    "echo b",
+				DependsOn: []string{"cyclic-a"},
+			},
+			"with-hooks": {
+				Script: "echo main >> hooks.txt",
+				Pre:    "echo pre >> hooks.txt",
+				Post:   "echo post >> hooks.txt",
+			},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	// Dependencies run once each, in declaration order, before the command
+	if err := executor.ExecuteCommand("build", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	order, err := os.ReadFile(filepath.Join(tempDir, "order.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "codegen\ncompile\nbuild\n"
+	if string(order) != want {
+		t.Errorf("execution order = %q, want %q", string(order), want)
+	}
+
+	// Cycles are rejected instead of recursing forever
+	if err := executor.ExecuteCommand("cyclic-a", nil); err == nil || !strings.Contains(err.Error(), "cycle") {
+		t.Errorf("expected dependency cycle error, got %v", err)
+	}
+
+	// Pre and post hooks wrap the main script
+	if err := executor.ExecuteCommand("with-hooks", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	hooks, err := os.ReadFile(filepath.Join(tempDir, "hooks.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(hooks) != "pre\nmain\npost\n" {
+		t.Errorf("hook order = %q, want %q", string(hooks), "pre\nmain\npost\n")
+	}
+
+	// Unknown dependencies are caught by validation
+	cfg.Commands["broken"] = config.CommandConfig{Script: "echo x", DependsOn: []string{"nope"}}
+	if err := executor.ValidateCommand("broken"); err == nil {
+		t.Error("expected error for dependency on unknown command")
+	}
+}
+
+func TestExecutor_TimeoutAndRetries(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("test scripts use POSIX shell syntax")
+	}
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"hung": {
+				Script:      "sleep 5",
+				Interpreter: "native",
+				Timeout:     "200ms",
+			},
+			"flaky": {
+				Script:      "test -f flag.txt || { touch flag.txt; false; }",
+				Interpreter: "native",
+				Retries:     1,
+			},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	// A hung script is killed when the timeout triggers
+	if err := executor.ExecuteCommand("hung", nil); err == nil || !strings.Contains(err.Error(), "timed out") {
+		t.Errorf("expected timeout error, got %v", err)
+	}
+
+	// A flaky script succeeds within its retry budget
+	if err := executor.ExecuteCommand("flaky", nil); err != nil {
+		t.Errorf("expected retry to succeed, got %v", err)
+	}
+}
+
+func TestExecutor_GroupQualifiedLookup(t *testing.T) {
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"migrate": {Script: "echo migrate", Group: "db"},
+			"build":   {Script: "echo build"},
+		},
+	}
+	e := &Executor{config: cfg}
+
+	if _, ok := e.lookupCommand("migrate"); !ok {
+		t.Error("plain name should resolve")
+	}
+	if _, ok := e.lookupCommand("db:migrate"); !ok {
+		t.Error("group-qualified name should resolve")
+	}
+	if _, ok := e.lookupCommand("web:migrate"); ok {
+		t.Error("wrong group must not resolve")
+	}
+	if _, ok := e.lookupCommand("db:build"); ok {
+		t.Error("ungrouped command must not resolve under a group")
+	}
+}
+
+func TestNativeShellSelection(t *testing.T) {
+	e := NewExecutor(&config.Config{Shell: "pwsh"}, nil, t.TempDir())
+
+	shell, args, script := e.nativeShell(config.CommandConfig{}, "Get-ChildItem")
+	if shell != "pwsh" {
+		t.Errorf("shell = %q, want pwsh (top-level default)", shell)
+	}
+	if len(args) == 0 || args[len(args)-1] != "-Command" {
+		t.Errorf("args = %v, want PowerShell -Command invocation", args)
+	}
+	if !strings.Contains(script, "Get-ChildItem") || !strings.Contains(script, "$LASTEXITCODE") {
+		t.Errorf("script = %q, want wrapped with exit-code propagation", script)
+	}
+
+	// Per-command shell beats the top-level default
+	shell, _, script = e.nativeShell(config.CommandConfig{Shell: "sh"}, "ls")
+	if shell != "sh" || script != "ls" {
+		t.Errorf("shell = %q, script = %q, want plain sh invocation", shell, script)
+	}
+}
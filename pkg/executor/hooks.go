@@ -0,0 +1,60 @@
+package executor
+
+import (
+	"fmt"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Lifecycle hooks: the config's hooks section runs project scripts at
+// well-defined points — around `mvx setup` and around configured commands —
+// with the resolved tool environment, so steps like `npm ci` or git hook
+// installation happen automatically instead of living in a README.
+
+// lifecycleHook returns the script registered for a lifecycle point
+func (e *Executor) lifecycleHook(point string) interface{} {
+	if e.config.Hooks == nil {
+		return nil
+	}
+	switch point {
+	case "preSetup":
+		return e.config.Hooks.PreSetup
+	case "postSetup":
+		return e.config.Hooks.PostSetup
+	case "preCommand":
+		return e.config.Hooks.PreCommand
+	case "postCommand":
+		return e.config.Hooks.PostCommand
+	case "onFailure":
+		return e.config.Hooks.OnFailure
+	}
+	return nil
+}
+
+// RunLifecycleHook runs the hook registered for the given lifecycle point, if
+// any, in the project root with the resolved tool environment. extraEnv
+// entries (KEY=VALUE) are appended for hook-specific context.
+func (e *Executor) RunLifecycleHook(point string, extraEnv ...string) error {
+	hook := e.lifecycleHook(point)
+	if hook == nil {
+		return nil
+	}
+
+	script, interpreter, err := config.ResolvePlatformScriptWithInterpreter(hook, "")
+	if err != nil {
+		return fmt.Errorf("failed to resolve %s hook: %w", point, err)
+	}
+	if script == "" {
+		return nil
+	}
+
+	env, err := e.setupEnvironment(config.CommandConfig{})
+	if err != nil {
+		return fmt.Errorf("failed to setup environment for %s hook: %w", point, err)
+	}
+	env = append(env, extraEnv...)
+
+	util.LogVerbose("Running %s hook: %s", point, script)
+	return e.executeScriptWithInterpreter(e.interpolate(script), e.projectRoot, env, interpreter, config.CommandConfig{})
+}
@@ -0,0 +1,52 @@
+package executor
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+)
+
+func TestExecutor_LifecycleHooks(t *testing.T) {
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Hooks: &config.HooksConfig{
+			PreCommand:  "echo hook-pre",
+			PostCommand: "echo hook-post",
+			OnFailure:   "echo hook-failure",
+		},
+		Commands: map[string]config.CommandConfig{
+			"ok":  {Script: "echo main", Interpreter: "native"},
+			"bad": {Script: "false", Interpreter: "native"},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	// preCommand and postCommand wrap a successful command
+	var out bytes.Buffer
+	if err := executor.withOutput(&out).ExecuteCommand("ok", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	for _, marker := range []string{"hook-pre", "main", "hook-post"} {
+		if !strings.Contains(out.String(), marker) {
+			t.Errorf("output missing %q:\n%s", marker, out.String())
+		}
+	}
+
+	// onFailure runs instead of postCommand when the command fails
+	out.Reset()
+	if err := executor.withOutput(&out).ExecuteCommand("bad", nil); err == nil {
+		t.Fatal("expected command failure")
+	}
+	if !strings.Contains(out.String(), "hook-failure") {
+		t.Errorf("output missing onFailure hook:\n%s", out.String())
+	}
+	if strings.Contains(out.String(), "hook-post") {
+		t.Errorf("postCommand hook must not run on failure:\n%s", out.String())
+	}
+}
@@ -0,0 +1,85 @@
+package executor
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/secrets"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// interpolationPattern matches ${...} placeholders in config values
+var interpolationPattern = regexp.MustCompile(`\$\{([a-zA-Z][a-zA-Z0-9_.-]*)\}`)
+
+// interpolate expands ${...} placeholders in a config value:
+//
+//	${env.VARNAME}       value of an environment variable
+//	${project.dir}       absolute project root directory
+//	${tools.<name>.home} installation directory of a configured tool
+//	${secret.NAME}       secret from the environment or the encrypted store
+//
+// Unresolvable placeholders are left untouched so shell-level ${VAR}
+// expansion in native scripts keeps working.
+func (e *Executor) interpolate(value string) string {
+	return interpolationPattern.ReplaceAllStringFunc(value, func(match string) string {
+		key := match[2 : len(match)-1]
+
+		switch {
+		case strings.HasPrefix(key, "env."):
+			return os.Getenv(strings.TrimPrefix(key, "env."))
+
+		case key == "project.dir":
+			return e.projectRoot
+
+		case strings.HasPrefix(key, "secret."):
+			name := strings.TrimPrefix(key, "secret.")
+			value, err := secrets.Resolve(name)
+			if err != nil {
+				util.LogVerbose("Cannot interpolate %s: %v", match, err)
+				return match
+			}
+			// Never let resolved secrets reach logs or reports
+			util.RegisterSensitiveValue(value)
+			return value
+
+		case strings.HasPrefix(key, "tools.") && strings.HasSuffix(key, ".home"):
+			toolName := strings.TrimSuffix(strings.TrimPrefix(key, "tools."), ".home")
+			home, err := e.toolHome(toolName)
+			if err != nil {
+				util.LogVerbose("Cannot interpolate %s: %v", match, err)
+				return match
+			}
+			return home
+		}
+
+		return match
+	})
+}
+
+// toolHome resolves the installation directory of a configured tool
+func (e *Executor) toolHome(toolName string) (string, error) {
+	toolConfig, exists := e.config.Tools[toolName]
+	if !exists {
+		return "", fmt.Errorf("tool %s is not configured", toolName)
+	}
+
+	tool, err := e.toolManager.GetTool(toolName)
+	if err != nil {
+		return "", err
+	}
+
+	version, err := e.toolManager.ResolveVersion(toolName, toolConfig)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve %s version: %w", toolName, err)
+	}
+
+	binPath, err := tool.GetPath(version, toolConfig)
+	if err != nil {
+		return "", err
+	}
+
+	// GetPath returns the bin directory; the home is its parent
+	return strings.TrimSuffix(binPath, "/bin"), nil
+}
@@ -0,0 +1,51 @@
+package executor
+
+import (
+	"os"
+	"strconv"
+	"strings"
+)
+
+// Default line counts kept when a failed step's buffered output is truncated
+const (
+	defaultOutputHead = 50
+	defaultOutputTail = 50
+)
+
+// outputLimit reads a truncation limit from an environment variable,
+// falling back to the default for unset or invalid values
+func outputLimit(envVar string, fallback int) int {
+	value := os.Getenv(envVar)
+	if value == "" {
+		return fallback
+	}
+	limit, err := strconv.Atoi(value)
+	if err != nil || limit < 0 {
+		return fallback
+	}
+	return limit
+}
+
+// truncateOutput shortens buffered command output to its head and tail lines
+// (MVX_OUTPUT_HEAD / MVX_OUTPUT_TAIL, default 50 each), marking how many
+// lines were elided. Short output is returned unchanged.
+func truncateOutput(output string) string {
+	head := outputLimit("MVX_OUTPUT_HEAD", defaultOutputHead)
+	tail := outputLimit("MVX_OUTPUT_TAIL", defaultOutputTail)
+
+	trimmed := strings.TrimRight(output, "\n")
+	if trimmed == "" {
+		return output
+	}
+	lines := strings.Split(trimmed, "\n")
+	if len(lines) <= head+tail {
+		return output
+	}
+
+	elided := len(lines) - head - tail
+	var result []string
+	result = append(result, lines[:head]...)
+	result = append(result, "... ("+strconv.Itoa(elided)+" lines omitted, set MVX_OUTPUT_HEAD/MVX_OUTPUT_TAIL to adjust) ...")
+	result = append(result, lines[len(lines)-tail:]...)
+	return strings.Join(result, "\n") + "\n"
+}
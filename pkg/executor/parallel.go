@@ -0,0 +1,278 @@
+package executor
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"os"
+	"regexp"
+	"strings"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Parallel execution: `mvx run --parallel lint test docs` runs several
+// commands concurrently, and a command whose script is an array can set
+// parallel: true to run its steps concurrently. Output is interleaved at
+// line granularity but prefixed with the command or step name so it stays
+// attributable.
+
+// prefixWriter prefixes every line written through it and serializes writes
+// to the shared destination
+type prefixWriter struct {
+	mu     *sync.Mutex
+	dest   io.Writer
+	prefix string
+	buf    []byte
+}
+
+func (w *prefixWriter) Write(p []byte) (int, error) {
+	w.buf = append(w.buf, p...)
+	for {
+		idx := bytes.IndexByte(w.buf, '\n')
+		if idx < 0 {
+			break
+		}
+		line := w.buf[:idx+1]
+		w.mu.Lock()
+		fmt.Fprintf(w.dest, "%s%s", w.prefix, line)
+		w.mu.Unlock()
+		w.buf = w.buf[idx+1:]
+	}
+	return len(p), nil
+}
+
+// flush emits any trailing output that did not end with a newline
+func (w *prefixWriter) flush() {
+	if len(w.buf) == 0 {
+		return
+	}
+	w.mu.Lock()
+	fmt.Fprintf(w.dest, "%s%s\n", w.prefix, w.buf)
+	w.mu.Unlock()
+	w.buf = nil
+}
+
+// withOutput returns a copy of the executor whose command output goes to the
+// given writer instead of the terminal
+func (e *Executor) withOutput(w io.Writer) *Executor {
+	clone := *e
+	clone.output = w
+	return &clone
+}
+
+// ExecuteCommandsParallel runs the named commands through a bounded worker
+// pool. Without continueOnError, a failure stops queued commands from
+// starting (already running commands finish); with it, every command runs
+// and all failures are reported.
+func (e *Executor) ExecuteCommandsParallel(names []string, maxConcurrent int, continueOnError bool) error {
+	for _, name := range names {
+		if err := e.ValidateCommand(name); err != nil {
+			return err
+		}
+	}
+	if maxConcurrent <= 0 || maxConcurrent > len(names) {
+		maxConcurrent = len(names)
+	}
+
+	var (
+		outputMu sync.Mutex // serializes prefixed terminal output
+		wg       sync.WaitGroup
+		sem      = make(chan struct{}, maxConcurrent)
+		errsMu   sync.Mutex
+		errs     []string
+	)
+
+	for _, name := range names {
+		errsMu.Lock()
+		stop := !continueOnError && len(errs) > 0
+		errsMu.Unlock()
+		if stop {
+			break
+		}
+
+		sem <- struct{}{}
+		wg.Add(1)
+		go func(name string) {
+			defer wg.Done()
+			defer func() { <-sem }()
+
+			writer := &prefixWriter{mu: &outputMu, dest: os.Stdout, prefix: "[" + name + "] "}
+			err := e.withOutput(writer).ExecuteCommand(name, nil)
+			writer.flush()
+			if err != nil {
+				errsMu.Lock()
+				errs = append(errs, fmt.Sprintf("%s: %v", name, err))
+				errsMu.Unlock()
+			}
+		}(name)
+	}
+	wg.Wait()
+
+	if len(errs) > 0 {
+		return fmt.Errorf("%d of %d commands failed:\n  %s", len(errs), len(names), strings.Join(errs, "\n  "))
+	}
+	return nil
+}
+
+// ExecuteCommandWithPrefix runs a command with every output line prefixed,
+// used by matrix runs to keep per-version output attributable
+func (e *Executor) ExecuteCommandWithPrefix(name string, args []string, prefix string) error {
+	var mu sync.Mutex
+	writer := &prefixWriter{mu: &mu, dest: os.Stdout, prefix: prefix}
+	err := e.withOutput(writer).ExecuteCommand(name, args)
+	writer.flush()
+	return err
+}
+
+// outputPlaceholderPattern matches ${outputs.<name>} references to captured
+// step output in later steps
+var outputPlaceholderPattern = regexp.MustCompile(`\$\{outputs\.([a-zA-Z][a-zA-Z0-9_-]*)\}`)
+
+// expandOutputPlaceholders substitutes ${outputs.<name>} with output captured
+// from earlier steps, leaving unknown names untouched
+func expandOutputPlaceholders(script string, outputs map[string]string) string {
+	return outputPlaceholderPattern.ReplaceAllStringFunc(script, func(match string) string {
+		name := outputPlaceholderPattern.FindStringSubmatch(match)[1]
+		if value, ok := outputs[name]; ok {
+			return value
+		}
+		return match
+	})
+}
+
+// scriptStepList reports whether a command's script is a step array
+func scriptStepList(script interface{}) ([]interface{}, bool) {
+	steps, ok := script.([]interface{})
+	return steps, ok && len(steps) > 0
+}
+
+// executeStepGroup runs a command's script steps, sequentially by default or
+// concurrently with parallel: true. Steps reference named arguments via
+// ${args.<name>}; the positional passthrough only applies to single-script
+// commands.
+func (e *Executor) executeStepGroup(steps []interface{}, argValues map[string]string, workDir string, env []string, cmdConfig config.CommandConfig) error {
+	type resolvedStep struct {
+		script          string
+		interpreter     string
+		continueOnError bool
+		condition       string
+		outputName      string
+	}
+	resolved := make([]resolvedStep, 0, len(steps))
+	for i, step := range steps {
+		// A { run: ..., continueOnError: true, if: ... } object carries
+		// per-step options
+		value := step
+		defaultInterpreter := cmdConfig.Interpreter
+		continueOnError := false
+		condition := ""
+		outputName := ""
+		if m, ok := step.(map[string]interface{}); ok {
+			if run, isStep := m["run"]; isStep {
+				value = run
+				if flag, ok := m["continueOnError"].(bool); ok {
+					continueOnError = flag
+				}
+				if interp, ok := m["interpreter"].(string); ok && interp != "" {
+					defaultInterpreter = interp
+				}
+				if cond, ok := m["if"].(string); ok {
+					condition = cond
+				}
+				if name, ok := m["output"].(string); ok {
+					outputName = name
+				}
+			}
+		}
+
+		script, interpreter, err := config.ResolvePlatformScriptWithInterpreter(value, defaultInterpreter)
+		if err != nil {
+			return fmt.Errorf("failed to resolve step %d: %w", i+1, err)
+		}
+		if cmdConfig.Parallel && outputName != "" {
+			return fmt.Errorf("step %d: output capture requires sequential steps", i+1)
+		}
+
+		processed := e.interpolate(expandArgPlaceholders(script, argValues))
+		resolved = append(resolved, resolvedStep{processed, interpreter, continueOnError, condition, outputName})
+	}
+
+	// Evaluate step conditions up front so skipped steps never launch
+	runnable := resolved[:0]
+	for i, step := range resolved {
+		if step.condition != "" {
+			ok, err := e.evaluateCondition(step.condition, workDir)
+			if err != nil {
+				return fmt.Errorf("step %d condition: %w", i+1, err)
+			}
+			if !ok {
+				util.LogVerbose("Skipping step %d/%d (condition %q is false)", i+1, len(resolved), step.condition)
+				continue
+			}
+		}
+		runnable = append(runnable, step)
+	}
+	resolved = runnable
+
+	if !cmdConfig.Parallel {
+		// Output captured via a step's output field feeds ${outputs.<name>}
+		// references in later steps
+		outputs := make(map[string]string)
+		for i, step := range resolved {
+			script := expandOutputPlaceholders(step.script, outputs)
+			util.LogVerbose("Running step %d/%d: %s", i+1, len(resolved), script)
+
+			var err error
+			if step.outputName != "" {
+				var captured bytes.Buffer
+				err = e.withOutput(&captured).executeScriptWithInterpreter(script, workDir, env, step.interpreter, cmdConfig)
+				outputs[step.outputName] = strings.TrimSpace(captured.String())
+			} else {
+				err = e.executeScriptWithInterpreter(script, workDir, env, step.interpreter, cmdConfig)
+			}
+			if err != nil {
+				if step.continueOnError {
+					fmt.Fprintf(e.stdout(), "  ⚠️  Step %d failed (continuing): %v\n", i+1, err)
+					continue
+				}
+				return fmt.Errorf("step %d failed: %w", i+1, err)
+			}
+		}
+		return nil
+	}
+
+	var (
+		outputMu sync.Mutex
+		wg       sync.WaitGroup
+		errsMu   sync.Mutex
+		errs     []string
+	)
+	for i, step := range resolved {
+		wg.Add(1)
+		go func(i int, step resolvedStep) {
+			defer wg.Done()
+
+			writer := &prefixWriter{mu: &outputMu, dest: e.stdout(), prefix: fmt.Sprintf("[step %d] ", i+1)}
+			err := e.withOutput(writer).executeScriptWithInterpreter(step.script, workDir, env, step.interpreter, cmdConfig)
+			writer.flush()
+			if err != nil {
+				if step.continueOnError {
+					util.LogVerbose("Step %d failed (continuing): %v", i+1, err)
+					return
+				}
+				errsMu.Lock()
+				errs = append(errs, fmt.Sprintf("step %d: %v", i+1, err))
+				errsMu.Unlock()
+			}
+		}(i, step)
+	}
+	wg.Wait()
+
+	if len(errs) > 0 {
+		return fmt.Errorf("%d of %d parallel steps failed:\n  %s", len(errs), len(resolved), strings.Join(errs, "\n  "))
+	}
+	return nil
+}
@@ -0,0 +1,155 @@
+package executor
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/tools"
+)
+
+func TestPrefixWriter(t *testing.T) {
+	var dest bytes.Buffer
+	var mu sync.Mutex
+	w := &prefixWriter{mu: &mu, dest: &dest, prefix: "[lint] "}
+
+	w.Write([]byte("first line\npartial"))
+	w.Write([]byte(" rest\n"))
+	w.Write([]byte("no newline"))
+	w.flush()
+
+	want := "[lint] first line\n[lint] partial rest\n[lint] no newline\n"
+	if dest.String() != want {
+		t.Errorf("prefixed output = %q, want %q", dest.String(), want)
+	}
+}
+
+func TestExecutor_StepGroups(t *testing.T) {
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"sequential": {
+				Script:      []interface{}{"echo one >> steps.txt", "echo two >> steps.txt"},
+				Interpreter: "native",
+			},
+			"failing": {
+				Script:      []interface{}{"false", "echo unreachable >> steps.txt"},
+				Interpreter: "native",
+			},
+			"concurrent": {
+				Script:      []interface{}{"echo a >> a.txt", "echo b >> b.txt"},
+				Interpreter: "native",
+				Parallel:    true,
+			},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	// Sequential steps run in order
+	if err := executor.ExecuteCommand("sequential", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	steps, err := os.ReadFile(filepath.Join(tempDir, "steps.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(steps) != "one\ntwo\n" {
+		t.Errorf("step output = %q, want %q", string(steps), "one\ntwo\n")
+	}
+
+	// A failing step stops the sequence
+	if err := executor.ExecuteCommand("failing", nil); err == nil || !strings.Contains(err.Error(), "step 1") {
+		t.Errorf("expected step 1 failure, got %v", err)
+	}
+	if _, err := os.Stat(filepath.Join(tempDir, "unreachable")); err == nil {
+		t.Error("steps after a failure must not run")
+	}
+
+	// Parallel steps all run
+	if err := executor.ExecuteCommand("concurrent", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	for _, file := range []string{"a.txt", "b.txt"} {
+		if _, err := os.Stat(filepath.Join(tempDir, file)); err != nil {
+			t.Errorf("parallel step output %s missing: %v", file, err)
+		}
+	}
+}
+
+func TestExecutor_StepObjects(t *testing.T) {
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"tolerant": {
+				Script: []interface{}{
+					"echo first >> steps.txt",
+					map[string]interface{}{"run": "false", "continueOnError": true},
+					"echo last >> steps.txt",
+				},
+				Interpreter: "native",
+			},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	// A continueOnError step failure does not stop the sequence
+	if err := executor.ExecuteCommand("tolerant", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	steps, err := os.ReadFile(filepath.Join(tempDir, "steps.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(steps) != "first\nlast\n" {
+		t.Errorf("step output = %q, want %q", string(steps), "first\nlast\n")
+	}
+}
+
+func TestExecutor_StepOutputCapture(t *testing.T) {
+	tools.ResetManager()
+
+	tempDir := t.TempDir()
+	cfg := &config.Config{
+		Commands: map[string]config.CommandConfig{
+			"versioned": {
+				Script: []interface{}{
+					map[string]interface{}{"run": "echo 1.2.3", "output": "version"},
+					"echo version=${outputs.version} > build.txt",
+				},
+				Interpreter: "native",
+			},
+		},
+	}
+	manager, _ := tools.NewManager()
+	executor := NewExecutor(cfg, manager, tempDir)
+
+	if err := executor.ExecuteCommand("versioned", nil); err != nil {
+		t.Fatalf("ExecuteCommand() error = %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, "build.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if strings.TrimSpace(string(content)) != "version=1.2.3" {
+		t.Errorf("captured output = %q, want version=1.2.3", strings.TrimSpace(string(content)))
+	}
+}
+
+func TestExpandOutputPlaceholders(t *testing.T) {
+	outputs := map[string]string{"gitSha": "abc123"}
+	got := expandOutputPlaceholders("docker build -t app:${outputs.gitSha} ${outputs.unknown}", outputs)
+	want := "docker build -t app:abc123 ${outputs.unknown}"
+	if got != want {
+		t.Errorf("expandOutputPlaceholders() = %q, want %q", got, want)
+	}
+}
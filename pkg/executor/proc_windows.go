@@ -0,0 +1,20 @@
+//go:build windows
+
+package executor
+
+import (
+	"fmt"
+	"os/exec"
+)
+
+// setProcessGroup is a no-op on Windows; killProcessGroup relies on
+// taskkill /T to take down the whole process tree
+func setProcessGroup(cmd *exec.Cmd) {}
+
+// killProcessGroup kills the child and its whole process tree
+func killProcessGroup(cmd *exec.Cmd) {
+	if cmd.Process == nil {
+		return
+	}
+	_ = exec.Command("taskkill", "/T", "/F", "/PID", fmt.Sprintf("%d", cmd.Process.Pid)).Run()
+}
@@ -0,0 +1,162 @@
+package executor
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Command prompts: a command's prompts section declares values collected
+// before the script runs — from --<name> flags, then MVX_INPUT_<NAME>
+// environment variables, then interactively when stdin is a terminal. This
+// replaces read/pause hacks inside scripts, which do not work on Windows.
+
+// promptArgConfigs exposes prompts to the argument parser so `--version 1.2.3`
+// and bare `--confirm` flags work the same as declared arguments
+func promptArgConfigs(prompts []config.CommandPromptConfig) []config.CommandArgConfig {
+	args := make([]config.CommandArgConfig, 0, len(prompts))
+	for _, prompt := range prompts {
+		argType := "string"
+		if prompt.Type == "confirm" {
+			argType = "bool"
+		}
+		args = append(args, config.CommandArgConfig{Name: prompt.Name, Description: prompt.Description, Type: argType})
+	}
+	return args
+}
+
+// promptEnvVar names the CI-friendly environment source for a prompt value
+// (e.g. release-version -> MVX_INPUT_RELEASE_VERSION)
+func promptEnvVar(name string) string {
+	sanitized := strings.NewReplacer("-", "_", ".", "_").Replace(strings.ToUpper(name))
+	return "MVX_INPUT_" + sanitized
+}
+
+// stdinIsTTY reports whether stdin is a terminal, i.e. prompting makes sense
+func stdinIsTTY() bool {
+	info, err := os.Stdin.Stat()
+	return err == nil && info.Mode()&os.ModeCharDevice != 0
+}
+
+// resolvePrompts fills in prompt values missing from the parsed arguments,
+// validates them, and aborts when a confirmation is declined
+func (e *Executor) resolvePrompts(prompts []config.CommandPromptConfig, values map[string]string) error {
+	for _, prompt := range prompts {
+		value := values[prompt.Name]
+		provided := value != "" && !(prompt.Type == "confirm" && value == "false")
+
+		if !provided {
+			if env := os.Getenv(promptEnvVar(prompt.Name)); env != "" {
+				value = env
+				provided = true
+			}
+		}
+
+		if !provided {
+			switch {
+			case stdinIsTTY():
+				answer, err := e.askPrompt(prompt)
+				if err != nil {
+					return err
+				}
+				value = answer
+			case prompt.Default != "":
+				value = prompt.Default
+			default:
+				return fmt.Errorf("input %s is required: pass --%s or set %s", prompt.Name, prompt.Name, promptEnvVar(prompt.Name))
+			}
+		}
+
+		switch prompt.Type {
+		case "confirm":
+			if !isAffirmative(value) {
+				return fmt.Errorf("aborted: %s was not confirmed", prompt.Name)
+			}
+			value = "true"
+		case "choice":
+			valid := false
+			for _, choice := range prompt.Choices {
+				if value == choice {
+					valid = true
+					break
+				}
+			}
+			if !valid {
+				return fmt.Errorf("input %s must be one of %s, got %q", prompt.Name, strings.Join(prompt.Choices, ", "), value)
+			}
+		case "password":
+			util.RegisterSensitiveValue(value)
+		}
+
+		values[prompt.Name] = value
+	}
+	return nil
+}
+
+// askPrompt reads a single prompt value interactively
+func (e *Executor) askPrompt(prompt config.CommandPromptConfig) (string, error) {
+	label := prompt.Description
+	if label == "" {
+		label = prompt.Name
+	}
+
+	switch prompt.Type {
+	case "confirm":
+		fmt.Fprintf(e.stdout(), "%s [y/N]: ", label)
+	case "choice":
+		fmt.Fprintf(e.stdout(), "%s (%s): ", label, strings.Join(prompt.Choices, "/"))
+	default:
+		if prompt.Default != "" {
+			fmt.Fprintf(e.stdout(), "%s [%s]: ", label, prompt.Default)
+		} else {
+			fmt.Fprintf(e.stdout(), "%s: ", label)
+		}
+	}
+
+	line, err := readPromptLine(prompt.Type == "password")
+	if err != nil {
+		return "", fmt.Errorf("failed to read input %s: %w", prompt.Name, err)
+	}
+	if line == "" {
+		line = prompt.Default
+	}
+	return line, nil
+}
+
+// readPromptLine reads one line from stdin, disabling terminal echo for
+// passwords where the platform allows it (best effort via stty)
+func readPromptLine(hidden bool) (string, error) {
+	if hidden && runtime.GOOS != "windows" {
+		echoOff := exec.Command("stty", "-echo")
+		echoOff.Stdin = os.Stdin
+		if err := echoOff.Run(); err == nil {
+			defer func() {
+				echoOn := exec.Command("stty", "echo")
+				echoOn.Stdin = os.Stdin
+				_ = echoOn.Run()
+				fmt.Println()
+			}()
+		}
+	}
+	line, err := bufio.NewReader(os.Stdin).ReadString('\n')
+	if err != nil {
+		return "", err
+	}
+	return strings.TrimRight(line, "\r\n"), nil
+}
+
+// isAffirmative interprets the usual yes spellings from flags, env vars and
+// interactive answers
+func isAffirmative(value string) bool {
+	switch strings.ToLower(strings.TrimSpace(value)) {
+	case "y", "yes", "true", "1":
+		return true
+	}
+	return false
+}
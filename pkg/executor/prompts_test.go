@@ -0,0 +1,73 @@
+package executor
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestResolvePrompts(t *testing.T) {
+	e := &Executor{}
+	prompts := []config.CommandPromptConfig{
+		{Name: "version"},
+		{Name: "proceed", Type: "confirm"},
+	}
+
+	// Values passed as flags are used as-is
+	values := map[string]string{"version": "1.2.3", "proceed": "true"}
+	if err := e.resolvePrompts(prompts, values); err != nil {
+		t.Fatalf("resolvePrompts() error = %v", err)
+	}
+	if values["version"] != "1.2.3" || values["proceed"] != "true" {
+		t.Errorf("resolved values = %v", values)
+	}
+
+	// Missing values fall back to MVX_INPUT_* env vars (the CI path)
+	t.Setenv("MVX_INPUT_VERSION", "2.0.0")
+	t.Setenv("MVX_INPUT_PROCEED", "yes")
+	values = map[string]string{}
+	if err := e.resolvePrompts(prompts, values); err != nil {
+		t.Fatalf("resolvePrompts() error = %v", err)
+	}
+	if values["version"] != "2.0.0" || values["proceed"] != "true" {
+		t.Errorf("resolved values = %v", values)
+	}
+
+	// A declined confirmation aborts the command
+	t.Setenv("MVX_INPUT_PROCEED", "no")
+	if err := e.resolvePrompts(prompts, map[string]string{"version": "1.0"}); err == nil || !strings.Contains(err.Error(), "aborted") {
+		t.Errorf("expected abort on declined confirmation, got %v", err)
+	}
+}
+
+func TestResolvePromptsValidation(t *testing.T) {
+	e := &Executor{}
+
+	// Without a flag, env var, TTY or default, the prompt is an error
+	err := e.resolvePrompts([]config.CommandPromptConfig{{Name: "target"}}, map[string]string{})
+	if err == nil || !strings.Contains(err.Error(), "MVX_INPUT_TARGET") {
+		t.Errorf("expected missing input error naming the env var, got %v", err)
+	}
+
+	// Defaults apply when stdin is not a terminal
+	values := map[string]string{}
+	if err := e.resolvePrompts([]config.CommandPromptConfig{{Name: "env", Type: "choice", Choices: []string{"dev", "prod"}, Default: "dev"}}, values); err != nil {
+		t.Fatalf("resolvePrompts() error = %v", err)
+	}
+	if values["env"] != "dev" {
+		t.Errorf("defaulted value = %q, want dev", values["env"])
+	}
+
+	// Choice values are validated wherever they came from
+	err = e.resolvePrompts([]config.CommandPromptConfig{{Name: "env", Type: "choice", Choices: []string{"dev", "prod"}}}, map[string]string{"env": "staging"})
+	if err == nil || !strings.Contains(err.Error(), "must be one of") {
+		t.Errorf("expected invalid choice error, got %v", err)
+	}
+}
+
+func TestPromptEnvVar(t *testing.T) {
+	if got := promptEnvVar("release-version"); got != "MVX_INPUT_RELEASE_VERSION" {
+		t.Errorf("promptEnvVar() = %q", got)
+	}
+}
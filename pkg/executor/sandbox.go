@@ -0,0 +1,106 @@
+package executor
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// sandboxWritablePaths returns the directories a sandboxed command may write
+// to: the project root, the mvx home (tool cache), the system temp directory
+// and any extra paths declared in sandbox_paths (resolved against the project
+// root when relative).
+func (e *Executor) sandboxWritablePaths(cmdConfig config.CommandConfig) []string {
+	paths := []string{e.projectRoot, os.TempDir()}
+
+	if mvxHome, err := util.MvxHome(); err == nil {
+		paths = append(paths, mvxHome)
+	}
+
+	for _, extra := range cmdConfig.SandboxPaths {
+		if !filepath.IsAbs(extra) {
+			extra = filepath.Join(e.projectRoot, extra)
+		}
+		paths = append(paths, extra)
+	}
+
+	// Only keep paths that exist — bwrap and sandbox-exec fail on missing binds
+	var existing []string
+	for _, path := range paths {
+		if _, err := os.Stat(path); err == nil {
+			existing = append(existing, path)
+		}
+	}
+	return existing
+}
+
+// wrapInSandbox rewrites a shell invocation so the command runs with writes
+// restricted to the declared paths. Uses bubblewrap (bwrap) on Linux and
+// sandbox-exec on macOS. When no sandbox helper is available the command
+// fails rather than silently running unconfined; MVX_ALLOW_UNSANDBOXED=true
+// is the explicit escape hatch for machines without one.
+func (e *Executor) wrapInSandbox(shellBin string, shellArgs []string, cmdConfig config.CommandConfig) (string, []string, error) {
+	writable := e.sandboxWritablePaths(cmdConfig)
+
+	switch runtime.GOOS {
+	case "linux":
+		bwrap, err := exec.LookPath("bwrap")
+		if err != nil {
+			if allowUnsandboxed() {
+				fmt.Printf("⚠️  Sandbox requested but bwrap (bubblewrap) is not installed, running unsandboxed (MVX_ALLOW_UNSANDBOXED=true)\n")
+				return shellBin, shellArgs, nil
+			}
+			return "", nil, fmt.Errorf("command requires a sandbox but bwrap (bubblewrap) is not installed; install it, or set MVX_ALLOW_UNSANDBOXED=true to run unconfined")
+		}
+		args := []string{"--ro-bind", "/", "/", "--dev", "/dev", "--proc", "/proc"}
+		for _, path := range writable {
+			args = append(args, "--bind", path, path)
+		}
+		args = append(args, "--die-with-parent", "--", shellBin)
+		args = append(args, shellArgs...)
+		util.LogVerbose("Sandboxing command with bwrap, writable paths: %v", writable)
+		return bwrap, args, nil
+
+	case "darwin":
+		profile := buildSandboxProfile(writable)
+		util.LogVerbose("Sandboxing command with sandbox-exec, writable paths: %v", writable)
+		args := append([]string{"-p", profile, shellBin}, shellArgs...)
+		return "/usr/bin/sandbox-exec", args, nil
+
+	default:
+		if allowUnsandboxed() {
+			fmt.Printf("⚠️  Sandbox mode is not supported on %s, running unsandboxed (MVX_ALLOW_UNSANDBOXED=true)\n", runtime.GOOS)
+			return shellBin, shellArgs, nil
+		}
+		return "", nil, fmt.Errorf("command requires a sandbox but sandbox mode is not supported on %s; set MVX_ALLOW_UNSANDBOXED=true to run unconfined", runtime.GOOS)
+	}
+}
+
+// allowUnsandboxed reports whether the user explicitly allowed sandbox
+// commands to run unconfined when no sandbox helper is available
+func allowUnsandboxed() bool {
+	return os.Getenv("MVX_ALLOW_UNSANDBOXED") == "true"
+}
+
+// buildSandboxProfile generates a sandbox-exec (Seatbelt) profile that allows
+// reads everywhere but restricts writes to the given paths.
+func buildSandboxProfile(writable []string) string {
+	var b strings.Builder
+	b.WriteString("(version 1)\n")
+	b.WriteString("(allow default)\n")
+	b.WriteString("(deny file-write*)\n")
+	for _, path := range writable {
+		fmt.Fprintf(&b, "(allow file-write* (subpath %q))\n", path)
+	}
+	// Device and temp writes most tools assume
+	b.WriteString("(allow file-write* (subpath \"/dev\"))\n")
+	b.WriteString("(allow file-write* (subpath \"/private/tmp\"))\n")
+	b.WriteString("(allow file-write* (subpath \"/private/var/tmp\"))\n")
+	return b.String()
+}
@@ -0,0 +1,38 @@
+package executor
+
+import (
+	"os/exec"
+	"runtime"
+	"strings"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestWrapInSandboxFailsWithoutHelper(t *testing.T) {
+	if runtime.GOOS != "linux" {
+		t.Skip("bwrap lookup is Linux-only")
+	}
+	if _, err := exec.LookPath("bwrap"); err == nil {
+		t.Skip("bwrap is installed; the unavailable-helper path cannot trigger")
+	}
+
+	e := &Executor{projectRoot: t.TempDir()}
+	cmdConfig := config.CommandConfig{Sandbox: true}
+
+	t.Setenv("MVX_ALLOW_UNSANDBOXED", "")
+	if _, _, err := e.wrapInSandbox("sh", []string{"-c", "true"}, cmdConfig); err == nil {
+		t.Error("expected an error when no sandbox helper is available")
+	} else if !strings.Contains(err.Error(), "MVX_ALLOW_UNSANDBOXED") {
+		t.Errorf("error should mention the escape hatch, got: %v", err)
+	}
+
+	t.Setenv("MVX_ALLOW_UNSANDBOXED", "true")
+	shell, args, err := e.wrapInSandbox("sh", []string{"-c", "true"}, cmdConfig)
+	if err != nil {
+		t.Fatalf("escape hatch should allow running unconfined: %v", err)
+	}
+	if shell != "sh" || len(args) != 2 {
+		t.Errorf("expected the original invocation back, got %s %v", shell, args)
+	}
+}
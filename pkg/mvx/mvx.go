@@ -0,0 +1,145 @@
+// Package mvx is the public library API for embedding mvx in other tools.
+//
+// IDE plugins, internal CLIs and build servers can use it to load a project's
+// configuration, resolve and install tools, construct the project environment
+// and run configured commands programmatically, instead of shelling out to
+// the mvx binary:
+//
+//	project, err := mvx.Open(".")
+//	if err != nil { ... }
+//	env, err := project.Environment()
+//	if err != nil { ... }
+//	if err := project.ExecuteCommand("build", nil); err != nil { ... }
+//
+// The package wraps pkg/config, pkg/tools and pkg/executor behind a small,
+// stable surface; those packages remain importable but their APIs move with
+// the implementation.
+package mvx
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/executor"
+	"github.com/gnodet/mvx/pkg/tools"
+)
+
+// Project is an opened mvx project: its root directory, effective
+// configuration and a tool manager wired to that configuration.
+type Project struct {
+	// Root is the project root directory (the one containing .mvx).
+	Root string
+	// Config is the effective configuration after stacking, extends
+	// resolution and profile application.
+	Config *config.Config
+
+	manager  *tools.Manager
+	executor *executor.Executor
+}
+
+// Open loads the mvx project containing dir, walking up to the nearest .mvx
+// directory like the CLI does. The returned Project is ready for version
+// resolution, installation and command execution.
+func Open(dir string) (*Project, error) {
+	root, err := FindProjectRoot(dir)
+	if err != nil {
+		return nil, err
+	}
+
+	cfg, err := config.LoadConfig(root)
+	if err != nil {
+		return nil, fmt.Errorf("failed to load configuration: %w", err)
+	}
+
+	manager, err := tools.NewManager()
+	if err != nil {
+		return nil, fmt.Errorf("failed to create tool manager: %w", err)
+	}
+	manager.RegisterCustomTools(cfg)
+	manager.RegisterProjectPlugins(root, cfg)
+	manager.LoadProjectLock(root)
+	manager.ConfigureRegistries(cfg)
+
+	return &Project{
+		Root:     root,
+		Config:   cfg,
+		manager:  manager,
+		executor: executor.NewExecutor(cfg, manager, root),
+	}, nil
+}
+
+// FindProjectRoot walks up from dir to the nearest directory containing a
+// .mvx directory. It returns an error when no project is found.
+func FindProjectRoot(dir string) (string, error) {
+	abs, err := filepath.Abs(dir)
+	if err != nil {
+		return "", err
+	}
+
+	for current := abs; ; {
+		mvxDir := filepath.Join(current, ".mvx")
+		if info, err := os.Stat(mvxDir); err == nil && info.IsDir() {
+			return current, nil
+		}
+		parent := filepath.Dir(current)
+		if parent == current {
+			return "", fmt.Errorf("no mvx project found in %s or any parent directory", abs)
+		}
+		current = parent
+	}
+}
+
+// ResolveVersion resolves a configured tool's version specification (e.g.
+// "21", "lts", "latest-3") to the concrete version that would be installed.
+func (p *Project) ResolveVersion(toolName string) (string, error) {
+	toolConfig, exists := p.Config.Tools[toolName]
+	if !exists {
+		return "", fmt.Errorf("tool %s is not configured in this project", toolName)
+	}
+	return p.manager.ResolveVersion(toolName, toolConfig)
+}
+
+// EnsureTool makes sure a configured tool is installed (installing it on
+// demand) and returns the directory containing its binaries.
+func (p *Project) EnsureTool(toolName string) (string, error) {
+	toolConfig, exists := p.Config.Tools[toolName]
+	if !exists {
+		return "", fmt.Errorf("tool %s is not configured in this project", toolName)
+	}
+	return p.manager.EnsureTool(toolName, toolConfig)
+}
+
+// EnsureTools installs all configured tools, downloading up to maxConcurrent
+// tools in parallel (0 uses the default concurrency).
+func (p *Project) EnsureTools(maxConcurrent int) error {
+	if maxConcurrent == 0 {
+		maxConcurrent = tools.GetDefaultConcurrency()
+	}
+	return p.manager.EnsureTools(p.Config, maxConcurrent)
+}
+
+// Environment constructs the project's environment variables (tool homes,
+// PATH additions and the configured environment section). Tools must be
+// installed for their entries to appear.
+func (p *Project) Environment() (map[string]string, error) {
+	return p.manager.SetupEnvironment(p.Config)
+}
+
+// Commands returns the configured command names mapped to their descriptions.
+func (p *Project) Commands() map[string]string {
+	return p.executor.ListCommands()
+}
+
+// ExecuteCommand runs a configured command with the given arguments, with
+// the same environment setup and interpreter handling as the CLI.
+func (p *Project) ExecuteCommand(name string, args []string) error {
+	return p.executor.ExecuteCommand(name, args)
+}
+
+// ToolManager exposes the underlying tool manager for advanced integrations
+// (listing available versions, registering additional tools, ...).
+func (p *Project) ToolManager() *tools.Manager {
+	return p.manager
+}
@@ -0,0 +1,47 @@
+package mvx
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestOpenFindsProjectFromSubdirectory(t *testing.T) {
+	root := t.TempDir()
+	mvxDir := filepath.Join(root, ".mvx")
+	nested := filepath.Join(root, "src", "main")
+	for _, dir := range []string{mvxDir, nested} {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if err := os.WriteFile(filepath.Join(mvxDir, "config.json5"), []byte(`{
+		project: { name: "embedded" },
+		commands: {
+			build: { description: "Build it", script: "true" },
+		},
+	}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	project, err := Open(nested)
+	if err != nil {
+		t.Fatalf("Open() error = %v", err)
+	}
+
+	if project.Root != root {
+		t.Errorf("expected root %s, got %s", root, project.Root)
+	}
+	if project.Config.Project.Name != "embedded" {
+		t.Errorf("expected project name embedded, got %s", project.Config.Project.Name)
+	}
+	if desc := project.Commands()["build"]; desc != "Build it" {
+		t.Errorf("expected build command description, got %q", desc)
+	}
+}
+
+func TestOpenWithoutProject(t *testing.T) {
+	if _, err := Open(t.TempDir()); err == nil {
+		t.Error("expected error when no .mvx directory exists, got nil")
+	}
+}
@@ -0,0 +1,329 @@
+package script
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/shell"
+)
+
+// Package script implements mvx-script, a small embedded scripting language
+// for commands that outgrow shell one-liners, so projects stop carrying
+// helper Python scripts that themselves need bootstrapping. Scripts are
+// line-oriented:
+//
+//	# comments and blank lines are ignored
+//	set NAME = <value>            assign a variable (value is expanded)
+//	set NAME = $(command)         run a command and capture its output
+//	print <text>                  print expanded text
+//	run <command line>            execute a command line via mvx-shell
+//	fail <message>                abort the script with an error
+//	if <condition> ... else ... end
+//	for NAME in <words> ... end   iterate words (glob patterns expand)
+//
+// Conditions support ==, != comparisons, exists <path>, ! negation and bare
+// value truthiness (non-empty, not "false"/"0"). $NAME and ${NAME} expand
+// script variables first, then environment variables; the executor's
+// ${args.x}, ${env.X} and tool placeholders are interpolated before the
+// script runs, and variables are exported to commands started with run.
+
+// Engine interprets an mvx-script source
+type Engine struct {
+	workDir string
+	env     []string
+	vars    map[string]string
+	output  io.Writer // destination for script output (defaults to os.Stdout)
+}
+
+// NewEngine creates a script engine rooted in the given working directory
+func NewEngine(workDir string, env []string) *Engine {
+	return &Engine{
+		workDir: workDir,
+		env:     env,
+		vars:    make(map[string]string),
+	}
+}
+
+// SetOutput redirects script output, e.g. to a prefixing writer when
+// several commands run in parallel
+func (e *Engine) SetOutput(w io.Writer) {
+	e.output = w
+}
+
+func (e *Engine) stdout() io.Writer {
+	if e.output != nil {
+		return e.output
+	}
+	return os.Stdout
+}
+
+// Run parses and executes a script
+func (e *Engine) Run(source string) error {
+	lines := strings.Split(source, "\n")
+	block, next, err := parseBlock(lines, 0)
+	if err != nil {
+		return err
+	}
+	if next < len(lines) {
+		return fmt.Errorf("mvx-script: line %d: unexpected %q without a matching if or for", next+1, strings.TrimSpace(lines[next]))
+	}
+	return e.execute(block)
+}
+
+// statement is one parsed script statement; if and for carry nested blocks
+type statement struct {
+	line     int
+	kind     string // set, print, run, fail, if, for
+	name     string // variable name for set and for
+	arg      string // raw remainder of the line
+	body     []statement
+	elseBody []statement
+}
+
+// parseBlock parses statements starting at line index start, stopping at an
+// else, end or the end of input; it returns the block and the index of the
+// line it stopped on
+func parseBlock(lines []string, start int) ([]statement, int, error) {
+	var block []statement
+	i := start
+	for i < len(lines) {
+		line := strings.TrimSpace(lines[i])
+		if line == "" || strings.HasPrefix(line, "#") {
+			i++
+			continue
+		}
+		if line == "end" || line == "else" {
+			return block, i, nil
+		}
+
+		keyword, rest, _ := strings.Cut(line, " ")
+		rest = strings.TrimSpace(rest)
+		stmt := statement{line: i + 1, kind: keyword, arg: rest}
+
+		switch keyword {
+		case "set":
+			name, value, found := strings.Cut(rest, "=")
+			stmt.name = strings.TrimSpace(name)
+			if !found || stmt.name == "" {
+				return nil, 0, fmt.Errorf("mvx-script: line %d: expected set NAME = value", i+1)
+			}
+			stmt.arg = strings.TrimSpace(value)
+			i++
+		case "print", "run", "fail":
+			i++
+		case "if":
+			if rest == "" {
+				return nil, 0, fmt.Errorf("mvx-script: line %d: if requires a condition", i+1)
+			}
+			body, next, err := parseBlock(lines, i+1)
+			if err != nil {
+				return nil, 0, err
+			}
+			stmt.body = body
+			if next >= len(lines) {
+				return nil, 0, fmt.Errorf("mvx-script: line %d: if without matching end", i+1)
+			}
+			if strings.TrimSpace(lines[next]) == "else" {
+				elseBody, afterElse, err := parseBlock(lines, next+1)
+				if err != nil {
+					return nil, 0, err
+				}
+				if afterElse >= len(lines) || strings.TrimSpace(lines[afterElse]) != "end" {
+					return nil, 0, fmt.Errorf("mvx-script: line %d: else without matching end", next+1)
+				}
+				stmt.elseBody = elseBody
+				next = afterElse
+			}
+			i = next + 1
+		case "for":
+			name, items, found := strings.Cut(rest, " in ")
+			stmt.name = strings.TrimSpace(name)
+			stmt.arg = strings.TrimSpace(items)
+			if !found || stmt.name == "" || stmt.arg == "" {
+				return nil, 0, fmt.Errorf("mvx-script: line %d: expected for NAME in <words>", i+1)
+			}
+			body, next, err := parseBlock(lines, i+1)
+			if err != nil {
+				return nil, 0, err
+			}
+			if next >= len(lines) || strings.TrimSpace(lines[next]) != "end" {
+				return nil, 0, fmt.Errorf("mvx-script: line %d: for without matching end", i+1)
+			}
+			stmt.body = body
+			i = next + 1
+		default:
+			return nil, 0, fmt.Errorf("mvx-script: line %d: unknown statement %q", i+1, keyword)
+		}
+
+		block = append(block, stmt)
+	}
+	return block, i, nil
+}
+
+// execute runs a block of statements
+func (e *Engine) execute(block []statement) error {
+	for _, stmt := range block {
+		switch stmt.kind {
+		case "set":
+			value, err := e.evaluate(stmt.arg)
+			if err != nil {
+				return fmt.Errorf("mvx-script: line %d: %w", stmt.line, err)
+			}
+			e.vars[stmt.name] = value
+		case "print":
+			fmt.Fprintln(e.stdout(), e.expand(stmt.arg))
+		case "run":
+			if err := e.runCommand(e.expand(stmt.arg), nil); err != nil {
+				return fmt.Errorf("mvx-script: line %d: %w", stmt.line, err)
+			}
+		case "fail":
+			return fmt.Errorf("mvx-script: line %d: %s", stmt.line, e.expand(stmt.arg))
+		case "if":
+			ok, err := e.condition(stmt.arg)
+			if err != nil {
+				return fmt.Errorf("mvx-script: line %d: %w", stmt.line, err)
+			}
+			branch := stmt.body
+			if !ok {
+				branch = stmt.elseBody
+			}
+			if err := e.execute(branch); err != nil {
+				return err
+			}
+		case "for":
+			items, err := e.forItems(stmt.arg)
+			if err != nil {
+				return fmt.Errorf("mvx-script: line %d: %w", stmt.line, err)
+			}
+			saved, had := e.vars[stmt.name]
+			for _, item := range items {
+				e.vars[stmt.name] = item
+				if err := e.execute(stmt.body); err != nil {
+					return err
+				}
+			}
+			if had {
+				e.vars[stmt.name] = saved
+			} else {
+				delete(e.vars, stmt.name)
+			}
+		}
+	}
+	return nil
+}
+
+// evaluate resolves the right-hand side of a set: either a $(command)
+// capture or an expanded value
+func (e *Engine) evaluate(expr string) (string, error) {
+	if strings.HasPrefix(expr, "$(") && strings.HasSuffix(expr, ")") {
+		var buf strings.Builder
+		if err := e.runCommand(e.expand(expr[2:len(expr)-1]), &buf); err != nil {
+			return "", err
+		}
+		return strings.TrimSpace(buf.String()), nil
+	}
+	return e.expand(expr), nil
+}
+
+// runCommand executes a command line via mvx-shell with the script's
+// variables exported as environment variables
+func (e *Engine) runCommand(commandLine string, capture io.Writer) error {
+	env := make([]string, 0, len(e.env)+len(e.vars))
+	env = append(env, e.env...)
+	for name, value := range e.vars {
+		env = append(env, name+"="+value)
+	}
+
+	sh := shell.NewMVXShell(e.workDir, env)
+	switch {
+	case capture != nil:
+		sh.SetOutput(capture)
+	case e.output != nil:
+		sh.SetOutput(e.output)
+	}
+	return sh.Execute(commandLine)
+}
+
+// forItems expands the word list of a for statement, globbing patterns that
+// contain wildcards
+func (e *Engine) forItems(expr string) ([]string, error) {
+	var items []string
+	for _, word := range strings.Fields(e.expand(expr)) {
+		if !strings.ContainsAny(word, "*?[") {
+			items = append(items, word)
+			continue
+		}
+		pattern := word
+		if !filepath.IsAbs(pattern) {
+			pattern = filepath.Join(e.workDir, pattern)
+		}
+		matches, err := filepath.Glob(pattern)
+		if err != nil {
+			return nil, fmt.Errorf("invalid pattern %s: %w", word, err)
+		}
+		sort.Strings(matches)
+		for _, match := range matches {
+			if rel, err := filepath.Rel(e.workDir, match); err == nil && !strings.HasPrefix(rel, "..") {
+				match = rel
+			}
+			items = append(items, match)
+		}
+	}
+	return items, nil
+}
+
+// condition evaluates an if condition
+func (e *Engine) condition(expr string) (bool, error) {
+	expr = strings.TrimSpace(expr)
+	if negated, found := strings.CutPrefix(expr, "!"); found {
+		result, err := e.condition(negated)
+		return !result, err
+	}
+	if path, found := strings.CutPrefix(expr, "exists "); found {
+		path = e.expand(strings.TrimSpace(path))
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(e.workDir, path)
+		}
+		_, err := os.Stat(path)
+		return err == nil, nil
+	}
+	if left, right, found := strings.Cut(expr, "!="); found {
+		return e.operand(left) != e.operand(right), nil
+	}
+	if left, right, found := strings.Cut(expr, "=="); found {
+		return e.operand(left) == e.operand(right), nil
+	}
+	value := e.operand(expr)
+	return value != "" && value != "false" && value != "0", nil
+}
+
+// operand expands one side of a comparison, stripping surrounding quotes
+func (e *Engine) operand(text string) string {
+	value := e.expand(strings.TrimSpace(text))
+	if len(value) >= 2 {
+		if (value[0] == '"' && value[len(value)-1] == '"') || (value[0] == '\'' && value[len(value)-1] == '\'') {
+			value = value[1 : len(value)-1]
+		}
+	}
+	return value
+}
+
+// expand replaces $NAME and ${NAME} with script variables, falling back to
+// environment variables
+func (e *Engine) expand(text string) string {
+	return os.Expand(text, func(name string) string {
+		if value, ok := e.vars[name]; ok {
+			return value
+		}
+		for _, envVar := range e.env {
+			if value, found := strings.CutPrefix(envVar, name+"="); found {
+				return value
+			}
+		}
+		return ""
+	})
+}
@@ -0,0 +1,160 @@
+package script
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func runScript(t *testing.T, workDir, source string) (string, error) {
+	t.Helper()
+	engine := NewEngine(workDir, os.Environ())
+	var buf bytes.Buffer
+	engine.SetOutput(&buf)
+	err := engine.Run(source)
+	return buf.String(), err
+}
+
+func TestEngine_SetAndPrint(t *testing.T) {
+	output, err := runScript(t, t.TempDir(), `
+set NAME = world
+print hello $NAME
+`)
+	if err != nil {
+		t.Fatalf("Run() error = %v", err)
+	}
+	if strings.TrimSpace(output) != "hello world" {
+		t.Errorf("output = %q, want hello world", output)
+	}
+}
+
+func TestEngine_IfElse(t *testing.T) {
+	tests := []struct {
+		name   string
+		source string
+		want   string
+	}{
+		{
+			name: "equality taken",
+			source: `set MODE = prod
+if $MODE == prod
+print release
+else
+print snapshot
+end`,
+			want: "release",
+		},
+		{
+			name: "else branch",
+			source: `set MODE = dev
+if $MODE == "prod"
+print release
+else
+print snapshot
+end`,
+			want: "snapshot",
+		},
+		{
+			name: "negated exists",
+			source: `if !exists missing-file
+print absent
+end`,
+			want: "absent",
+		},
+		{
+			name: "truthiness",
+			source: `set FLAG = 0
+if $FLAG
+print on
+else
+print off
+end`,
+			want: "off",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			output, err := runScript(t, t.TempDir(), tt.source)
+			if err != nil {
+				t.Fatalf("Run() error = %v", err)
+			}
+			if strings.TrimSpace(output) != tt.want {
+				t.Errorf("output = %q, want %q", output, tt.want)
+			}
+		})
+	}
+}
+
+func TestEngine_ForLoopWithGlob(t *testing.T) {
+	tempDir := t.TempDir()
+	for _, name := range []string{"a.txt", "b.txt", "c.log"} {
+		if err := os.WriteFile(filepath.Join(tempDir, name), nil, 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	output, err := runScript(t, tempDir, `
+for F in *.txt
+print found $F
+end
+`)
+	if err != nil {
+		t.Fatalf("Run() error = %v", err)
+	}
+	lines := strings.Split(strings.TrimSpace(output), "\n")
+	if len(lines) != 2 || lines[0] != "found a.txt" || lines[1] != "found b.txt" {
+		t.Errorf("output lines = %v, want [found a.txt, found b.txt]", lines)
+	}
+}
+
+func TestEngine_RunAndCapture(t *testing.T) {
+	tempDir := t.TempDir()
+
+	output, err := runScript(t, tempDir, `
+set GREETING = $(echo captured)
+run mkdir out-dir
+print $GREETING
+`)
+	if err != nil {
+		t.Fatalf("Run() error = %v", err)
+	}
+	if strings.TrimSpace(output) != "captured" {
+		t.Errorf("output = %q, want captured", output)
+	}
+	if _, err := os.Stat(filepath.Join(tempDir, "out-dir")); err != nil {
+		t.Errorf("run did not create directory: %v", err)
+	}
+}
+
+func TestEngine_Fail(t *testing.T) {
+	_, err := runScript(t, t.TempDir(), `
+set REASON = broken
+fail build is $REASON
+`)
+	if err == nil || !strings.Contains(err.Error(), "build is broken") {
+		t.Errorf("Run() error = %v, want failure mentioning the reason", err)
+	}
+}
+
+func TestEngine_ParseErrors(t *testing.T) {
+	tests := []struct {
+		name   string
+		source string
+	}{
+		{"unknown statement", "frobnicate now"},
+		{"if without end", "if $X == 1\nprint y"},
+		{"for without in", "for X\nprint $X\nend"},
+		{"stray end", "print ok\nend"},
+		{"set without name", "set = value"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := runScript(t, t.TempDir(), tt.source); err == nil {
+				t.Errorf("Run(%q) should fail", tt.source)
+			}
+		})
+	}
+}
@@ -0,0 +1,209 @@
+// Package secrets stores credentials encrypted at rest so config files can
+// reference them via ${secret.NAME} without ever committing values.
+//
+// Secrets live in ~/.mvx/secrets.enc, encrypted with AES-256-GCM. The key is
+// read from MVX_SECRETS_KEY (hex, for CI) or from ~/.mvx/secrets.key, which
+// is generated on first use with 0600 permissions.
+package secrets
+
+import (
+	"crypto/aes"
+	"crypto/cipher"
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// EnvSecretsKey overrides the key file, e.g. in CI where no keychain exists
+const EnvSecretsKey = "MVX_SECRETS_KEY"
+
+// Resolve returns the value of a named secret. Environment variables take
+// precedence over the encrypted store, so CI can inject secrets without
+// provisioning a key file.
+func Resolve(name string) (string, error) {
+	if value := os.Getenv(name); value != "" {
+		return value, nil
+	}
+
+	store, err := load()
+	if err != nil {
+		return "", err
+	}
+	value, exists := store[name]
+	if !exists {
+		return "", fmt.Errorf("secret %s is not set (set it with 'mvx secret set %s' or export it)", name, name)
+	}
+	return value, nil
+}
+
+// Set stores a secret in the encrypted store
+func Set(name, value string) error {
+	store, err := load()
+	if err != nil {
+		return err
+	}
+	store[name] = value
+	return save(store)
+}
+
+// Remove deletes a secret from the encrypted store
+func Remove(name string) error {
+	store, err := load()
+	if err != nil {
+		return err
+	}
+	if _, exists := store[name]; !exists {
+		return fmt.Errorf("secret %s is not set", name)
+	}
+	delete(store, name)
+	return save(store)
+}
+
+// List returns the names (never the values) of all stored secrets
+func List() ([]string, error) {
+	store, err := load()
+	if err != nil {
+		return nil, err
+	}
+	var names []string
+	for name := range store {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names, nil
+}
+
+// mvxHome returns the mvx home directory (~/.mvx or MVX_HOME)
+func mvxHome() (string, error) {
+	return util.MvxHome()
+}
+
+// loadKey returns the AES-256 key, generating the key file on first use
+func loadKey() ([]byte, error) {
+	if keyHex := os.Getenv(EnvSecretsKey); keyHex != "" {
+		key, err := hex.DecodeString(keyHex)
+		if err != nil || len(key) != 32 {
+			return nil, fmt.Errorf("%s must be 64 hex characters (32 bytes)", EnvSecretsKey)
+		}
+		return key, nil
+	}
+
+	mvxDir, err := mvxHome()
+	if err != nil {
+		return nil, err
+	}
+	keyPath := filepath.Join(mvxDir, "secrets.key")
+
+	if data, err := os.ReadFile(keyPath); err == nil {
+		key, err := hex.DecodeString(string(data))
+		if err != nil || len(key) != 32 {
+			return nil, fmt.Errorf("corrupt key file %s", keyPath)
+		}
+		return key, nil
+	}
+
+	// First use: generate a key and store it with restrictive permissions
+	key := make([]byte, 32)
+	if _, err := rand.Read(key); err != nil {
+		return nil, err
+	}
+	if err := os.MkdirAll(mvxDir, 0755); err != nil {
+		return nil, err
+	}
+	if err := os.WriteFile(keyPath, []byte(hex.EncodeToString(key)), 0600); err != nil {
+		return nil, fmt.Errorf("failed to write key file: %w", err)
+	}
+	return key, nil
+}
+
+// storePath returns the path of the encrypted secrets file
+func storePath() (string, error) {
+	mvxDir, err := mvxHome()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(mvxDir, "secrets.enc"), nil
+}
+
+// load decrypts and parses the secrets store (empty when absent)
+func load() (map[string]string, error) {
+	path, err := storePath()
+	if err != nil {
+		return nil, err
+	}
+
+	ciphertext, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return make(map[string]string), nil
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	aead, err := newAEAD()
+	if err != nil {
+		return nil, err
+	}
+	if len(ciphertext) < aead.NonceSize() {
+		return nil, fmt.Errorf("corrupt secrets store %s", path)
+	}
+
+	nonce, sealed := ciphertext[:aead.NonceSize()], ciphertext[aead.NonceSize():]
+	plaintext, err := aead.Open(nil, nonce, sealed, nil)
+	if err != nil {
+		return nil, fmt.Errorf("failed to decrypt secrets store (wrong key?): %w", err)
+	}
+
+	store := make(map[string]string)
+	if err := json.Unmarshal(plaintext, &store); err != nil {
+		return nil, err
+	}
+	return store, nil
+}
+
+// save encrypts and writes the secrets store
+func save(store map[string]string) error {
+	path, err := storePath()
+	if err != nil {
+		return err
+	}
+
+	plaintext, err := json.Marshal(store)
+	if err != nil {
+		return err
+	}
+
+	aead, err := newAEAD()
+	if err != nil {
+		return err
+	}
+	nonce := make([]byte, aead.NonceSize())
+	if _, err := rand.Read(nonce); err != nil {
+		return err
+	}
+	ciphertext := aead.Seal(nonce, nonce, plaintext, nil)
+
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(path, ciphertext, 0600)
+}
+
+// newAEAD builds the AES-256-GCM cipher from the configured key
+func newAEAD() (cipher.AEAD, error) {
+	key, err := loadKey()
+	if err != nil {
+		return nil, err
+	}
+	block, err := aes.NewCipher(key)
+	if err != nil {
+		return nil, err
+	}
+	return cipher.NewGCM(block)
+}
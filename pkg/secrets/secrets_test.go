@@ -0,0 +1,66 @@
+package secrets
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestSecretRoundTrip(t *testing.T) {
+	tempDir := t.TempDir()
+	t.Setenv("HOME", tempDir)
+	t.Setenv("USERPROFILE", tempDir) // Windows
+	os.Unsetenv(EnvSecretsKey)
+
+	if err := Set("NEXUS_TOKEN", "s3cret-value"); err != nil {
+		t.Fatalf("Set() error = %v", err)
+	}
+
+	value, err := Resolve("NEXUS_TOKEN")
+	if err != nil {
+		t.Fatalf("Resolve() error = %v", err)
+	}
+	if value != "s3cret-value" {
+		t.Errorf("Expected s3cret-value, got %s", value)
+	}
+
+	// The value must not appear in plaintext on disk
+	data, err := os.ReadFile(filepath.Join(tempDir, ".mvx", "secrets.enc"))
+	if err != nil {
+		t.Fatalf("Failed to read secrets store: %v", err)
+	}
+	if strings.Contains(string(data), "s3cret-value") {
+		t.Error("Secret stored in plaintext")
+	}
+
+	names, err := List()
+	if err != nil {
+		t.Fatalf("List() error = %v", err)
+	}
+	if len(names) != 1 || names[0] != "NEXUS_TOKEN" {
+		t.Errorf("Expected [NEXUS_TOKEN], got %v", names)
+	}
+
+	if err := Remove("NEXUS_TOKEN"); err != nil {
+		t.Fatalf("Remove() error = %v", err)
+	}
+	if _, err := Resolve("NEXUS_TOKEN"); err == nil {
+		t.Error("Expected error resolving removed secret")
+	}
+}
+
+func TestResolveEnvPrecedence(t *testing.T) {
+	tempDir := t.TempDir()
+	t.Setenv("HOME", tempDir)
+	t.Setenv("USERPROFILE", tempDir)
+	t.Setenv("CI_TOKEN", "from-env")
+
+	value, err := Resolve("CI_TOKEN")
+	if err != nil {
+		t.Fatalf("Resolve() error = %v", err)
+	}
+	if value != "from-env" {
+		t.Errorf("Expected env value to win, got %s", value)
+	}
+}
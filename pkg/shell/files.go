@@ -0,0 +1,242 @@
+package shell
+
+import (
+	"archive/zip"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Portable file and download built-ins, implemented natively so command
+// scripts stop shelling out to platform-specific utilities like cp, xcopy,
+// curl and unzip.
+
+// move renames a file or directory, falling back to copy-and-remove when the
+// destination is on a different filesystem
+func (s *MVXShell) move(args []string) error {
+	paths := filterFlags(args)
+	if len(paths) != 2 {
+		return fmt.Errorf("move: expected 2 arguments (source, destination), got %d", len(paths))
+	}
+
+	src := paths[0]
+	dst := paths[1]
+	if !filepath.IsAbs(src) {
+		src = filepath.Join(s.workDir, src)
+	}
+	if !filepath.IsAbs(dst) {
+		dst = filepath.Join(s.workDir, dst)
+	}
+
+	// Moving into an existing directory keeps the source name, like mv
+	if info, err := os.Stat(dst); err == nil && info.IsDir() {
+		dst = filepath.Join(dst, filepath.Base(src))
+	}
+
+	if err := os.Rename(src, dst); err == nil {
+		return nil
+	}
+	if err := copyPath(src, dst); err != nil {
+		return fmt.Errorf("move: failed to move %s to %s: %w", src, dst, err)
+	}
+	if err := os.RemoveAll(src); err != nil {
+		return fmt.Errorf("move: failed to remove %s: %w", src, err)
+	}
+	return nil
+}
+
+// glob prints the paths matching a pattern, one per line, so scripts can
+// enumerate files without ls or find
+func (s *MVXShell) glob(args []string) error {
+	patterns := filterFlags(args)
+	if len(patterns) == 0 {
+		return fmt.Errorf("glob: missing pattern argument")
+	}
+
+	var matches []string
+	for _, pattern := range patterns {
+		base := pattern
+		if !filepath.IsAbs(base) {
+			base = filepath.Join(s.workDir, base)
+		}
+		found, err := filepath.Glob(base)
+		if err != nil {
+			return fmt.Errorf("glob: invalid pattern %s: %w", pattern, err)
+		}
+		for _, match := range found {
+			// Report paths relative to the working directory when possible
+			if rel, err := filepath.Rel(s.workDir, match); err == nil && !strings.HasPrefix(rel, "..") {
+				match = rel
+			}
+			matches = append(matches, match)
+		}
+	}
+
+	sort.Strings(matches)
+	for _, match := range matches {
+		fmt.Fprintln(s.stdout(), match)
+	}
+	return nil
+}
+
+// download fetches a URL to a local file without relying on curl or wget
+// being installed
+func (s *MVXShell) download(args []string) error {
+	paths := filterFlags(args)
+	if len(paths) != 2 {
+		return fmt.Errorf("download: expected 2 arguments (url, destination), got %d", len(paths))
+	}
+	if util.IsOffline() {
+		return fmt.Errorf("download: offline mode is enabled (MVX_OFFLINE)")
+	}
+
+	url := paths[0]
+	dst := paths[1]
+	if !filepath.IsAbs(dst) {
+		dst = filepath.Join(s.workDir, dst)
+	}
+
+	client := util.HTTPClient(10 * time.Minute)
+	resp, err := client.Get(url)
+	if err != nil {
+		return fmt.Errorf("download: failed to fetch %s: %w", url, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("download: %s returned %s", url, resp.Status)
+	}
+
+	if err := os.MkdirAll(filepath.Dir(dst), 0755); err != nil {
+		return fmt.Errorf("download: failed to create directory for %s: %w", dst, err)
+	}
+	file, err := os.Create(dst)
+	if err != nil {
+		return fmt.Errorf("download: failed to create %s: %w", dst, err)
+	}
+	defer file.Close()
+
+	written, err := io.Copy(file, resp.Body)
+	if err != nil {
+		return fmt.Errorf("download: failed to write %s: %w", dst, err)
+	}
+	fmt.Fprintf(s.stdout(), "⬇️  %s -> %s (%d bytes)\n", url, paths[1], written)
+	return nil
+}
+
+// unzip extracts a zip archive into a destination directory (the working
+// directory by default)
+func (s *MVXShell) unzip(args []string) error {
+	paths := filterFlags(args)
+	if len(paths) < 1 || len(paths) > 2 {
+		return fmt.Errorf("unzip: expected 1 or 2 arguments (archive, [destination]), got %d", len(paths))
+	}
+
+	archive := paths[0]
+	if !filepath.IsAbs(archive) {
+		archive = filepath.Join(s.workDir, archive)
+	}
+	dest := s.workDir
+	if len(paths) == 2 {
+		dest = paths[1]
+		if !filepath.IsAbs(dest) {
+			dest = filepath.Join(s.workDir, dest)
+		}
+	}
+
+	reader, err := zip.OpenReader(archive)
+	if err != nil {
+		return fmt.Errorf("unzip: failed to open %s: %w", archive, err)
+	}
+	defer reader.Close()
+
+	for _, file := range reader.File {
+		path := filepath.Join(dest, file.Name)
+
+		// Guard against zip-slip: entries must stay inside the destination
+		if !strings.HasPrefix(path, filepath.Clean(dest)+string(os.PathSeparator)) && path != filepath.Clean(dest) {
+			return fmt.Errorf("unzip: entry %s escapes destination directory", file.Name)
+		}
+
+		if file.FileInfo().IsDir() {
+			if err := os.MkdirAll(path, 0755); err != nil {
+				return fmt.Errorf("unzip: failed to create directory %s: %w", path, err)
+			}
+			continue
+		}
+
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			return fmt.Errorf("unzip: failed to create directory for %s: %w", path, err)
+		}
+		if err := extractZipEntry(file, path); err != nil {
+			return fmt.Errorf("unzip: failed to extract %s: %w", file.Name, err)
+		}
+	}
+	return nil
+}
+
+// extractZipEntry writes a single archive entry to disk, preserving the
+// executable bit from the archive
+func extractZipEntry(file *zip.File, path string) error {
+	src, err := file.Open()
+	if err != nil {
+		return err
+	}
+	defer src.Close()
+
+	mode := file.Mode().Perm()
+	if mode == 0 {
+		mode = 0644
+	}
+	dst, err := os.OpenFile(path, os.O_WRONLY|os.O_CREATE|os.O_TRUNC, mode)
+	if err != nil {
+		return err
+	}
+	defer dst.Close()
+
+	_, err = io.Copy(dst, src)
+	return err
+}
+
+// copyPath copies a file or directory tree from src to dst
+func copyPath(src, dst string) error {
+	info, err := os.Stat(src)
+	if err != nil {
+		return err
+	}
+	if !info.IsDir() {
+		return copyFile(src, dst)
+	}
+
+	if err := os.MkdirAll(dst, 0755); err != nil {
+		return err
+	}
+	entries, err := os.ReadDir(src)
+	if err != nil {
+		return err
+	}
+	for _, entry := range entries {
+		if err := copyPath(filepath.Join(src, entry.Name()), filepath.Join(dst, entry.Name())); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// filterFlags drops "-"-prefixed arguments so spellings like rm -r and
+// mkdir -p work; the built-ins are always recursive
+func filterFlags(args []string) []string {
+	var paths []string
+	for _, arg := range args {
+		if !strings.HasPrefix(arg, "-") {
+			paths = append(paths, arg)
+		}
+	}
+	return paths
+}
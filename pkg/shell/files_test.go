@@ -0,0 +1,159 @@
+package shell
+
+import (
+	"archive/zip"
+	"bytes"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestMVXShell_Move(t *testing.T) {
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+
+	srcPath := filepath.Join(tempDir, "source.txt")
+	if err := os.WriteFile(srcPath, []byte("content"), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+
+	if err := shell.move([]string{"source.txt", "renamed.txt"}); err != nil {
+		t.Errorf("move() error = %v", err)
+	}
+	if _, err := os.Stat(srcPath); !os.IsNotExist(err) {
+		t.Errorf("move() did not remove source %s", srcPath)
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, "renamed.txt"))
+	if err != nil || string(content) != "content" {
+		t.Errorf("move() destination content = %q, err = %v", content, err)
+	}
+
+	// Moving into an existing directory keeps the source name
+	if err := os.Mkdir(filepath.Join(tempDir, "dir"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := shell.move([]string{"renamed.txt", "dir"}); err != nil {
+		t.Errorf("move() into directory error = %v", err)
+	}
+	if _, err := os.Stat(filepath.Join(tempDir, "dir", "renamed.txt")); err != nil {
+		t.Errorf("move() into directory did not place the file: %v", err)
+	}
+}
+
+func TestMVXShell_CopyDirectory(t *testing.T) {
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+
+	if err := os.MkdirAll(filepath.Join(tempDir, "src", "nested"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(tempDir, "src", "nested", "file.txt"), []byte("deep"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := shell.copy([]string{"-r", "src", "dst"}); err != nil {
+		t.Errorf("copy() directory error = %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, "dst", "nested", "file.txt"))
+	if err != nil || string(content) != "deep" {
+		t.Errorf("copy() directory content = %q, err = %v", content, err)
+	}
+}
+
+func TestMVXShell_RemoveRecursiveFlag(t *testing.T) {
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+
+	dirPath := filepath.Join(tempDir, "dir")
+	if err := os.MkdirAll(filepath.Join(dirPath, "nested"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := shell.remove([]string{"-rf", "dir"}); err != nil {
+		t.Errorf("remove() error = %v", err)
+	}
+	if _, err := os.Stat(dirPath); !os.IsNotExist(err) {
+		t.Errorf("remove() did not remove directory %s", dirPath)
+	}
+}
+
+func TestMVXShell_Glob(t *testing.T) {
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+
+	for _, name := range []string{"a.jar", "b.jar", "c.txt"} {
+		if err := os.WriteFile(filepath.Join(tempDir, name), nil, 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	var buf bytes.Buffer
+	shell.SetOutput(&buf)
+	if err := shell.glob([]string{"*.jar"}); err != nil {
+		t.Errorf("glob() error = %v", err)
+	}
+
+	lines := strings.Fields(buf.String())
+	if len(lines) != 2 || lines[0] != "a.jar" || lines[1] != "b.jar" {
+		t.Errorf("glob() output = %v, want [a.jar b.jar]", lines)
+	}
+}
+
+func TestMVXShell_Download(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Write([]byte("payload"))
+	}))
+	defer server.Close()
+
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+	shell.SetOutput(&bytes.Buffer{})
+
+	if err := shell.download([]string{server.URL + "/file", filepath.Join("out", "file.bin")}); err != nil {
+		t.Errorf("download() error = %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, "out", "file.bin"))
+	if err != nil || string(content) != "payload" {
+		t.Errorf("download() content = %q, err = %v", content, err)
+	}
+
+	if err := shell.download([]string{server.URL + "/missing"}); err == nil {
+		t.Error("download() should require url and destination")
+	}
+}
+
+func TestMVXShell_Unzip(t *testing.T) {
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, os.Environ())
+
+	archivePath := filepath.Join(tempDir, "archive.zip")
+	archive, err := os.Create(archivePath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	writer := zip.NewWriter(archive)
+	entry, err := writer.Create("dir/hello.txt")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := entry.Write([]byte("hello")); err != nil {
+		t.Fatal(err)
+	}
+	if err := writer.Close(); err != nil {
+		t.Fatal(err)
+	}
+	if err := archive.Close(); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := shell.unzip([]string{"archive.zip", "extracted"}); err != nil {
+		t.Errorf("unzip() error = %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(tempDir, "extracted", "dir", "hello.txt"))
+	if err != nil || string(content) != "hello" {
+		t.Errorf("unzip() content = %q, err = %v", content, err)
+	}
+}
@@ -16,6 +16,8 @@ func GenerateHook(shellType, mvxPath string) (string, error) {
 		return generateFishHook(mvxPath), nil
 	case "powershell":
 		return generatePowerShellHook(mvxPath), nil
+	case "nu", "nushell":
+		return generateNuHook(mvxPath), nil
 	default:
 		return "", fmt.Errorf("unsupported shell: %s", shellType)
 	}
@@ -62,7 +64,7 @@ _mvx_hook() {
 
             # Run mvx env to get environment variables
             local env_output
-            if env_output=$("$mvx_script" env --shell bash 2>/dev/null); then
+            if env_output=$("$mvx_script" env --shell bash --hook 2>/dev/null); then
                 eval "$env_output"
             fi
         fi
@@ -128,7 +130,7 @@ _mvx_hook() {
 
             # Run mvx env to get environment variables
             local env_output
-            if env_output=$("$mvx_script" env --shell zsh 2>/dev/null); then
+            if env_output=$("$mvx_script" env --shell zsh --hook 2>/dev/null); then
                 eval "$env_output"
             fi
         fi
@@ -192,7 +194,7 @@ function _mvx_hook --on-variable PWD
             end
 
             # Run mvx env to get environment variables
-            set -l env_output ($mvx_script env --shell fish 2>/dev/null)
+            set -l env_output ($mvx_script env --shell fish --hook 2>/dev/null)
             if test $status -eq 0
                 eval $env_output
             end
@@ -262,7 +264,7 @@ function global:_mvx_hook {
             }
 
             # Run mvx env to get environment variables
-            $env_output = & $mvx_script env --shell powershell 2>$null
+            $env_output = & $mvx_script env --shell powershell --hook 2>$null
             if ($LASTEXITCODE -eq 0) {
                 Invoke-Expression $env_output
             }
@@ -298,3 +300,44 @@ function global:mvx-deactivate {
 }
 `, mvxPathEscaped)
 }
+
+// generateNuHook generates nushell integration. Nushell has no eval, so the
+// hook loads the JSON environment from 'mvx env --shell nu' via load-env on
+// every directory change.
+func generateNuHook(mvxPath string) string {
+	return fmt.Sprintf(`# mvx shell integration for nushell
+# Generated by: mvx activate nu
+# Add to your config: mvx activate nu | save -f ~/.config/nushell/mvx-hook.nu
+# then 'source ~/.config/nushell/mvx-hook.nu' from config.nu
+
+$env.config = ($env.config | upsert hooks.env_change.PWD {|config|
+    let existing = ($config | get -i hooks.env_change.PWD | default [])
+    $existing | append {|before, after|
+        # Look for a .mvx directory in the new directory or its parents
+        mut dir = $after
+        mut mvx_dir = ""
+        loop {
+            if ($dir | path join ".mvx" | path exists) {
+                $mvx_dir = $dir
+                break
+            }
+            let parent = ($dir | path dirname)
+            if $parent == $dir { break }
+            $dir = $parent
+        }
+
+        if $mvx_dir != "" {
+            # Prefer the project bootstrap script, fall back to global mvx
+            mut mvx_bin = ($mvx_dir | path join "mvx")
+            if not ($mvx_bin | path exists) {
+                $mvx_bin = "%s"
+            }
+            let env_json = (do -i { ^$mvx_bin env --shell nu --hook } | complete)
+            if $env_json.exit_code == 0 and ($env_json.stdout | str trim) != "" {
+                $env_json.stdout | from json | load-env
+            }
+        }
+    }
+})
+`, mvxPath)
+}
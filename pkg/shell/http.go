@@ -0,0 +1,159 @@
+package shell
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// httpRequest performs an HTTP request without relying on curl/wget being
+// installed, so deployment and webhook steps work on every platform.
+// Usage: http <METHOD> <URL> [options]
+//
+//	-H "Name: Value"   add a request header (repeatable; values support $VAR)
+//	-d <body>          request body (use @file to read the body from a file)
+//	-o <file>          write the response body to a file
+//	-e <VAR>           capture the response body into a variable for later commands
+//	--retry <n>        retry up to n times on connection errors and 5xx responses
+func (s *MVXShell) httpRequest(args []string) error {
+	if len(args) < 2 {
+		return fmt.Errorf("http: usage: http <METHOD> <URL> [-H header]... [-d body] [-o file] [-e VAR] [--retry n]")
+	}
+
+	method := strings.ToUpper(args[0])
+	url := args[1]
+
+	var headers []string
+	var body, outputFile, captureVar string
+	retries := 0
+
+	rest := args[2:]
+	for i := 0; i < len(rest); i++ {
+		flagValue := func(flag string) (string, error) {
+			if i+1 >= len(rest) {
+				return "", fmt.Errorf("http: %s requires a value", flag)
+			}
+			i++
+			return rest[i], nil
+		}
+
+		var err error
+		switch rest[i] {
+		case "-H":
+			var header string
+			if header, err = flagValue("-H"); err == nil {
+				headers = append(headers, header)
+			}
+		case "-d":
+			body, err = flagValue("-d")
+		case "-o":
+			outputFile, err = flagValue("-o")
+		case "-e":
+			captureVar, err = flagValue("-e")
+		case "--retry":
+			var value string
+			if value, err = flagValue("--retry"); err == nil {
+				if retries, err = strconv.Atoi(value); err != nil {
+					err = fmt.Errorf("http: invalid --retry count %s", value)
+				}
+			}
+		default:
+			err = fmt.Errorf("http: unknown option %s", rest[i])
+		}
+		if err != nil {
+			return err
+		}
+	}
+
+	// @file reads the request body from a file, like curl -d @file
+	if strings.HasPrefix(body, "@") {
+		path := strings.TrimPrefix(body, "@")
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(s.workDir, path)
+		}
+		data, err := os.ReadFile(path)
+		if err != nil {
+			return fmt.Errorf("http: failed to read body file: %w", err)
+		}
+		body = string(data)
+	}
+
+	client := util.HTTPClient(60 * time.Second)
+
+	var resp *http.Response
+	var lastErr error
+	for attempt := 0; attempt <= retries; attempt++ {
+		if attempt > 0 {
+			delay := time.Duration(attempt) * 2 * time.Second
+			fmt.Printf("🔁 http: retrying in %s (attempt %d/%d)\n", delay, attempt+1, retries+1)
+			time.Sleep(delay)
+		}
+
+		req, err := http.NewRequest(method, url, strings.NewReader(body))
+		if err != nil {
+			return fmt.Errorf("http: invalid request: %w", err)
+		}
+		for _, header := range headers {
+			name, value, found := strings.Cut(header, ":")
+			if !found {
+				return fmt.Errorf("http: invalid header %q (expected \"Name: Value\")", header)
+			}
+			req.Header.Set(strings.TrimSpace(name), strings.TrimSpace(value))
+		}
+
+		resp, lastErr = client.Do(req)
+		if lastErr != nil {
+			continue
+		}
+		if resp.StatusCode >= 500 && attempt < retries {
+			resp.Body.Close()
+			lastErr = fmt.Errorf("http: %s %s returned %s", method, url, resp.Status)
+			continue
+		}
+		lastErr = nil
+		break
+	}
+	if lastErr != nil {
+		return fmt.Errorf("http: request failed: %w", lastErr)
+	}
+	defer resp.Body.Close()
+
+	responseBody, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return fmt.Errorf("http: failed to read response: %w", err)
+	}
+
+	if resp.StatusCode >= 400 {
+		return fmt.Errorf("http: %s %s returned %s: %s", method, url, resp.Status, strings.TrimSpace(string(responseBody)))
+	}
+
+	switch {
+	case outputFile != "":
+		path := outputFile
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(s.workDir, path)
+		}
+		if err := os.WriteFile(path, responseBody, 0644); err != nil {
+			return fmt.Errorf("http: failed to write %s: %w", outputFile, err)
+		}
+		fmt.Printf("🌐 %s %s -> %s (%d bytes to %s)\n", method, url, resp.Status, len(responseBody), outputFile)
+	case captureVar != "":
+		// Make the response available as $VAR to subsequent commands
+		s.env = append(s.env, captureVar+"="+strings.TrimSpace(string(responseBody)))
+		fmt.Printf("🌐 %s %s -> %s (captured into $%s)\n", method, url, resp.Status, captureVar)
+	default:
+		fmt.Printf("🌐 %s %s -> %s\n", method, url, resp.Status)
+		if len(responseBody) > 0 {
+			fmt.Println(string(responseBody))
+		}
+	}
+
+	return nil
+}
@@ -0,0 +1,46 @@
+package shell
+
+import (
+	"fmt"
+	"net"
+	"net/http"
+	"path/filepath"
+	"strconv"
+)
+
+// serve runs a tiny static file server, so dev commands like "serve docs"
+// or "serve target/site 9000" work identically on every platform.
+// Usage: serve [directory] [port] — directory defaults to the working
+// directory and port defaults to 8000.
+func (s *MVXShell) serve(args []string) error {
+	if len(args) > 2 {
+		return fmt.Errorf("serve: expected at most 2 arguments (directory, port), got %d", len(args))
+	}
+
+	dir := s.workDir
+	port := 8000
+
+	for _, arg := range args {
+		if p, err := strconv.Atoi(arg); err == nil {
+			port = p
+			continue
+		}
+		dir = arg
+		if !filepath.IsAbs(dir) {
+			dir = filepath.Join(s.workDir, dir)
+		}
+	}
+
+	listener, err := net.Listen("tcp", fmt.Sprintf("127.0.0.1:%d", port))
+	if err != nil {
+		return fmt.Errorf("serve: failed to listen on port %d: %w", port, err)
+	}
+
+	fmt.Printf("🌐 Serving %s at http://127.0.0.1:%d/ (Ctrl+C to stop)\n", dir, port)
+
+	server := &http.Server{Handler: http.FileServer(http.Dir(dir))}
+	if err := server.Serve(listener); err != nil && err != http.ErrServerClosed {
+		return fmt.Errorf("serve: %w", err)
+	}
+	return nil
+}
@@ -2,6 +2,7 @@ package shell
 
 import (
 	"fmt"
+	"io"
 	"os"
 	"os/exec"
 	"path/filepath"
@@ -15,6 +16,7 @@ import (
 type MVXShell struct {
 	workDir string
 	env     []string
+	output  io.Writer // destination for command output (defaults to os.Stdout)
 }
 
 // NewMVXShell creates a new cross-platform shell instance
@@ -25,6 +27,29 @@ func NewMVXShell(workDir string, env []string) *MVXShell {
 	}
 }
 
+// SetOutput redirects command output, e.g. to a prefixing writer when
+// commands run in parallel
+func (s *MVXShell) SetOutput(w io.Writer) {
+	s.output = w
+}
+
+// stdout returns the configured output destination
+func (s *MVXShell) stdout() io.Writer {
+	if s.output != nil {
+		return s.output
+	}
+	return os.Stdout
+}
+
+// stderr returns the error output destination, following the configured
+// writer so parallel output stays attributable
+func (s *MVXShell) stderr() io.Writer {
+	if s.output != nil {
+		return s.output
+	}
+	return os.Stderr
+}
+
 // Execute executes a script using the cross-platform interpreter
 func (s *MVXShell) Execute(script string) error {
 	chains, err := parseCommands(script)
@@ -454,8 +479,20 @@ func (s *MVXShell) executeCommand(cmd Command) error {
 		return s.remove(expandedCmd.Args)
 	case "copy", "cp":
 		return s.copy(expandedCmd.Args)
+	case "move", "mv":
+		return s.move(expandedCmd.Args)
+	case "glob":
+		return s.glob(expandedCmd.Args)
+	case "download":
+		return s.download(expandedCmd.Args)
+	case "unzip":
+		return s.unzip(expandedCmd.Args)
 	case "open":
 		return s.open(expandedCmd.Args)
+	case "serve":
+		return s.serve(expandedCmd.Args)
+	case "http":
+		return s.httpRequest(expandedCmd.Args)
 	default:
 		// Execute as external command
 		return s.executeExternal(expandedCmd)
@@ -484,7 +521,7 @@ func (s *MVXShell) changeDirectory(args []string) error {
 
 // echo prints text to stdout (variable expansion handled at command level)
 func (s *MVXShell) echo(args []string, cmdEnv map[string]string) error {
-	fmt.Println(strings.Join(args, " "))
+	fmt.Fprintln(s.stdout(), strings.Join(args, " "))
 	return nil
 }
 
@@ -569,13 +606,15 @@ func (s *MVXShell) makeDirectory(args []string) error {
 	return nil
 }
 
-// remove removes files and directories
+// remove removes files and directories (always recursive, so flags like
+// -r and -rf are accepted and ignored)
 func (s *MVXShell) remove(args []string) error {
-	if len(args) == 0 {
+	paths := filterFlags(args)
+	if len(paths) == 0 {
 		return fmt.Errorf("rm: missing file argument")
 	}
 
-	for _, path := range args {
+	for _, path := range paths {
 		if !filepath.IsAbs(path) {
 			path = filepath.Join(s.workDir, path)
 		}
@@ -586,14 +625,16 @@ func (s *MVXShell) remove(args []string) error {
 	return nil
 }
 
-// copy copies files
+// copy copies files and directory trees (always recursive, so flags like
+// -r are accepted and ignored)
 func (s *MVXShell) copy(args []string) error {
-	if len(args) != 2 {
-		return fmt.Errorf("copy: expected 2 arguments (source, destination), got %d", len(args))
+	paths := filterFlags(args)
+	if len(paths) != 2 {
+		return fmt.Errorf("copy: expected 2 arguments (source, destination), got %d", len(paths))
 	}
 
-	src := args[0]
-	dst := args[1]
+	src := paths[0]
+	dst := paths[1]
 
 	if !filepath.IsAbs(src) {
 		src = filepath.Join(s.workDir, src)
@@ -602,28 +643,36 @@ func (s *MVXShell) copy(args []string) error {
 		dst = filepath.Join(s.workDir, dst)
 	}
 
-	return copyFile(src, dst)
+	// Copying into an existing directory keeps the source name, like cp
+	if srcInfo, err := os.Stat(src); err == nil && !srcInfo.IsDir() {
+		if dstInfo, err := os.Stat(dst); err == nil && dstInfo.IsDir() {
+			dst = filepath.Join(dst, filepath.Base(src))
+		}
+	}
+
+	return copyPath(src, dst)
 }
 
-// open opens a file or directory using the platform's default application
+// open opens a file, directory or URL using the platform's default application
 func (s *MVXShell) open(args []string) error {
 	if len(args) != 1 {
 		return fmt.Errorf("open: expected 1 argument, got %d", len(args))
 	}
 
-	path := args[0]
-	if !filepath.IsAbs(path) {
-		path = filepath.Join(s.workDir, path)
+	target := args[0]
+	if !isURL(target) && !filepath.IsAbs(target) {
+		target = filepath.Join(s.workDir, target)
 	}
 
 	var cmd *exec.Cmd
 	switch runtime.GOOS {
 	case "windows":
-		cmd = exec.Command("explorer", path)
+		// rundll32 handles both files and URLs without cmd quoting issues
+		cmd = exec.Command("rundll32", "url.dll,FileProtocolHandler", target)
 	case "darwin":
-		cmd = exec.Command("open", path)
+		cmd = exec.Command("open", target)
 	default:
-		cmd = exec.Command("xdg-open", path)
+		cmd = exec.Command("xdg-open", target)
 	}
 
 	cmd.Dir = s.workDir
@@ -631,6 +680,11 @@ func (s *MVXShell) open(args []string) error {
 	return cmd.Run()
 }
 
+// isURL reports whether a string is an http(s) URL rather than a file path
+func isURL(s string) bool {
+	return strings.HasPrefix(s, "http://") || strings.HasPrefix(s, "https://")
+}
+
 // executeExternal executes an external command
 func (s *MVXShell) executeExternal(cmd Command) error {
 	util.LogVerbose("mvx-shell executing external command: %s %v", cmd.Name, cmd.Args)
@@ -676,8 +730,8 @@ func (s *MVXShell) executeExternal(cmd Command) error {
 	}
 
 	execCmd.Env = env
-	execCmd.Stdout = os.Stdout
-	execCmd.Stderr = os.Stderr
+	execCmd.Stdout = s.stdout()
+	execCmd.Stderr = s.stderr()
 	execCmd.Stdin = os.Stdin
 
 	return execCmd.Run()
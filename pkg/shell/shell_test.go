@@ -1,6 +1,9 @@
 package shell
 
 import (
+	"fmt"
+	"net/http"
+	"net/http/httptest"
 	"os"
 	"path/filepath"
 	"strings"
@@ -968,3 +971,48 @@ func TestCommandChainOperatorLogic(t *testing.T) {
 		os.Remove(testDir)
 	})
 }
+
+func TestHTTPBuiltin(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("X-Test") != "yes" {
+			http.Error(w, "missing header", http.StatusBadRequest)
+			return
+		}
+		fmt.Fprint(w, "hello")
+	}))
+	defer server.Close()
+
+	tempDir := t.TempDir()
+	shell := NewMVXShell(tempDir, []string{})
+
+	// Response body written to a file
+	if err := shell.httpRequest([]string{"GET", server.URL, "-H", "X-Test: yes", "-o", "out.txt"}); err != nil {
+		t.Fatalf("httpRequest() error = %v", err)
+	}
+	data, err := os.ReadFile(filepath.Join(tempDir, "out.txt"))
+	if err != nil {
+		t.Fatalf("Failed to read output file: %v", err)
+	}
+	if string(data) != "hello" {
+		t.Errorf("Expected response body %q, got %q", "hello", string(data))
+	}
+
+	// Response body captured into a variable for later commands
+	if err := shell.httpRequest([]string{"GET", server.URL, "-H", "X-Test: yes", "-e", "RESPONSE"}); err != nil {
+		t.Fatalf("httpRequest() error = %v", err)
+	}
+	found := false
+	for _, envVar := range shell.env {
+		if envVar == "RESPONSE=hello" {
+			found = true
+		}
+	}
+	if !found {
+		t.Errorf("Expected RESPONSE=hello in shell environment, got %v", shell.env)
+	}
+
+	// 4xx responses are errors
+	if err := shell.httpRequest([]string{"GET", server.URL}); err == nil {
+		t.Error("Expected error for 400 response, got nil")
+	}
+}
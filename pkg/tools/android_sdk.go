@@ -0,0 +1,248 @@
+package tools
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Compile-time interface validation
+var _ Tool = (*AndroidSdkTool)(nil)
+var _ EnvironmentProvider = (*AndroidSdkTool)(nil)
+var _ DependencyProvider = (*AndroidSdkTool)(nil)
+
+// AndroidSdkTool implements Tool interface for Android SDK command-line tools management
+// Downloads commandlinetools from https://dl.google.com/android/repository/ and drives
+// sdkmanager non-interactively to install the SDK packages requested in the configuration.
+type AndroidSdkTool struct {
+	*BaseTool
+}
+
+func getSdkmanagerBinaryName() string {
+	if NewPlatformMapper().IsWindows() {
+		return BinarySdkmanager + ".bat"
+	}
+	return BinarySdkmanager
+}
+
+// NewAndroidSdkTool creates a new Android SDK tool instance
+func NewAndroidSdkTool(manager *Manager) *AndroidSdkTool {
+	return &AndroidSdkTool{
+		BaseTool: NewBaseTool(manager, ToolAndroidSdk, getSdkmanagerBinaryName()),
+	}
+}
+
+// Install downloads commandlinetools and installs the configured SDK packages
+func (a *AndroidSdkTool) Install(version string, cfg config.ToolConfig) error {
+	// Check if we should use system tool instead of downloading
+	if UseSystemTool(a.GetToolName()) {
+		return a.StandardInstall(version, cfg, a.getDownloadURL)
+	}
+
+	// Create installation directory (this becomes ANDROID_HOME)
+	installDir, err := a.CreateInstallDir(version, "")
+	if err != nil {
+		return InstallError(a.GetToolName(), version, fmt.Errorf("failed to create install directory: %w", err))
+	}
+
+	a.PrintDownloadMessage(version)
+
+	// Download and extract commandlinetools
+	if err := a.DownloadAndExtract(a.getDownloadURL(version), installDir, version, cfg); err != nil {
+		return InstallError(a.GetToolName(), version, err)
+	}
+
+	// Accept licenses and install requested SDK packages non-interactively
+	if err := a.installPackages(installDir, cfg); err != nil {
+		return InstallError(a.GetToolName(), version, err)
+	}
+
+	return nil
+}
+
+// installPackages accepts SDK licenses and installs the packages listed in the configuration
+func (a *AndroidSdkTool) installPackages(sdkRoot string, cfg config.ToolConfig) error {
+	sdkmanager, err := a.findSdkmanager(sdkRoot)
+	if err != nil {
+		return err
+	}
+
+	// Accept all licenses up-front so package installation never prompts
+	fmt.Printf("  📄 Accepting Android SDK licenses...\n")
+	if err := a.runSdkmanager(sdkmanager, sdkRoot, []string{"--licenses"}); err != nil {
+		return fmt.Errorf("failed to accept Android SDK licenses: %w", err)
+	}
+
+	if len(cfg.Packages) == 0 {
+		util.LogVerbose("No Android SDK packages configured, skipping sdkmanager install")
+		return nil
+	}
+
+	fmt.Printf("  📦 Installing %d Android SDK package(s)...\n", len(cfg.Packages))
+	if err := a.runSdkmanager(sdkmanager, sdkRoot, cfg.Packages); err != nil {
+		return fmt.Errorf("failed to install Android SDK packages %v: %w", cfg.Packages, err)
+	}
+
+	return nil
+}
+
+// runSdkmanager runs sdkmanager with the given arguments, feeding "y" answers on stdin
+// so license prompts are accepted without user interaction
+func (a *AndroidSdkTool) runSdkmanager(sdkmanager, sdkRoot string, args []string) error {
+	fullArgs := append([]string{fmt.Sprintf("--sdk_root=%s", sdkRoot)}, args...)
+	cmd := exec.Command(sdkmanager, fullArgs...)
+	// sdkmanager prompts "Accept? (y/N)" per license; feed enough answers for all of them
+	cmd.Stdin = strings.NewReader(strings.Repeat("y\n", 100))
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("sdkmanager %v failed: %w\nOutput: %s", args, err, output)
+	}
+	util.LogVerbose("sdkmanager %v completed successfully", args)
+	return nil
+}
+
+// findSdkmanager locates the sdkmanager binary below the SDK root
+func (a *AndroidSdkTool) findSdkmanager(sdkRoot string) (string, error) {
+	pathResolver := NewPathResolver(a.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(sdkRoot, a.GetBinaryName())
+	if err != nil {
+		return "", fmt.Errorf("sdkmanager not found under %s: %w", sdkRoot, err)
+	}
+	return filepath.Join(binDir, a.GetBinaryName()), nil
+}
+
+// IsInstalled checks if the specified version is installed
+func (a *AndroidSdkTool) IsInstalled(version string, cfg config.ToolConfig) bool {
+	return a.StandardIsInstalled(version, cfg, a.GetPath)
+}
+
+// GetPath returns the binary path for the specified version (for PATH management)
+func (a *AndroidSdkTool) GetPath(version string, cfg config.ToolConfig) (string, error) {
+	return a.StandardGetPath(version, cfg, a.getInstalledPath)
+}
+
+func (a *AndroidSdkTool) GetBinaryName() string {
+	return getSdkmanagerBinaryName()
+}
+
+// getInstalledPath returns the path for an installed Android SDK version
+func (a *AndroidSdkTool) getInstalledPath(version string, cfg config.ToolConfig) (string, error) {
+	installDir := a.manager.GetToolVersionDir(a.GetToolName(), version, "")
+	pathResolver := NewPathResolver(a.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(installDir, a.GetBinaryName())
+	if err != nil {
+		return "", err
+	}
+	return binDir, nil
+}
+
+// Verify checks if the installation is working correctly
+func (a *AndroidSdkTool) Verify(version string, cfg config.ToolConfig) error {
+	verifyConfig := VerificationConfig{
+		BinaryName:  a.GetBinaryName(),
+		VersionArgs: []string{"--version"},
+		DebugInfo:   false,
+	}
+	return a.StandardVerifyWithConfig(version, cfg, verifyConfig)
+}
+
+// ListVersions returns available commandlinetools versions for installation
+func (a *AndroidSdkTool) ListVersions() ([]string, error) {
+	// Google does not publish a version index for commandlinetools,
+	// so we maintain a list of known build numbers (newest first)
+	return []string{
+		"11076708", // commandlinetools 12.0
+		"10406996", // commandlinetools 11.0
+		"9477386",  // commandlinetools 10.0
+		"8512546",  // commandlinetools 8.0
+	}, nil
+}
+
+// GetDisplayName returns the human-readable name for the Android SDK (implements ToolMetadataProvider)
+func (a *AndroidSdkTool) GetDisplayName() string {
+	return "Android SDK Command-line Tools"
+}
+
+// GetDependencies returns the list of tools that the Android SDK depends on (implements DependencyProvider)
+// sdkmanager is a JVM application and needs a JDK to run
+func (a *AndroidSdkTool) GetDependencies() []string {
+	return []string{ToolJava}
+}
+
+// SetupEnvironment sets up Android-specific environment variables (implements EnvironmentProvider)
+func (a *AndroidSdkTool) SetupEnvironment(version string, cfg config.ToolConfig, envManager *EnvironmentManager) error {
+	// ANDROID_HOME is the SDK root (the installation directory itself)
+	sdkRoot := a.manager.GetToolVersionDir(a.GetToolName(), version, "")
+	envManager.SetEnv(EnvAndroidHome, sdkRoot)
+	util.LogVerbose("Set %s=%s for Android SDK %s", EnvAndroidHome, sdkRoot, version)
+
+	// Add platform-tools (adb, fastboot) to PATH if installed via sdkmanager
+	platformTools := filepath.Join(sdkRoot, "platform-tools")
+	if _, err := os.Stat(platformTools); err == nil {
+		envManager.AddToPath(platformTools)
+		util.LogVerbose("Added %s to PATH for Android SDK %s", platformTools, version)
+	}
+
+	return nil
+}
+
+// ResolveVersion resolves an Android SDK version specification to a concrete version
+func (a *AndroidSdkTool) ResolveVersion(versionSpec, distribution string) (string, error) {
+	versions, err := a.ListVersions()
+	if err != nil {
+		return "", err
+	}
+
+	// commandlinetools versions are plain build numbers, so semantic version
+	// matching does not apply - support "latest" and exact build numbers only
+	if versionSpec == "latest" || versionSpec == "" {
+		return versions[0], nil
+	}
+
+	for _, v := range versions {
+		if v == versionSpec {
+			return v, nil
+		}
+	}
+
+	// Accept unknown build numbers as-is (newer than our known list)
+	return versionSpec, nil
+}
+
+// getDownloadURL returns the download URL for the specified version
+func (a *AndroidSdkTool) getDownloadURL(version string) string {
+	platformMapper := NewPlatformMapper()
+
+	var osName string
+	switch platformMapper.GetOS() {
+	case "darwin":
+		osName = "mac"
+	case "windows":
+		osName = "win"
+	default:
+		osName = "linux"
+	}
+
+	return fmt.Sprintf("%s/commandlinetools-%s-%s_latest.zip", AndroidRepositoryBase, osName, version)
+}
+
+// GetDownloadURL implements URLProvider interface for the Android SDK
+func (a *AndroidSdkTool) GetDownloadURL(version string) string {
+	return a.getDownloadURL(version)
+}
+
+// GetChecksum implements ChecksumProvider interface for the Android SDK
+// Google does not publish standalone checksum files for commandlinetools archives
+func (a *AndroidSdkTool) GetChecksum(version string, cfg config.ToolConfig, filename string) (ChecksumInfo, error) {
+	return ChecksumInfo{}, fmt.Errorf("no checksum source available for Android SDK commandlinetools")
+}
+
+// SupportsChecksumVerification returns whether this tool supports checksum verification
+func (a *AndroidSdkTool) SupportsChecksumVerification() bool {
+	return false
+}
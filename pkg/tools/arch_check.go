@@ -0,0 +1,133 @@
+package tools
+
+import (
+	"debug/elf"
+	"debug/macho"
+	"debug/pe"
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// binaryPlatform describes the OS/arch a binary was built for, as detected
+// from its executable header
+type binaryPlatform struct {
+	OS   string // "linux", "darwin", "windows" or "" when unknown
+	Arch string // "amd64", "arm64", ... or "" when unknown
+}
+
+// detectBinaryPlatform inspects the executable header of the file at path and
+// returns the platform it was built for. Script wrappers and unknown formats
+// return an empty platform (no error) since they are platform-neutral.
+func detectBinaryPlatform(path string) (binaryPlatform, error) {
+	if f, err := elf.Open(path); err == nil {
+		defer f.Close()
+		return binaryPlatform{OS: "linux", Arch: elfArchName(f.Machine)}, nil
+	}
+
+	if f, err := macho.Open(path); err == nil {
+		defer f.Close()
+		return binaryPlatform{OS: "darwin", Arch: machoArchName(f.Cpu)}, nil
+	}
+
+	if f, err := macho.OpenFat(path); err == nil {
+		defer f.Close()
+		// Universal binaries run on any architecture they contain
+		for _, arch := range f.Arches {
+			if machoArchName(arch.Cpu) == runtime.GOARCH {
+				return binaryPlatform{OS: "darwin", Arch: runtime.GOARCH}, nil
+			}
+		}
+		if len(f.Arches) > 0 {
+			return binaryPlatform{OS: "darwin", Arch: machoArchName(f.Arches[0].Cpu)}, nil
+		}
+		return binaryPlatform{OS: "darwin"}, nil
+	}
+
+	if f, err := pe.Open(path); err == nil {
+		defer f.Close()
+		return binaryPlatform{OS: "windows", Arch: peArchName(f.Machine)}, nil
+	}
+
+	// Not a recognized native executable (shell script, batch file, ...)
+	return binaryPlatform{}, nil
+}
+
+func elfArchName(machine elf.Machine) string {
+	switch machine {
+	case elf.EM_X86_64:
+		return "amd64"
+	case elf.EM_AARCH64:
+		return "arm64"
+	case elf.EM_386:
+		return "386"
+	case elf.EM_ARM:
+		return "arm"
+	case elf.EM_RISCV:
+		return "riscv64"
+	default:
+		return ""
+	}
+}
+
+func machoArchName(cpu macho.Cpu) string {
+	switch cpu {
+	case macho.CpuAmd64:
+		return "amd64"
+	case macho.CpuArm64:
+		return "arm64"
+	case macho.Cpu386:
+		return "386"
+	default:
+		return ""
+	}
+}
+
+func peArchName(machine uint16) string {
+	switch machine {
+	case pe.IMAGE_FILE_MACHINE_AMD64:
+		return "amd64"
+	case pe.IMAGE_FILE_MACHINE_ARM64:
+		return "arm64"
+	case pe.IMAGE_FILE_MACHINE_I386:
+		return "386"
+	default:
+		return ""
+	}
+}
+
+// checkToolArchitecture verifies that an installed tool binary matches the
+// current OS/arch before it is used. A mismatched binary (e.g. an x64 JDK
+// restored from a CI cache onto an ARM runner) would otherwise fail later
+// with a cryptic "exec format error".
+func checkToolArchitecture(tool Tool, toolName, version, binDir string) error {
+	if binDir == "" {
+		return nil // system tool from PATH, nothing to check
+	}
+
+	exe := filepath.Join(binDir, tool.GetBinaryName())
+	if _, err := os.Stat(exe); err != nil {
+		return nil // binary layout differs, skip the check rather than guessing
+	}
+
+	platform, err := detectBinaryPlatform(exe)
+	if err != nil || platform.OS == "" {
+		return nil // unreadable or platform-neutral (script wrapper)
+	}
+
+	if platform.OS != runtime.GOOS || (platform.Arch != "" && platform.Arch != runtime.GOARCH) {
+		installDir := filepath.Dir(binDir)
+		return fmt.Errorf("%s %s was installed for %s/%s but this machine is %s/%s\n"+
+			"  The installation at %s likely came from a cache created on a different machine.\n"+
+			"  Fix: remove the directory and re-run 'mvx setup' to install the correct build:\n"+
+			"    rm -rf %q",
+			toolName, version, platform.OS, platform.Arch, runtime.GOOS, runtime.GOARCH,
+			installDir, installDir)
+	}
+
+	util.LogVerbose("Architecture check passed for %s %s (%s/%s)", toolName, version, platform.OS, platform.Arch)
+	return nil
+}
@@ -187,6 +187,10 @@ func (b *BaseTool) Download(url, version string, cfg config.ToolConfig) (string,
 	// Get the tool instance for checksum verification
 	if tool, err := b.manager.GetTool(b.toolName); err == nil {
 		downloadConfig.Tool = tool
+		// Tools with alternate download hosts get a fallback URL chain
+		if provider, ok := tool.(FallbackURLProvider); ok {
+			downloadConfig.FallbackURLs = fallbackURLsExcluding(provider.GetFallbackURLs(version), url)
+		}
 	}
 
 	// Perform robust download with checksum verification
@@ -198,16 +202,37 @@ func (b *BaseTool) Download(url, version string, cfg config.ToolConfig) (string,
 
 	// Show user-friendly URL instead of long redirect URLs
 	displayURL := getUserFriendlyURL(result.FinalURL)
-	fmt.Printf("  📦 Downloaded %d bytes from %s\n", result.Size, displayURL)
+	util.LogInfo("  📦 Downloaded %d bytes from %s", result.Size, displayURL)
 
 	// Return the path to the downloaded file
 	return tmpFile.Name(), nil
 }
 
-// Extract extracts an archive file to the destination directory
+// Extract extracts an archive file to the destination directory. Extraction
+// goes through a staging directory that is renamed into place, so a crashed
+// or concurrent run never leaves a half-extracted install behind.
 func (b *BaseTool) Extract(archivePath, destDir string) error {
+	staging := destDir + ".partial"
+	os.RemoveAll(staging)
+
 	// Use automatic archive type detection based on file extension
-	return ExtractArchive(archivePath, destDir)
+	if err := ExtractArchive(archivePath, staging); err != nil {
+		os.RemoveAll(staging)
+		return err
+	}
+
+	// Hardlink files identical to previously installed versions to save disk space
+	dedupDirectory(staging)
+
+	if err := os.RemoveAll(destDir); err != nil {
+		os.RemoveAll(staging)
+		return fmt.Errorf("failed to replace installation directory: %w", err)
+	}
+	if err := os.Rename(staging, destDir); err != nil {
+		os.RemoveAll(staging)
+		return fmt.Errorf("failed to finalize installation directory: %w", err)
+	}
+	return nil
 }
 
 // VerificationConfig contains configuration for tool verification
@@ -471,7 +496,7 @@ func (b *BaseTool) GetDisplayName() string {
 // PrintDownloadMessage prints a standardized download message
 func (b *BaseTool) PrintDownloadMessage(version string) {
 	toolDisplayName := b.GetDisplayName()
-	fmt.Printf("  ⏳ Downloading %s %s...\n", toolDisplayName, version)
+	util.LogInfo("  ⏳ Downloading %s %s...", toolDisplayName, version)
 }
 
 // StandardInstall provides a standard installation flow for most tools
@@ -482,8 +507,8 @@ func (b *BaseTool) StandardInstall(version string, cfg config.ToolConfig, getDow
 
 		// Try primary binary name in PATH
 		if toolPath, err := exec.LookPath(b.binaryName); err == nil {
-			fmt.Printf("  🔗 Using system %s from PATH: %s\n", b.toolName, toolPath)
-			fmt.Printf("  ✅ System %s configured (mvx will use system PATH)\n", b.toolName)
+			util.LogInfo("  🔗 Using system %s from PATH: %s", b.toolName, toolPath)
+			util.LogInfo("  ✅ System %s configured (mvx will use system PATH)", b.toolName)
 			return nil
 		}
 
@@ -499,6 +524,21 @@ func (b *BaseTool) StandardInstall(version string, cfg config.ToolConfig, getDow
 	// Get download URL
 	downloadURL := getDownloadURL(version)
 
+	// Internal registries override the public download URL and may pin a checksum
+	if artifact, fromRegistry, regErr := b.manager.registryArtifactFor(b.toolName, version); fromRegistry {
+		if regErr == nil && artifact.downloadURL() != "" {
+			downloadURL = artifact.downloadURL()
+			if artifact.Checksum != nil && cfg.Checksum == nil {
+				cfg.Checksum = artifact.Checksum
+			}
+			util.LogVerbose("Using registry download URL for %s %s: %s", b.toolName, version, downloadURL)
+		} else if reg, _ := b.manager.registryFor(b.toolName); reg.Exclusive {
+			return InstallError(b.toolName, version, fmt.Errorf("exclusive registry did not provide a download URL: %v", regErr))
+		} else {
+			util.LogVerbose("Registry artifact lookup for %s %s failed, using public URL: %v", b.toolName, version, regErr)
+		}
+	}
+
 	// Print download message
 	b.PrintDownloadMessage(version)
 
@@ -528,7 +568,7 @@ func (b *BaseTool) StandardInstall(version string, cfg config.ToolConfig, getDow
 				// }
 				return InstallError(b.toolName, version, fmt.Errorf("installation verification failed: %w", err))
 			}
-			fmt.Printf("  ✅ %s %s installation verification successful\n", b.toolName, version)
+			util.LogInfo("  ✅ %s %s installation verification successful", b.toolName, version)
 		}
 	}
 
@@ -604,15 +644,25 @@ type InstalledVersion struct {
 
 // ListInstalledVersions returns all installed versions for this tool and distribution.
 // If distribution is empty, returns all distributions.
+// Versions provisioned in the read-only shared cache layer are included too.
 func (b *BaseTool) ListInstalledVersions(distribution string) []InstalledVersion {
+	var installed []InstalledVersion
+
+	// Shared read-only layer first (per-user installs below may shadow them)
+	for _, shared := range listSharedInstalledVersions(b.toolName) {
+		if distribution != "" && shared.Distribution != distribution {
+			continue
+		}
+		installed = append(installed, shared)
+	}
+
 	toolsDir := b.manager.GetToolsDir()
 	toolDir := filepath.Join(toolsDir, b.toolName)
 	entries, err := os.ReadDir(toolDir)
 	if err != nil {
-		return nil
+		return installed
 	}
 
-	var installed []InstalledVersion
 	for _, entry := range entries {
 		if !entry.IsDir() {
 			continue
@@ -744,6 +794,7 @@ func (b *BaseTool) StandardIsInstalled(versionSpec string, cfg config.ToolConfig
 	}
 
 	b.clearPathCache()
+	b.manager.recordInstall()
 	util.LogVerbose("Successfully installed %s %s on demand", b.toolName, targetVersion)
 	return true
 }
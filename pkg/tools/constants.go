@@ -34,6 +34,16 @@ const (
 	GoGithubAPIBase    = "https://api.github.com/repos/golang/go"
 	ApacheMavenBase    = "https://archive.apache.org/dist/maven"
 	ApacheDistBase     = "https://dist.apache.org/repos/dist/release/maven"
+
+	AndroidRepositoryBase = "https://dl.google.com/android/repository"
+
+	JdkJavaNetBase = "https://jdk.java.net"
+
+	DotNetReleaseMetadataBase = "https://builds.dotnet.microsoft.com/dotnet/release-metadata"
+
+	// NodeJSUnofficialDistBase hosts the musl builds for Alpine, which
+	// nodejs.org does not publish
+	NodeJSUnofficialDistBase = "https://unofficial-builds.nodejs.org/download/release"
 )
 
 // Environment Variable Names
@@ -58,6 +68,9 @@ const (
 	EnvNodeHome  = "NODE_HOME"
 	EnvGoRoot    = "GOROOT"
 	EnvGoPath    = "GOPATH"
+
+	EnvAndroidHome = "ANDROID_HOME"
+	EnvDotNetRoot  = "DOTNET_ROOT"
 )
 
 // File Extensions
@@ -85,11 +98,14 @@ const (
 
 // Tool Names (for consistency)
 const (
-	ToolJava  = "java"
-	ToolMaven = "maven"
-	ToolMvnd  = "mvnd"
-	ToolNode  = "node"
-	ToolGo    = "go"
+	ToolJava       = "java"
+	ToolMaven      = "maven"
+	ToolMvnd       = "mvnd"
+	ToolNode       = "node"
+	ToolGo         = "go"
+	ToolAndroidSdk = "android-sdk"
+	ToolDotNet     = "dotnet"
+	ToolJReleaser  = "jreleaser"
 )
 
 // Platform Strings
@@ -103,9 +119,12 @@ const (
 
 // Binary Names
 const (
-	BinaryJava  = "java"
-	BinaryMaven = "mvn"
-	BinaryMvnd  = "mvnd"
-	BinaryNode  = "node"
-	BinaryGo    = "go"
+	BinaryJava       = "java"
+	BinaryMaven      = "mvn"
+	BinaryMvnd       = "mvnd"
+	BinaryNode       = "node"
+	BinaryGo         = "go"
+	BinarySdkmanager = "sdkmanager"
+	BinaryDotNet     = "dotnet"
+	BinaryJReleaser  = "jreleaser"
 )
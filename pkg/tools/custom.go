@@ -0,0 +1,212 @@
+package tools
+
+import (
+	"fmt"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Compile-time interface validation
+var _ Tool = (*CustomTool)(nil)
+
+// CustomTool implements Tool interface for URL-based custom tools declared
+// entirely in the project configuration. This lets teams pin internal CLIs
+// without waiting for first-class support in pkg/tools:
+//
+//	tools: {
+//	  "my-cli": {
+//	    version: "1.2.3",
+//	    type: "custom",
+//	    url: "https://internal.example.com/my-cli/{version}/my-cli-{os}-{arch}.tar.gz",
+//	    binaryPath: "bin",
+//	    checksum: { type: "sha256", value: "..." },
+//	  }
+//	}
+//
+// Per-platform URLs can be given via "urls" keyed by os-arch (e.g. "linux-amd64"),
+// with an optional "default" entry as fallback.
+type CustomTool struct {
+	*BaseTool
+	toolConfig config.ToolConfig // declaration from the project configuration
+}
+
+// NewCustomTool creates a custom tool instance from its configuration entry
+func NewCustomTool(manager *Manager, name string, cfg config.ToolConfig) *CustomTool {
+	return &CustomTool{
+		BaseTool:   NewBaseTool(manager, name, customBinaryName(name, cfg)),
+		toolConfig: cfg,
+	}
+}
+
+// customBinaryName returns the binary name for a custom tool, defaulting to the tool name
+func customBinaryName(name string, cfg config.ToolConfig) string {
+	binaryName := cfg.BinaryName
+	if binaryName == "" {
+		binaryName = name
+	}
+	if NewPlatformMapper().IsWindows() && !strings.HasSuffix(binaryName, ExtExe) {
+		binaryName += ExtExe
+	}
+	return binaryName
+}
+
+// Install downloads and installs the specified custom tool version
+func (c *CustomTool) Install(version string, cfg config.ToolConfig) error {
+	return c.StandardInstall(version, cfg, c.getDownloadURL)
+}
+
+// IsInstalled checks if the specified version is installed
+func (c *CustomTool) IsInstalled(version string, cfg config.ToolConfig) bool {
+	return c.StandardIsInstalled(version, cfg, c.GetPath)
+}
+
+// GetPath returns the binary path for the specified version (for PATH management)
+func (c *CustomTool) GetPath(version string, cfg config.ToolConfig) (string, error) {
+	return c.StandardGetPath(version, cfg, c.getInstalledPath)
+}
+
+// getInstalledPath returns the path for an installed custom tool version
+func (c *CustomTool) getInstalledPath(version string, cfg config.ToolConfig) (string, error) {
+	installDir := c.manager.GetToolVersionDir(c.GetToolName(), version, "")
+
+	// If the config declares where the binary lives, trust it
+	binaryPath := cfg.BinaryPath
+	if binaryPath == "" {
+		binaryPath = c.toolConfig.BinaryPath
+	}
+	if binaryPath != "" {
+		return filepath.Join(installDir, filepath.FromSlash(binaryPath)), nil
+	}
+
+	// Otherwise search the extracted archive for the binary
+	pathResolver := NewPathResolver(c.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(installDir, c.GetBinaryName())
+	if err != nil {
+		return "", err
+	}
+	return binDir, nil
+}
+
+// Verify checks if the installation is working correctly
+func (c *CustomTool) Verify(version string, cfg config.ToolConfig) error {
+	// Custom tools may not support a --version flag, so only verify that the
+	// binary exists at the expected location
+	binDir, err := c.getInstalledPath(version, cfg)
+	if err != nil {
+		return VerifyError(c.GetToolName(), version, fmt.Errorf("failed to get binary path: %w", err))
+	}
+	if !c.BaseTool.IsInstalled(binDir) {
+		return VerifyError(c.GetToolName(), version, fmt.Errorf("binary %s not found in %s", c.GetBinaryName(), binDir))
+	}
+	return nil
+}
+
+// ListVersions returns available versions for installation
+// GitHub-backed tools list the repository's releases; other custom tools have
+// no registry, so only the configured version is known
+func (c *CustomTool) ListVersions() ([]string, error) {
+	if c.toolConfig.Repo != "" {
+		return c.listGitHubVersions()
+	}
+	if c.toolConfig.Version != "" {
+		return []string{c.toolConfig.Version}, nil
+	}
+	return nil, nil
+}
+
+// GetDisplayName returns the human-readable name for this custom tool
+func (c *CustomTool) GetDisplayName() string {
+	return fmt.Sprintf("%s (custom)", c.GetToolName())
+}
+
+// ResolveVersion resolves a custom tool version specification to a concrete version
+// Custom tool versions are opaque strings, so they resolve to themselves
+func (c *CustomTool) ResolveVersion(versionSpec, distribution string) (string, error) {
+	if versionSpec == "" {
+		return "", fmt.Errorf("custom tool %s requires an explicit version", c.GetToolName())
+	}
+	return versionSpec, nil
+}
+
+// getDownloadURL returns the download URL for the specified version
+func (c *CustomTool) getDownloadURL(version string) string {
+	// GitHub-backed tools match the asset pattern against the real release
+	if c.toolConfig.Repo != "" {
+		url, err := c.resolveGitHubAssetURL(version)
+		if err != nil {
+			util.LogVerbose("Failed to resolve GitHub asset for %s %s: %v", c.GetToolName(), version, err)
+			return ""
+		}
+		return url
+	}
+
+	platformMapper := NewPlatformMapper()
+
+	// Per-platform URL map takes precedence over the URL template
+	urlTemplate := c.toolConfig.URL
+	if len(c.toolConfig.URLs) > 0 {
+		if url, exists := c.toolConfig.URLs[platformMapper.GetGenericPlatform()]; exists {
+			urlTemplate = url
+		} else if url, exists := c.toolConfig.URLs["default"]; exists {
+			urlTemplate = url
+		} else {
+			util.LogVerbose("No URL configured for custom tool %s on platform %s",
+				c.GetToolName(), platformMapper.GetGenericPlatform())
+		}
+	}
+
+	// Expand placeholders
+	replacer := strings.NewReplacer(
+		"{version}", version,
+		"{os}", platformMapper.GetOS(),
+		"{arch}", platformMapper.GetArch(),
+	)
+	return replacer.Replace(urlTemplate)
+}
+
+// GetDownloadURL implements URLProvider interface for custom tools
+func (c *CustomTool) GetDownloadURL(version string) string {
+	return c.getDownloadURL(version)
+}
+
+// GetChecksum implements ChecksumProvider interface for custom tools
+// The checksum comes straight from the project configuration
+func (c *CustomTool) GetChecksum(version string, cfg config.ToolConfig, filename string) (ChecksumInfo, error) {
+	checksumCfg := cfg.Checksum
+	if checksumCfg == nil {
+		checksumCfg = c.toolConfig.Checksum
+	}
+	if checksumCfg == nil || (checksumCfg.Value == "" && checksumCfg.URL == "") {
+		return ChecksumInfo{}, fmt.Errorf("no checksum configured for custom tool %s", c.GetToolName())
+	}
+
+	checksumType := ChecksumType(checksumCfg.Type)
+	if checksumType == "" {
+		checksumType = SHA256
+	}
+
+	return ChecksumInfo{
+		Type:     checksumType,
+		Value:    checksumCfg.Value,
+		URL:      checksumCfg.URL,
+		Filename: checksumCfg.Filename,
+	}, nil
+}
+
+// RegisterCustomTools registers all custom tools declared in the configuration
+// so they behave like first-class tools (install, PATH setup, tool commands)
+func (m *Manager) RegisterCustomTools(cfg *config.Config) {
+	for toolName, toolConfig := range cfg.Tools {
+		if toolConfig.Type != "custom" {
+			continue
+		}
+		if _, exists := m.tools[toolName]; exists {
+			continue // already registered (built-in tools win)
+		}
+		m.RegisterTool(NewCustomTool(m, toolName, toolConfig))
+		util.LogVerbose("Registered custom tool: %s", toolName)
+	}
+}
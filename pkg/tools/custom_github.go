@@ -0,0 +1,149 @@
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+// assetRegexp compiles the configured asset pattern for a version and the
+// current platform. The pattern is a regular expression where {version},
+// {os} and {arch} expand (regex-quoted) before compilation, e.g.
+// "mytool-{version}-{os}-{arch}\.tar\.gz".
+func assetRegexp(pattern, toolVersion string) (*regexp.Regexp, error) {
+	platformMapper := NewPlatformMapper()
+	replacer := strings.NewReplacer(
+		"{version}", regexp.QuoteMeta(toolVersion),
+		"{os}", regexp.QuoteMeta(platformMapper.GetOS()),
+		"{arch}", regexp.QuoteMeta(platformMapper.GetArch()),
+	)
+	compiled, err := regexp.Compile("^" + replacer.Replace(pattern) + "$")
+	if err != nil {
+		return nil, fmt.Errorf("invalid asset pattern %s: %w", pattern, err)
+	}
+	return compiled, nil
+}
+
+// githubRelease is the subset of the GitHub release API used for asset matching
+type githubRelease struct {
+	TagName    string `json:"tag_name"`
+	Prerelease bool   `json:"prerelease"`
+	Assets     []struct {
+		Name               string `json:"name"`
+		BrowserDownloadURL string `json:"browser_download_url"`
+	} `json:"assets"`
+}
+
+// fetchGitHubRelease fetches a release by tag, trying both "v1.2.3" and "1.2.3"
+func (c *CustomTool) fetchGitHubRelease(toolVersion string) (*githubRelease, error) {
+	for _, tag := range []string{"v" + toolVersion, toolVersion} {
+		url := fmt.Sprintf("%s/repos/%s/releases/tags/%s", GitHubAPIBase, c.toolConfig.Repo, tag)
+		resp, err := c.manager.Get(url)
+		if err != nil {
+			continue
+		}
+		if resp.StatusCode != 200 {
+			resp.Body.Close()
+			continue
+		}
+		var release githubRelease
+		err = json.NewDecoder(resp.Body).Decode(&release)
+		resp.Body.Close()
+		if err != nil {
+			return nil, err
+		}
+		return &release, nil
+	}
+	return nil, fmt.Errorf("no release found for %s %s in %s", c.GetToolName(), toolVersion, c.toolConfig.Repo)
+}
+
+// resolveGitHubAssetURL matches the configured asset pattern against the real
+// release assets and returns the download URL of the single match.
+func (c *CustomTool) resolveGitHubAssetURL(toolVersion string) (string, error) {
+	pattern, err := assetRegexp(c.toolConfig.AssetPattern, toolVersion)
+	if err != nil {
+		return "", err
+	}
+
+	release, err := c.fetchGitHubRelease(toolVersion)
+	if err != nil {
+		return "", err
+	}
+
+	var matches []string
+	var names []string
+	for _, asset := range release.Assets {
+		names = append(names, asset.Name)
+		if pattern.MatchString(asset.Name) {
+			matches = append(matches, asset.BrowserDownloadURL)
+		}
+	}
+
+	switch len(matches) {
+	case 1:
+		return matches[0], nil
+	case 0:
+		return "", fmt.Errorf("asset pattern %s matched no assets of %s %s (available: %s)",
+			c.toolConfig.AssetPattern, c.toolConfig.Repo, release.TagName, strings.Join(names, ", "))
+	default:
+		return "", fmt.Errorf("asset pattern %s matched %d assets of %s %s, make it more specific",
+			c.toolConfig.AssetPattern, len(matches), c.toolConfig.Repo, release.TagName)
+	}
+}
+
+// listGitHubVersions lists release versions of the configured GitHub repository
+func (c *CustomTool) listGitHubVersions() ([]string, error) {
+	url := fmt.Sprintf("%s/repos/%s/releases?per_page=50", GitHubAPIBase, c.toolConfig.Repo)
+	resp, err := c.manager.Get(url)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != 200 {
+		return nil, fmt.Errorf("%s releases fetch failed: %d", c.toolConfig.Repo, resp.StatusCode)
+	}
+
+	var releases []githubRelease
+	if err := json.NewDecoder(resp.Body).Decode(&releases); err != nil {
+		return nil, err
+	}
+
+	var versions []string
+	for _, release := range releases {
+		if release.Prerelease {
+			continue
+		}
+		versions = append(versions, strings.TrimPrefix(release.TagName, "v"))
+	}
+	return version.SortVersions(versions), nil
+}
+
+// ResolveDownloadURL resolves the download URL for a version without
+// installing anything, backing `mvx tools resolve` so users can validate
+// URL templates and asset patterns against real releases.
+func (c *CustomTool) ResolveDownloadURL(toolVersion string) (string, error) {
+	if c.toolConfig.Repo != "" {
+		return c.resolveGitHubAssetURL(toolVersion)
+	}
+	url := c.getDownloadURL(toolVersion)
+	if url == "" {
+		return "", fmt.Errorf("no URL configured for custom tool %s on this platform", c.GetToolName())
+	}
+	util.LogVerbose("Resolved custom tool %s %s to %s", c.GetToolName(), toolVersion, url)
+	return url, nil
+}
+
+// GetProvenance implements ProvenanceProvider for repo-based custom tools:
+// releases on GitHub can carry build attestations verifiable against the
+// publishing repository
+func (c *CustomTool) GetProvenance(toolVersion string, cfg config.ToolConfig, filename string) (ProvenanceInfo, error) {
+	if c.toolConfig.Repo == "" {
+		return ProvenanceInfo{}, nil
+	}
+	return ProvenanceInfo{Repo: c.toolConfig.Repo}, nil
+}
@@ -0,0 +1,151 @@
+package tools
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Hardlink deduplication: files extracted for a new tool version are hashed
+// and linked against a content-addressed store under ~/.mvx/cache/cas, so
+// files identical across versions (most of a JDK between patch releases)
+// occupy disk space once. Linking is best effort: a store on another
+// filesystem or a filesystem without hardlink support simply keeps the plain
+// copy. Set MVX_NO_DEDUP=true to disable.
+
+// dedupMinSize skips tiny files where the inode churn outweighs the savings
+const dedupMinSize = 4 * 1024
+
+// dedupEnabled reports whether hardlink deduplication is active
+func dedupEnabled() bool {
+	return os.Getenv("MVX_NO_DEDUP") != "true"
+}
+
+// contentStoreDir returns the content-addressed store directory
+func contentStoreDir() (string, error) {
+	cacheDir, err := util.MvxCacheDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(cacheDir, "cas"), nil
+}
+
+// dedupDirectory replaces regular files under dir with hardlinks into the
+// content store, seeding the store with files it has not seen before. All
+// failures are silently tolerated: the worst case is an undeduplicated copy.
+func dedupDirectory(dir string) {
+	if !dedupEnabled() {
+		return
+	}
+	store, err := contentStoreDir()
+	if err != nil || os.MkdirAll(store, 0755) != nil {
+		return
+	}
+
+	var linked, saved int64
+	filepath.Walk(dir, func(path string, info os.FileInfo, err error) error {
+		if err != nil || !info.Mode().IsRegular() || info.Size() < dedupMinSize {
+			return nil
+		}
+
+		sum, err := hashFileSHA256(path)
+		if err != nil {
+			return nil
+		}
+		// Hardlinked files share one inode, and with it their permission
+		// bits, so the mode is part of the store key
+		key := fmt.Sprintf("%s-%o", sum, info.Mode().Perm())
+		entry := filepath.Join(store, key[:2], key)
+
+		if entryInfo, statErr := os.Stat(entry); statErr == nil && entryInfo.Size() == info.Size() {
+			// Known content: swap the fresh copy for a link to the store
+			tmp := path + ".dedup"
+			if os.Link(entry, tmp) != nil {
+				return nil
+			}
+			if os.Rename(tmp, path) != nil {
+				os.Remove(tmp)
+				return nil
+			}
+			linked++
+			saved += info.Size()
+			return nil
+		}
+
+		// New content: seed the store so the next version can link to it
+		if os.MkdirAll(filepath.Dir(entry), 0755) == nil {
+			os.Link(path, entry)
+		}
+		return nil
+	})
+
+	if linked > 0 {
+		util.LogVerbose("Deduplicated %d files (%s) against the content store", linked, util.FormatBytes(saved))
+	}
+}
+
+// PruneContentStore removes store entries whose last tool copy is gone: once
+// a file's link count drops to 1 the store holds the only reference, and its
+// bytes are not reclaimed until the store entry goes too. Returns the number
+// of entries removed and the bytes freed.
+func PruneContentStore() (int, int64) {
+	store, err := contentStoreDir()
+	if err != nil {
+		return 0, 0
+	}
+
+	var removed int
+	var freed int64
+	filepath.Walk(store, func(path string, info os.FileInfo, err error) error {
+		if err != nil || !info.Mode().IsRegular() {
+			return nil
+		}
+		// Entries with other links alive, or an unknown link count, stay
+		if fileLinkCount(info) != 1 {
+			return nil
+		}
+		if os.Remove(path) == nil {
+			removed++
+			freed += info.Size()
+		}
+		return nil
+	})
+	return removed, freed
+}
+
+// ExclusiveDirSize sums the sizes of files under dir whose bytes go away when
+// the directory is removed: files hardlinked from elsewhere (the content
+// store, other versions) keep their inode alive and are not counted
+func ExclusiveDirSize(dir string) int64 {
+	var size int64
+	_ = filepath.Walk(dir, func(_ string, info os.FileInfo, err error) error {
+		if err != nil || info.IsDir() {
+			return nil
+		}
+		if !info.Mode().IsRegular() || fileLinkCount(info) <= 1 {
+			size += info.Size()
+		}
+		return nil
+	})
+	return size
+}
+
+// hashFileSHA256 returns the hex-encoded SHA-256 digest of a file
+func hashFileSHA256(path string) (string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return "", err
+	}
+	defer file.Close()
+
+	hasher := sha256.New()
+	if _, err := io.Copy(hasher, file); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(hasher.Sum(nil)), nil
+}
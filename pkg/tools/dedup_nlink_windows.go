@@ -0,0 +1,11 @@
+//go:build windows
+
+package tools
+
+import "os"
+
+// fileLinkCount returns 0 on Windows: os.FileInfo carries no link count
+// there, and callers treat an unknown count as "still in use"
+func fileLinkCount(info os.FileInfo) uint64 {
+	return 0
+}
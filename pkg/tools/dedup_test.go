@@ -0,0 +1,132 @@
+package tools
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+)
+
+func TestDedupDirectoryLinksIdenticalFiles(t *testing.T) {
+	t.Setenv("HOME", t.TempDir())
+	t.Setenv("USERPROFILE", os.Getenv("HOME")) // Windows home
+
+	content := bytes.Repeat([]byte("identical content\n"), 1024) // above dedupMinSize
+
+	first := t.TempDir()
+	firstFile := filepath.Join(first, "lib.so")
+	if err := os.WriteFile(firstFile, content, 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(first)
+
+	second := t.TempDir()
+	secondFile := filepath.Join(second, "lib.so")
+	if err := os.WriteFile(secondFile, content, 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(second)
+
+	firstInfo, err := os.Stat(firstFile)
+	if err != nil {
+		t.Fatal(err)
+	}
+	secondInfo, err := os.Stat(secondFile)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !os.SameFile(firstInfo, secondInfo) {
+		t.Errorf("expected %s and %s to share an inode after deduplication", firstFile, secondFile)
+	}
+
+	got, err := os.ReadFile(secondFile)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(got, content) {
+		t.Errorf("deduplicated file content changed")
+	}
+}
+
+func TestDedupDirectorySkipsSmallAndDisabled(t *testing.T) {
+	t.Setenv("HOME", t.TempDir())
+
+	small := []byte("tiny") // below dedupMinSize
+	first := t.TempDir()
+	if err := os.WriteFile(filepath.Join(first, "readme"), small, 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(first)
+
+	store, err := contentStoreDir()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if entries, _ := os.ReadDir(store); len(entries) != 0 {
+		t.Errorf("small files should not be added to the content store")
+	}
+
+	t.Setenv("MVX_NO_DEDUP", "true")
+	big := bytes.Repeat([]byte("x"), dedupMinSize)
+	second := t.TempDir()
+	if err := os.WriteFile(filepath.Join(second, "lib.so"), big, 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(second)
+	if entries, _ := os.ReadDir(store); len(entries) != 0 {
+		t.Errorf("MVX_NO_DEDUP=true should disable deduplication")
+	}
+}
+
+func TestPruneContentStoreRemovesOrphans(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("link counts are not available on Windows")
+	}
+	t.Setenv("HOME", t.TempDir())
+
+	content := bytes.Repeat([]byte("orphaned content\n"), 1024) // above dedupMinSize
+	installDir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(installDir, "lib.so"), content, 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(installDir)
+
+	// The install still links to the store entry, so nothing is orphaned
+	if removed, _ := PruneContentStore(); removed != 0 {
+		t.Errorf("expected no orphans while the install exists, removed %d", removed)
+	}
+
+	// Removing the install leaves the store holding the only link
+	if err := os.RemoveAll(installDir); err != nil {
+		t.Fatal(err)
+	}
+	removed, freed := PruneContentStore()
+	if removed != 1 {
+		t.Errorf("expected 1 orphaned entry to be removed, got %d", removed)
+	}
+	if freed != int64(len(content)) {
+		t.Errorf("expected %d freed bytes, got %d", len(content), freed)
+	}
+}
+
+func TestExclusiveDirSizeIgnoresSharedFiles(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("link counts are not available on Windows")
+	}
+	t.Setenv("HOME", t.TempDir())
+
+	shared := bytes.Repeat([]byte("shared content\n"), 1024) // above dedupMinSize
+	dir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dir, "lib.so"), shared, 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(dir, "only-here"), []byte("solo"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	dedupDirectory(dir) // links lib.so into the content store
+
+	if got := ExclusiveDirSize(dir); got != int64(len("solo")) {
+		t.Errorf("expected only the unshared file to count, got %d bytes", got)
+	}
+}
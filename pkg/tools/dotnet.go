@@ -0,0 +1,299 @@
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+// Compile-time interface validation
+var _ Tool = (*DotNetTool)(nil)
+var _ EnvironmentProvider = (*DotNetTool)(nil)
+
+// DotNetTool manages the .NET SDK
+// Uses the official release metadata that also backs dotnet-install, so
+// channel specs like "8.0" resolve exactly like the Microsoft scripts.
+type DotNetTool struct {
+	*BaseTool
+}
+
+func getDotNetBinaryName() string {
+	if NewPlatformMapper().IsWindows() {
+		return BinaryDotNet + ExtExe
+	}
+	return BinaryDotNet
+}
+
+// NewDotNetTool creates a new .NET SDK tool instance
+func NewDotNetTool(manager *Manager) *DotNetTool {
+	return &DotNetTool{
+		BaseTool: NewBaseTool(manager, ToolDotNet, getDotNetBinaryName()),
+	}
+}
+
+func (d *DotNetTool) Install(version string, cfg config.ToolConfig) error {
+	return d.StandardInstall(version, cfg, d.getDownloadURL)
+}
+
+func (d *DotNetTool) IsInstalled(version string, cfg config.ToolConfig) bool {
+	return d.StandardIsInstalled(version, cfg, d.GetPath)
+}
+
+func (d *DotNetTool) GetPath(version string, cfg config.ToolConfig) (string, error) {
+	return d.StandardGetPath(version, cfg, d.getInstalledPath)
+}
+
+func (d *DotNetTool) GetBinaryName() string {
+	return getDotNetBinaryName()
+}
+
+// getInstalledPath returns the path for an installed .NET SDK version.
+// The SDK archive extracts the dotnet binary at the installation root.
+func (d *DotNetTool) getInstalledPath(version string, cfg config.ToolConfig) (string, error) {
+	installDir := d.manager.GetToolVersionDir(d.GetToolName(), version, "")
+	pathResolver := NewPathResolver(d.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(installDir, d.GetBinaryName())
+	if err != nil {
+		return "", err
+	}
+	return binDir, nil
+}
+
+func (d *DotNetTool) Verify(version string, cfg config.ToolConfig) error {
+	verifyConfig := VerificationConfig{
+		BinaryName:  d.GetBinaryName(),
+		VersionArgs: []string{"--version"},
+		DebugInfo:   false,
+	}
+	return d.StandardVerifyWithConfig(version, cfg, verifyConfig)
+}
+
+func (d *DotNetTool) ListVersions() ([]string, error) {
+	index, err := d.fetchReleasesIndex()
+	if err != nil {
+		// minimal fallback
+		return []string{"9.0.101", "8.0.404", "6.0.428"}, nil
+	}
+	var versions []string
+	for _, channel := range index.ReleasesIndex {
+		if channel.LatestSDK != "" {
+			versions = append(versions, channel.LatestSDK)
+		}
+	}
+	return version.SortVersions(versions), nil
+}
+
+// GetDisplayName returns the human-readable name for .NET (implements ToolMetadataProvider)
+func (d *DotNetTool) GetDisplayName() string {
+	return ".NET SDK"
+}
+
+// SetupEnvironment sets up .NET-specific environment variables (implements EnvironmentProvider)
+func (d *DotNetTool) SetupEnvironment(version string, cfg config.ToolConfig, envManager *EnvironmentManager) error {
+	// Convert EnvironmentManager to map for the existing helper
+	envVars := envManager.ToMap()
+	err := d.SetupHomeEnvironment(version, cfg, envVars, EnvDotNetRoot, d.GetPath)
+	// Update the environment manager with any changes
+	for key, value := range envVars {
+		if key != "PATH" { // PATH is handled separately by EnvironmentManager
+			envManager.SetEnv(key, value)
+		}
+	}
+	return err
+}
+
+// dotnetReleasesIndex mirrors releases-index.json from the release metadata
+type dotnetReleasesIndex struct {
+	ReleasesIndex []dotnetChannel `json:"releases-index"`
+}
+
+type dotnetChannel struct {
+	ChannelVersion string `json:"channel-version"`
+	LatestSDK      string `json:"latest-sdk"`
+	ReleasesJSON   string `json:"releases.json"`
+	SupportPhase   string `json:"support-phase"`
+}
+
+// dotnetReleases mirrors a channel's releases.json
+type dotnetReleases struct {
+	Releases []struct {
+		SDK dotnetSDK `json:"sdk"`
+	} `json:"releases"`
+}
+
+type dotnetSDK struct {
+	Version string `json:"version"`
+	Files   []struct {
+		Name string `json:"name"`
+		RID  string `json:"rid"`
+		URL  string `json:"url"`
+		Hash string `json:"hash"`
+	} `json:"files"`
+}
+
+func (d *DotNetTool) fetchReleasesIndex() (*dotnetReleasesIndex, error) {
+	resp, err := d.manager.Get(DotNetReleaseMetadataBase + "/releases-index.json")
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != 200 {
+		return nil, fmt.Errorf("dotnet releases index fetch failed: %d", resp.StatusCode)
+	}
+	var index dotnetReleasesIndex
+	if err := json.NewDecoder(resp.Body).Decode(&index); err != nil {
+		return nil, err
+	}
+	return &index, nil
+}
+
+// fetchChannelReleases fetches the releases.json for the channel owning version
+func (d *DotNetTool) fetchChannelReleases(version string) (*dotnetReleases, error) {
+	index, err := d.fetchReleasesIndex()
+	if err != nil {
+		return nil, err
+	}
+
+	channel := channelOf(version)
+	for _, c := range index.ReleasesIndex {
+		if c.ChannelVersion != channel {
+			continue
+		}
+		resp, err := d.manager.Get(c.ReleasesJSON)
+		if err != nil {
+			return nil, err
+		}
+		defer resp.Body.Close()
+		if resp.StatusCode != 200 {
+			return nil, fmt.Errorf("dotnet channel %s releases fetch failed: %d", channel, resp.StatusCode)
+		}
+		var releases dotnetReleases
+		if err := json.NewDecoder(resp.Body).Decode(&releases); err != nil {
+			return nil, err
+		}
+		return &releases, nil
+	}
+
+	return nil, fmt.Errorf("unknown .NET channel %s", channel)
+}
+
+// channelOf extracts the channel ("8.0") from a version or channel spec
+func channelOf(version string) string {
+	parts := strings.SplitN(version, ".", 3)
+	if len(parts) >= 2 {
+		return parts[0] + "." + parts[1]
+	}
+	return version
+}
+
+// dotnetRID returns the .NET runtime identifier for the current platform
+func dotnetRID() string {
+	platformMapper := NewPlatformMapper()
+	osMapping := map[string]string{
+		"darwin":  "osx",
+		"windows": "win",
+	}
+	archMapping := map[string]string{
+		"amd64": "x64",
+	}
+	return platformMapper.MapOS(osMapping) + "-" + platformMapper.MapArchitecture(archMapping)
+}
+
+// findSDKFile returns the SDK archive entry for a version and the current platform
+func (d *DotNetTool) findSDKFile(version string) (url, hash string, err error) {
+	releases, err := d.fetchChannelReleases(version)
+	if err != nil {
+		return "", "", err
+	}
+
+	rid := dotnetRID()
+	ext := ExtTarGz
+	if NewPlatformMapper().IsWindows() {
+		ext = ExtZip
+	}
+
+	for _, release := range releases.Releases {
+		if release.SDK.Version != version {
+			continue
+		}
+		for _, file := range release.SDK.Files {
+			if file.RID == rid && strings.HasSuffix(file.Name, ext) {
+				return file.URL, file.Hash, nil
+			}
+		}
+	}
+
+	return "", "", fmt.Errorf(".NET SDK %s has no archive for %s", version, rid)
+}
+
+func (d *DotNetTool) getDownloadURL(version string) string {
+	url, _, err := d.findSDKFile(version)
+	if err != nil {
+		util.LogVerbose("Failed to get .NET SDK download URL for %s: %v", version, err)
+		return ""
+	}
+	return url
+}
+
+// GetDownloadURL implements URLProvider interface for .NET
+func (d *DotNetTool) GetDownloadURL(version string) string {
+	return d.getDownloadURL(version)
+}
+
+// GetChecksum implements ChecksumProvider interface using the release metadata hashes
+func (d *DotNetTool) GetChecksum(version string, cfg config.ToolConfig, filename string) (ChecksumInfo, error) {
+	_, hash, err := d.findSDKFile(version)
+	if err != nil {
+		return ChecksumInfo{}, err
+	}
+	return ChecksumInfo{
+		Type:  SHA512,
+		Value: hash,
+	}, nil
+}
+
+// ResolveVersion resolves a .NET version or channel spec to a concrete SDK version
+func (d *DotNetTool) ResolveVersion(versionSpec, distribution string) (string, error) {
+	index, err := d.fetchReleasesIndex()
+	if err != nil {
+		return "", err
+	}
+
+	// Channel specs ("8.0", "8") resolve to the channel's latest SDK,
+	// mirroring dotnet-install --channel
+	for _, channel := range index.ReleasesIndex {
+		if versionSpec == channel.ChannelVersion || versionSpec == strings.SplitN(channel.ChannelVersion, ".", 2)[0] {
+			return channel.LatestSDK, nil
+		}
+	}
+
+	// "lts" resolves to the newest channel in active LTS support
+	if versionSpec == "lts" {
+		var ltsVersions []string
+		for _, channel := range index.ReleasesIndex {
+			if channel.SupportPhase == "active" || channel.SupportPhase == "maintenance" {
+				ltsVersions = append(ltsVersions, channel.LatestSDK)
+			}
+		}
+		if len(ltsVersions) > 0 {
+			return version.SortVersions(ltsVersions)[0], nil
+		}
+	}
+
+	// Exact SDK versions pass through if the channel knows them
+	releases, err := d.fetchChannelReleases(versionSpec)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve .NET version %s: %w", versionSpec, err)
+	}
+	for _, release := range releases.Releases {
+		if release.SDK.Version == versionSpec {
+			return versionSpec, nil
+		}
+	}
+
+	return "", fmt.Errorf("unknown .NET SDK version %s", versionSpec)
+}
@@ -3,6 +3,8 @@ package tools
 import (
 	"bytes"
 	"context"
+	"crypto/sha256"
+	"encoding/hex"
 	"fmt"
 	"io"
 	"net/http"
@@ -19,6 +21,7 @@ import (
 // DownloadConfig contains configuration for robust downloads with checksum verification
 type DownloadConfig struct {
 	URL           string
+	FallbackURLs  []string // Alternate URLs tried in order when every attempt against URL fails
 	DestPath      string
 	MaxRetries    int
 	RetryDelay    time.Duration
@@ -67,8 +70,49 @@ type DownloadResult struct {
 	FinalURL    string
 }
 
-// RobustDownload performs a robust download with validation and retries
+// RobustDownload performs a robust download with validation and retries,
+// falling through the configured fallback URLs when every attempt against a
+// candidate fails (e.g. the Apache archive host for a release the CDN no
+// longer serves)
 func RobustDownload(config *DownloadConfig) (*DownloadResult, error) {
+	candidates := append([]string{config.URL}, config.FallbackURLs...)
+
+	var lastErr error
+	for i, candidate := range candidates {
+		if i > 0 {
+			toolPrefix := ""
+			if config.ToolName != "" {
+				toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
+			}
+			util.LogInfo("  🔄 %sFalling back to %s", toolPrefix, getUserFriendlyURL(candidate))
+		}
+
+		attempt := *config
+		attempt.URL = candidate
+		attempt.FallbackURLs = nil
+		result, err := robustDownloadURL(&attempt)
+		if err == nil {
+			return result, nil
+		}
+		lastErr = err
+	}
+
+	return nil, lastErr
+}
+
+// fallbackURLsExcluding filters fallback candidates that duplicate the primary URL
+func fallbackURLsExcluding(urls []string, primary string) []string {
+	var filtered []string
+	for _, u := range urls {
+		if u != "" && u != primary {
+			filtered = append(filtered, u)
+		}
+	}
+	return filtered
+}
+
+// robustDownloadURL downloads a single URL with validation and retries
+func robustDownloadURL(config *DownloadConfig) (*DownloadResult, error) {
 	// Apply URL replacements if configured
 	originalURL := config.URL
 	urlReplacer, err := LoadURLReplacer()
@@ -81,8 +125,39 @@ func RobustDownload(config *DownloadConfig) (*DownloadResult, error) {
 			if config.ToolName != "" {
 				toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
 			}
-			fmt.Printf("  🔄 %sUsing URL replacement: %s\n", toolPrefix, getUserFriendlyURL(config.URL))
+			util.LogInfo("  🔄 %sUsing URL replacement: %s", toolPrefix, getUserFriendlyURL(config.URL))
+		}
+	}
+
+	// Route through a configured mirror before any request is made
+	if mirrored := applyMirrors(config.URL); mirrored != config.URL {
+		toolPrefix := ""
+		if config.ToolName != "" {
+			toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
 		}
+		util.LogInfo("  🪞 %sUsing mirror: %s", toolPrefix, getUserFriendlyURL(mirrored))
+		config.URL = mirrored
+	}
+
+	// Enforce the download host allowlist on the final (post-mirror) URL
+	if err := checkHostAllowed(config.URL); err != nil {
+		return nil, err
+	}
+
+	// Air-gapped bundles: provision from the local archive cache when a
+	// bundle import (mvx bundle install) cached this URL's archive
+	if result, ok := tryCachedArchive(config); ok {
+		return result, nil
+	}
+
+	// Offline mode: reaching this point means the tool is neither installed
+	// nor cached, so fail with the exact missing artifact
+	if util.IsOffline() {
+		missing := config.URL
+		if config.ToolName != "" {
+			missing = fmt.Sprintf("%s %s (%s)", config.ToolName, config.Version, config.URL)
+		}
+		return nil, fmt.Errorf("offline mode: %s is not installed and cannot be downloaded", missing)
 	}
 
 	var lastErr error
@@ -93,7 +168,7 @@ func RobustDownload(config *DownloadConfig) (*DownloadResult, error) {
 			if config.ToolName != "" {
 				toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
 			}
-			fmt.Printf("  🔄 %sRetry attempt %d/%d after %v...\n", toolPrefix, attempt, config.MaxRetries, config.RetryDelay)
+			util.LogInfo("  🔄 %sRetry attempt %d/%d after %v...", toolPrefix, attempt, config.MaxRetries, config.RetryDelay)
 			time.Sleep(config.RetryDelay * time.Duration(attempt)) // Exponential backoff
 		}
 
@@ -113,25 +188,26 @@ func RobustDownload(config *DownloadConfig) (*DownloadResult, error) {
 	return nil, fmt.Errorf("download failed after %d attempts: %w", config.MaxRetries+1, lastErr)
 }
 
-// attemptDownload performs a single download attempt
+// attemptDownload performs a single download attempt, resuming a cached
+// partial file from an earlier interrupted attempt when the server supports
+// HTTP ranges
 func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
-	// Create temporary file
-	tempFile, err := os.CreateTemp("", "mvx-download-*.tmp")
+	tempFile, resumeFrom, validator, err := openPartialFile(config.URL)
 	if err != nil {
-		return nil, fmt.Errorf("failed to create temporary file: %w", err)
+		return nil, fmt.Errorf("failed to create download file: %w", err)
 	}
-	defer os.Remove(tempFile.Name())
 	defer tempFile.Close()
 
 	// Create HTTP client with granular timeouts for better handling of slow servers
 	configProvider := NewDownloadConfigProvider(NewEnvironmentConfigProvider())
 
+	transport := util.NewHTTPTransport()
+	transport.TLSHandshakeTimeout = configProvider.GetTLSTimeout()
+	transport.ResponseHeaderTimeout = configProvider.GetResponseTimeout()
+	transport.IdleConnTimeout = configProvider.GetIdleTimeout()
+
 	client := &http.Client{
-		Transport: &http.Transport{
-			TLSHandshakeTimeout:   configProvider.GetTLSTimeout(),
-			ResponseHeaderTimeout: configProvider.GetResponseTimeout(),
-			IdleConnTimeout:       configProvider.GetIdleTimeout(),
-		},
+		Transport: util.TraceTransport(transport),
 		// Use context timeout instead of global client timeout for better control
 		CheckRedirect: func(req *http.Request, via []*http.Request) error {
 			if len(via) >= MaxRedirects {
@@ -153,12 +229,20 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 	// Set user agent
 	req.Header.Set("User-Agent", "mvx/1.0 (https://github.com/gnodet/mvx)")
 
+	// Resume an interrupted download when we have partial data and a
+	// validator; If-Range makes the server fall back to a full response if
+	// the file changed upstream
+	if resumeFrom > 0 && validator != "" {
+		req.Header.Set("Range", fmt.Sprintf("bytes=%d-", resumeFrom))
+		req.Header.Set("If-Range", validator)
+	}
+
 	// Perform request with progress indication for slow servers
 	toolPrefix := ""
 	if config.ToolName != "" {
 		toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
 	}
-	fmt.Printf("  🌐 %sConnecting to server...\n", toolPrefix)
+	util.LogInfo("  🌐 %sConnecting to server...", toolPrefix)
 
 	resp, err := client.Do(req)
 	if err != nil {
@@ -166,13 +250,36 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 	}
 	defer resp.Body.Close()
 
-	fmt.Printf("  📡 %sServer responded, starting download...\n", toolPrefix)
+	util.LogInfo("  📡 %sServer responded, starting download...", toolPrefix)
 
 	// Check status code
-	if resp.StatusCode != http.StatusOK {
+	switch resp.StatusCode {
+	case http.StatusOK:
+		// Full response: any partial data we had is stale
+		if resumeFrom > 0 {
+			if err := tempFile.Truncate(0); err != nil {
+				return nil, fmt.Errorf("failed to reset partial download: %w", err)
+			}
+			if _, err := tempFile.Seek(0, io.SeekStart); err != nil {
+				return nil, fmt.Errorf("failed to reset partial download: %w", err)
+			}
+			resumeFrom = 0
+		}
+	case http.StatusPartialContent:
+		util.LogInfo("  ⏯️  %sResuming download from %d bytes...", toolPrefix, resumeFrom)
+		if _, err := tempFile.Seek(0, io.SeekEnd); err != nil {
+			return nil, fmt.Errorf("failed to resume partial download: %w", err)
+		}
+	case http.StatusRequestedRangeNotSatisfiable:
+		discardPartialDownload(config.URL)
+		return nil, fmt.Errorf("server rejected resume range, restarting download")
+	default:
 		return nil, fmt.Errorf("HTTP %d: %s", resp.StatusCode, resp.Status)
 	}
 
+	// Remember the validator so the next attempt can resume this file
+	saveDownloadValidator(config.URL, resp)
+
 	// Validate content type if specified
 	if config.ExpectedType != "" {
 		contentType := resp.Header.Get("Content-Type")
@@ -181,27 +288,42 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 		}
 	}
 
-	// Check content length if available
+	// Check content length if available (the resumed portion counts too)
 	if contentLength := resp.ContentLength; contentLength > 0 {
-		if contentLength < config.MinSize {
-			return nil, fmt.Errorf("content too small: %d bytes (minimum %d)", contentLength, config.MinSize)
+		if resumeFrom+contentLength < config.MinSize {
+			return nil, fmt.Errorf("content too small: %d bytes (minimum %d)", resumeFrom+contentLength, config.MinSize)
 		}
-		if contentLength > config.MaxSize {
-			return nil, fmt.Errorf("content too large: %d bytes (maximum %d)", contentLength, config.MaxSize)
+		if resumeFrom+contentLength > config.MaxSize {
+			return nil, fmt.Errorf("content too large: %d bytes (maximum %d)", resumeFrom+contentLength, config.MaxSize)
 		}
 	}
 
-	// Download with size tracking
-	written, err := io.Copy(tempFile, resp.Body)
+	// Download with progress reporting; on failure the partial file stays in
+	// the cache so the next attempt can resume it
+	label := config.ToolName
+	if label == "" {
+		label = extractFilenameFromURL(config.URL)
+	}
+	total := int64(0)
+	if resp.ContentLength > 0 {
+		total = resumeFrom + resp.ContentLength
+	}
+	progress := util.NewProgressWriter(label, total, resumeFrom)
+	copied, err := io.Copy(tempFile, io.TeeReader(resp.Body, progress))
+	progress.Finish()
+	recordDownloadSize(config.ToolName, copied)
 	if err != nil {
 		return nil, fmt.Errorf("download failed: %w", err)
 	}
+	written := resumeFrom + copied
 
-	// Validate downloaded size
+	// Validate downloaded size; a complete-but-wrong file must not be resumed
 	if written < config.MinSize {
+		discardPartialDownload(config.URL)
 		return nil, fmt.Errorf("downloaded file too small: %d bytes (minimum %d)", written, config.MinSize)
 	}
 	if written > config.MaxSize {
+		discardPartialDownload(config.URL)
 		return nil, fmt.Errorf("downloaded file too large: %d bytes (maximum %d)", written, config.MaxSize)
 	}
 
@@ -211,16 +333,27 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 	// Validate file content if requested
 	if config.ValidateMagic {
 		if err := validateFileFormat(tempFile.Name(), config.URL); err != nil {
+			discardPartialDownload(config.URL)
 			return nil, fmt.Errorf("file validation failed: %w", err)
 		}
 	}
 
-	// Verify checksum if tool is available
+	// Verify checksum if tool is available. This re-validates resumed files
+	// end to end, so a corrupted partial never reaches the destination.
 	if config.Tool != nil {
 		// Update config with final URL for better filename detection
 		finalConfig := *config
 		finalConfig.URL = resp.Request.URL.String()
 		if err := verifyChecksum(tempFile.Name(), &finalConfig); err != nil {
+			discardPartialDownload(config.URL)
+			return nil, err
+		}
+		if err := verifySignature(tempFile.Name(), &finalConfig); err != nil {
+			discardPartialDownload(config.URL)
+			return nil, err
+		}
+		if err := verifyProvenance(tempFile.Name(), &finalConfig); err != nil {
+			discardPartialDownload(config.URL)
 			return nil, err
 		}
 	}
@@ -234,6 +367,7 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 	if err := moveFileWithRetry(tempFile.Name(), config.DestPath); err != nil {
 		return nil, fmt.Errorf("failed to move file to destination: %w", err)
 	}
+	os.Remove(downloadValidatorPath(config.URL))
 
 	return &DownloadResult{
 		Size:        written,
@@ -243,6 +377,171 @@ func attemptDownload(config *DownloadConfig) (*DownloadResult, error) {
 	}, nil
 }
 
+// CachedArchivePath returns the local archive cache location for a download
+// URL. Bundle imports populate this cache so setup can provision tools with
+// zero network access.
+func CachedArchivePath(rawURL string) string {
+	cacheDir, err := util.MvxCacheDir()
+	if err != nil {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(rawURL))
+	return filepath.Join(cacheDir, "archives", hex.EncodeToString(sum[:]))
+}
+
+// tryCachedArchive serves a download from the local archive cache, running
+// the same format and checksum verification as a network download. Corrupt
+// cache entries are dropped so the caller falls back to the network.
+func tryCachedArchive(config *DownloadConfig) (*DownloadResult, bool) {
+	if result, ok := tryArchiveFile(CachedArchivePath(config.URL), true, config); ok {
+		return result, true
+	}
+	// Read-only shared tier (MVX_SHARED_CACHE_DIR): archives pre-seeded by a
+	// platform team on an NFS mount or baked container layer
+	return tryArchiveFile(sharedCachedArchivePath(config.URL), false, config)
+}
+
+// tryArchiveFile serves a download from one cached archive file. Corrupt
+// entries are removed only from the writable tier; the shared tier is never
+// written to.
+func tryArchiveFile(cached string, writable bool, config *DownloadConfig) (*DownloadResult, bool) {
+	if cached == "" {
+		return nil, false
+	}
+	info, err := os.Stat(cached)
+	if err != nil {
+		return nil, false
+	}
+
+	tempFile, err := os.CreateTemp("", "mvx-archive-*.tmp")
+	if err != nil {
+		return nil, false
+	}
+	defer os.Remove(tempFile.Name())
+
+	source, err := os.Open(cached)
+	if err != nil {
+		tempFile.Close()
+		return nil, false
+	}
+	_, err = io.Copy(tempFile, source)
+	source.Close()
+	tempFile.Close()
+	if err != nil {
+		return nil, false
+	}
+
+	if config.ValidateMagic {
+		if err := validateFileFormat(tempFile.Name(), config.URL); err != nil {
+			util.LogVerbose("Dropping invalid cached archive for %s: %v", config.URL, err)
+			if writable {
+				os.Remove(cached)
+			}
+			return nil, false
+		}
+	}
+	if config.Tool != nil {
+		if err := verifyChecksum(tempFile.Name(), config); err != nil {
+			util.LogVerbose("Dropping cached archive for %s: %v", config.URL, err)
+			if writable {
+				os.Remove(cached)
+			}
+			return nil, false
+		}
+	}
+
+	if err := os.MkdirAll(filepath.Dir(config.DestPath), 0755); err != nil {
+		return nil, false
+	}
+	if err := moveFileWithRetry(tempFile.Name(), config.DestPath); err != nil {
+		return nil, false
+	}
+
+	toolPrefix := ""
+	if config.ToolName != "" {
+		toolPrefix = fmt.Sprintf("[%s] ", config.ToolName)
+	}
+	util.LogInfo("  📦 %sUsing cached archive for %s", toolPrefix, getUserFriendlyURL(config.URL))
+
+	return &DownloadResult{
+		Size:     info.Size(),
+		FinalURL: config.URL,
+	}, true
+}
+
+// partialDownloadPath returns the cache location where an in-progress
+// download is kept between attempts, or "" when no home directory is
+// available (pure temp-file fallback)
+func partialDownloadPath(rawURL string) string {
+	cacheDir, err := util.MvxCacheDir()
+	if err != nil {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(rawURL))
+	return filepath.Join(cacheDir, "downloads", hex.EncodeToString(sum[:])+".partial")
+}
+
+// downloadValidatorPath returns the sidecar file storing the ETag or
+// Last-Modified validator for a partial download
+func downloadValidatorPath(rawURL string) string {
+	if path := partialDownloadPath(rawURL); path != "" {
+		return path + ".meta"
+	}
+	return ""
+}
+
+// openPartialFile opens the cached partial file for a URL, returning the
+// number of bytes already present and the saved validator. Without a cache
+// directory it falls back to a throwaway temp file.
+func openPartialFile(rawURL string) (*os.File, int64, string, error) {
+	path := partialDownloadPath(rawURL)
+	if path != "" {
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err == nil {
+			if file, err := os.OpenFile(path, os.O_CREATE|os.O_RDWR, 0644); err == nil {
+				size := int64(0)
+				if info, err := file.Stat(); err == nil {
+					size = info.Size()
+				}
+				validator := ""
+				if data, err := os.ReadFile(downloadValidatorPath(rawURL)); err == nil {
+					validator = strings.TrimSpace(string(data))
+				}
+				return file, size, validator, nil
+			}
+		}
+	}
+
+	file, err := os.CreateTemp("", "mvx-download-*.tmp")
+	return file, 0, "", err
+}
+
+// saveDownloadValidator records the response's ETag (or Last-Modified) so a
+// later attempt can resume the partial file with If-Range
+func saveDownloadValidator(rawURL string, resp *http.Response) {
+	path := downloadValidatorPath(rawURL)
+	if path == "" {
+		return
+	}
+	validator := resp.Header.Get("ETag")
+	if validator == "" {
+		validator = resp.Header.Get("Last-Modified")
+	}
+	if validator == "" {
+		os.Remove(path)
+		return
+	}
+	_ = os.WriteFile(path, []byte(validator), 0644)
+}
+
+// discardPartialDownload drops a partial file and its validator so the next
+// attempt starts from scratch
+func discardPartialDownload(rawURL string) {
+	if path := partialDownloadPath(rawURL); path != "" {
+		os.Remove(path)
+		os.Remove(path + ".meta")
+	}
+}
+
 // validateFileFormat validates the downloaded file format based on magic bytes
 func validateFileFormat(filePath, url string) error {
 	file, err := os.Open(filePath)
@@ -435,11 +734,22 @@ func verifyChecksum(filePath string, config *DownloadConfig) error {
 		hasChecksum = true
 	}
 
+	// Fall back to the checksum pinned in the lockfile at lock time
+	if !hasChecksum {
+		if manager := config.Tool.GetManager(); manager != nil {
+			if lockedInfo, ok := manager.lockedChecksum(config.ToolName, config.Version, config.Config); ok {
+				checksumInfo = lockedInfo
+				hasChecksum = true
+				util.LogInfo("  🔒 Using checksum pinned in %s", LockFileName)
+			}
+		}
+	}
+
 	if !hasChecksum {
 		// Try to get checksum from tool using dynamic lookup
 		// Extract filename from URL, handling redirects and query parameters
 		filename := extractFilenameFromURL(config.URL)
-		fmt.Printf("  🔍 Attempting to find checksum for file: %s\n", filename)
+		util.LogInfo("  🔍 Attempting to find checksum for file: %s", filename)
 
 		// Use tool's GetChecksum method for dynamic checksum resolution
 		if dynamicChecksum, err := config.Tool.GetChecksum(config.Version, config.Config, filename); err == nil {
@@ -454,36 +764,37 @@ func verifyChecksum(filePath string, config *DownloadConfig) error {
 	}
 
 	if !hasChecksum {
-		if config.Tool.SupportsChecksumVerification() {
-			fmt.Printf("⚠️  No checksum available for %s %s\n", config.ToolName, config.Version)
-			fmt.Printf("   Consider adding checksum verification to your configuration for enhanced security.\n")
+		// A tool that publishes checksums but yields none is suspicious:
+		// refuse the download unless the user explicitly opts out
+		if config.Tool.SupportsChecksumVerification() && !allowUnverifiedDownloads() {
+			os.Remove(filePath)
+			return fmt.Errorf("no checksum available for %s %s and verification is mandatory "+
+				"(set MVX_ALLOW_UNVERIFIED_DOWNLOADS=true to bypass at your own risk)",
+				config.ToolName, config.Version)
 		}
+		fmt.Printf("⚠️  No checksum available for %s %s, skipping verification\n", config.ToolName, config.Version)
 		return nil
 	}
 
-	// Check if checksum verification is required
-	isRequired := config.Config.Checksum != nil && config.Config.Checksum.Required
-
-	// Create checksum verifier
+	// Verification is mandatory whenever a checksum is known: a mismatch
+	// means a corrupted or tampered archive and always fails the download
 	verifier := NewChecksumVerifier(config.Tool.GetManager())
-
-	if isRequired {
-		// Strict verification - fail on error
-		if err := verifier.VerifyFile(filePath, checksumInfo); err != nil {
-			// Remove the downloaded file on checksum failure
-			os.Remove(filePath)
-			// Don't panic, return error instead for better error handling
-			return fmt.Errorf("checksum verification failed (required): %v", err)
-		}
-		fmt.Printf("  ✅ Checksum verified successfully (required)\n")
-	} else {
-		// Optional verification - warn on error
-		verifier.VerifyFileWithWarning(filePath, checksumInfo)
+	if err := verifier.VerifyFile(filePath, checksumInfo); err != nil {
+		// Remove the downloaded file on checksum failure
+		os.Remove(filePath)
+		return fmt.Errorf("checksum verification failed for %s %s: %w", config.ToolName, config.Version, err)
 	}
+	util.LogInfo("  ✅ Checksum verified successfully")
 
 	return nil
 }
 
+// allowUnverifiedDownloads reports whether the user opted out of mandatory
+// checksum verification for tools that should provide one
+func allowUnverifiedDownloads() bool {
+	return os.Getenv("MVX_ALLOW_UNVERIFIED_DOWNLOADS") == "true"
+}
+
 // extractFilenameFromURL extracts the filename from a URL, handling redirects and query parameters
 func extractFilenameFromURL(urlStr string) string {
 	// Parse the URL
@@ -14,6 +14,61 @@ import (
 	"github.com/gnodet/mvx/pkg/util"
 )
 
+// maxExtractedFileSize caps the decompressed size of a single archive entry,
+// guarding against decompression bombs. Variable so tests can lower it.
+var maxExtractedFileSize int64 = 8 << 30 // 8 GiB
+
+// sanitizeExtractionPath resolves an archive entry name against the
+// destination directory, rejecting absolute paths and entries that would
+// escape the destination (zip-slip)
+func sanitizeExtractionPath(dest, name string) (string, error) {
+	if name == "" {
+		return "", fmt.Errorf("archive entry has an empty name")
+	}
+	if filepath.IsAbs(name) || strings.HasPrefix(name, "/") || strings.HasPrefix(name, "\\") {
+		return "", fmt.Errorf("archive entry %q uses an absolute path", name)
+	}
+
+	targetPath := filepath.Join(dest, name)
+	cleanDest := filepath.Clean(dest)
+	if targetPath != cleanDest && !strings.HasPrefix(targetPath, cleanDest+string(os.PathSeparator)) {
+		return "", fmt.Errorf("archive entry %q escapes the destination directory", name)
+	}
+	return targetPath, nil
+}
+
+// validateSymlinkTarget rejects symlinks whose target resolves outside the
+// destination directory, so a malicious archive cannot plant links to /etc
+// or the user's home
+func validateSymlinkTarget(dest, targetPath, linkname string) error {
+	if linkname == "" {
+		return fmt.Errorf("symlink %s has an empty target", targetPath)
+	}
+	if filepath.IsAbs(linkname) {
+		return fmt.Errorf("symlink %s has an absolute target %q", targetPath, linkname)
+	}
+
+	resolved := filepath.Join(filepath.Dir(targetPath), linkname)
+	cleanDest := filepath.Clean(dest)
+	if resolved != cleanDest && !strings.HasPrefix(resolved, cleanDest+string(os.PathSeparator)) {
+		return fmt.Errorf("symlink %s points outside the destination directory (target: %s)", targetPath, linkname)
+	}
+	return nil
+}
+
+// copyLimited copies an archive entry's content, failing once it exceeds
+// maxExtractedFileSize instead of filling the disk
+func copyLimited(dst io.Writer, src io.Reader, name string) error {
+	written, err := io.Copy(dst, io.LimitReader(src, maxExtractedFileSize+1))
+	if err != nil {
+		return err
+	}
+	if written > maxExtractedFileSize {
+		return fmt.Errorf("archive entry %s exceeds the %d byte size limit", name, maxExtractedFileSize)
+	}
+	return nil
+}
+
 // detectSingleTopLevelDirectory checks if all files in a ZIP archive are under a single top-level directory
 // Returns the directory prefix to strip, or empty string if no stripping should be done
 func detectSingleTopLevelDirectory(files []*zip.File) string {
@@ -38,6 +93,9 @@ func detectSingleTopLevelDirectory(files []*zip.File) string {
 		if firstComponent == "" {
 			return "" // Absolute path, don't strip
 		}
+		if firstComponent == "." || firstComponent == ".." {
+			return "" // Never strip traversal components, sanitization must see them
+		}
 
 		if topLevelDir == "" {
 			topLevelDir = firstComponent
@@ -86,6 +144,9 @@ func detectSingleTopLevelDirectoryTar(headers []*tar.Header) string {
 		if firstComponent == "" {
 			return "" // Absolute path, don't strip
 		}
+		if firstComponent == "." || firstComponent == ".." {
+			return "" // Never strip traversal components, sanitization must see them
+		}
 
 		if topLevelDir == "" {
 			topLevelDir = firstComponent
@@ -126,6 +187,13 @@ func extractZipFile(src, dest string) error {
 	// Check if archive contains a single top-level directory
 	stripPrefix := detectSingleTopLevelDirectory(reader.File)
 
+	// Zip entries are independently addressable, so the workers decompress
+	// whole entries concurrently; directories are created up front
+	var pool *extractPool
+	if parallelExtractionEnabled() {
+		pool = newExtractPool()
+	}
+
 	// Extract files
 	for _, file := range reader.File {
 		// Skip the top-level directory if we're stripping it
@@ -140,18 +208,25 @@ func extractZipFile(src, dest string) error {
 			}
 		}
 
-		targetPath := filepath.Join(dest, relativePath)
-
-		// Security check: ensure the file path is within destDir
-		if !strings.HasPrefix(targetPath, filepath.Clean(dest)+string(os.PathSeparator)) {
-			return fmt.Errorf("invalid file path in ZIP: %s", file.Name)
+		// Security check: reject absolute paths and entries escaping destDir
+		targetPath, err := sanitizeExtractionPath(dest, relativePath)
+		if err != nil {
+			if pool != nil {
+				pool.wait()
+			}
+			return fmt.Errorf("invalid file path in ZIP: %w", err)
 		}
 
 		if file.FileInfo().IsDir() {
 			// Create directory
-			if err := os.MkdirAll(targetPath, file.FileInfo().Mode()); err != nil {
+			if err := os.MkdirAll(util.LongPath(targetPath), file.FileInfo().Mode()); err != nil {
+				if pool != nil {
+					pool.wait()
+				}
 				return fmt.Errorf("failed to create directory %s: %w", targetPath, err)
 			}
+		} else if pool != nil {
+			pool.submit(extractWriteJob{targetPath: targetPath, mode: file.FileInfo().Mode(), open: file.Open})
 		} else {
 			// Extract file
 			if err := extractSingleZipFile(file, targetPath); err != nil {
@@ -160,11 +235,17 @@ func extractZipFile(src, dest string) error {
 		}
 	}
 
+	if pool != nil {
+		return pool.wait()
+	}
 	return nil
 }
 
 // extractSingleZipFile extracts a single file from ZIP archive
 func extractSingleZipFile(file *zip.File, targetPath string) error {
+	// Extended-length form so deep JDK trees extract on Windows
+	targetPath = util.LongPath(targetPath)
+
 	// Create parent directory
 	if err := os.MkdirAll(filepath.Dir(targetPath), 0755); err != nil {
 		return err
@@ -190,31 +271,82 @@ func extractSingleZipFile(file *zip.File, targetPath string) error {
 	}
 	defer targetFile.Close()
 
-	// Copy content
-	_, err = io.Copy(targetFile, reader)
-	return err
+	// Copy content, capped to guard against decompression bombs
+	return copyLimited(targetFile, reader, targetPath)
 }
 
-// extractTarGzFile extracts a tar.gz file to the destination directory
-func extractTarGzFile(src, dest string) error {
+// tarStream couples a decompressed tar stream with the resources backing it
+type tarStream struct {
+	io.Reader
+	closeFunc func() error
+}
+
+func (s *tarStream) Close() error { return s.closeFunc() }
+
+// openGzipStream opens a .tar.gz archive and returns the decompressed stream
+func openGzipStream(src string) (io.ReadCloser, error) {
 	file, err := os.Open(src)
 	if err != nil {
-		return fmt.Errorf("failed to open archive: %w", err)
+		return nil, fmt.Errorf("failed to open archive: %w", err)
 	}
-	defer file.Close()
-
-	// Create gzip reader
 	gzReader, err := gzip.NewReader(file)
 	if err != nil {
-		return fmt.Errorf("failed to create gzip reader: %w", err)
+		file.Close()
+		return nil, fmt.Errorf("failed to create gzip reader: %w", err)
+	}
+	return &tarStream{Reader: gzReader, closeFunc: func() error {
+		gzReader.Close()
+		return file.Close()
+	}}, nil
+}
+
+// openXzStream decompresses a .tar.xz archive through the system xz binary,
+// feeding the tar stream back in-process so the hardened tar path (path
+// sanitization, symlink validation, size caps) applies to its entries
+func openXzStream(src string) (io.ReadCloser, error) {
+	xz, err := exec.LookPath("xz")
+	if err != nil {
+		return nil, fmt.Errorf("extracting tar.xz archives requires the xz binary to be installed")
+	}
+	cmd := exec.Command(xz, "--decompress", "--stdout", src)
+	stdout, err := cmd.StdoutPipe()
+	if err != nil {
+		return nil, err
+	}
+	if err := cmd.Start(); err != nil {
+		return nil, fmt.Errorf("failed to start xz: %w", err)
+	}
+	return &tarStream{Reader: stdout, closeFunc: func() error {
+		stdout.Close()
+		return cmd.Wait()
+	}}, nil
+}
+
+// extractTarGzFile extracts a tar.gz file to the destination directory
+func extractTarGzFile(src, dest string) error {
+	return extractTarArchive(src, dest, openGzipStream)
+}
+
+// extractTarXzFile extracts a tar.xz file to the destination directory
+func extractTarXzFile(src, dest string) error {
+	return extractTarArchive(src, dest, openXzStream)
+}
+
+// extractTarArchive extracts the tar stream produced by open to the
+// destination directory. The stream is opened twice: once to detect a single
+// top-level directory to strip, then again to extract.
+func extractTarArchive(src, dest string, open func(string) (io.ReadCloser, error)) error {
+	stream, err := open(src)
+	if err != nil {
+		return err
 	}
-	defer gzReader.Close()
 
 	// Create tar reader
-	tarReader := tar.NewReader(gzReader)
+	tarReader := tar.NewReader(stream)
 
 	// Create destination directory
 	if err := os.MkdirAll(dest, 0755); err != nil {
+		stream.Close()
 		return fmt.Errorf("failed to create destination directory: %w", err)
 	}
 
@@ -226,6 +358,7 @@ func extractTarGzFile(src, dest string) error {
 			break
 		}
 		if err != nil {
+			stream.Close()
 			return fmt.Errorf("failed to read tar header: %w", err)
 		}
 		headers = append(headers, header)
@@ -234,21 +367,46 @@ func extractTarGzFile(src, dest string) error {
 	// Detect if we should strip a single top-level directory
 	stripPrefix := detectSingleTopLevelDirectoryTar(headers)
 
-	// Reopen the file for second pass
-	file.Close()
-	file, err = os.Open(src)
+	// Reopen the stream for second pass
+	stream.Close()
+	stream, err = open(src)
 	if err != nil {
-		return fmt.Errorf("failed to reopen archive: %w", err)
+		return err
 	}
-	defer file.Close()
+	defer stream.Close()
 
-	gzReader, err = gzip.NewReader(file)
-	if err != nil {
-		return fmt.Errorf("failed to create gzip reader: %w", err)
+	tarReader = tar.NewReader(stream)
+
+	// The tar stream itself is sequential, but disk writes need not be:
+	// small entries are buffered off the stream and written by the worker
+	// pool while decompression continues. Symlinks are created after all
+	// writes have settled so they never race against a concurrent file.
+	var pool *extractPool
+	if parallelExtractionEnabled() {
+		pool = newExtractPool()
 	}
-	defer gzReader.Close()
+	type pendingSymlink struct {
+		linkname   string
+		targetPath string
+	}
+	var symlinks []pendingSymlink
 
-	tarReader = tar.NewReader(gzReader)
+	finish := func(err error) error {
+		if pool != nil {
+			if poolErr := pool.wait(); err == nil {
+				err = poolErr
+			}
+		}
+		if err != nil {
+			return err
+		}
+		for _, link := range symlinks {
+			if err := createSymlinkSafely(link.linkname, link.targetPath); err != nil {
+				return fmt.Errorf("failed to create symlink %s: %w", link.targetPath, err)
+			}
+		}
+		return nil
+	}
 
 	// Second pass: extract files
 	for {
@@ -257,7 +415,7 @@ func extractTarGzFile(src, dest string) error {
 			break
 		}
 		if err != nil {
-			return fmt.Errorf("failed to read tar header: %w", err)
+			return finish(fmt.Errorf("failed to read tar header: %w", err))
 		}
 
 		// Skip the top-level directory if we're stripping it
@@ -272,40 +430,52 @@ func extractTarGzFile(src, dest string) error {
 			}
 		}
 
-		targetPath := filepath.Join(dest, relativePath)
-
-		// Security check: ensure the file path is within destDir
-		if !strings.HasPrefix(targetPath, filepath.Clean(dest)+string(os.PathSeparator)) {
-			return fmt.Errorf("invalid file path in tar: %s", header.Name)
+		// Security check: reject absolute paths and entries escaping destDir
+		targetPath, err := sanitizeExtractionPath(dest, relativePath)
+		if err != nil {
+			return finish(fmt.Errorf("invalid file path in tar: %w", err))
 		}
 
 		switch header.Typeflag {
 		case tar.TypeDir:
 			// Create directory
-			if err := os.MkdirAll(targetPath, os.FileMode(header.Mode)); err != nil {
-				return fmt.Errorf("failed to create directory %s: %w", targetPath, err)
+			if err := os.MkdirAll(util.LongPath(targetPath), os.FileMode(header.Mode)); err != nil {
+				return finish(fmt.Errorf("failed to create directory %s: %w", targetPath, err))
 			}
 		case tar.TypeReg:
-			// Extract regular file
+			if pool != nil && header.Size <= parallelExtractBufferLimit {
+				data, err := io.ReadAll(tarReader)
+				if err != nil {
+					return finish(fmt.Errorf("failed to read tar entry %s: %w", header.Name, err))
+				}
+				pool.submit(extractWriteJob{targetPath: targetPath, mode: os.FileMode(header.Mode), data: data})
+				continue
+			}
+			// Extract regular file (large entries are written inline to
+			// bound memory use)
 			if err := extractSingleTarFile(tarReader, targetPath, os.FileMode(header.Mode)); err != nil {
-				return fmt.Errorf("failed to extract file %s: %w", targetPath, err)
+				return finish(fmt.Errorf("failed to extract file %s: %w", targetPath, err))
 			}
 		case tar.TypeSymlink:
-			// Create symlink, handling existing symlinks
-			if err := createSymlinkSafely(header.Linkname, targetPath); err != nil {
-				return fmt.Errorf("failed to create symlink %s: %w", targetPath, err)
+			// Create symlink after checking its target stays inside destDir
+			if err := validateSymlinkTarget(dest, targetPath, header.Linkname); err != nil {
+				return finish(err)
 			}
+			symlinks = append(symlinks, pendingSymlink{linkname: header.Linkname, targetPath: targetPath})
 		default:
 			// Skip other file types (char devices, block devices, etc.)
 			util.LogVerbose("Skipping unsupported file type %d for %s", header.Typeflag, header.Name)
 		}
 	}
 
-	return nil
+	return finish(nil)
 }
 
 // extractSingleTarFile extracts a single file from tar reader
 func extractSingleTarFile(tarReader *tar.Reader, targetPath string, mode os.FileMode) error {
+	// Extended-length form so deep JDK trees extract on Windows
+	targetPath = util.LongPath(targetPath)
+
 	// Create parent directory
 	if err := os.MkdirAll(filepath.Dir(targetPath), 0755); err != nil {
 		return err
@@ -323,13 +493,15 @@ func extractSingleTarFile(tarReader *tar.Reader, targetPath string, mode os.File
 	}
 	defer file.Close()
 
-	// Copy content
-	_, err = io.Copy(file, tarReader)
-	return err
+	// Copy content, capped to guard against decompression bombs
+	return copyLimited(file, tarReader, targetPath)
 }
 
 // createSymlinkSafely creates a symlink, handling existing files/symlinks
 func createSymlinkSafely(linkname, targetPath string) error {
+	// Extended-length form so deep JDK trees extract on Windows
+	targetPath = util.LongPath(targetPath)
+
 	// Check if target already exists
 	if _, err := os.Lstat(targetPath); err == nil {
 		// Target exists, check if it's already the correct symlink
@@ -356,25 +528,6 @@ func createSymlinkSafely(linkname, targetPath string) error {
 	return os.Symlink(linkname, targetPath)
 }
 
-// extractTarXzFile extracts a tar.xz file using system tar command
-func extractTarXzFile(src, dest string) error {
-	// Create destination directory
-	if err := os.MkdirAll(dest, 0755); err != nil {
-		return fmt.Errorf("failed to create destination directory: %w", err)
-	}
-
-	// Use system tar command for tar.xz files
-	cmd := exec.Command("tar", "-xJf", src, "-C", dest)
-	cmd.Stdout = os.Stdout
-	cmd.Stderr = os.Stderr
-
-	if err := cmd.Run(); err != nil {
-		return fmt.Errorf("failed to extract tar.xz file: %w", err)
-	}
-
-	return nil
-}
-
 // detectArchiveType detects the archive type from file extension
 func detectArchiveType(filename string) string {
 	filename = strings.ToLower(filename)
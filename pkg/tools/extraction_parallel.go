@@ -0,0 +1,148 @@
+package tools
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Parallel extraction overlaps decompression and disk writes across a
+// worker pool: tar entries are read off the (sequential) stream into
+// buffers and written concurrently, and zip entries are decompressed
+// entirely inside the workers. On fast disks this cuts JDK install time
+// significantly. MVX_NO_PARALLEL_EXTRACT=true (or --no-parallel-extract)
+// restores sequential extraction — concurrent writes can be slower on
+// network filesystems.
+
+// parallelExtractBufferLimit caps how large a tar entry may be to go
+// through the in-memory write queue; larger entries are written inline to
+// bound memory use
+const parallelExtractBufferLimit int64 = 8 << 20 // 8 MiB
+
+// parallelExtractionEnabled reports whether the worker-pool path is active
+func parallelExtractionEnabled() bool {
+	if disabled := os.Getenv("MVX_NO_PARALLEL_EXTRACT"); disabled == "1" || disabled == "true" {
+		return false
+	}
+	return runtime.NumCPU() > 1
+}
+
+// extractWriteJob is one file to materialize: either pre-read content
+// (tar) or a lazy reader decompressed inside the worker (zip)
+type extractWriteJob struct {
+	targetPath string
+	mode       os.FileMode
+	data       []byte
+	open       func() (io.ReadCloser, error)
+}
+
+// extractPool runs file writes across workers, keeping the first error
+type extractPool struct {
+	jobs chan extractWriteJob
+	wg   sync.WaitGroup
+
+	mu  sync.Mutex
+	err error
+}
+
+// newExtractPool starts one worker per CPU
+func newExtractPool() *extractPool {
+	workers := runtime.NumCPU()
+	p := &extractPool{jobs: make(chan extractWriteJob, workers*2)}
+	for i := 0; i < workers; i++ {
+		p.wg.Add(1)
+		go func() {
+			defer p.wg.Done()
+			for job := range p.jobs {
+				// After a failure, drain the queue without writing
+				if p.failed() {
+					continue
+				}
+				if err := writeExtractedFile(job); err != nil {
+					p.fail(err)
+				}
+			}
+		}()
+	}
+	return p
+}
+
+func (p *extractPool) fail(err error) {
+	p.mu.Lock()
+	if p.err == nil {
+		p.err = err
+	}
+	p.mu.Unlock()
+}
+
+func (p *extractPool) failed() bool {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	return p.err != nil
+}
+
+// submit queues one file write
+func (p *extractPool) submit(job extractWriteJob) {
+	p.jobs <- job
+}
+
+// wait closes the queue, waits for the workers and returns the first error
+func (p *extractPool) wait() error {
+	close(p.jobs)
+	p.wg.Wait()
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	return p.err
+}
+
+// writeExtractedFile materializes one job with the same permission handling
+// as the sequential path
+func writeExtractedFile(job extractWriteJob) error {
+	// Extended-length form so deep JDK trees extract on Windows
+	targetPath := util.LongPath(job.targetPath)
+
+	if err := os.MkdirAll(filepath.Dir(targetPath), 0755); err != nil {
+		return err
+	}
+
+	mode := job.mode
+	if mode&0200 == 0 {
+		mode |= 0200 // Add write permission for owner
+	}
+
+	file, err := os.OpenFile(targetPath, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, mode)
+	if err != nil {
+		return err
+	}
+
+	if job.open != nil {
+		reader, err := job.open()
+		if err != nil {
+			file.Close()
+			return err
+		}
+		err = copyLimited(file, reader, targetPath)
+		reader.Close()
+		if closeErr := file.Close(); err == nil {
+			err = closeErr
+		}
+		if err != nil {
+			return fmt.Errorf("failed to extract file %s: %w", targetPath, err)
+		}
+		return nil
+	}
+
+	_, err = file.Write(job.data)
+	if closeErr := file.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		return fmt.Errorf("failed to write file %s: %w", targetPath, err)
+	}
+	return nil
+}
@@ -0,0 +1,112 @@
+package tools
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+)
+
+// writeTestTarGz builds a small archive with a directory, files with
+// distinct permissions and a symlink
+func writeTestTarGz(t *testing.T, path string) {
+	t.Helper()
+	file, err := os.Create(path)
+	if err != nil {
+		t.Fatalf("failed to create archive: %v", err)
+	}
+	defer file.Close()
+	gzWriter := gzip.NewWriter(file)
+	defer gzWriter.Close()
+	tarWriter := tar.NewWriter(gzWriter)
+	defer tarWriter.Close()
+
+	entries := []struct {
+		name     string
+		typeflag byte
+		mode     int64
+		content  string
+		linkname string
+	}{
+		{name: "tool-1.0/", typeflag: tar.TypeDir, mode: 0755},
+		{name: "tool-1.0/bin/", typeflag: tar.TypeDir, mode: 0755},
+		{name: "tool-1.0/bin/tool", typeflag: tar.TypeReg, mode: 0755, content: "#!/bin/sh\necho tool\n"},
+		{name: "tool-1.0/lib/data.txt", typeflag: tar.TypeReg, mode: 0644, content: "data"},
+		{name: "tool-1.0/bin/alias", typeflag: tar.TypeSymlink, mode: 0777, linkname: "tool"},
+	}
+	for _, entry := range entries {
+		header := &tar.Header{
+			Name:     entry.name,
+			Typeflag: entry.typeflag,
+			Mode:     entry.mode,
+			Linkname: entry.linkname,
+			Size:     int64(len(entry.content)),
+		}
+		if err := tarWriter.WriteHeader(header); err != nil {
+			t.Fatalf("failed to write header %s: %v", entry.name, err)
+		}
+		if entry.content != "" {
+			if _, err := tarWriter.Write([]byte(entry.content)); err != nil {
+				t.Fatalf("failed to write content %s: %v", entry.name, err)
+			}
+		}
+	}
+}
+
+func checkExtractedTree(t *testing.T, dest string) {
+	t.Helper()
+	content, err := os.ReadFile(filepath.Join(dest, "bin", "tool"))
+	if err != nil {
+		t.Fatalf("extracted file missing: %v", err)
+	}
+	if string(content) != "#!/bin/sh\necho tool\n" {
+		t.Errorf("unexpected content: %q", content)
+	}
+	if runtime.GOOS != "windows" {
+		info, err := os.Stat(filepath.Join(dest, "bin", "tool"))
+		if err != nil {
+			t.Fatalf("stat failed: %v", err)
+		}
+		if info.Mode()&0100 == 0 {
+			t.Errorf("executable bit lost: %v", info.Mode())
+		}
+		link, err := os.Readlink(filepath.Join(dest, "bin", "alias"))
+		if err != nil {
+			t.Fatalf("symlink missing: %v", err)
+		}
+		if link != "tool" {
+			t.Errorf("unexpected symlink target %q", link)
+		}
+	}
+	if _, err := os.Stat(filepath.Join(dest, "lib", "data.txt")); err != nil {
+		t.Errorf("second file missing: %v", err)
+	}
+}
+
+func TestExtractTarGzParallelMatchesSequential(t *testing.T) {
+	archive := filepath.Join(t.TempDir(), "tool.tar.gz")
+	writeTestTarGz(t, archive)
+
+	t.Setenv("MVX_NO_PARALLEL_EXTRACT", "")
+	parallelDest := t.TempDir()
+	if err := extractTarGzFile(archive, parallelDest); err != nil {
+		t.Fatalf("parallel extraction failed: %v", err)
+	}
+	checkExtractedTree(t, parallelDest)
+
+	t.Setenv("MVX_NO_PARALLEL_EXTRACT", "true")
+	sequentialDest := t.TempDir()
+	if err := extractTarGzFile(archive, sequentialDest); err != nil {
+		t.Fatalf("sequential extraction failed: %v", err)
+	}
+	checkExtractedTree(t, sequentialDest)
+}
+
+func TestParallelExtractionEnabled(t *testing.T) {
+	t.Setenv("MVX_NO_PARALLEL_EXTRACT", "true")
+	if parallelExtractionEnabled() {
+		t.Error("MVX_NO_PARALLEL_EXTRACT=true should disable parallel extraction")
+	}
+}
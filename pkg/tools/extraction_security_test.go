@@ -0,0 +1,317 @@
+package tools
+
+import (
+	"archive/tar"
+	"archive/zip"
+	"bytes"
+	"compress/gzip"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// writeZipArchive writes a ZIP file with the given name -> content entries
+func writeZipArchive(t *testing.T, path string, entries map[string]string) {
+	t.Helper()
+	var buf bytes.Buffer
+	w := zip.NewWriter(&buf)
+	for name, content := range entries {
+		f, err := w.Create(name)
+		if err != nil {
+			t.Fatalf("Failed to create zip entry %s: %v", name, err)
+		}
+		if _, err := f.Write([]byte(content)); err != nil {
+			t.Fatalf("Failed to write zip entry %s: %v", name, err)
+		}
+	}
+	if err := w.Close(); err != nil {
+		t.Fatalf("Failed to close zip writer: %v", err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0644); err != nil {
+		t.Fatalf("Failed to write zip archive: %v", err)
+	}
+}
+
+// tarEntry describes one entry of a crafted tar.gz archive
+type tarEntry struct {
+	name     string
+	typeflag byte
+	linkname string
+	content  string
+}
+
+// writeTarGzArchive writes a tar.gz file with the given entries
+func writeTarGzArchive(t *testing.T, path string, entries []tarEntry) {
+	t.Helper()
+	var buf bytes.Buffer
+	gz := gzip.NewWriter(&buf)
+	tw := tar.NewWriter(gz)
+	for _, entry := range entries {
+		header := &tar.Header{
+			Name:     entry.name,
+			Typeflag: entry.typeflag,
+			Linkname: entry.linkname,
+			Mode:     0755,
+			Size:     int64(len(entry.content)),
+		}
+		if err := tw.WriteHeader(header); err != nil {
+			t.Fatalf("Failed to write tar header %s: %v", entry.name, err)
+		}
+		if entry.typeflag == tar.TypeReg {
+			if _, err := tw.Write([]byte(entry.content)); err != nil {
+				t.Fatalf("Failed to write tar entry %s: %v", entry.name, err)
+			}
+		}
+	}
+	if err := tw.Close(); err != nil {
+		t.Fatalf("Failed to close tar writer: %v", err)
+	}
+	if err := gz.Close(); err != nil {
+		t.Fatalf("Failed to close gzip writer: %v", err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0644); err != nil {
+		t.Fatalf("Failed to write tar.gz archive: %v", err)
+	}
+}
+
+// writeTarXzArchive writes a tar.xz file with the given entries, compressing
+// with the system xz binary; skips the test when xz is not installed
+func writeTarXzArchive(t *testing.T, path string, entries []tarEntry) {
+	t.Helper()
+	if _, err := exec.LookPath("xz"); err != nil {
+		t.Skip("xz binary not installed")
+	}
+
+	var buf bytes.Buffer
+	tw := tar.NewWriter(&buf)
+	for _, entry := range entries {
+		header := &tar.Header{
+			Name:     entry.name,
+			Typeflag: entry.typeflag,
+			Linkname: entry.linkname,
+			Mode:     0755,
+			Size:     int64(len(entry.content)),
+		}
+		if err := tw.WriteHeader(header); err != nil {
+			t.Fatalf("Failed to write tar header %s: %v", entry.name, err)
+		}
+		if entry.typeflag == tar.TypeReg {
+			if _, err := tw.Write([]byte(entry.content)); err != nil {
+				t.Fatalf("Failed to write tar entry %s: %v", entry.name, err)
+			}
+		}
+	}
+	if err := tw.Close(); err != nil {
+		t.Fatalf("Failed to close tar writer: %v", err)
+	}
+
+	tarPath := strings.TrimSuffix(path, ".xz")
+	if err := os.WriteFile(tarPath, buf.Bytes(), 0644); err != nil {
+		t.Fatalf("Failed to write tar archive: %v", err)
+	}
+	if output, err := exec.Command("xz", tarPath).CombinedOutput(); err != nil {
+		t.Fatalf("Failed to compress tar archive with xz: %v\n%s", err, output)
+	}
+}
+
+func TestExtractZipRejectsPathTraversal(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "evil.zip")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeZipArchive(t, archive, map[string]string{
+		"../escape.txt": "pwned",
+	})
+
+	err := extractZipFile(archive, dest)
+	if err == nil {
+		t.Fatal("Expected error for zip-slip entry, got nil")
+	}
+	if _, statErr := os.Stat(filepath.Join(tmpDir, "escape.txt")); statErr == nil {
+		t.Error("Zip-slip entry was written outside the destination directory")
+	}
+}
+
+func TestExtractZipRejectsAbsolutePath(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "evil.zip")
+
+	writeZipArchive(t, archive, map[string]string{
+		"/tmp/mvx-absolute-escape.txt": "pwned",
+	})
+
+	err := extractZipFile(archive, filepath.Join(tmpDir, "dest"))
+	if err == nil {
+		t.Fatal("Expected error for absolute path entry, got nil")
+	}
+	if !strings.Contains(err.Error(), "absolute path") {
+		t.Errorf("Expected absolute path error, got: %v", err)
+	}
+}
+
+func TestExtractTarRejectsPathTraversal(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "evil.tar.gz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarGzArchive(t, archive, []tarEntry{
+		{name: "ok.txt", typeflag: tar.TypeReg, content: "fine"},
+		{name: "../escape.txt", typeflag: tar.TypeReg, content: "pwned"},
+	})
+
+	err := extractTarGzFile(archive, dest)
+	if err == nil {
+		t.Fatal("Expected error for tar path traversal entry, got nil")
+	}
+	if _, statErr := os.Stat(filepath.Join(tmpDir, "escape.txt")); statErr == nil {
+		t.Error("Traversal entry was written outside the destination directory")
+	}
+}
+
+func TestExtractTarRejectsSymlinkEscape(t *testing.T) {
+	tmpDir := t.TempDir()
+	dest := filepath.Join(tmpDir, "dest")
+
+	cases := []struct {
+		name     string
+		linkname string
+	}{
+		{"relative escape", "../../outside"},
+		{"absolute target", "/etc/passwd"},
+	}
+
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			archive := filepath.Join(t.TempDir(), "evil.tar.gz")
+			writeTarGzArchive(t, archive, []tarEntry{
+				{name: "dir/", typeflag: tar.TypeDir},
+				{name: "dir/link", typeflag: tar.TypeSymlink, linkname: tc.linkname},
+			})
+
+			if err := extractTarGzFile(archive, dest); err == nil {
+				t.Errorf("Expected error for symlink target %q, got nil", tc.linkname)
+			}
+		})
+	}
+}
+
+func TestExtractTarAllowsInternalSymlink(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "ok.tar.gz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarGzArchive(t, archive, []tarEntry{
+		{name: "tool/", typeflag: tar.TypeDir},
+		{name: "tool/bin/", typeflag: tar.TypeDir},
+		{name: "tool/bin/real", typeflag: tar.TypeReg, content: "#!/bin/sh\n"},
+		{name: "tool/bin/alias", typeflag: tar.TypeSymlink, linkname: "real"},
+	})
+
+	if err := extractTarGzFile(archive, dest); err != nil {
+		t.Fatalf("Expected internal symlink to extract, got error: %v", err)
+	}
+	link, err := os.Readlink(filepath.Join(dest, "bin", "alias"))
+	if err != nil {
+		t.Fatalf("Failed to read extracted symlink: %v", err)
+	}
+	if link != "real" {
+		t.Errorf("Expected symlink target 'real', got %q", link)
+	}
+}
+
+func TestExtractTarXzRejectsPathTraversal(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "evil.tar.xz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarXzArchive(t, archive, []tarEntry{
+		{name: "ok.txt", typeflag: tar.TypeReg, content: "fine"},
+		{name: "../escape.txt", typeflag: tar.TypeReg, content: "pwned"},
+	})
+
+	err := extractTarXzFile(archive, dest)
+	if err == nil {
+		t.Fatal("Expected error for tar.xz path traversal entry, got nil")
+	}
+	if _, statErr := os.Stat(filepath.Join(tmpDir, "escape.txt")); statErr == nil {
+		t.Error("Traversal entry was written outside the destination directory")
+	}
+}
+
+func TestExtractTarXzRejectsSymlinkEscape(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "evil.tar.xz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarXzArchive(t, archive, []tarEntry{
+		{name: "dir/", typeflag: tar.TypeDir},
+		{name: "dir/link", typeflag: tar.TypeSymlink, linkname: "../../outside"},
+	})
+
+	if err := extractTarXzFile(archive, dest); err == nil {
+		t.Error("Expected error for escaping symlink in tar.xz, got nil")
+	}
+}
+
+func TestExtractTarXzExtractsRegularFiles(t *testing.T) {
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "ok.tar.xz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarXzArchive(t, archive, []tarEntry{
+		{name: "tool/", typeflag: tar.TypeDir},
+		{name: "tool/bin/", typeflag: tar.TypeDir},
+		{name: "tool/bin/run", typeflag: tar.TypeReg, content: "#!/bin/sh\n"},
+	})
+
+	if err := extractTarXzFile(archive, dest); err != nil {
+		t.Fatalf("Expected tar.xz archive to extract, got error: %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(dest, "bin", "run"))
+	if err != nil {
+		t.Fatalf("Failed to read extracted file: %v", err)
+	}
+	if string(content) != "#!/bin/sh\n" {
+		t.Errorf("Extracted file content mismatch: %q", content)
+	}
+}
+
+func TestExtractRejectsOversizedEntry(t *testing.T) {
+	oldLimit := maxExtractedFileSize
+	maxExtractedFileSize = 16
+	defer func() { maxExtractedFileSize = oldLimit }()
+
+	tmpDir := t.TempDir()
+	archive := filepath.Join(tmpDir, "big.tar.gz")
+	dest := filepath.Join(tmpDir, "dest")
+
+	writeTarGzArchive(t, archive, []tarEntry{
+		{name: "big.bin", typeflag: tar.TypeReg, content: strings.Repeat("x", 64)},
+	})
+
+	err := extractTarGzFile(archive, dest)
+	if err == nil {
+		t.Fatal("Expected error for entry above the size limit, got nil")
+	}
+	if !strings.Contains(err.Error(), "size limit") {
+		t.Errorf("Expected size limit error, got: %v", err)
+	}
+}
+
+func TestSanitizeExtractionPath(t *testing.T) {
+	dest := filepath.Join(t.TempDir(), "dest")
+
+	if _, err := sanitizeExtractionPath(dest, "bin/tool"); err != nil {
+		t.Errorf("Expected plain relative path to be accepted, got: %v", err)
+	}
+	if _, err := sanitizeExtractionPath(dest, "a/../b"); err != nil {
+		t.Errorf("Expected internal .. that stays inside dest to be accepted, got: %v", err)
+	}
+	for _, name := range []string{"../evil", "a/../../evil", "/abs/path", ""} {
+		if _, err := sanitizeExtractionPath(dest, name); err == nil {
+			t.Errorf("Expected %q to be rejected", name)
+		}
+	}
+}
@@ -0,0 +1,113 @@
+package tools
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// setupGraalVMComponents installs and validates the GraalVM components
+// (native-image, js, espresso, ...) declared in the tool configuration.
+// Older GraalVM releases ship the `gu` updater; newer releases (JDK 21+)
+// bundle native-image directly and removed gu, so both layouts are handled.
+func (j *JavaTool) setupGraalVMComponents(version, distribution string, cfg config.ToolConfig) error {
+	if !strings.HasPrefix(distribution, "graalvm") || len(cfg.Components) == 0 {
+		return nil
+	}
+
+	componentConfig := cfg
+	componentConfig.Distribution = distribution
+	binDir, err := j.GetPath(version, componentConfig)
+	if err != nil {
+		return fmt.Errorf("failed to locate GraalVM installation: %w", err)
+	}
+
+	guPath := filepath.Join(binDir, "gu")
+	if NewPlatformMapper().IsWindows() {
+		guPath += ExtCmd
+	}
+
+	if _, err := os.Stat(guPath); err == nil {
+		// gu-based GraalVM: install each requested component
+		for _, component := range cfg.Components {
+			fmt.Printf("  📦 Installing GraalVM component: %s\n", component)
+			cmd := exec.Command(guPath, "install", component)
+			cmd.Stdout = os.Stdout
+			cmd.Stderr = os.Stderr
+			if err := cmd.Run(); err != nil {
+				return fmt.Errorf("failed to install GraalVM component %s: %w", component, err)
+			}
+		}
+	} else {
+		// gu-less GraalVM (JDK 21+): components must be bundled, so only validate
+		util.LogVerbose("GraalVM %s has no gu updater, checking bundled components", version)
+		for _, component := range cfg.Components {
+			if err := checkBundledComponent(binDir, component); err != nil {
+				return err
+			}
+		}
+	}
+
+	// native-image compiles through the host C toolchain, which fresh
+	// machines frequently lack — fail here with a fix rather than deep
+	// inside a native build
+	for _, component := range cfg.Components {
+		if component == "native-image" {
+			if err := checkNativeToolchain(); err != nil {
+				return err
+			}
+		}
+	}
+
+	fmt.Printf("  ✅ GraalVM components ready: %s\n", strings.Join(cfg.Components, ", "))
+	return nil
+}
+
+// checkBundledComponent verifies that a component's launcher exists in a
+// gu-less GraalVM installation.
+func checkBundledComponent(binDir, component string) error {
+	// Components map to launchers in bin/; espresso is exposed through
+	// java -truffle so only its language home can be checked
+	launcher := component
+	if component == "espresso" {
+		espressoHome := filepath.Join(binDir, "..", "languages", "java")
+		if _, err := os.Stat(espressoHome); err != nil {
+			return fmt.Errorf("GraalVM component espresso is not bundled in this release — use a GraalVM build that includes it or install it from a gu-based release")
+		}
+		return nil
+	}
+
+	launcherPath := filepath.Join(binDir, launcher)
+	if NewPlatformMapper().IsWindows() {
+		launcherPath += ExtCmd
+	}
+	if _, err := os.Stat(launcherPath); err != nil {
+		return fmt.Errorf("GraalVM component %s is not bundled in this release — recent GraalVM downloads include native-image by default, other components need a standalone download", component)
+	}
+	return nil
+}
+
+// checkNativeToolchain verifies that a native C toolchain is available for
+// native-image builds, returning an actionable error when it is missing.
+func checkNativeToolchain() error {
+	if NewPlatformMapper().IsWindows() {
+		if _, err := exec.LookPath("cl.exe"); err != nil {
+			return fmt.Errorf("native-image requires the Visual Studio C toolchain but cl.exe was not found in PATH — install 'Visual Studio Build Tools' with the C++ workload and run from a 'x64 Native Tools' prompt")
+		}
+		return nil
+	}
+
+	for _, compiler := range []string{"cc", "gcc", "clang"} {
+		if _, err := exec.LookPath(compiler); err == nil {
+			util.LogVerbose("Found native C compiler: %s", compiler)
+			return nil
+		}
+	}
+
+	return fmt.Errorf("native-image requires a C toolchain but no cc/gcc/clang was found in PATH — install build-essential (Debian/Ubuntu), 'Development Tools' (Fedora/RHEL) or run 'xcode-select --install' (macOS)")
+}
@@ -0,0 +1,70 @@
+package tools
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+)
+
+// Hermetic mode runs commands with a minimal, fully mvx-controlled
+// environment: the inherited PATH and everything not on the essential or
+// passthrough lists is stripped, so builds cannot accidentally depend on
+// system Java/Maven/Node or developer-specific variables.
+
+// hermeticEssentialVars are inherited variables every process needs to
+// function (locale, temp dirs, the user's identity and terminal)
+var hermeticEssentialVars = []string{
+	"HOME", "USER", "LOGNAME", "SHELL", "TERM",
+	"TMPDIR", "TEMP", "TMP",
+	"LANG", "LC_ALL", "TZ",
+	// Windows essentials
+	"SYSTEMROOT", "SYSTEMDRIVE", "WINDIR", "COMSPEC", "PATHEXT",
+	"USERPROFILE", "HOMEDRIVE", "HOMEPATH", "APPDATA", "LOCALAPPDATA",
+	"PROGRAMDATA", "PROCESSOR_ARCHITECTURE", "NUMBER_OF_PROCESSORS",
+}
+
+// HermeticBaseEnvironment returns the minimal base environment for hermetic
+// execution: essential variables, MVX_* settings, any passthrough names from
+// the configuration, and a bare system PATH (tool directories are layered on
+// top by environment setup).
+func HermeticBaseEnvironment(passthrough []string) []string {
+	keep := make(map[string]bool, len(hermeticEssentialVars)+len(passthrough))
+	for _, name := range hermeticEssentialVars {
+		keep[name] = true
+	}
+	for _, name := range passthrough {
+		keep[name] = true
+	}
+
+	var base []string
+	for _, envVar := range os.Environ() {
+		name, _, found := strings.Cut(envVar, "=")
+		if !found {
+			continue
+		}
+		// MVX_* settings pass through so nested mvx invocations behave the same
+		if keep[name] || strings.HasPrefix(name, "MVX_") {
+			base = append(base, envVar)
+		}
+	}
+
+	base = append(base, "PATH="+hermeticSystemPath())
+	return base
+}
+
+// hermeticSystemPath returns the bare PATH hermetic commands start from:
+// enough for shells and coreutils, without any toolchain directories
+func hermeticSystemPath() string {
+	if runtime.GOOS == "windows" {
+		systemRoot := os.Getenv("SYSTEMROOT")
+		if systemRoot == "" {
+			systemRoot = `C:\Windows`
+		}
+		return strings.Join([]string{
+			filepath.Join(systemRoot, "system32"),
+			systemRoot,
+		}, string(os.PathListSeparator))
+	}
+	return strings.Join([]string{"/usr/bin", "/bin", "/usr/sbin", "/sbin"}, string(os.PathListSeparator))
+}
@@ -0,0 +1,33 @@
+package tools
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestHermeticBaseEnvironment(t *testing.T) {
+	t.Setenv("MVX_PROFILE", "ci")
+	t.Setenv("SOME_RANDOM_TOOL_HOME", "/opt/random")
+	t.Setenv("KEEP_ME", "yes")
+
+	base := HermeticBaseEnvironment([]string{"KEEP_ME"})
+
+	byName := make(map[string]string)
+	for _, envVar := range base {
+		name, value, _ := strings.Cut(envVar, "=")
+		byName[name] = value
+	}
+
+	if _, exists := byName["SOME_RANDOM_TOOL_HOME"]; exists {
+		t.Error("non-essential variables should be stripped")
+	}
+	if byName["KEEP_ME"] != "yes" {
+		t.Error("passthrough variables should be kept")
+	}
+	if byName["MVX_PROFILE"] != "ci" {
+		t.Error("MVX_* variables should be kept")
+	}
+	if byName["PATH"] == "" {
+		t.Error("a bare system PATH should be provided")
+	}
+}
@@ -0,0 +1,84 @@
+package tools
+
+import (
+	"fmt"
+	"net/url"
+	"os"
+	"strings"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// Download host policy: when the project config (security.allowedHosts) or
+// the org policy (MVX_ALLOWED_HOSTS, comma-separated) declares an allowlist,
+// every resolved download URL must match it. Both lists apply independently,
+// so an org policy cannot be widened by a project config. Org-level extended
+// configs are enforceable too: a parent's security.allowedHosts survives the
+// extends merge (see mergeConfigs).
+var (
+	allowedHostsMutex sync.RWMutex
+	projectHostList   []string
+)
+
+// configureAllowedHosts records the project's download host allowlist.
+// security.allowedHosts is the canonical key; the legacy top-level
+// allowed_hosts is still honored when the security block does not set one.
+func configureAllowedHosts(cfg *config.Config) {
+	allowedHostsMutex.Lock()
+	defer allowedHostsMutex.Unlock()
+	projectHostList = cfg.AllowedHosts
+	if cfg.Security != nil && len(cfg.Security.AllowedHosts) > 0 {
+		projectHostList = cfg.Security.AllowedHosts
+	}
+}
+
+// checkHostAllowed verifies a download URL against the project and org host
+// allowlists. Empty lists allow everything.
+func checkHostAllowed(rawURL string) error {
+	parsed, err := url.Parse(rawURL)
+	if err != nil {
+		return fmt.Errorf("invalid download URL %s: %w", rawURL, err)
+	}
+	host := parsed.Hostname()
+
+	allowedHostsMutex.RLock()
+	project := projectHostList
+	allowedHostsMutex.RUnlock()
+
+	if len(project) > 0 && !hostMatchesAny(host, project) {
+		return fmt.Errorf("download host %s is not in the project's allowed_hosts list (%s)",
+			host, strings.Join(project, ", "))
+	}
+
+	if policy := os.Getenv("MVX_ALLOWED_HOSTS"); policy != "" {
+		var org []string
+		for _, entry := range strings.Split(policy, ",") {
+			if entry = strings.TrimSpace(entry); entry != "" {
+				org = append(org, entry)
+			}
+		}
+		if len(org) > 0 && !hostMatchesAny(host, org) {
+			return fmt.Errorf("download host %s is not allowed by MVX_ALLOWED_HOSTS (%s)",
+				host, strings.Join(org, ", "))
+		}
+	}
+
+	return nil
+}
+
+// hostMatchesAny reports whether host matches one of the patterns. Patterns
+// are exact host names or "*.example.com" wildcards covering subdomains.
+func hostMatchesAny(host string, patterns []string) bool {
+	for _, pattern := range patterns {
+		if strings.EqualFold(host, pattern) {
+			return true
+		}
+		if suffix, ok := strings.CutPrefix(pattern, "*."); ok {
+			if strings.EqualFold(host, suffix) || strings.HasSuffix(strings.ToLower(host), "."+strings.ToLower(suffix)) {
+				return true
+			}
+		}
+	}
+	return false
+}
@@ -0,0 +1,70 @@
+package tools
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestHostMatchesAny(t *testing.T) {
+	cases := []struct {
+		host     string
+		patterns []string
+		want     bool
+	}{
+		{"downloads.apache.org", []string{"downloads.apache.org"}, true},
+		{"Downloads.Apache.Org", []string{"downloads.apache.org"}, true},
+		{"archive.apache.org", []string{"downloads.apache.org"}, false},
+		{"cdn.mirror.example.com", []string{"*.example.com"}, true},
+		{"example.com", []string{"*.example.com"}, true},
+		{"evil-example.com", []string{"*.example.com"}, false},
+		{"anything.org", nil, false},
+	}
+
+	for _, tc := range cases {
+		if got := hostMatchesAny(tc.host, tc.patterns); got != tc.want {
+			t.Errorf("hostMatchesAny(%q, %v) = %v, want %v", tc.host, tc.patterns, got, tc.want)
+		}
+	}
+}
+
+func TestCheckHostAllowed(t *testing.T) {
+	defer configureAllowedHosts(&config.Config{})
+
+	// No allowlists configured: everything is allowed
+	configureAllowedHosts(&config.Config{})
+	if err := checkHostAllowed("https://nodejs.org/dist/v20.0.0/node.tar.gz"); err != nil {
+		t.Errorf("expected no error without allowlists, got: %v", err)
+	}
+
+	// Project allowlist rejects other hosts
+	configureAllowedHosts(&config.Config{AllowedHosts: []string{"mirror.company.com"}})
+	if err := checkHostAllowed("https://mirror.company.com/java/21.tar.gz"); err != nil {
+		t.Errorf("expected allowed host to pass, got: %v", err)
+	}
+	if err := checkHostAllowed("https://nodejs.org/dist/v20.0.0/node.tar.gz"); err == nil {
+		t.Error("expected disallowed host to be rejected")
+	}
+
+	// security.allowedHosts takes precedence over the legacy top-level key
+	configureAllowedHosts(&config.Config{
+		AllowedHosts: []string{"legacy.company.com"},
+		Security:     &config.SecurityConfig{AllowedHosts: []string{"mirror.company.com"}},
+	})
+	if err := checkHostAllowed("https://mirror.company.com/java/21.tar.gz"); err != nil {
+		t.Errorf("expected security.allowedHosts host to pass, got: %v", err)
+	}
+	if err := checkHostAllowed("https://legacy.company.com/java/21.tar.gz"); err == nil {
+		t.Error("expected legacy list to be superseded by security.allowedHosts")
+	}
+
+	// Org policy applies on top of the project allowlist
+	configureAllowedHosts(&config.Config{AllowedHosts: []string{"*.company.com", "nodejs.org"}})
+	t.Setenv("MVX_ALLOWED_HOSTS", "*.company.com")
+	if err := checkHostAllowed("https://nodejs.org/dist/v20.0.0/node.tar.gz"); err == nil {
+		t.Error("expected org policy to reject host outside MVX_ALLOWED_HOSTS")
+	}
+	if err := checkHostAllowed("https://mirror.company.com/node.tar.gz"); err != nil {
+		t.Errorf("expected host allowed by both lists to pass, got: %v", err)
+	}
+}
@@ -0,0 +1,66 @@
+package tools
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Cross-process install locking: multiple mvx runs on the same machine (CI
+// jobs sharing ~/.mvx) must not install the same tool version concurrently.
+// Locks are plain files created with O_EXCL under ~/.mvx/locks; waiters poll
+// until the holder releases the lock, then reuse the finished install.
+const (
+	installLockPoll  = 500 * time.Millisecond
+	installLockStale = 30 * time.Minute // abandoned locks (crashed process) are broken after this
+)
+
+// acquireInstallLock takes the exclusive lock for a tool version install and
+// returns the release function. When no lock directory can be created the
+// install proceeds unlocked (best effort, as before locking existed).
+func acquireInstallLock(toolName, version, distribution string) func() {
+	mvxHome, err := util.MvxHome()
+	if err != nil {
+		return func() {}
+	}
+	lockDir := filepath.Join(mvxHome, "locks")
+	if err := os.MkdirAll(lockDir, 0755); err != nil {
+		return func() {}
+	}
+
+	name := toolName + "-" + version
+	if distribution != "" {
+		name += "@" + distribution
+	}
+	lockPath := filepath.Join(lockDir, name+".lock")
+
+	waiting := false
+	for {
+		file, err := os.OpenFile(lockPath, os.O_CREATE|os.O_EXCL|os.O_WRONLY, 0644)
+		if err == nil {
+			fmt.Fprintf(file, "%d\n", os.Getpid())
+			file.Close()
+			return func() { os.Remove(lockPath) }
+		}
+		if !os.IsExist(err) {
+			// Lock directory unusable (permissions?): proceed unlocked
+			return func() {}
+		}
+
+		// Break locks left behind by a crashed process
+		if info, statErr := os.Stat(lockPath); statErr == nil && time.Since(info.ModTime()) > installLockStale {
+			util.LogVerbose("Breaking stale install lock %s", lockPath)
+			os.Remove(lockPath)
+			continue
+		}
+
+		if !waiting {
+			util.LogInfo("  ⏳ Waiting for another mvx process installing %s %s...", toolName, version)
+			waiting = true
+		}
+		time.Sleep(installLockPoll)
+	}
+}
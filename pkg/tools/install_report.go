@@ -0,0 +1,66 @@
+package tools
+
+import (
+	"sync"
+	"time"
+)
+
+// Install reporting: EnsureTool records what happened to every tool it
+// touched (cache hit or fresh install, duration, download size, failure),
+// so `mvx setup --report` can emit a structured artifact for CI upload and
+// fleet-wide aggregation.
+
+// InstallRecord describes the outcome of ensuring one tool
+type InstallRecord struct {
+	Tool            string `json:"tool"`
+	Version         string `json:"version"`
+	Distribution    string `json:"distribution,omitempty"`
+	CacheHit        bool   `json:"cacheHit"`        // already installed, nothing downloaded
+	DurationMs      int64  `json:"durationMs"`      // wall time of EnsureTool
+	DownloadedBytes int64  `json:"downloadedBytes"` // archive bytes fetched (0 on cache hits)
+	Error           string `json:"error,omitempty"` // failure reason, empty on success
+}
+
+var (
+	installReportMutex sync.Mutex
+	installRecords     []InstallRecord
+	downloadSizes      = map[string]int64{}
+)
+
+// recordInstallResult appends one tool outcome to the report
+func recordInstallResult(record InstallRecord) {
+	installReportMutex.Lock()
+	defer installReportMutex.Unlock()
+	installRecords = append(installRecords, record)
+}
+
+// recordDownloadSize accumulates archive bytes fetched for a tool
+func recordDownloadSize(toolName string, bytes int64) {
+	if toolName == "" || bytes <= 0 {
+		return
+	}
+	installReportMutex.Lock()
+	defer installReportMutex.Unlock()
+	downloadSizes[toolName] += bytes
+}
+
+// downloadedBytes returns the archive bytes fetched for a tool so far
+func downloadedBytes(toolName string) int64 {
+	installReportMutex.Lock()
+	defer installReportMutex.Unlock()
+	return downloadSizes[toolName]
+}
+
+// InstallRecords returns a copy of the outcomes recorded in this process
+func InstallRecords() []InstallRecord {
+	installReportMutex.Lock()
+	defer installReportMutex.Unlock()
+	records := make([]InstallRecord, len(installRecords))
+	copy(records, installRecords)
+	return records
+}
+
+// sinceMs returns elapsed wall time in milliseconds
+func sinceMs(start time.Time) int64 {
+	return time.Since(start).Milliseconds()
+}
@@ -0,0 +1,29 @@
+package tools
+
+import "testing"
+
+func TestInstallReportRecording(t *testing.T) {
+	before := len(InstallRecords())
+
+	recordDownloadSize("reporttool", 1024)
+	recordDownloadSize("reporttool", 512)
+	if got := downloadedBytes("reporttool"); got != 1536 {
+		t.Errorf("expected accumulated download size 1536, got %d", got)
+	}
+
+	recordInstallResult(InstallRecord{Tool: "reporttool", Version: "1.0.0", DownloadedBytes: 1536})
+	records := InstallRecords()
+	if len(records) != before+1 {
+		t.Fatalf("expected %d records, got %d", before+1, len(records))
+	}
+	last := records[len(records)-1]
+	if last.Tool != "reporttool" || last.DownloadedBytes != 1536 {
+		t.Errorf("unexpected record: %+v", last)
+	}
+
+	// InstallRecords returns a copy; mutating it must not affect the report
+	records[len(records)-1].Tool = "mutated"
+	if InstallRecords()[len(records)-1].Tool != "reporttool" {
+		t.Error("InstallRecords should return a copy")
+	}
+}
@@ -0,0 +1,196 @@
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Internal registry support lets enterprises point mvx at an internal HTTP
+// registry serving tool metadata (versions, URLs, checksums) and artifacts,
+// so discovery and downloads never have to touch the public internet.
+//
+// Registry layout (relative to the configured base URL):
+//
+//	{tool}/versions.json       -> {"versions": ["1.2.3", ...]} or a plain array
+//	{tool}/{version}.json      -> {"url": "...", "urls": {"linux-amd64": "..."}, "checksum": {...}}
+//
+// Authentication uses a bearer token from the env var named in authEnv, or
+// basic credentials from ~/.netrc for the registry host.
+
+// registryArtifact describes a downloadable artifact resolved from an internal registry
+type registryArtifact struct {
+	URL      string                 `json:"url,omitempty"`
+	URLs     map[string]string      `json:"urls,omitempty"` // per-platform URLs keyed by os-arch
+	Checksum *config.ChecksumConfig `json:"checksum,omitempty"`
+}
+
+// ConfigureRegistries stores the download policy from the project
+// configuration (internal registries, mirrors, host allowlist, signature
+// policy) so tool discovery and downloads can consult them
+func (m *Manager) ConfigureRegistries(cfg *config.Config) {
+	m.registries = cfg.Registries
+	configureMirrors(cfg)
+	configureAllowedHosts(cfg)
+	configureSignaturePolicy(cfg)
+	configureProvenancePolicy(cfg)
+}
+
+// registryFor returns the registry configured for a tool, checking the
+// tool-specific entry first and then the "*" catch-all entry
+func (m *Manager) registryFor(toolName string) (config.RegistryConfig, bool) {
+	if reg, exists := m.registries[toolName]; exists {
+		return reg, true
+	}
+	if reg, exists := m.registries["*"]; exists {
+		return reg, true
+	}
+	return config.RegistryConfig{}, false
+}
+
+// registryGet performs an authenticated GET against an internal registry
+func (m *Manager) registryGet(reg config.RegistryConfig, path string) ([]byte, error) {
+	requestURL := strings.TrimSuffix(reg.URL, "/") + "/" + path
+
+	req, err := http.NewRequest(http.MethodGet, requestURL, nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if reg.AuthEnv != "" {
+		if token := os.Getenv(reg.AuthEnv); token != "" {
+			req.Header.Set("Authorization", "Bearer "+token)
+		} else {
+			util.LogVerbose("Registry auth env var %s is not set", reg.AuthEnv)
+		}
+	} else if login, password, found := netrcCredentials(requestURL); found {
+		req.SetBasicAuth(login, password)
+	}
+
+	util.LogVerbose("Registry GET: %s", requestURL)
+	resp, err := m.httpClient.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("registry request %s returned status %d", requestURL, resp.StatusCode)
+	}
+
+	return io.ReadAll(resp.Body)
+}
+
+// fetchRegistryVersions fetches the available versions for a tool from its
+// configured internal registry. Returns (nil, false) when no registry applies.
+func (m *Manager) fetchRegistryVersions(toolName string) ([]string, bool, error) {
+	reg, exists := m.registryFor(toolName)
+	if !exists {
+		return nil, false, nil
+	}
+
+	body, err := m.registryGet(reg, toolName+"/versions.json")
+	if err != nil {
+		return nil, true, fmt.Errorf("failed to fetch versions for %s from registry: %w", toolName, err)
+	}
+
+	// Accept both {"versions": [...]} and a plain JSON array
+	var wrapped struct {
+		Versions []string `json:"versions"`
+	}
+	if err := json.Unmarshal(body, &wrapped); err == nil && len(wrapped.Versions) > 0 {
+		return wrapped.Versions, true, nil
+	}
+	var plain []string
+	if err := json.Unmarshal(body, &plain); err == nil {
+		return plain, true, nil
+	}
+
+	return nil, true, fmt.Errorf("invalid versions.json for %s in registry", toolName)
+}
+
+// registryArtifactFor fetches artifact metadata (download URL, checksum) for a
+// tool version from its configured internal registry.
+// Returns (artifact, true, nil) on success and (_, false, nil) when no registry applies.
+func (m *Manager) registryArtifactFor(toolName, version string) (registryArtifact, bool, error) {
+	reg, exists := m.registryFor(toolName)
+	if !exists {
+		return registryArtifact{}, false, nil
+	}
+
+	body, err := m.registryGet(reg, fmt.Sprintf("%s/%s.json", toolName, version))
+	if err != nil {
+		return registryArtifact{}, true, fmt.Errorf("failed to fetch %s %s metadata from registry: %w", toolName, version, err)
+	}
+
+	var artifact registryArtifact
+	if err := json.Unmarshal(body, &artifact); err != nil {
+		return registryArtifact{}, true, fmt.Errorf("invalid artifact metadata for %s %s in registry: %w", toolName, version, err)
+	}
+
+	return artifact, true, nil
+}
+
+// resolveRegistryDownloadURL returns the registry-provided download URL for the
+// current platform, or empty string if the registry does not override the URL
+func (a registryArtifact) downloadURL() string {
+	if len(a.URLs) > 0 {
+		platform := NewPlatformMapper().GetGenericPlatform()
+		if url, exists := a.URLs[platform]; exists {
+			return url
+		}
+		if url, exists := a.URLs["default"]; exists {
+			return url
+		}
+	}
+	return a.URL
+}
+
+// netrcCredentials looks up basic-auth credentials for a URL's host in ~/.netrc
+func netrcCredentials(requestURL string) (login, password string, found bool) {
+	parsed, err := url.Parse(requestURL)
+	if err != nil {
+		return "", "", false
+	}
+
+	homeDir, err := os.UserHomeDir()
+	if err != nil {
+		return "", "", false
+	}
+
+	data, err := os.ReadFile(filepath.Join(homeDir, ".netrc"))
+	if err != nil {
+		return "", "", false
+	}
+
+	// Minimal netrc parsing: token stream of "machine <host> login <l> password <p>"
+	fields := strings.Fields(string(data))
+	var currentMachine string
+	for i := 0; i < len(fields)-1; i++ {
+		switch fields[i] {
+		case "machine":
+			currentMachine = fields[i+1]
+		case "login":
+			if currentMachine == parsed.Hostname() {
+				login = fields[i+1]
+			}
+		case "password":
+			if currentMachine == parsed.Hostname() {
+				password = fields[i+1]
+			}
+		}
+	}
+
+	if login != "" || password != "" {
+		return login, password, true
+	}
+	return "", "", false
+}
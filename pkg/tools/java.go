@@ -9,6 +9,7 @@ import (
 	"os/exec"
 	"path/filepath"
 	"sort"
+	"strconv"
 	"strings"
 
 	"github.com/gnodet/mvx/pkg/config"
@@ -107,6 +108,10 @@ type JavaTool struct {
 	*BaseTool
 }
 
+// javaFallbackDistributions are tried in order when the requested
+// distribution does not provide the version for the current platform
+var javaFallbackDistributions = []string{"temurin", "zulu", "microsoft", "corretto", "liberica", "semeru"}
+
 func getJavaBinaryName() string {
 	if NewPlatformMapper().IsWindows() {
 		return BinaryJava + ExtExe
@@ -123,6 +128,11 @@ func NewJavaTool(manager *Manager) *JavaTool {
 
 // Install downloads and installs the specified Java version
 func (j *JavaTool) Install(version string, cfg config.ToolConfig) error {
+	// Early-access and project builds (Loom, Valhalla, ...) come from jdk.java.net
+	if source := javaSource(version, cfg); source != "" {
+		return j.installFromJdkJavaNet(version, cfg, source)
+	}
+
 	distribution := cfg.Distribution
 	if distribution == "" {
 		distribution = "temurin" // Default to Eclipse Temurin
@@ -206,6 +216,11 @@ func (j *JavaTool) installWithDistribution(version string, cfg config.ToolConfig
 		return InstallError(j.toolName, version, fmt.Errorf("installation verification failed: %w", err))
 	}
 
+	// Install and validate GraalVM components (native-image, js, espresso, ...)
+	if err := j.setupGraalVMComponents(version, distribution, cfg); err != nil {
+		return InstallError(j.toolName, version, err)
+	}
+
 	fmt.Printf("  ✅ %s %s installation verification successful\n", j.toolName, version)
 	return nil
 }
@@ -241,8 +256,7 @@ func (j *JavaTool) getDownloadURLWithChecksum(version, distribution string) (str
 	}
 
 	// If primary distribution fails, try fallback distributions
-	fallbackDistributions := []string{"temurin", "zulu", "microsoft", "corretto"}
-	for _, fallback := range fallbackDistributions {
+	for _, fallback := range javaFallbackDistributions {
 		if fallback == distribution {
 			continue // Already tried this one
 		}
@@ -255,6 +269,16 @@ func (j *JavaTool) getDownloadURLWithChecksum(version, distribution string) (str
 		}
 	}
 
+	// Windows on ARM runs x64 binaries under emulation — last resort when
+	// no distribution publishes a native aarch64 build
+	if platformMapper.IsWindowsARM64() {
+		fmt.Printf("  🔄 No windows/aarch64 build found, falling back to x64 (emulated)\n")
+		result, err := j.tryDiscoDistributionWithChecksum(version, distribution, osName, "x64", releaseStatus)
+		if err == nil && result.DownloadURL != "" {
+			return result.DownloadURL, result.PackageID, nil
+		}
+	}
+
 	return "", "", URLGenerationError(ToolJava, version, fmt.Errorf("Java %s not available in any supported distribution for %s/%s", version, osName, arch))
 }
 
@@ -276,6 +300,9 @@ func (j *JavaTool) IsInstalled(version string, cfg config.ToolConfig) bool {
 	if distribution == "" {
 		distribution = "temurin"
 	}
+	if source := javaSource(version, cfg); source != "" {
+		distribution = source
+	}
 	fullVersion, err := j.ResolveVersion(version, distribution)
 	if err != nil {
 		util.LogVerbose("Failed to resolve full Java version for check: %v", err)
@@ -309,6 +336,9 @@ func (j *JavaTool) getJavaHomeUncached(version string, cfg config.ToolConfig) (s
 	if distribution == "" {
 		distribution = "temurin"
 	}
+	if source := javaSource(version, cfg); source != "" {
+		distribution = source
+	}
 
 	// If using system Java, return system JAVA_HOME if available (no version compatibility check)
 	if UseSystemTool(ToolJava) {
@@ -455,6 +485,10 @@ func (j *JavaTool) GetDistributions() []Distribution {
 			Name:        "microsoft",
 			DisplayName: "Microsoft Build of OpenJDK",
 		},
+		{
+			Name:        "semeru",
+			DisplayName: "IBM Semeru (OpenJ9)",
+		},
 	}
 }
 
@@ -557,8 +591,76 @@ func (j *JavaTool) getDiscoVersions(distribution string) ([]string, error) {
 	return versions, nil
 }
 
+// SearchVersions implements VersionSearcher for Java using the Foojay Disco
+// API. Filters may name a distribution ("temurin", "zulu", ...), a release
+// status ("ga", "ea"), "lts", or a major version number. When a major
+// version is given, the precise builds available for the current platform
+// are returned instead of the major version list.
+func (j *JavaTool) SearchVersions(filters []string) ([]string, error) {
+	distribution := ""
+	releaseStatus := ""
+	ltsOnly := false
+	major := ""
+
+	for _, filter := range filters {
+		switch f := strings.ToLower(filter); f {
+		case "lts":
+			ltsOnly = true
+		case "ga", "ea":
+			releaseStatus = f
+		default:
+			if _, err := strconv.Atoi(f); err == nil {
+				major = f
+			} else {
+				distribution = f
+			}
+		}
+	}
+
+	// A concrete major version: list the precise platform builds
+	if major != "" {
+		status := releaseStatus
+		if status == "" {
+			status = "ga"
+		}
+		return j.getDetailedVersionsForMajorWithStatus(major, distribution, status)
+	}
+
+	majors, err := j.getDiscoVersions(distribution)
+	if err != nil {
+		return nil, err
+	}
+
+	var versions []string
+	for _, v := range majors {
+		isEA := strings.HasSuffix(v, "-ea")
+		if releaseStatus == "ga" && isEA {
+			continue
+		}
+		if releaseStatus == "ea" && !isEA {
+			continue
+		}
+		majorNum, numErr := strconv.Atoi(strings.TrimSuffix(v, "-ea"))
+		isLTS := numErr == nil && !isEA && isJavaLTSMajor(majorNum)
+		if ltsOnly && !isLTS {
+			continue
+		}
+		if isLTS {
+			v += " (LTS)"
+		}
+		versions = append(versions, v)
+	}
+	return versions, nil
+}
+
 // getDetailedVersionsForMajor fetches detailed versions (e.g., "17.0.16") for a specific major version and distribution
 func (j *JavaTool) getDetailedVersionsForMajor(majorVersion, distribution string) ([]string, error) {
+	return j.getDetailedVersionsForMajorWithStatus(majorVersion, distribution, "ga")
+}
+
+// getDetailedVersionsForMajorWithStatus fetches detailed versions for a major
+// version, distribution and release status ("ga" or "ea")
+func (j *JavaTool) getDetailedVersionsForMajorWithStatus(majorVersion, distribution, releaseStatus string) ([]string, error) {
 	if distribution == "" {
 		distribution = "temurin"
 	}
@@ -579,8 +681,8 @@ func (j *JavaTool) getDetailedVersionsForMajor(majorVersion, distribution string
 	osName := platformMapper.MapOS(osMapping)
 
 	// Query packages for this major version and distribution
-	url := fmt.Sprintf("%s/packages?version=%s&distribution=%s&operating_system=%s&architecture=%s&package_type=jdk&release_status=ga&latest=available",
-		FoojayDiscoAPIBase, majorVersion, distribution, osName, arch)
+	url := fmt.Sprintf("%s/packages?version=%s&distribution=%s&operating_system=%s&architecture=%s&package_type=jdk&release_status=%s&latest=available",
+		FoojayDiscoAPIBase, majorVersion, distribution, osName, arch, releaseStatus)
 
 	util.LogVerbose("Fetching detailed versions for Java %s (%s) from: %s", majorVersion, distribution, url)
 
@@ -666,8 +768,7 @@ func (j *JavaTool) getDiscoURL(version, distribution string) (string, error) {
 	}
 
 	// If primary distribution fails, try fallback distributions
-	fallbackDistributions := []string{"temurin", "zulu", "microsoft", "corretto"}
-	for _, fallback := range fallbackDistributions {
+	for _, fallback := range javaFallbackDistributions {
 		if fallback == distribution {
 			continue // Already tried this one
 		}
@@ -680,6 +781,16 @@ func (j *JavaTool) getDiscoURL(version, distribution string) (string, error) {
 		}
 	}
 
+	// Windows on ARM runs x64 binaries under emulation — last resort when
+	// no distribution publishes a native aarch64 build
+	if platformMapper.IsWindowsARM64() {
+		fmt.Printf("  🔄 No windows/aarch64 build found, falling back to x64 (emulated)\n")
+		downloadURL, err := j.tryDiscoDistribution(version, distribution, osName, "x64", releaseStatus)
+		if err == nil && downloadURL != "" {
+			return downloadURL, nil
+		}
+	}
+
 	return "", URLGenerationError("java", version, fmt.Errorf("Java %s not available in any supported distribution for %s/%s", version, osName, arch))
 }
 
@@ -818,17 +929,21 @@ func (j *JavaTool) tryDiscoDistributionWithChecksum(version, distribution, osNam
 	}
 
 	// Select the best package with priority order:
-	// 1. glibc packages (Linux tar.gz with glibc)
-	// 2. musl packages (Linux tar.gz with musl)
+	// 1. packages matching the host libc (glibc, or musl on Alpine)
+	// 2. packages for the other libc (may need a compatibility layer)
 	// 3. tar.gz packages (all platforms - preferred for size/compatibility)
 	// 4. zip packages (all platforms - fallback)
 	// 5. other packages (final fallback)
-	if glibcPkg != nil {
-		selectedPkg = glibcPkg
-		util.LogVerbose("Selected glibc package: %s (lib_c_type: %s)", selectedPkg.Filename, selectedPkg.LibCType)
-	} else if muslPkg != nil {
-		selectedPkg = muslPkg
-		util.LogVerbose("Selected musl package: %s (lib_c_type: %s)", selectedPkg.Filename, selectedPkg.LibCType)
+	hostLibcPkg, otherLibcPkg := glibcPkg, muslPkg
+	if NewPlatformMapper().IsMusl() {
+		hostLibcPkg, otherLibcPkg = muslPkg, glibcPkg
+	}
+	if hostLibcPkg != nil {
+		selectedPkg = hostLibcPkg
+		util.LogVerbose("Selected package matching host libc: %s (lib_c_type: %s)", selectedPkg.Filename, selectedPkg.LibCType)
+	} else if otherLibcPkg != nil {
+		selectedPkg = otherLibcPkg
+		util.LogVerbose("Selected package despite libc mismatch: %s (lib_c_type: %s)", selectedPkg.Filename, selectedPkg.LibCType)
 	} else if tarGzPkg != nil {
 		selectedPkg = tarGzPkg
 		util.LogVerbose("Selected TAR.GZ package: %s", selectedPkg.Filename)
@@ -964,6 +1079,25 @@ func (j *JavaTool) ResolveVersion(versionSpec, distribution string) (string, err
 		return versionSpec, nil
 	}
 
+	// Early-access versions are passed to jdk.java.net as-is
+	if strings.HasSuffix(versionSpec, "-ea") {
+		return versionSpec, nil
+	}
+
+	// "lts" resolves to the newest LTS major line, so configs track the
+	// current LTS without edits every six months
+	if versionSpec == "lts" {
+		majorVersions, err := j.getDiscoVersions(distribution)
+		if err != nil {
+			return "", err
+		}
+		ltsMajor := latestJavaLTSMajor(majorVersions)
+		if ltsMajor == "" {
+			return "", fmt.Errorf("no LTS version available for Java distribution %s", distribution)
+		}
+		return j.ResolveVersion(ltsMajor, distribution)
+	}
+
 	// Parse the version spec to determine if we need detailed versions
 	spec, err := version.ParseSpec(versionSpec)
 	if err != nil {
@@ -999,6 +1133,36 @@ func (j *JavaTool) ResolveVersion(versionSpec, distribution string) (string, err
 	return majorResolved, nil
 }
 
+// latestJavaLTSMajor returns the highest LTS major among the available major
+// versions ("" if none). LTS releases are 8, 11, 17, 21 and every fourth
+// release after 21 (25, 29, ...).
+func latestJavaLTSMajor(majorVersions []string) string {
+	best := 0
+	for _, v := range majorVersions {
+		major, err := strconv.Atoi(v)
+		if err != nil {
+			continue
+		}
+		if isJavaLTSMajor(major) && major > best {
+			best = major
+		}
+	}
+	if best == 0 {
+		return ""
+	}
+	return strconv.Itoa(best)
+}
+
+// isJavaLTSMajor reports whether a Java major version is a long-term support
+// release
+func isJavaLTSMajor(major int) bool {
+	switch major {
+	case 8, 11, 17:
+		return true
+	}
+	return major >= 21 && (major-21)%4 == 0
+}
+
 // GetDownloadURL implements Tool interface for Java
 func (j *JavaTool) GetDownloadURL(version string) string {
 	// Use default distribution (temurin) for URL generation
@@ -0,0 +1,134 @@
+package tools
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"regexp"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// javaSource returns the jdk.java.net source for a Java tool configuration,
+// or "" when the regular Disco API flow should be used. Early-access versions
+// without an explicit source default to jdk.java.net.
+func javaSource(version string, cfg config.ToolConfig) string {
+	if cfg.Source != "" {
+		return cfg.Source
+	}
+	if strings.HasSuffix(version, "-ea") {
+		return "jdk.java.net"
+	}
+	return ""
+}
+
+// javaSourcePage returns the jdk.java.net page listing builds for a source.
+// Plain EA versions use the feature release page (e.g. /24/); project builds
+// (Loom, Valhalla, ...) use their project page (e.g. /loom/).
+func javaSourcePage(source, version string) string {
+	if source == "jdk.java.net" || source == "ea" {
+		major := strings.TrimSuffix(version, "-ea")
+		return fmt.Sprintf("%s/%s/", JdkJavaNetBase, major)
+	}
+	return fmt.Sprintf("%s/%s/", JdkJavaNetBase, source)
+}
+
+// getJdkJavaNetURL scrapes the jdk.java.net page for the download link
+// matching the current platform. The pages have no JSON API, but the
+// download.java.net links follow a stable os-arch naming scheme.
+func (j *JavaTool) getJdkJavaNetURL(version, source string) (string, error) {
+	platformMapper := NewPlatformMapper()
+
+	archMapping := map[string]string{
+		"amd64": "x64",
+		"arm64": "aarch64",
+	}
+	arch := platformMapper.MapArchitecture(archMapping)
+
+	osMapping := map[string]string{
+		"darwin": "macos",
+	}
+	osName := platformMapper.MapOS(osMapping)
+
+	ext := "tar\\.gz"
+	if platformMapper.IsWindows() {
+		ext = "zip"
+	}
+
+	pageURL := javaSourcePage(source, version)
+	util.LogVerbose("Fetching jdk.java.net page: %s", pageURL)
+
+	resp, err := j.manager.Get(pageURL)
+	if err != nil {
+		return "", fmt.Errorf("failed to fetch %s: %w", pageURL, err)
+	}
+	defer resp.Body.Close()
+
+	body, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return "", fmt.Errorf("failed to read %s: %w", pageURL, err)
+	}
+
+	pattern := regexp.MustCompile(fmt.Sprintf(`https://download\.java\.net/java/[^"]*_%s-%s_bin\.%s`, osName, arch, ext))
+	match := pattern.Find(body)
+	if match == nil {
+		return "", fmt.Errorf("no %s-%s build found on %s", osName, arch, pageURL)
+	}
+
+	return string(match), nil
+}
+
+// installFromJdkJavaNet installs an early-access or project build from
+// jdk.java.net, verifying it against the published .sha256 file.
+func (j *JavaTool) installFromJdkJavaNet(version string, cfg config.ToolConfig, source string) error {
+	if UseSystemTool(j.toolName) {
+		util.LogVerbose("%s=true, forcing use of system %s", getSystemToolEnvVar(j.toolName), j.toolName)
+		return nil
+	}
+
+	downloadURL, err := j.getJdkJavaNetURL(version, source)
+	if err != nil {
+		return InstallError(j.toolName, version, fmt.Errorf("failed to get jdk.java.net download URL: %w", err))
+	}
+
+	// EA builds are installed under the source name so they never collide
+	// with a GA installation of the same major version
+	installDir, err := j.CreateInstallDir(version, source)
+	if err != nil {
+		return InstallError(j.toolName, version, fmt.Errorf("failed to create install directory: %w", err))
+	}
+
+	j.PrintDownloadMessage(version)
+
+	// download.java.net publishes a .sha256 file alongside every archive
+	configWithChecksum := cfg
+	configWithChecksum.Checksum = &config.ChecksumConfig{
+		Type: "sha256",
+		URL:  downloadURL + ".sha256",
+	}
+
+	archivePath, err := j.Download(downloadURL, version, configWithChecksum)
+	if err != nil {
+		return InstallError(j.toolName, version, err)
+	}
+	defer os.Remove(archivePath)
+
+	if err := j.Extract(archivePath, installDir); err != nil {
+		return InstallError(j.toolName, version, err)
+	}
+
+	verifyConfig := cfg
+	verifyConfig.Distribution = source
+	if err := j.Verify(version, verifyConfig); err != nil {
+		if removeErr := os.RemoveAll(installDir); removeErr != nil {
+			util.LogVerbose("Failed to clean up installation directory %s: %v", installDir, removeErr)
+		}
+		fmt.Printf("  🧹 Cleaning up failed installation directory...\n")
+		return InstallError(j.toolName, version, fmt.Errorf("installation verification failed: %w", err))
+	}
+
+	fmt.Printf("  ✅ %s %s installation verification successful\n", j.toolName, version)
+	return nil
+}
@@ -202,3 +202,19 @@ func TestJavaToolWithSystemJava(t *testing.T) {
 	// The old behavior was more strict and would fail if JAVA_HOME was invalid
 	t.Logf("Note: Standardized approach is more permissive than old Java-specific logic")
 }
+
+func TestLatestJavaLTSMajor(t *testing.T) {
+	available := []string{"17", "18", "19", "20", "21", "22", "23", "24"}
+	if got := latestJavaLTSMajor(available); got != "21" {
+		t.Errorf("latestJavaLTSMajor() = %s, want 21", got)
+	}
+
+	withNext := append(available, "25", "26")
+	if got := latestJavaLTSMajor(withNext); got != "25" {
+		t.Errorf("latestJavaLTSMajor() = %s, want 25", got)
+	}
+
+	if got := latestJavaLTSMajor([]string{"18", "19", "20"}); got != "" {
+		t.Errorf("latestJavaLTSMajor() = %s, want empty for no LTS", got)
+	}
+}
@@ -0,0 +1,159 @@
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+// Compile-time interface validation
+var _ Tool = (*JReleaserTool)(nil)
+var _ DependencyProvider = (*JReleaserTool)(nil)
+
+// JReleaserTool manages JReleaser
+// Downloads the platform-independent distribution from GitHub releases,
+// so release commands run a pinned version instead of whatever the Maven
+// plugin resolves at runtime.
+type JReleaserTool struct {
+	*BaseTool
+}
+
+func getJReleaserBinaryName() string {
+	if NewPlatformMapper().IsWindows() {
+		return BinaryJReleaser + ".bat"
+	}
+	return BinaryJReleaser
+}
+
+// NewJReleaserTool creates a new JReleaser tool instance
+func NewJReleaserTool(manager *Manager) *JReleaserTool {
+	return &JReleaserTool{
+		BaseTool: NewBaseTool(manager, ToolJReleaser, getJReleaserBinaryName()),
+	}
+}
+
+func (j *JReleaserTool) Install(version string, cfg config.ToolConfig) error {
+	return j.StandardInstall(version, cfg, j.getDownloadURL)
+}
+
+func (j *JReleaserTool) IsInstalled(version string, cfg config.ToolConfig) bool {
+	return j.StandardIsInstalled(version, cfg, j.GetPath)
+}
+
+func (j *JReleaserTool) GetPath(version string, cfg config.ToolConfig) (string, error) {
+	return j.StandardGetPath(version, cfg, j.getInstalledPath)
+}
+
+func (j *JReleaserTool) GetBinaryName() string {
+	return getJReleaserBinaryName()
+}
+
+// getInstalledPath returns the path for an installed JReleaser version
+func (j *JReleaserTool) getInstalledPath(version string, cfg config.ToolConfig) (string, error) {
+	installDir := j.manager.GetToolVersionDir(j.GetToolName(), version, "")
+	pathResolver := NewPathResolver(j.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(installDir, j.GetBinaryName())
+	if err != nil {
+		return "", err
+	}
+	return binDir, nil
+}
+
+func (j *JReleaserTool) Verify(version string, cfg config.ToolConfig) error {
+	verifyConfig := VerificationConfig{
+		BinaryName:  j.GetBinaryName(),
+		VersionArgs: []string{"--version"},
+		DebugInfo:   false,
+	}
+	return j.StandardVerifyWithConfig(version, cfg, verifyConfig)
+}
+
+func (j *JReleaserTool) ListVersions() ([]string, error) {
+	versions, err := j.fetchGitHubVersions()
+	if err != nil {
+		// minimal fallback
+		return []string{"1.15.0", "1.14.0", "1.13.1"}, nil
+	}
+	return version.SortVersions(versions), nil
+}
+
+// GetDisplayName returns the human-readable name for JReleaser (implements ToolMetadataProvider)
+func (j *JReleaserTool) GetDisplayName() string {
+	return "JReleaser"
+}
+
+// GetDependencies returns the list of tools that JReleaser depends on (implements DependencyProvider)
+// The distribution is a Java application launched through its bin scripts.
+func (j *JReleaserTool) GetDependencies() []string {
+	return []string{ToolJava}
+}
+
+// fetchGitHubVersions lists release tags from the JReleaser GitHub repository
+func (j *JReleaserTool) fetchGitHubVersions() ([]string, error) {
+	resp, err := j.manager.Get(GitHubAPIBase + "/repos/jreleaser/jreleaser/releases?per_page=50")
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != 200 {
+		return nil, fmt.Errorf("jreleaser releases fetch failed: %d", resp.StatusCode)
+	}
+
+	var releases []struct {
+		TagName    string `json:"tag_name"`
+		Prerelease bool   `json:"prerelease"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&releases); err != nil {
+		return nil, err
+	}
+
+	var versions []string
+	for _, release := range releases {
+		if release.Prerelease || release.TagName == "early-access" {
+			continue
+		}
+		versions = append(versions, strings.TrimPrefix(release.TagName, "v"))
+	}
+	return versions, nil
+}
+
+func (j *JReleaserTool) getDownloadURL(version string) string {
+	return fmt.Sprintf("https://github.com/jreleaser/jreleaser/releases/download/v%[1]s/jreleaser-%[1]s.zip", version)
+}
+
+// GetDownloadURL implements URLProvider interface for JReleaser
+func (j *JReleaserTool) GetDownloadURL(version string) string {
+	return j.getDownloadURL(version)
+}
+
+// GetChecksum implements ChecksumProvider using the published checksums file
+func (j *JReleaserTool) GetChecksum(version string, cfg config.ToolConfig, filename string) (ChecksumInfo, error) {
+	return ChecksumInfo{
+		Type:     SHA256,
+		URL:      fmt.Sprintf("https://github.com/jreleaser/jreleaser/releases/download/v%s/checksums_sha256.txt", version),
+		Filename: fmt.Sprintf("jreleaser-%s.zip", version),
+	}, nil
+}
+
+// ResolveVersion resolves a JReleaser version specification to a concrete version
+func (j *JReleaserTool) ResolveVersion(versionSpec, distribution string) (string, error) {
+	availableVersions, err := j.ListVersions()
+	if err != nil {
+		return "", err
+	}
+
+	spec, err := version.ParseSpec(versionSpec)
+	if err != nil {
+		return "", fmt.Errorf("invalid version specification %s: %w", versionSpec, err)
+	}
+
+	resolved, err := spec.Resolve(availableVersions)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve JReleaser version %s: %w", versionSpec, err)
+	}
+
+	return resolved, nil
+}
@@ -0,0 +1,115 @@
+package tools
+
+import (
+	"os"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// JVM option environment variables populated from config profiles
+const (
+	EnvMavenOpts       = "MAVEN_OPTS"
+	EnvGradleOpts      = "GRADLE_OPTS"
+	EnvJavaToolOptions = "JAVA_TOOL_OPTIONS"
+
+	// EnvJvmProfile selects the active JVM options profile
+	EnvJvmProfile = "MVX_JVM_PROFILE"
+)
+
+// resolveJvmProfile determines the active JVM options profile name.
+// Explicit MVX_JVM_PROFILE wins, then "ci" when running under CI, then "dev".
+func resolveJvmProfile(cfg *config.Config) string {
+	if profile := os.Getenv(EnvJvmProfile); profile != "" {
+		return profile
+	}
+	if os.Getenv("CI") != "" {
+		if _, exists := cfg.JvmProfiles["ci"]; exists {
+			return "ci"
+		}
+	}
+	return "dev"
+}
+
+// applyJvmProfiles injects JVM options from the active profile into
+// MAVEN_OPTS, GRADLE_OPTS and JAVA_TOOL_OPTIONS so managed Java tools pick
+// them up without per-developer shell exports.
+// The "default" profile (if present) always applies; the active profile is
+// appended after it so it can add or override options.
+func applyJvmProfiles(cfg *config.Config, envManager *EnvironmentManager) {
+	if len(cfg.JvmProfiles) == 0 && !hasDeclarativeJvmOptions(cfg) {
+		return
+	}
+
+	// Only inject when the project actually manages a Java tool
+	if _, hasJava := cfg.Tools[ToolJava]; !hasJava {
+		util.LogVerbose("Skipping JVM profiles: no java tool configured")
+		return
+	}
+
+	activeProfile := resolveJvmProfile(cfg)
+	util.LogVerbose("Active JVM options profile: %s", activeProfile)
+
+	// Declarative base options (java.options) apply before any profile,
+	// so profiles can extend or override them
+	var options []string
+	if cfg.Java != nil {
+		options = append(options, cfg.Java.Options...)
+	}
+	if defaultProfile, exists := cfg.JvmProfiles["default"]; exists {
+		options = append(options, defaultProfile.Options...)
+	}
+	if profile, exists := cfg.JvmProfiles[activeProfile]; exists && activeProfile != "default" {
+		options = append(options, profile.Options...)
+	} else if activeProfile != "default" && len(cfg.JvmProfiles) > 0 {
+		util.LogVerbose("JVM profile %s not defined in configuration", activeProfile)
+	}
+
+	// maven.jvmArgs only affect MAVEN_OPTS, like the per-variable profile lists
+	mavenExtras := profileExtras(cfg, activeProfile, func(p config.JvmProfileConfig) []string { return p.MavenOpts })
+	if cfg.Maven != nil {
+		mavenExtras = append(append([]string{}, cfg.Maven.JvmArgs...), mavenExtras...)
+	}
+
+	applyJvmOptions(envManager, EnvMavenOpts, options, mavenExtras)
+	applyJvmOptions(envManager, EnvGradleOpts, options, profileExtras(cfg, activeProfile, func(p config.JvmProfileConfig) []string { return p.GradleOpts }))
+	applyJvmOptions(envManager, EnvJavaToolOptions, options, profileExtras(cfg, activeProfile, func(p config.JvmProfileConfig) []string { return p.JavaToolOptions }))
+}
+
+// hasDeclarativeJvmOptions reports whether java.options or maven.jvmArgs
+// declare JVM options outside any profile
+func hasDeclarativeJvmOptions(cfg *config.Config) bool {
+	if cfg.Java != nil && len(cfg.Java.Options) > 0 {
+		return true
+	}
+	return cfg.Maven != nil && len(cfg.Maven.JvmArgs) > 0
+}
+
+// profileExtras collects variable-specific options from the default and active profiles
+func profileExtras(cfg *config.Config, activeProfile string, get func(config.JvmProfileConfig) []string) []string {
+	var extras []string
+	if defaultProfile, exists := cfg.JvmProfiles["default"]; exists {
+		extras = append(extras, get(defaultProfile)...)
+	}
+	if profile, exists := cfg.JvmProfiles[activeProfile]; exists && activeProfile != "default" {
+		extras = append(extras, get(profile)...)
+	}
+	return extras
+}
+
+// applyJvmOptions appends the configured options to an environment variable,
+// preserving any value already present in the environment
+func applyJvmOptions(envManager *EnvironmentManager, envVar string, common, extras []string) {
+	combined := append(append([]string{}, common...), extras...)
+	if len(combined) == 0 {
+		return
+	}
+
+	value := strings.Join(combined, " ")
+	if existing, exists := envManager.GetEnv(envVar); exists && existing != "" {
+		value = existing + " " + value
+	}
+	envManager.SetEnv(envVar, value)
+	util.LogVerbose("Set %s=%s from JVM profile", envVar, value)
+}
@@ -0,0 +1,48 @@
+package tools
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestApplyJvmProfilesDeclarativeOptions(t *testing.T) {
+	t.Setenv(EnvJvmProfile, "dev")
+
+	cfg := &config.Config{
+		Tools: map[string]config.ToolConfig{ToolJava: {Version: "21"}},
+		Java:  &config.JavaConfig{Options: []string{"-Dfile.encoding=UTF-8"}},
+		Maven: &config.MavenConfig{JvmArgs: []string{"-Xmx2g"}},
+	}
+
+	em := NewEnvironmentManager()
+	applyJvmProfiles(cfg, em)
+
+	if got, _ := em.GetEnv(EnvMavenOpts); got != "-Dfile.encoding=UTF-8 -Xmx2g" {
+		t.Errorf("unexpected MAVEN_OPTS: %q", got)
+	}
+	if got, _ := em.GetEnv(EnvJavaToolOptions); got != "-Dfile.encoding=UTF-8" {
+		t.Errorf("unexpected JAVA_TOOL_OPTIONS: %q", got)
+	}
+}
+
+func TestApplyJvmProfilesProfileOverridesBase(t *testing.T) {
+	t.Setenv(EnvJvmProfile, "ci")
+
+	cfg := &config.Config{
+		Tools: map[string]config.ToolConfig{ToolJava: {Version: "21"}},
+		Java:  &config.JavaConfig{Options: []string{"-Xmx2g"}},
+		JvmProfiles: map[string]config.JvmProfileConfig{
+			"ci": {Options: []string{"-Xmx6g"}},
+		},
+	}
+
+	em := NewEnvironmentManager()
+	applyJvmProfiles(cfg, em)
+
+	// The profile option comes after the declarative base, so the JVM's
+	// last-wins semantics give CI the bigger heap
+	if got, _ := em.GetEnv(EnvMavenOpts); got != "-Xmx2g -Xmx6g" {
+		t.Errorf("unexpected MAVEN_OPTS: %q", got)
+	}
+}
@@ -0,0 +1,233 @@
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// LockFileName is the lockfile location relative to the project root
+const LockFileName = ".mvx/mvx.lock"
+
+// LockFile pins the exact resolved state of every configured tool, so
+// version ranges and aliases ("21", "lts", "latest-3") keep producing the
+// same environment on every machine, and upstream re-releases are detected.
+type LockFile struct {
+	Tools map[string]LockedTool `json:"tools"`
+}
+
+// LockedTool records what a tool's version spec resolved to when the
+// lockfile was written
+type LockedTool struct {
+	Spec         string `json:"spec"`                   // version spec from the config at lock time
+	Version      string `json:"version"`                // concrete resolved version
+	Distribution string `json:"distribution,omitempty"` // distribution at lock time (Java)
+	URL          string `json:"url,omitempty"`          // resolved download URL
+	Checksum     string `json:"checksum,omitempty"`     // expected archive checksum (type:value)
+	Provenance   string `json:"provenance,omitempty"`   // attestation verification result ("verified:gh", "unavailable", ...)
+}
+
+// LockFilePath returns the lockfile path for a project root
+func LockFilePath(projectRoot string) string {
+	return filepath.Join(projectRoot, filepath.FromSlash(LockFileName))
+}
+
+// LoadLockFile reads the project lockfile; a missing file is not an error
+// and returns nil
+func LoadLockFile(projectRoot string) (*LockFile, error) {
+	data, err := os.ReadFile(LockFilePath(projectRoot))
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", LockFileName, err)
+	}
+
+	var lock LockFile
+	if err := json.Unmarshal(data, &lock); err != nil {
+		return nil, fmt.Errorf("failed to parse %s: %w", LockFileName, err)
+	}
+	return &lock, nil
+}
+
+// Save writes the lockfile with stable key order
+func (l *LockFile) Save(projectRoot string) error {
+	data, err := json.MarshalIndent(l, "", "  ")
+	if err != nil {
+		return err
+	}
+	data = append(data, '\n')
+	if err := os.WriteFile(LockFilePath(projectRoot), data, 0644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", LockFileName, err)
+	}
+	return nil
+}
+
+// LoadProjectLock loads the project lockfile into the manager so version
+// resolution honors pinned versions. Load failures only disable pinning.
+func (m *Manager) LoadProjectLock(projectRoot string) {
+	// Record the project in the known-projects registry so cache pruning
+	// knows its tool versions are still referenced
+	RegisterProjectUsage(projectRoot)
+
+	lock, err := LoadLockFile(projectRoot)
+	if err != nil {
+		util.LogVerbose("Ignoring lockfile: %v", err)
+		return
+	}
+	m.cacheMutex.Lock()
+	m.lockFile = lock
+	m.cacheMutex.Unlock()
+}
+
+// lockedVersion returns the pinned version for a tool when the lockfile
+// entry still matches the configured spec and distribution
+func (m *Manager) lockedVersion(toolName string, toolConfig config.ToolConfig) (string, bool) {
+	m.cacheMutex.RLock()
+	lock := m.lockFile
+	m.cacheMutex.RUnlock()
+
+	if lock == nil {
+		return "", false
+	}
+	entry, exists := lock.Tools[toolName]
+	if !exists || entry.Version == "" {
+		return "", false
+	}
+	// A changed spec or distribution invalidates the pin (re-lock via setup)
+	if entry.Spec != toolConfig.Version || entry.Distribution != toolConfig.Distribution {
+		return "", false
+	}
+	return entry.Version, true
+}
+
+// LockFileSatisfied reports whether the loaded lockfile fully covers the
+// configuration: every platform-matching tool has a pin for its current
+// spec and distribution, and the pinned version is installed and has passed
+// verification. When it holds, startup needs no network I/O and no
+// re-resolution — environment assembly reads everything from the lockfile
+// and the local cache.
+func (m *Manager) LockFileSatisfied(cfg *config.Config) bool {
+	m.cacheMutex.RLock()
+	lock := m.lockFile
+	m.cacheMutex.RUnlock()
+	if lock == nil {
+		return false
+	}
+
+	for toolName, toolConfig := range cfg.Tools {
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+		// System-tool overrides and version overrides bypass the lockfile
+		if os.Getenv(fmt.Sprintf("MVX_USE_SYSTEM_%s", strings.ToUpper(toolName))) == "true" {
+			continue
+		}
+		if getToolVersionOverride(toolName) != "" {
+			return false
+		}
+
+		locked, found := m.lockedVersion(toolName, toolConfig)
+		if !found {
+			return false
+		}
+		resolvedConfig := toolConfig
+		resolvedConfig.Version = locked
+
+		tool, err := m.GetTool(toolName)
+		if err != nil || !tool.IsInstalled(locked, resolvedConfig) {
+			return false
+		}
+		if !m.isToolVerified(toolName, locked, toolConfig.Distribution) {
+			return false
+		}
+	}
+	return true
+}
+
+// lockedChecksum returns the checksum pinned for a tool when the lockfile
+// entry matches the configured spec, distribution and resolved version
+func (m *Manager) lockedChecksum(toolName, resolvedVersion string, toolConfig config.ToolConfig) (ChecksumInfo, bool) {
+	m.cacheMutex.RLock()
+	lock := m.lockFile
+	m.cacheMutex.RUnlock()
+
+	if lock == nil {
+		return ChecksumInfo{}, false
+	}
+	entry, exists := lock.Tools[toolName]
+	if !exists || entry.Checksum == "" ||
+		entry.Spec != toolConfig.Version || entry.Distribution != toolConfig.Distribution ||
+		entry.Version != resolvedVersion {
+		return ChecksumInfo{}, false
+	}
+
+	checksumType, value, found := strings.Cut(entry.Checksum, ":")
+	if !found || value == "" {
+		return ChecksumInfo{}, false
+	}
+	return ChecksumInfo{Type: ChecksumType(checksumType), Value: value}, true
+}
+
+// WriteLockFile resolves every configured tool and writes the lockfile,
+// recording resolved versions, download URLs and checksums where available
+func (m *Manager) WriteLockFile(projectRoot string, cfg *config.Config) error {
+	lock := &LockFile{Tools: make(map[string]LockedTool)}
+
+	// Drop the old pins first so locking resolves fresh versions
+	m.cacheMutex.Lock()
+	m.lockFile = nil
+	m.cacheMutex.Unlock()
+
+	var names []string
+	for toolName := range cfg.Tools {
+		names = append(names, toolName)
+	}
+	sort.Strings(names)
+
+	for _, toolName := range names {
+		toolConfig := cfg.Tools[toolName]
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
+		tool, err := m.GetTool(toolName)
+		if err != nil {
+			util.LogVerbose("Not locking unknown tool %s", toolName)
+			continue
+		}
+
+		resolved, err := m.resolveVersion(toolName, toolConfig)
+		if err != nil {
+			return fmt.Errorf("failed to resolve %s %s for lockfile: %w", toolName, toolConfig.Version, err)
+		}
+
+		entry := LockedTool{
+			Spec:         toolConfig.Version,
+			Version:      resolved,
+			Distribution: toolConfig.Distribution,
+			URL:          tool.GetDownloadURL(resolved),
+		}
+		if provider, ok := tool.(ChecksumProvider); ok && entry.URL != "" {
+			if info, err := provider.GetChecksum(resolved, toolConfig, extractFilenameFromURL(entry.URL)); err == nil && info.Value != "" {
+				entry.Checksum = fmt.Sprintf("%s:%s", info.Type, info.Value)
+			}
+		}
+		// Attestation verification outcome from an install in this process
+		entry.Provenance = ProvenanceResult(toolName, resolved)
+		lock.Tools[toolName] = entry
+	}
+
+	// Refresh the in-memory lock so this process honors what it just wrote
+	m.cacheMutex.Lock()
+	m.lockFile = lock
+	m.cacheMutex.Unlock()
+
+	return lock.Save(projectRoot)
+}
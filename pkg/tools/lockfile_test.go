@@ -0,0 +1,130 @@
+package tools
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestLockFileRoundTrip(t *testing.T) {
+	projectRoot := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(projectRoot, ".mvx"), 0755); err != nil {
+		t.Fatalf("failed to create .mvx dir: %v", err)
+	}
+
+	lock := &LockFile{Tools: map[string]LockedTool{
+		"java": {
+			Spec:         "21",
+			Version:      "21.0.2",
+			Distribution: "temurin",
+			URL:          "https://example.com/jdk-21.0.2.tar.gz",
+			Checksum:     "sha256:abc123",
+		},
+	}}
+	if err := lock.Save(projectRoot); err != nil {
+		t.Fatalf("Save failed: %v", err)
+	}
+
+	loaded, err := LoadLockFile(projectRoot)
+	if err != nil {
+		t.Fatalf("LoadLockFile failed: %v", err)
+	}
+	if loaded == nil {
+		t.Fatal("expected a lockfile, got nil")
+	}
+	if loaded.Tools["java"] != lock.Tools["java"] {
+		t.Errorf("round-trip mismatch: got %+v, want %+v", loaded.Tools["java"], lock.Tools["java"])
+	}
+}
+
+func TestLoadLockFileMissing(t *testing.T) {
+	lock, err := LoadLockFile(t.TempDir())
+	if err != nil {
+		t.Fatalf("missing lockfile should not be an error, got: %v", err)
+	}
+	if lock != nil {
+		t.Errorf("expected nil lockfile for missing file, got %+v", lock)
+	}
+}
+
+func TestLockedVersion(t *testing.T) {
+	manager, err := NewManager()
+	if err != nil {
+		t.Fatalf("failed to create manager: %v", err)
+	}
+	manager.lockFile = &LockFile{Tools: map[string]LockedTool{
+		"java": {Spec: "21", Version: "21.0.2", Distribution: "temurin"},
+	}}
+
+	// Matching spec and distribution honors the pin
+	if version, ok := manager.lockedVersion("java", config.ToolConfig{Version: "21", Distribution: "temurin"}); !ok || version != "21.0.2" {
+		t.Errorf("expected pinned 21.0.2, got %q (ok=%v)", version, ok)
+	}
+
+	// A changed spec invalidates the pin
+	if _, ok := manager.lockedVersion("java", config.ToolConfig{Version: "22", Distribution: "temurin"}); ok {
+		t.Error("changed spec should invalidate the pin")
+	}
+
+	// A changed distribution invalidates the pin
+	if _, ok := manager.lockedVersion("java", config.ToolConfig{Version: "21", Distribution: "zulu"}); ok {
+		t.Error("changed distribution should invalidate the pin")
+	}
+
+	// Unlocked tools are not pinned
+	if _, ok := manager.lockedVersion("node", config.ToolConfig{Version: "20"}); ok {
+		t.Error("tool absent from the lockfile should not be pinned")
+	}
+}
+
+func TestLockedChecksum(t *testing.T) {
+	manager, err := NewManager()
+	if err != nil {
+		t.Fatalf("failed to create manager: %v", err)
+	}
+	manager.lockFile = &LockFile{Tools: map[string]LockedTool{
+		"maven": {Spec: "3", Version: "3.9.9", Checksum: "sha512:deadbeef"},
+	}}
+
+	info, ok := manager.lockedChecksum("maven", "3.9.9", config.ToolConfig{Version: "3"})
+	if !ok || info.Type != SHA512 || info.Value != "deadbeef" {
+		t.Errorf("expected sha512:deadbeef, got %+v (ok=%v)", info, ok)
+	}
+
+	// A different resolved version must not reuse the pinned checksum
+	if _, ok := manager.lockedChecksum("maven", "3.9.10", config.ToolConfig{Version: "3"}); ok {
+		t.Error("changed resolved version should invalidate the pinned checksum")
+	}
+}
+
+func TestLockFileSatisfied(t *testing.T) {
+	t.Setenv("MVX_HOME", t.TempDir())
+	manager, err := NewManager()
+	if err != nil {
+		t.Fatalf("failed to create manager: %v", err)
+	}
+	cfg := &config.Config{Tools: map[string]config.ToolConfig{
+		"java": {Version: "21", Distribution: "temurin"},
+	}}
+
+	// No lockfile loaded: never satisfied
+	if manager.LockFileSatisfied(cfg) {
+		t.Error("expected unsatisfied without a lockfile")
+	}
+
+	// Pin present but the version is not installed in the (empty) cache
+	manager.lockFile = &LockFile{Tools: map[string]LockedTool{
+		"java": {Spec: "21", Version: "21.0.2", Distribution: "temurin"},
+	}}
+	if manager.LockFileSatisfied(cfg) {
+		t.Error("expected unsatisfied when the pinned version is not installed")
+	}
+
+	// A tool without a pin breaks coverage
+	cfg.Tools["node"] = config.ToolConfig{Version: "22"}
+	if manager.LockFileSatisfied(cfg) {
+		t.Error("expected unsatisfied when a tool has no pin")
+	}
+}
@@ -7,6 +7,7 @@ import (
 	"fmt"
 	"io"
 	"net/http"
+	neturl "net/url"
 	"os"
 	"path/filepath"
 	"strconv"
@@ -47,12 +48,30 @@ type Manager struct {
 	cacheDir       string
 	tools          map[string]Tool
 	registry       *ToolRegistry
+	registries     map[string]config.RegistryConfig // internal registries keyed by tool name
 	versionCache   map[string]VersionCacheEntry
 	installedCache map[string]bool           // Cache for IsInstalled checks
 	pathCache      map[string]string         // Cache for GetPath results
 	httpCache      map[string]HTTPCacheEntry // In-memory HTTP response cache
 	cacheMutex     sync.RWMutex
 	httpClient     *http.Client
+	installCount   int       // number of installs performed by this process (cold-start telemetry)
+	lockFile       *LockFile // pinned resolutions from .mvx/mvx.lock (nil when absent)
+}
+
+// InstallCount returns how many tool installations this process performed,
+// distinguishing cold starts (downloads happened) from warm runs.
+func (m *Manager) InstallCount() int {
+	m.cacheMutex.RLock()
+	defer m.cacheMutex.RUnlock()
+	return m.installCount
+}
+
+// recordInstall bumps the cold-start counter
+func (m *Manager) recordInstall() {
+	m.cacheMutex.Lock()
+	m.installCount++
+	m.cacheMutex.Unlock()
 }
 
 var (
@@ -130,6 +149,21 @@ type DistributionVersionProvider interface {
 	ListVersionsForDistribution(distribution string) ([]string, error)
 }
 
+// VersionSearcher is an optional interface for tools with a richer search
+// backend than substring filtering (e.g. Java's Foojay Disco API)
+type VersionSearcher interface {
+	// SearchVersions returns versions matching tool-specific filters
+	SearchVersions(filters []string) ([]string, error)
+}
+
+// FallbackURLProvider is an optional interface for tools with alternate
+// download locations tried when the primary URL fails (e.g. the Apache
+// archive host for releases evicted from the CDN)
+type FallbackURLProvider interface {
+	// GetFallbackURLs returns alternate download URLs for a version, in order of preference
+	GetFallbackURLs(version string) []string
+}
+
 // DependencyProvider is an optional interface for tools that depend on other tools
 type DependencyProvider interface {
 	// GetDependencies returns a list of tool names that this tool depends on
@@ -159,18 +193,19 @@ func NewManager() (*Manager, error) {
 		return globalManager, nil
 	}
 
-	homeDir, err := os.UserHomeDir()
+	cacheDir, err := util.MvxHome()
 	if err != nil {
-		return nil, fmt.Errorf("failed to get user home directory: %w", err)
+		return nil, err
 	}
 
-	cacheDir := filepath.Join(homeDir, ".mvx")
-
 	// Create cache directory if it doesn't exist
 	if err := os.MkdirAll(cacheDir, 0755); err != nil {
 		return nil, fmt.Errorf("failed to create cache directory %s: %w", cacheDir, err)
 	}
 
+	// Normalize permissions so the per-user cache stays usable on shared agents
+	ensureUserCachePermissions(cacheDir)
+
 	manager := &Manager{
 		cacheDir:       cacheDir,
 		tools:          make(map[string]Tool),
@@ -178,9 +213,7 @@ func NewManager() (*Manager, error) {
 		installedCache: make(map[string]bool),
 		pathCache:      make(map[string]string),
 		httpCache:      make(map[string]HTTPCacheEntry),
-		httpClient: &http.Client{
-			Timeout: getTimeoutFromEnv("MVX_HTTP_TIMEOUT", 120*time.Second), // Default: 2 minutes for slow servers
-		},
+		httpClient: util.HTTPClient(getTimeoutFromEnv("MVX_HTTP_TIMEOUT", 120*time.Second)), // Default: 2 minutes for slow servers
 	}
 
 	// Create registry after manager is initialized (to avoid circular dependency)
@@ -233,10 +266,11 @@ func (m *Manager) Get(url string) (*http.Response, error) {
 	}
 	m.cacheMutex.RUnlock()
 
-	// Check disk cache (24 hours, unless MVX_FORCE_REFRESH is set)
+	// Check disk cache (TTL via MVX_METADATA_CACHE_TTL, default 24 hours,
+	// unless MVX_FORCE_REFRESH is set)
 	// Cache all metadata API responses (Foojay, GitHub, Node.js, Apache)
 	if os.Getenv("MVX_FORCE_REFRESH") != "true" {
-		if body, found := m.getDiskCachedResponse(url); found {
+		if body, found := m.getDiskCachedResponse(url, false); found {
 			if os.Getenv("MVX_VERBOSE") == "true" {
 				fmt.Printf("💾 HTTP GET (disk cache): %s\n", url)
 			}
@@ -256,16 +290,50 @@ func (m *Manager) Get(url string) (*http.Response, error) {
 		}
 	}
 
+	// Offline mode: serve stale cached metadata or fail, never hit the network
+	if util.IsOffline() {
+		if body, found := m.getDiskCachedResponse(url, true); found {
+			if os.Getenv("MVX_VERBOSE") == "true" {
+				fmt.Printf("💾 HTTP GET (offline, stale cache): %s\n", url)
+			}
+			return &http.Response{
+				StatusCode: 200,
+				Body:       io.NopCloser(bytes.NewReader(body)),
+				Header:     make(http.Header),
+			}, nil
+		}
+		return nil, fmt.Errorf("offline mode: no cached metadata for %s (run once with network access, or pin versions in %s)", url, LockFileName)
+	}
+
 	// Log the request if verbose mode is enabled
 	if os.Getenv("MVX_VERBOSE") == "true" {
 		fmt.Printf("🌐 HTTP GET: %s\n", url)
 	}
 
-	resp, err := m.httpClient.Get(url)
+	req, err := http.NewRequest("GET", url, nil)
+	if err != nil {
+		return nil, err
+	}
+	// Authenticate GitHub API calls when a token is available: the anonymous
+	// rate limit (60 requests/hour) is too low for busy CI environments
+	if token := githubToken(); token != "" && isGitHubAPIURL(url) {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+
+	resp, err := m.httpClient.Do(req)
 	if err != nil {
 		if os.Getenv("MVX_VERBOSE") == "true" {
 			fmt.Printf("❌ HTTP GET failed: %s - %v\n", url, err)
 		}
+		// Offline: fall back to expired cached metadata rather than failing
+		if body, found := m.getDiskCachedResponse(url, true); found {
+			util.LogInfo("  📡 Network unavailable, using cached metadata for %s", url)
+			return &http.Response{
+				StatusCode: 200,
+				Body:       io.NopCloser(bytes.NewReader(body)),
+				Header:     make(http.Header),
+			}, nil
+		}
 		return nil, err
 	}
 
@@ -273,6 +341,19 @@ func (m *Manager) Get(url string) (*http.Response, error) {
 		fmt.Printf("✅ HTTP GET %d: %s\n", resp.StatusCode, url)
 	}
 
+	// Rate limited (GitHub returns 403 or 429): serve stale cached metadata
+	if resp.StatusCode == http.StatusForbidden || resp.StatusCode == http.StatusTooManyRequests {
+		if body, found := m.getDiskCachedResponse(url, true); found {
+			resp.Body.Close()
+			util.LogInfo("  📡 Rate limited (HTTP %d), using cached metadata for %s", resp.StatusCode, url)
+			return &http.Response{
+				StatusCode: 200,
+				Body:       io.NopCloser(bytes.NewReader(body)),
+				Header:     make(http.Header),
+			}, nil
+		}
+	}
+
 	// Cache successful responses (200 OK)
 	if resp.StatusCode == 200 {
 		// Read the body
@@ -306,6 +387,27 @@ func (m *Manager) Get(url string) (*http.Response, error) {
 	return resp, nil
 }
 
+// githubToken returns the credential used for GitHub API calls, preferring
+// the mvx-specific variable over the conventional CI one
+func githubToken() string {
+	if token := os.Getenv("MVX_GITHUB_TOKEN"); token != "" {
+		return token
+	}
+	return os.Getenv("GITHUB_TOKEN")
+}
+
+// isGitHubAPIURL reports whether a URL targets the GitHub API, where the
+// authentication token raises the rate limit. The token is deliberately not
+// sent to other hosts (including release asset CDNs).
+func isGitHubAPIURL(rawURL string) bool {
+	parsed, err := neturl.Parse(rawURL)
+	if err != nil {
+		return false
+	}
+	host := strings.ToLower(parsed.Hostname())
+	return host == "api.github.com" || host == "github.com"
+}
+
 // RegisterTool registers a tool with the manager
 func (m *Manager) RegisterTool(tool Tool) {
 	m.tools[tool.GetToolName()] = tool
@@ -345,11 +447,14 @@ type ToolFactory func(*Manager) Tool
 // toolFactories contains all available tool factories for auto-discovery
 // This registry allows tools to be registered dynamically, following the Open/Closed Principle
 var toolFactories = map[string]ToolFactory{
-	ToolJava:  func(m *Manager) Tool { return NewJavaTool(m) },
-	ToolMaven: func(m *Manager) Tool { return NewMavenTool(m) },
-	ToolMvnd:  func(m *Manager) Tool { return NewMvndTool(m) },
-	ToolNode:  func(m *Manager) Tool { return NewNodeTool(m) },
-	ToolGo:    func(m *Manager) Tool { return NewGoTool(m) },
+	ToolJava:       func(m *Manager) Tool { return NewJavaTool(m) },
+	ToolMaven:      func(m *Manager) Tool { return NewMavenTool(m) },
+	ToolMvnd:       func(m *Manager) Tool { return NewMvndTool(m) },
+	ToolNode:       func(m *Manager) Tool { return NewNodeTool(m) },
+	ToolGo:         func(m *Manager) Tool { return NewGoTool(m) },
+	ToolAndroidSdk: func(m *Manager) Tool { return NewAndroidSdkTool(m) },
+	ToolDotNet:     func(m *Manager) Tool { return NewDotNetTool(m) },
+	ToolJReleaser:  func(m *Manager) Tool { return NewJReleaserTool(m) },
 }
 
 // discoverAndRegisterTools automatically discovers and registers all available tools
@@ -361,8 +466,8 @@ func (m *Manager) discoverAndRegisterTools() error {
 		util.LogVerbose("Registered tool: %s", toolName)
 	}
 
-	// Future enhancement: could also load tools from configuration files
-	// This would allow users to register custom tools without code changes
+	// Register third-party tools provided by plugin manifests in ~/.mvx/plugins
+	m.discoverPluginTools()
 
 	return nil
 }
@@ -390,6 +495,11 @@ func (m *Manager) SearchToolVersions(toolName string, filters []string) ([]strin
 		return nil, err
 	}
 
+	// Tools with a dedicated search backend interpret the filters themselves
+	if searcher, ok := tool.(VersionSearcher); ok {
+		return searcher.SearchVersions(filters)
+	}
+
 	versions, err := tool.ListVersions()
 	if err != nil {
 		return nil, fmt.Errorf("failed to get versions for %s: %w", toolName, err)
@@ -494,6 +604,21 @@ func (m *Manager) EnsureTools(cfg *config.Config, maxConcurrent int) error {
 		maxConcurrent = GetDefaultConcurrency()
 	}
 
+	// Drop tools that declare os/arch conditions not matching this platform
+	filtered := *cfg
+	filtered.Tools = make(map[string]config.ToolConfig, len(cfg.Tools))
+	for toolName, toolConfig := range cfg.Tools {
+		if !toolConfig.MatchesPlatform() {
+			util.LogVerbose("Skipping %s: not applicable on this OS/arch", toolName)
+			continue
+		}
+		filtered.Tools[toolName] = toolConfig
+	}
+	cfg = &filtered
+	if len(cfg.Tools) == 0 {
+		return nil
+	}
+
 	// Resolve dependency order
 	orderedTools, err := m.resolveDependencyOrder(cfg)
 	if err != nil {
@@ -508,30 +633,77 @@ func (m *Manager) EnsureTools(cfg *config.Config, maxConcurrent int) error {
 		if err != nil {
 			return fmt.Errorf("failed to ensure %s is installed: %w", toolName, err)
 		}
-		fmt.Printf("✅ %s is ready\n", toolName)
+		util.LogInfo("✅ %s is ready", toolName)
 		return nil
 	}
 
-	fmt.Printf("📦 Ensuring %d tools are installed (max %d concurrent)...\n", len(cfg.Tools), maxConcurrent)
-
-	// Install tools in dependency order
-	// Tools with dependencies must be installed sequentially, but independent tools can be parallel
-	completed := 0
-	for _, toolName := range orderedTools {
-		toolConfig := cfg.Tools[toolName]
+	util.LogInfo("📦 Ensuring %d tools are installed (max %d concurrent)...", len(cfg.Tools), maxConcurrent)
+
+	// Group tools into dependency levels: tools within a level are independent
+	// of each other, so each level runs through a bounded worker pool while
+	// levels themselves execute in order.
+	var (
+		progressMutex sync.Mutex
+		completed     int
+	)
+	for _, level := range m.dependencyLevels(cfg, orderedTools) {
+		var wg sync.WaitGroup
+		errs := make(chan error, len(level))
+		sem := make(chan struct{}, maxConcurrent)
+
+		for _, toolName := range level {
+			wg.Add(1)
+			go func(toolName string, toolConfig config.ToolConfig) {
+				defer wg.Done()
+				sem <- struct{}{}
+				defer func() { <-sem }()
+
+				if _, err := m.EnsureTool(toolName, toolConfig); err != nil {
+					errs <- fmt.Errorf("failed to ensure %s is installed: %w", toolName, err)
+					return
+				}
 
-		if _, err := m.EnsureTool(toolName, toolConfig); err != nil {
-			return fmt.Errorf("failed to ensure %s is installed: %w", toolName, err)
+				progressMutex.Lock()
+				completed++
+				util.LogInfo("  ✅ %s is ready (%d/%d tools)", toolName, completed, len(cfg.Tools))
+				progressMutex.Unlock()
+			}(toolName, cfg.Tools[toolName])
 		}
 
-		completed++
-		fmt.Printf("  ✅ %s is ready (%d/%d tools)\n", toolName, completed, len(cfg.Tools))
+		wg.Wait()
+		close(errs)
+		for err := range errs {
+			return err
+		}
 	}
 
-	fmt.Printf("✅ All %d tools are ready\n", len(cfg.Tools))
+	util.LogInfo("✅ All %d tools are ready", len(cfg.Tools))
 	return nil
 }
 
+// dependencyLevels partitions tools into waves where every tool's
+// dependencies live in an earlier wave, so each wave can install in parallel
+func (m *Manager) dependencyLevels(cfg *config.Config, orderedTools []string) [][]string {
+	levelOf := make(map[string]int, len(orderedTools))
+	var levels [][]string
+
+	for _, toolName := range orderedTools {
+		level := 0
+		for _, dep := range m.getToolDependencies(toolName, cfg) {
+			if depLevel, ok := levelOf[dep]; ok && depLevel+1 > level {
+				level = depLevel + 1
+			}
+		}
+		levelOf[toolName] = level
+		for len(levels) <= level {
+			levels = append(levels, nil)
+		}
+		levels[level] = append(levels[level], toolName)
+	}
+
+	return levels
+}
+
 // resolveDependencyOrder resolves the installation order for tools based on their dependencies
 // Uses a simple topological sort with Kahn's algorithm
 func (m *Manager) resolveDependencyOrder(cfg *config.Config) ([]string, error) {
@@ -607,6 +779,11 @@ func (m *Manager) GetToolsNeedingInstallation(cfg *config.Config) (map[string]co
 	needInstallation := make(map[string]config.ToolConfig)
 
 	for toolName, toolConfig := range cfg.Tools {
+		// Tools restricted to other platforms are never installed here
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
 		tool, err := m.GetTool(toolName)
 		if err != nil {
 			return nil, fmt.Errorf("unknown tool %s: %w", toolName, err)
@@ -645,8 +822,14 @@ func (m *Manager) GetToolDir(toolName string) string {
 	return filepath.Join(m.GetToolsDir(), toolName)
 }
 
-// GetToolVersionDir returns the directory for a specific tool version
+// GetToolVersionDir returns the directory for a specific tool version.
+// Installations present in the read-only shared cache layer (see
+// MVX_SHARED_TOOLS_DIR) take precedence over the per-user writable cache.
 func (m *Manager) GetToolVersionDir(toolName, version string, distribution string) string {
+	if sharedDir := sharedToolVersionDir(toolName, version, distribution); sharedDir != "" {
+		return sharedDir
+	}
+
 	versionDir := version
 	if distribution != "" {
 		versionDir = fmt.Sprintf("%s@%s", version, distribution)
@@ -654,6 +837,86 @@ func (m *Manager) GetToolVersionDir(toolName, version string, distribution strin
 	return filepath.Join(m.GetToolDir(toolName), versionDir)
 }
 
+// UsageMarkerName is the file inside an installed version directory whose
+// mtime records when the version was last used, consulted by cache pruning
+const UsageMarkerName = ".mvx-last-used"
+
+// touchToolUsage stamps a version's last-use marker (best effort; the shared
+// read-only cache layer is never written to)
+func (m *Manager) touchToolUsage(toolName, version, distribution string) {
+	versionDir := version
+	if distribution != "" {
+		versionDir = fmt.Sprintf("%s@%s", version, distribution)
+	}
+	dir := filepath.Join(m.GetToolDir(toolName), versionDir)
+	if _, err := os.Stat(dir); err != nil {
+		return
+	}
+	marker := filepath.Join(dir, UsageMarkerName)
+	now := time.Now()
+	if err := os.Chtimes(marker, now, now); err != nil {
+		_ = os.WriteFile(marker, nil, 0644)
+	}
+}
+
+// HealthMarkerName is the file written inside an installed version
+// directory once post-install verification (java -version, mvn -v, ...)
+// has succeeded, so 'mvx tools verify' can tell a never-verified install
+// from one that passed
+const HealthMarkerName = ".mvx-verified"
+
+// markToolVerified stamps a version's health marker after its verification
+// command succeeded (best effort)
+func (m *Manager) markToolVerified(toolName, version, distribution string) {
+	versionDir := version
+	if distribution != "" {
+		versionDir = fmt.Sprintf("%s@%s", version, distribution)
+	}
+	dir := filepath.Join(m.GetToolDir(toolName), versionDir)
+	if _, err := os.Stat(dir); err != nil {
+		return
+	}
+	marker := filepath.Join(dir, HealthMarkerName)
+	if _, err := os.Stat(marker); err == nil {
+		return
+	}
+	_ = os.WriteFile(marker, []byte(time.Now().UTC().Format(time.RFC3339)+"\n"), 0644)
+}
+
+// isToolVerified reports whether a version's health marker is present
+func (m *Manager) isToolVerified(toolName, version, distribution string) bool {
+	versionDir := version
+	if distribution != "" {
+		versionDir = fmt.Sprintf("%s@%s", version, distribution)
+	}
+	_, err := os.Stat(filepath.Join(m.GetToolDir(toolName), versionDir, HealthMarkerName))
+	return err == nil
+}
+
+// RepairTool removes a corrupted installation (half-extracted archive,
+// broken symlinks) and reinstalls it from scratch
+func (m *Manager) RepairTool(toolName string, cfg config.ToolConfig) error {
+	resolved, err := m.ResolveVersion(toolName, cfg)
+	if err != nil {
+		return fmt.Errorf("failed to resolve version for %s: %w", toolName, err)
+	}
+
+	dir := m.GetToolVersionDir(toolName, resolved, cfg.Distribution)
+	if err := os.RemoveAll(dir); err != nil {
+		return fmt.Errorf("failed to remove corrupted %s %s: %w", toolName, resolved, err)
+	}
+
+	// Drop the caches so EnsureTool sees the removal
+	cacheKey := m.getCacheKey(toolName, resolved, cfg.Distribution)
+	m.cacheMutex.Lock()
+	delete(m.pathCache, cacheKey)
+	delete(m.installedCache, cacheKey)
+	m.cacheMutex.Unlock()
+
+	_, err = m.EnsureTool(toolName, cfg)
+	return err
+}
+
 // getCacheKey generates a cache key for tool operations
 func (m *Manager) getCacheKey(toolName, version, distribution string) string {
 	return fmt.Sprintf("%s:%s:%s", toolName, version, distribution)
@@ -692,10 +955,15 @@ func (m *Manager) isToolInstalled(toolName, version string, cfg config.ToolConfi
 // - Path retrieval (with caching)
 // All in one atomic, cached operation.
 func (m *Manager) EnsureTool(toolName string, cfg config.ToolConfig) (string, error) {
+	started := time.Now()
+
 	// Resolve version
 	resolvedVersion, err := m.resolveVersion(toolName, cfg)
 	if err != nil {
-		return "", fmt.Errorf("failed to resolve version for %s: %w", toolName, err)
+		err = fmt.Errorf("failed to resolve version for %s: %w", toolName, err)
+		recordInstallResult(InstallRecord{Tool: toolName, Version: cfg.Version, Distribution: cfg.Distribution,
+			DurationMs: sinceMs(started), Error: err.Error()})
+		return "", err
 	}
 
 	resolvedConfig := cfg
@@ -717,25 +985,75 @@ func (m *Manager) EnsureTool(toolName string, cfg config.ToolConfig) (string, er
 		return "", err
 	}
 
-	// Check if installed
-	if !tool.IsInstalled(resolvedVersion, resolvedConfig) {
-		// Auto-install
-		util.LogVerbose("Auto-installing %s %s...", toolName, resolvedVersion)
-		if err := tool.Install(resolvedVersion, resolvedConfig); err != nil {
-			return "", fmt.Errorf("failed to install %s %s: %w", toolName, resolvedVersion, err)
+	// Check if installed (also the cache-hit signal for install reporting)
+	cacheHit := tool.IsInstalled(resolvedVersion, resolvedConfig)
+	bytesBefore := downloadedBytes(toolName)
+	record := func(failure error) {
+		entry := InstallRecord{
+			Tool:            toolName,
+			Version:         resolvedVersion,
+			Distribution:    cfg.Distribution,
+			CacheHit:        cacheHit,
+			DurationMs:      sinceMs(started),
+			DownloadedBytes: downloadedBytes(toolName) - bytesBefore,
+		}
+		if failure != nil {
+			entry.Error = failure.Error()
 		}
+		recordInstallResult(entry)
+	}
+
+	if !cacheHit {
+		// Serialize against other mvx processes sharing ~/.mvx: the second
+		// waiter finds the first's finished install in the re-check below
+		unlock := acquireInstallLock(toolName, resolvedVersion, cfg.Distribution)
 
-		// Verify installation
-		if err := tool.Verify(resolvedVersion, resolvedConfig); err != nil {
-			return "", fmt.Errorf("failed to verify %s %s: %w", toolName, resolvedVersion, err)
+		if !tool.IsInstalled(resolvedVersion, resolvedConfig) {
+			// Auto-install. Under lazy setup this is the tool's first use,
+			// which deserves a visible one-time message rather than a
+			// seemingly hung command
+			if lazy := os.Getenv("MVX_SETUP_LAZY"); lazy == "1" || lazy == "true" {
+				fmt.Printf("📦 Installing %s %s on first use...\n", toolName, resolvedVersion)
+			} else {
+				util.LogVerbose("Auto-installing %s %s...", toolName, resolvedVersion)
+			}
+			if err := tool.Install(resolvedVersion, resolvedConfig); err != nil {
+				unlock()
+				err = fmt.Errorf("failed to install %s %s: %w", toolName, resolvedVersion, err)
+				record(err)
+				return "", err
+			}
+			m.recordInstall()
+
+			// Verify installation
+			if err := tool.Verify(resolvedVersion, resolvedConfig); err != nil {
+				unlock()
+				err = fmt.Errorf("failed to verify %s %s: %w", toolName, resolvedVersion, err)
+				record(err)
+				return "", err
+			}
+			m.markToolVerified(toolName, resolvedVersion, cfg.Distribution)
+		} else {
+			util.LogVerbose("Reusing %s %s installed by a concurrent mvx process", toolName, resolvedVersion)
 		}
+		unlock()
 	}
 
 	// Get path
 	path, err := tool.GetPath(resolvedVersion, resolvedConfig)
 	if err != nil {
-		return "", fmt.Errorf("failed to get path for %s %s: %w", toolName, resolvedVersion, err)
+		err = fmt.Errorf("failed to get path for %s %s: %w", toolName, resolvedVersion, err)
+		record(err)
+		return "", err
+	}
+
+	// Fail early with a clear explanation if the binaries were built for a
+	// different OS/arch (e.g. restored from a cache created on another machine)
+	if err := checkToolArchitecture(tool, toolName, resolvedVersion, path); err != nil {
+		record(err)
+		return "", err
 	}
+	record(nil)
 
 	// Cache the result
 	m.cacheMutex.Lock()
@@ -743,21 +1061,39 @@ func (m *Manager) EnsureTool(toolName string, cfg config.ToolConfig) (string, er
 	m.installedCache[cacheKey] = true
 	m.cacheMutex.Unlock()
 
+	m.touchToolUsage(toolName, resolvedVersion, cfg.Distribution)
+	// Stamp the health marker so the lockfile fast path can trust this
+	// install on later invocations
+	m.markToolVerified(toolName, resolvedVersion, cfg.Distribution)
+
 	return path, nil
 }
 
 // SetupEnvironment sets up environment variables for installed tools
 func (m *Manager) SetupEnvironment(cfg *config.Config) (map[string]string, error) {
+	return m.setupEnvironmentWithBase(cfg, os.Environ())
+}
+
+// SetupHermeticEnvironment is SetupEnvironment on a minimal base instead of
+// the inherited environment, so builds cannot accidentally pick up system
+// Java/Maven/Node. The passthrough list names inherited variables to keep.
+func (m *Manager) SetupHermeticEnvironment(cfg *config.Config, passthrough []string) (map[string]string, error) {
+	return m.setupEnvironmentWithBase(cfg, HermeticBaseEnvironment(passthrough))
+}
+
+// setupEnvironmentWithBase builds the resolved environment starting from the
+// given base environment slice
+func (m *Manager) setupEnvironmentWithBase(cfg *config.Config, base []string) (map[string]string, error) {
 	// Create environment manager
 	envManager := NewEnvironmentManager()
 
-	// Add system environment variables first (except PATH - we'll handle that after tools)
+	// Add base environment variables first (except PATH - we'll handle that after tools)
 	var systemPath string
-	for _, envVar := range os.Environ() {
+	for _, envVar := range base {
 		parts := strings.SplitN(envVar, "=", 2)
 		if len(parts) == 2 {
 			if parts[0] == "PATH" {
-				// Store system PATH for later - we want tool paths to take priority
+				// Store base PATH for later - we want tool paths to take priority
 				systemPath = parts[1]
 			} else {
 				envManager.SetEnv(parts[0], parts[1])
@@ -767,11 +1103,16 @@ func (m *Manager) SetupEnvironment(cfg *config.Config) (map[string]string, error
 
 	// Override with config environment
 	for key, value := range cfg.Environment {
-		envManager.SetEnv(key, value)
+		envManager.SetEnv(key, string(value))
 	}
 
 	// Add tool-specific environment variables and PATH entries
 	for toolName, toolConfig := range cfg.Tools {
+		// Skip tools restricted to other platforms
+		if !toolConfig.MatchesPlatform() {
+			continue
+		}
+
 		// Check if user wants to use system tool instead
 		systemEnvVar := fmt.Sprintf("MVX_USE_SYSTEM_%s", strings.ToUpper(toolName))
 		if os.Getenv(systemEnvVar) == "true" {
@@ -821,6 +1162,9 @@ func (m *Manager) SetupEnvironment(cfg *config.Config) (map[string]string, error
 		}
 	}
 
+	// Inject JVM options from the active profile (MAVEN_OPTS, GRADLE_OPTS, JAVA_TOOL_OPTIONS)
+	applyJvmProfiles(cfg, envManager)
+
 	// Add system PATH directories after tool directories (lower priority)
 	if systemPath != "" {
 		for _, dir := range strings.Split(systemPath, string(os.PathListSeparator)) {
@@ -870,6 +1214,13 @@ func (m *Manager) resolveVersion(toolName string, toolConfig config.ToolConfig)
 		return m.resolveVersionInternal(toolName, overrideConfig)
 	}
 
+	// Honor the lockfile: a pin matching the configured spec wins over
+	// online resolution, keeping ranges and aliases reproducible
+	if locked, found := m.lockedVersion(toolName, toolConfig); found {
+		util.LogVerbose("Using locked version for %s %s: %s", toolName, toolConfig.Version, locked)
+		return locked, nil
+	}
+
 	// Fast path: Check if version is already concrete (no resolution needed)
 	if m.isConcreteVersion(toolName, toolConfig.Version) {
 		return toolConfig.Version, nil
@@ -882,6 +1233,11 @@ func (m *Manager) resolveVersion(toolName string, toolConfig config.ToolConfig)
 func (m *Manager) resolveVersionInternal(toolName string, toolConfig config.ToolConfig) (string, error) {
 	distribution := toolConfig.Distribution
 
+	// ltsOnly projects never let "latest" land on a non-LTS release
+	if toolConfig.LtsOnly && (toolConfig.Version == "latest" || toolConfig.Version == "") {
+		toolConfig.Version = "lts"
+	}
+
 	// Check cache first
 	if cached, found := m.getCachedVersion(toolName, toolConfig.Version, distribution); found {
 		util.LogVerbose("Using cached version resolution: %s %s (%s) -> %s", toolName, toolConfig.Version, distribution, cached)
@@ -896,16 +1252,43 @@ func (m *Manager) resolveVersionInternal(toolName string, toolConfig config.Tool
 		return "", fmt.Errorf("unknown tool: %s", toolName)
 	}
 
+	// Internal registries override public version discovery
+	if registryVersions, fromRegistry, regErr := m.fetchRegistryVersions(toolName); fromRegistry {
+		if regErr == nil {
+			spec, specErr := version.ParseSpec(toolConfig.Version)
+			if specErr == nil {
+				spec.IncludePrereleases = toolConfig.AllowPrerelease
+				if resolved, resolveErr := spec.Resolve(registryVersions); resolveErr == nil {
+					m.setCachedVersion(toolName, toolConfig.Version, distribution, resolved)
+					return resolved, nil
+				}
+			}
+		}
+		if reg, _ := m.registryFor(toolName); reg.Exclusive {
+			return "", fmt.Errorf("failed to resolve %s %s from exclusive registry: %v", toolName, toolConfig.Version, regErr)
+		}
+		util.LogVerbose("Registry resolution for %s failed, falling back to public discovery: %v", toolName, regErr)
+	}
+
 	// Check if tool implements VersionResolver interface
 	var resolved string
-	if resolver, ok := tool.(VersionResolver); ok {
-		resolved, err = resolver.ResolveVersion(toolConfig.Version, distribution)
-		if err != nil {
-			return "", err
+	if toolConfig.AllowPrerelease {
+		// Resolve against the full version list with pre-releases enabled;
+		// tool resolvers exclude RCs and betas by default
+		if r, preErr := m.resolveWithPrereleases(tool, toolConfig.Version); preErr == nil {
+			resolved = r
+		}
+	}
+	if resolved == "" {
+		if resolver, ok := tool.(VersionResolver); ok {
+			resolved, err = resolver.ResolveVersion(toolConfig.Version, distribution)
+			if err != nil {
+				return "", err
+			}
+		} else {
+			// Fallback: return version as-is for tools that don't implement VersionResolver
+			resolved = toolConfig.Version
 		}
-	} else {
-		// Fallback: return version as-is for tools that don't implement VersionResolver
-		resolved = toolConfig.Version
 	}
 
 	util.LogVerbose("Resolved %s %s (%s) -> %s (caching for 24h)", toolName, toolConfig.Version, distribution, resolved)
@@ -916,6 +1299,22 @@ func (m *Manager) resolveVersionInternal(toolName string, toolConfig config.Tool
 	return resolved, nil
 }
 
+// resolveWithPrereleases resolves a version spec against the tool's full
+// version list with pre-release matching enabled (allowPrerelease config)
+func (m *Manager) resolveWithPrereleases(tool Tool, versionSpec string) (string, error) {
+	spec, err := version.ParseSpec(versionSpec)
+	if err != nil {
+		return "", err
+	}
+	spec.IncludePrereleases = true
+
+	available, err := tool.ListVersions()
+	if err != nil {
+		return "", err
+	}
+	return spec.Resolve(available)
+}
+
 // isConcreteVersion checks if a version specification is already concrete and doesn't need resolution
 func (m *Manager) isConcreteVersion(toolName, versionSpec string) bool {
 	// Handle special cases that always need resolution
@@ -997,8 +1396,9 @@ func (m *Manager) getCachedVersion(toolName, versionSpec, distribution string) (
 		return "", false
 	}
 
-	// Check if cache entry is still valid (less than 24 hours old)
-	if time.Since(entry.Timestamp) > 24*time.Hour {
+	// Check if cache entry is still valid (less than 24 hours old).
+	// Offline mode accepts expired entries: stale beats unavailable.
+	if time.Since(entry.Timestamp) > 24*time.Hour && !util.IsOffline() {
 		return "", false
 	}
 
@@ -1091,9 +1491,22 @@ func (m *Manager) EnsureToolInstalled(cfg *config.Config, toolName string) error
 	return err
 }
 
-// getDiskCachedResponse retrieves a cached HTTP response from disk
-// Returns the response body and true if found and valid (less than 24 hours old)
-func (m *Manager) getDiskCachedResponse(url string) ([]byte, bool) {
+// metadataCacheTTL returns how long cached metadata responses stay fresh,
+// configurable via MVX_METADATA_CACHE_TTL (Go duration, default 24 hours)
+func metadataCacheTTL() time.Duration {
+	if ttlStr := os.Getenv("MVX_METADATA_CACHE_TTL"); ttlStr != "" {
+		if ttl, err := time.ParseDuration(ttlStr); err == nil && ttl > 0 {
+			return ttl
+		}
+	}
+	return 24 * time.Hour
+}
+
+// getDiskCachedResponse retrieves a cached HTTP response from disk.
+// Fresh entries (younger than the TTL) are always returned; expired entries
+// are kept on disk and only returned with allowStale, which serves as the
+// offline/rate-limited fallback.
+func (m *Manager) getDiskCachedResponse(url string, allowStale bool) ([]byte, bool) {
 	cacheFile := m.getDiskCacheFilePath(url)
 
 	data, err := os.ReadFile(cacheFile)
@@ -1106,14 +1519,10 @@ func (m *Manager) getDiskCachedResponse(url string) ([]byte, bool) {
 		return nil, false
 	}
 
-	// Check if cache is still valid (less than 24 hours old)
-	if time.Since(entry.Timestamp) > 24*time.Hour {
-		// Clean up expired cache file
-		os.Remove(cacheFile)
+	if time.Since(entry.Timestamp) > metadataCacheTTL() && !allowStale {
 		return nil, false
 	}
 
-	// Decode base64 body
 	body := []byte(entry.Body)
 	return body, true
 }
@@ -1145,5 +1554,5 @@ func (m *Manager) getDiskCacheFilePath(url string) string {
 	// Create a safe filename from the URL using SHA256 hash
 	// This avoids filesystem issues with special characters and ensures uniqueness
 	hash := fmt.Sprintf("%x", sha256.Sum256([]byte(url)))
-	return filepath.Join(m.cacheDir, "http_cache", hash+".json")
+	return filepath.Join(m.cacheDir, "cache", "metadata", hash+".json")
 }
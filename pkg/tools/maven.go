@@ -6,14 +6,17 @@ import (
 	"net/http"
 	"os"
 	"strings"
+	"time"
 
 	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/gnodet/mvx/pkg/version"
 )
 
 // Compile-time interface validation
 var _ Tool = (*MavenTool)(nil)
 var _ DependencyProvider = (*MavenTool)(nil)
+var _ FallbackURLProvider = (*MavenTool)(nil)
 var _ EnvironmentProvider = (*MavenTool)(nil)
 
 // MavenTool implements Tool interface for Maven management
@@ -51,15 +54,12 @@ func (m *MavenTool) installWithFallback(version string, cfg config.ToolConfig) e
 		return InstallError(m.GetToolName(), version, fmt.Errorf("failed to create install directory: %w", err))
 	}
 
-	// Try both URLs with reduced retries instead of exhausting retries on first URL
-	primaryURL := m.getDownloadURL(version)
-	archiveURL := m.getArchiveDownloadURL(version)
+	// Download through the generic fallback chain: the CDN URL first, then
+	// the archive URL from GetFallbackURLs
 	m.PrintDownloadMessage(version)
-
-	// Try to download with alternating URLs and reduced retries per URL
-	archivePath, err := m.downloadWithAlternatingURLs(primaryURL, archiveURL, version, cfg)
+	archivePath, err := m.Download(m.getDownloadURL(version), version, cfg)
 	if err != nil {
-		return InstallError(m.GetToolName(), version, fmt.Errorf("download failed from both primary and archive URLs: %w", err))
+		return InstallError(m.GetToolName(), version, err)
 	}
 	defer os.Remove(archivePath) // Clean up downloaded file
 
@@ -220,6 +220,12 @@ func (m *MavenTool) getArchiveDownloadURL(version string) string {
 	return fmt.Sprintf(ApacheMavenBase+"/maven-3/%s/binaries/apache-maven-%s-bin.zip", version, version)
 }
 
+// GetFallbackURLs returns the Apache archive URL, tried when dist.apache.org
+// no longer serves a release (implements FallbackURLProvider)
+func (m *MavenTool) GetFallbackURLs(version string) []string {
+	return []string{m.getArchiveDownloadURL(version)}
+}
+
 // ResolveVersion resolves a Maven version specification to a concrete version
 func (m *MavenTool) ResolveVersion(versionSpec, distribution string) (string, error) {
 	availableVersions, err := m.ListVersions()
@@ -278,9 +284,23 @@ func (m *MavenTool) GetChecksum(version string, cfg config.ToolConfig, filename
 	}, nil
 }
 
+// GetSignature implements SignatureProvider for Maven: Apache publishes a
+// detached PGP signature (.asc) next to every release archive
+func (m *MavenTool) GetSignature(version string, cfg config.ToolConfig, filename string) (SignatureInfo, error) {
+	downloadURL := m.getDownloadURL(version)
+	if downloadURL == "" {
+		return SignatureInfo{}, fmt.Errorf("no download URL available for Maven %s", version)
+	}
+	return SignatureInfo{
+		Type:    "pgp",
+		URL:     downloadURL + ".asc",
+		KeysURL: ApacheDistBase + "/KEYS",
+	}, nil
+}
+
 // fetchChecksumFromURL fetches checksum from a URL
 func (m *MavenTool) fetchChecksumFromURL(url string) (string, error) {
-	resp, err := http.Get(url)
+	resp, err := util.HTTPClient(30 * time.Second).Get(url)
 	if err != nil {
 		return "", fmt.Errorf("failed to fetch checksum: %w", err)
 	}
@@ -304,61 +324,3 @@ func (m *MavenTool) fetchChecksumFromURL(url string) (string, error) {
 	return checksum, nil
 }
 
-// downloadWithAlternatingURLs tries downloading from both URLs with reduced retries per URL
-// instead of exhausting all retries on the first URL before trying the second
-func (m *MavenTool) downloadWithAlternatingURLs(primaryURL, archiveURL, version string, cfg config.ToolConfig) (string, error) {
-	urls := []struct {
-		url  string
-		name string
-	}{
-		{primaryURL, "primary"},
-		{archiveURL, "archive"},
-	}
-
-	maxRetries := 3    // Total retries across both URLs
-	retriesPerURL := 1 // Reduced retries per URL to allow trying both
-
-	var lastErr error
-
-	for attempt := 0; attempt < maxRetries; attempt++ {
-		urlIndex := attempt % len(urls)
-		currentURL := urls[urlIndex]
-
-		if attempt > 0 && urlIndex == 0 {
-			fmt.Printf("  🔄 Trying %s URL again (attempt %d)...\n", currentURL.name, (attempt/len(urls))+1)
-		} else if urlIndex == 1 {
-			fmt.Printf("  🔄 Switching to %s URL...\n", currentURL.name)
-		}
-
-		// Create download config with reduced retries per URL
-		downloadConfig := DefaultDownloadConfig(currentURL.url, "")
-		downloadConfig.MaxRetries = retriesPerURL
-		// Note: MinSize/MaxSize/ExpectedType removed - we rely on checksums for validation
-		downloadConfig.ToolName = m.toolName
-		downloadConfig.Version = version
-		downloadConfig.Config = cfg
-		downloadConfig.Tool = m
-
-		// Create temporary file for download (Maven always uses ZIP)
-		tmpFile, err := os.CreateTemp("", "maven-*.zip")
-		if err != nil {
-			lastErr = fmt.Errorf("failed to create temporary file: %w", err)
-			continue
-		}
-		downloadConfig.DestPath = tmpFile.Name()
-		tmpFile.Close()
-
-		_, err = RobustDownload(downloadConfig)
-		if err == nil {
-			fmt.Printf("  ✅ Successfully downloaded from %s URL\n", currentURL.name)
-			return downloadConfig.DestPath, nil
-		}
-
-		lastErr = err
-		fmt.Printf("  ⚠️  Download from %s URL failed: %v\n", currentURL.name, err)
-		// Clean up failed download
-		os.Remove(downloadConfig.DestPath)
-	}
-
-	return "", fmt.Errorf("all download attempts failed, last error: %w", lastErr)
-}
@@ -0,0 +1,59 @@
+package tools
+
+import (
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+// Mirror policy: the project config's mirrors section maps upstream URL
+// prefixes to internal mirrors (Artifactory/Nexus generic repos), e.g.
+//
+//	mirrors: {
+//	  "https://github.com/": "https://artifactory.company.com/github/",
+//	  "https://archive.apache.org/": "https://nexus.company.com/apache/",
+//	}
+//
+// Mirrors rewrite download URLs before any request is made, so build hosts
+// that block direct upstream access only ever talk to the mirror. The host
+// allowlist is enforced on the rewritten URL.
+var (
+	mirrorsMutex   sync.RWMutex
+	projectMirrors map[string]string
+)
+
+// configureMirrors records the project's mirror mappings
+func configureMirrors(cfg *config.Config) {
+	mirrorsMutex.Lock()
+	defer mirrorsMutex.Unlock()
+	projectMirrors = cfg.Mirrors
+}
+
+// applyMirrors rewrites a URL through the configured mirror mappings. The
+// longest matching prefix wins so specific mirrors override broad ones.
+func applyMirrors(rawURL string) string {
+	mirrorsMutex.RLock()
+	mirrors := projectMirrors
+	mirrorsMutex.RUnlock()
+
+	if len(mirrors) == 0 {
+		return rawURL
+	}
+
+	prefixes := make([]string, 0, len(mirrors))
+	for prefix := range mirrors {
+		prefixes = append(prefixes, prefix)
+	}
+	sort.Slice(prefixes, func(i, j int) bool {
+		return len(prefixes[i]) > len(prefixes[j])
+	})
+
+	for _, prefix := range prefixes {
+		if rest, ok := strings.CutPrefix(rawURL, prefix); ok {
+			return mirrors[prefix] + rest
+		}
+	}
+	return rawURL
+}
@@ -0,0 +1,45 @@
+package tools
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestApplyMirrors(t *testing.T) {
+	defer configureMirrors(&config.Config{})
+
+	// No mirrors configured: URLs pass through untouched
+	configureMirrors(&config.Config{})
+	url := "https://github.com/apache/maven/archive/v4.0.0.tar.gz"
+	if got := applyMirrors(url); got != url {
+		t.Errorf("applyMirrors(%q) = %q, want unchanged", url, got)
+	}
+
+	configureMirrors(&config.Config{Mirrors: map[string]string{
+		"https://github.com/":              "https://artifactory.company.com/github/",
+		"https://github.com/apache/":      "https://artifactory.company.com/asf-github/",
+		"https://archive.apache.org/dist/": "https://nexus.company.com/apache/",
+	}})
+
+	cases := []struct {
+		url  string
+		want string
+	}{
+		// Longest prefix wins
+		{"https://github.com/apache/maven/archive/v4.tar.gz",
+			"https://artifactory.company.com/asf-github/maven/archive/v4.tar.gz"},
+		{"https://github.com/nodejs/node/releases/v20.tar.gz",
+			"https://artifactory.company.com/github/nodejs/node/releases/v20.tar.gz"},
+		{"https://archive.apache.org/dist/maven/maven-3.9.6.tar.gz",
+			"https://nexus.company.com/apache/maven/maven-3.9.6.tar.gz"},
+		// No matching prefix
+		{"https://nodejs.org/dist/v20.0.0/node.tar.gz",
+			"https://nodejs.org/dist/v20.0.0/node.tar.gz"},
+	}
+	for _, tc := range cases {
+		if got := applyMirrors(tc.url); got != tc.want {
+			t.Errorf("applyMirrors(%q) = %q, want %q", tc.url, got, tc.want)
+		}
+	}
+}
@@ -6,14 +6,17 @@ import (
 	"net/http"
 	"os"
 	"strings"
+	"time"
 
 	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
 	"github.com/gnodet/mvx/pkg/version"
 )
 
 // Compile-time interface validation
 var _ Tool = (*MvndTool)(nil)
 var _ DependencyProvider = (*MvndTool)(nil)
+var _ FallbackURLProvider = (*MvndTool)(nil)
 var _ EnvironmentProvider = (*MvndTool)(nil)
 
 // MvndTool implements Tool interface for Maven Daemon management
@@ -155,6 +158,12 @@ func (m *MvndTool) getArchiveDownloadURL(version string) string {
 	return fmt.Sprintf("https://archive.apache.org/dist/maven/mvnd/%s/maven-mvnd-%s-%s.zip", version, version, platform)
 }
 
+// GetFallbackURLs returns the Apache archive URL, tried when dist.apache.org
+// no longer serves a release (implements FallbackURLProvider)
+func (m *MvndTool) GetFallbackURLs(version string) []string {
+	return []string{m.getArchiveDownloadURL(version)}
+}
+
 // getPlatformString returns the platform string for mvnd downloads
 func (m *MvndTool) getPlatformString() string {
 	platformMapper := NewPlatformMapper()
@@ -171,6 +180,11 @@ func (m *MvndTool) getPlatformString() string {
 		}
 		return "darwin-amd64"
 	case "windows":
+		if platformMapper.IsARM64() {
+			// mvnd has no windows/arm64 build; the amd64 build runs under
+			// the OS's x64 emulation
+			util.LogVerbose("No mvnd build for windows/arm64, using the amd64 build (x64 emulation)")
+		}
 		return "windows-amd64"
 	default:
 		return "linux-amd64" // fallback
@@ -231,9 +245,23 @@ func (m *MvndTool) GetChecksum(version string, cfg config.ToolConfig, filename s
 	}, nil
 }
 
+// GetSignature implements SignatureProvider for Maven Daemon: Apache
+// publishes a detached PGP signature (.asc) next to every release archive
+func (m *MvndTool) GetSignature(version string, cfg config.ToolConfig, filename string) (SignatureInfo, error) {
+	downloadURL := m.getDownloadURL(version)
+	if downloadURL == "" {
+		return SignatureInfo{}, fmt.Errorf("no download URL available for Maven Daemon %s", version)
+	}
+	return SignatureInfo{
+		Type:    "pgp",
+		URL:     downloadURL + ".asc",
+		KeysURL: ApacheDistBase + "/KEYS",
+	}, nil
+}
+
 // fetchChecksumFromURL fetches checksum from a URL (same as Maven)
 func (m *MvndTool) fetchChecksumFromURL(url string) (string, error) {
-	resp, err := http.Get(url)
+	resp, err := util.HTTPClient(30 * time.Second).Get(url)
 	if err != nil {
 		return "", fmt.Errorf("failed to fetch checksum: %w", err)
 	}
@@ -270,15 +298,12 @@ func (m *MvndTool) installWithFallback(version string, cfg config.ToolConfig) er
 		return InstallError("mvnd", version, fmt.Errorf("failed to create install directory: %w", err))
 	}
 
-	// Try both URLs with reduced retries instead of exhausting retries on first URL
-	primaryURL := m.getDownloadURL(version)
-	archiveURL := m.getArchiveDownloadURL(version)
+	// Download through the generic fallback chain: the CDN URL first, then
+	// the archive URL from GetFallbackURLs
 	m.PrintDownloadMessage(version)
-
-	// Try to download with alternating URLs and reduced retries per URL
-	archivePath, err := m.downloadWithAlternatingURLs(primaryURL, archiveURL, version, cfg)
+	archivePath, err := m.Download(m.getDownloadURL(version), version, cfg)
 	if err != nil {
-		return InstallError("mvnd", version, fmt.Errorf("download failed from both primary and archive URLs: %w", err))
+		return InstallError("mvnd", version, err)
 	}
 
 	// Extract archive
@@ -295,61 +320,3 @@ func (m *MvndTool) installWithFallback(version string, cfg config.ToolConfig) er
 	return nil
 }
 
-// downloadWithAlternatingURLs tries downloading from both URLs with reduced retries per URL
-// instead of exhausting all retries on the first URL before trying the second
-func (m *MvndTool) downloadWithAlternatingURLs(primaryURL, archiveURL, version string, cfg config.ToolConfig) (string, error) {
-	urls := []struct {
-		url  string
-		name string
-	}{
-		{primaryURL, "primary"},
-		{archiveURL, "archive"},
-	}
-
-	maxRetries := 3    // Total retries across both URLs
-	retriesPerURL := 1 // Reduced retries per URL to allow trying both
-
-	var lastErr error
-
-	for attempt := 0; attempt < maxRetries; attempt++ {
-		urlIndex := attempt % len(urls)
-		currentURL := urls[urlIndex]
-
-		if attempt > 0 && urlIndex == 0 {
-			fmt.Printf("  🔄 Trying %s URL again (attempt %d)...\n", currentURL.name, (attempt/len(urls))+1)
-		} else if urlIndex == 1 {
-			fmt.Printf("  🔄 Switching to %s URL...\n", currentURL.name)
-		}
-
-		// Create download config with reduced retries per URL
-		downloadConfig := DefaultDownloadConfig(currentURL.url, "")
-		downloadConfig.MaxRetries = retriesPerURL
-		// Note: MinSize/MaxSize/ExpectedType removed - we rely on checksums for validation
-		downloadConfig.ToolName = "mvnd"
-		downloadConfig.Version = version
-		downloadConfig.Config = cfg
-		downloadConfig.Tool = m
-
-		// Create temporary file for download (Mvnd always uses ZIP)
-		tmpFile, err := os.CreateTemp("", "mvnd-*.zip")
-		if err != nil {
-			lastErr = fmt.Errorf("failed to create temporary file: %w", err)
-			continue
-		}
-		downloadConfig.DestPath = tmpFile.Name()
-		tmpFile.Close()
-
-		_, err = RobustDownload(downloadConfig)
-		if err == nil {
-			fmt.Printf("  ✅ Successfully downloaded from %s URL\n", currentURL.name)
-			return downloadConfig.DestPath, nil
-		}
-
-		lastErr = err
-		fmt.Printf("  ⚠️  Download from %s URL failed: %v\n", currentURL.name, err)
-		// Clean up failed download
-		os.Remove(downloadConfig.DestPath)
-	}
-
-	return "", fmt.Errorf("all download attempts failed, last error: %w", lastErr)
-}
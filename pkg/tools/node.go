@@ -135,6 +135,18 @@ func (n *NodeTool) fetchNodeIndex() ([]nodeIndexEntry, error) {
 	return entries, nil
 }
 
+// isLTS reports whether an index entry is an LTS release. The lts field can
+// be false (not LTS), true (LTS without codename) or a codename string.
+func (e nodeIndexEntry) isLTS() bool {
+	if ltsValue, ok := e.LTS.(bool); ok {
+		return ltsValue
+	}
+	if ltsString, ok := e.LTS.(string); ok {
+		return ltsString != ""
+	}
+	return false
+}
+
 // fetchNodeLTSVersions fetches available Node.js LTS versions
 func (n *NodeTool) fetchNodeLTSVersions() ([]string, error) {
 	entries, err := n.fetchNodeIndex()
@@ -143,15 +155,58 @@ func (n *NodeTool) fetchNodeLTSVersions() ([]string, error) {
 	}
 	var versions []string
 	for _, e := range entries {
-		// LTS can be false (not LTS), true (LTS but no codename), or a string (LTS with codename)
-		if e.LTS != nil && e.LTS != false {
-			// Check if it's a boolean true or a string (both indicate LTS)
-			if ltsValue, ok := e.LTS.(bool); ok && ltsValue {
-				versions = append(versions, strings.TrimPrefix(e.Version, "v"))
-			} else if ltsString, ok := e.LTS.(string); ok && ltsString != "" {
-				versions = append(versions, strings.TrimPrefix(e.Version, "v"))
+		if e.isLTS() {
+			versions = append(versions, strings.TrimPrefix(e.Version, "v"))
+		}
+	}
+	return versions, nil
+}
+
+// SearchVersions implements VersionSearcher for Node.js. The "lts" filter
+// restricts output to LTS releases; remaining filters match as substrings.
+// LTS versions are annotated so humans can tell them apart.
+func (n *NodeTool) SearchVersions(filters []string) ([]string, error) {
+	ltsOnly := false
+	var substrings []string
+	for _, filter := range filters {
+		if strings.EqualFold(filter, "lts") {
+			ltsOnly = true
+		} else {
+			substrings = append(substrings, filter)
+		}
+	}
+
+	entries, err := n.fetchNodeIndex()
+	if err != nil {
+		return nil, err
+	}
+	ltsSet := make(map[string]bool)
+	var raw []string
+	for _, e := range entries {
+		v := strings.TrimPrefix(e.Version, "v")
+		if ltsOnly && !e.isLTS() {
+			continue
+		}
+		ltsSet[v] = e.isLTS()
+		raw = append(raw, v)
+	}
+
+	var versions []string
+	for _, v := range version.SortVersions(raw) {
+		matched := len(substrings) == 0
+		for _, substring := range substrings {
+			if strings.Contains(strings.ToLower(v), strings.ToLower(substring)) {
+				matched = true
+				break
 			}
 		}
+		if !matched {
+			continue
+		}
+		if ltsSet[v] {
+			v += " (LTS)"
+		}
+		versions = append(versions, v)
 	}
 	return versions, nil
 }
@@ -159,42 +214,62 @@ func (n *NodeTool) fetchNodeLTSVersions() ([]string, error) {
 func (n *NodeTool) getDownloadURL(version string) string {
 	platformMapper := NewPlatformMapper()
 
-	// Generate Node.js platform string
-	var platform string
+	// Determine file extension
+	var fileExt string
+	if platformMapper.IsWindows() {
+		fileExt = ExtZip
+	} else {
+		fileExt = ExtTarGz
+	}
+
+	return fmt.Sprintf(nodeDistBase()+"/v%[1]s/node-v%[1]s-%[2]s%[3]s", version, nodePlatformString(), fileExt)
+}
+
+// nodePlatformString returns the platform component of Node.js artifact
+// names (linux-x64, darwin-arm64, win-arm64, linux-x64-musl, ...)
+func nodePlatformString() string {
+	platformMapper := NewPlatformMapper()
+
 	switch platformMapper.GetOS() {
 	case "linux":
-		if platformMapper.GetArch() == "arm64" {
-			platform = "linux-arm64"
-		} else {
-			platform = "linux-x64"
+		if platformMapper.IsMusl() {
+			// nodejs.org only ships glibc binaries; Alpine uses the musl
+			// builds from unofficial-builds, published for x64 only
+			return "linux-x64-musl"
+		}
+		if platformMapper.IsARM64() {
+			return "linux-arm64"
 		}
+		return "linux-x64"
 	case "darwin":
-		if platformMapper.GetArch() == "arm64" {
-			platform = "darwin-arm64"
-		} else {
-			platform = "darwin-x64"
+		if platformMapper.IsARM64() {
+			return "darwin-arm64"
 		}
+		return "darwin-x64"
 	case "windows":
-		platform = "win-x64"
+		if platformMapper.IsARM64() {
+			return "win-arm64"
+		}
+		return "win-x64"
 	default:
-		platform = "linux-x64" // fallback
+		return "linux-x64" // fallback
 	}
+}
 
-	// Determine file extension
-	var fileExt string
-	if platformMapper.IsWindows() {
-		fileExt = ExtZip
-	} else {
-		fileExt = ExtTarGz
+// nodeDistBase returns the download server for the current platform: the
+// official dist server, or unofficial-builds for musl artifacts. Both hosts
+// use the same release layout, including SHASUMS256.txt.
+func nodeDistBase() string {
+	if NewPlatformMapper().IsMusl() {
+		return NodeJSUnofficialDistBase
 	}
-
-	return fmt.Sprintf(NodeJSDistBase+"/v%[1]s/node-v%[1]s-%[2]s%[3]s", version, platform, fileExt)
+	return NodeJSDistBase
 }
 
 // ResolveVersion resolves a Node version specification to a concrete version
 func (n *NodeTool) ResolveVersion(versionSpec, distribution string) (string, error) {
-	// Special handling for "lts"
-	if versionSpec == "lts" {
+	// Special handling for "lts" ("lts/*" is the nvm-style spelling)
+	if versionSpec == "lts" || versionSpec == "lts/*" {
 		lts, err := n.fetchNodeLTSVersions()
 		if err != nil || len(lts) == 0 {
 			return "", fmt.Errorf("failed to resolve Node LTS version")
@@ -241,7 +316,7 @@ func (n *NodeTool) GetChecksum(version string, cfg config.ToolConfig, filename s
 
 // fetchNodeChecksum fetches Node.js checksum from SHASUMS256.txt
 func (n *NodeTool) fetchNodeChecksum(version, filename string) (string, error) {
-	url := fmt.Sprintf("%s/v%s/SHASUMS256.txt", NodeJSDistBase, version)
+	url := fmt.Sprintf("%s/v%s/SHASUMS256.txt", nodeDistBase(), version)
 
 	resp, err := n.manager.Get(url)
 	if err != nil {
@@ -312,23 +387,7 @@ func (n *NodeTool) parseNodeChecksumFile(content, filename string) (string, erro
 
 // getNodeFilename determines the correct Node.js filename based on version and platform
 func (n *NodeTool) getNodeFilename(version string) string {
-	platform := ""
-	switch runtime.GOOS {
-	case "linux":
-		if runtime.GOARCH == "arm64" {
-			platform = "linux-arm64"
-		} else {
-			platform = "linux-x64"
-		}
-	case "darwin":
-		if runtime.GOARCH == "arm64" {
-			platform = "darwin-arm64"
-		} else {
-			platform = "darwin-x64"
-		}
-	case "windows":
-		platform = "win-x64"
-	}
+	platform := nodePlatformString()
 
 	// Windows uses zip, others tar.gz
 	if runtime.GOOS == "windows" {
@@ -98,3 +98,30 @@ func TestNodeToolTempFileExtension(t *testing.T) {
 		})
 	}
 }
+
+func TestNodeMuslDownloadURL(t *testing.T) {
+	if runtime.GOOS != "linux" {
+		t.Skip("musl builds are Linux-only")
+	}
+
+	manager, err := NewManager()
+	if err != nil {
+		t.Fatalf("Failed to create manager: %v", err)
+	}
+	nodeTool := NewNodeTool(manager)
+
+	t.Setenv("MVX_FORCE_MUSL", "true")
+	url := nodeTool.getDownloadURL("20.19.5")
+	if !strings.Contains(url, "linux-x64-musl") {
+		t.Errorf("Expected musl platform in URL, got %s", url)
+	}
+	if !strings.HasPrefix(url, NodeJSUnofficialDistBase) {
+		t.Errorf("Expected unofficial-builds host for musl, got %s", url)
+	}
+
+	t.Setenv("MVX_FORCE_MUSL", "false")
+	url = nodeTool.getDownloadURL("20.19.5")
+	if strings.Contains(url, "musl") || !strings.HasPrefix(url, NodeJSDistBase) {
+		t.Errorf("Expected official glibc URL, got %s", url)
+	}
+}
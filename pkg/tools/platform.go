@@ -2,7 +2,10 @@ package tools
 
 import (
 	"fmt"
+	"os"
+	"path/filepath"
 	"runtime"
+	"sync"
 )
 
 // PlatformInfo contains platform detection information
@@ -91,3 +94,56 @@ func (pm *PlatformMapper) IsARM64() bool {
 func (pm *PlatformMapper) IsAMD64() bool {
 	return pm.platform.Arch == "amd64"
 }
+
+// IsWindowsARM64 returns true on Windows ARM64. Tools without a native
+// arm64 artifact can fall back to the amd64 build there, which runs under
+// the OS's x64 emulation.
+func (pm *PlatformMapper) IsWindowsARM64() bool {
+	return pm.IsWindows() && pm.IsARM64()
+}
+
+// IsMusl returns true on Linux systems using the musl C library (Alpine
+// containers), where glibc-linked tool binaries do not run
+func (pm *PlatformMapper) IsMusl() bool {
+	return pm.IsLinux() && muslLibc()
+}
+
+// LibC returns the host C library family ("glibc" or "musl") on Linux and
+// an empty string on other operating systems
+func (pm *PlatformMapper) LibC() string {
+	if !pm.IsLinux() {
+		return ""
+	}
+	if pm.IsMusl() {
+		return "musl"
+	}
+	return "glibc"
+}
+
+var (
+	muslProbeOnce sync.Once
+	muslProbed    bool
+)
+
+// muslLibc probes whether the Linux userland is musl-based.
+// MVX_FORCE_MUSL=true/false overrides detection for environments where the
+// probe files are not visible (e.g. minimal containers).
+func muslLibc() bool {
+	switch os.Getenv("MVX_FORCE_MUSL") {
+	case "true", "1":
+		return true
+	case "false", "0":
+		return false
+	}
+	muslProbeOnce.Do(func() {
+		if _, err := os.Stat("/etc/alpine-release"); err == nil {
+			muslProbed = true
+			return
+		}
+		// musl's dynamic loader is installed as /lib/ld-musl-<arch>.so.1
+		if matches, _ := filepath.Glob("/lib/ld-musl-*"); len(matches) > 0 {
+			muslProbed = true
+		}
+	})
+	return muslProbed
+}
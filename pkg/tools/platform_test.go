@@ -0,0 +1,50 @@
+package tools
+
+import (
+	"runtime"
+	"testing"
+)
+
+func TestMuslForceOverride(t *testing.T) {
+	pm := NewPlatformMapper()
+
+	t.Setenv("MVX_FORCE_MUSL", "true")
+	if pm.IsMusl() != (runtime.GOOS == "linux") {
+		t.Errorf("MVX_FORCE_MUSL=true: IsMusl() = %v on %s", pm.IsMusl(), runtime.GOOS)
+	}
+
+	t.Setenv("MVX_FORCE_MUSL", "false")
+	if pm.IsMusl() {
+		t.Error("MVX_FORCE_MUSL=false should disable musl detection")
+	}
+}
+
+func TestLibC(t *testing.T) {
+	pm := NewPlatformMapper()
+
+	t.Setenv("MVX_FORCE_MUSL", "true")
+	if runtime.GOOS == "linux" {
+		if got := pm.LibC(); got != "musl" {
+			t.Errorf("expected musl, got %q", got)
+		}
+	} else {
+		if got := pm.LibC(); got != "" {
+			t.Errorf("expected empty libc off Linux, got %q", got)
+		}
+	}
+
+	t.Setenv("MVX_FORCE_MUSL", "false")
+	if runtime.GOOS == "linux" {
+		if got := pm.LibC(); got != "glibc" {
+			t.Errorf("expected glibc, got %q", got)
+		}
+	}
+}
+
+func TestIsWindowsARM64(t *testing.T) {
+	pm := NewPlatformMapper()
+	want := runtime.GOOS == "windows" && runtime.GOARCH == "arm64"
+	if pm.IsWindowsARM64() != want {
+		t.Errorf("IsWindowsARM64() = %v, want %v", pm.IsWindowsARM64(), want)
+	}
+}
@@ -0,0 +1,245 @@
+package tools
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+	"github.com/gnodet/mvx/pkg/version"
+)
+
+// Compile-time interface validation
+var _ Tool = (*PluginTool)(nil)
+
+// PluginManifest describes a tool provided by a third-party plugin.
+// Manifests are JSON5 files discovered from ~/.mvx/plugins or listed in the
+// project configuration's "plugins" section. They let external providers
+// register new tools with mvx — known versions, download URLs and checksums —
+// without waiting for first-class support in pkg/tools.
+type PluginManifest struct {
+	Name        string                           `json:"name" yaml:"name"`
+	DisplayName string                           `json:"displayName,omitempty" yaml:"displayName,omitempty"`
+	BinaryName  string                           `json:"binaryName,omitempty" yaml:"binaryName,omitempty"`
+	BinaryPath  string                           `json:"binaryPath,omitempty" yaml:"binaryPath,omitempty"`
+	URL         string                           `json:"url,omitempty" yaml:"url,omitempty"`   // template with {version}, {os}, {arch}
+	URLs        map[string]string                `json:"urls,omitempty" yaml:"urls,omitempty"` // per-platform overrides keyed by os-arch
+	Versions    []string                         `json:"versions,omitempty" yaml:"versions,omitempty"`
+	Checksums   map[string]config.ChecksumConfig `json:"checksums,omitempty" yaml:"checksums,omitempty"` // keyed by version
+}
+
+// Validate checks that a plugin manifest is usable
+func (p *PluginManifest) Validate() error {
+	if p.Name == "" {
+		return fmt.Errorf("plugin manifest: name is required")
+	}
+	if p.URL == "" && len(p.URLs) == 0 {
+		return fmt.Errorf("plugin manifest %s: url or urls is required", p.Name)
+	}
+	return nil
+}
+
+// PluginTool implements Tool interface for tools registered via plugin manifests
+type PluginTool struct {
+	*BaseTool
+	manifest PluginManifest
+}
+
+// NewPluginTool creates a tool instance from a plugin manifest
+func NewPluginTool(manager *Manager, manifest PluginManifest) *PluginTool {
+	binaryName := manifest.BinaryName
+	if binaryName == "" {
+		binaryName = manifest.Name
+	}
+	if NewPlatformMapper().IsWindows() && !strings.HasSuffix(binaryName, ExtExe) {
+		binaryName += ExtExe
+	}
+	return &PluginTool{
+		BaseTool: NewBaseTool(manager, manifest.Name, binaryName),
+		manifest: manifest,
+	}
+}
+
+// Install downloads and installs the specified version
+func (p *PluginTool) Install(version string, cfg config.ToolConfig) error {
+	return p.StandardInstall(version, cfg, p.getDownloadURL)
+}
+
+// IsInstalled checks if the specified version is installed
+func (p *PluginTool) IsInstalled(version string, cfg config.ToolConfig) bool {
+	return p.StandardIsInstalled(version, cfg, p.GetPath)
+}
+
+// GetPath returns the binary path for the specified version (for PATH management)
+func (p *PluginTool) GetPath(version string, cfg config.ToolConfig) (string, error) {
+	return p.StandardGetPath(version, cfg, p.getInstalledPath)
+}
+
+// getInstalledPath returns the path for an installed plugin tool version
+func (p *PluginTool) getInstalledPath(version string, cfg config.ToolConfig) (string, error) {
+	installDir := p.manager.GetToolVersionDir(p.GetToolName(), version, "")
+	if p.manifest.BinaryPath != "" {
+		return filepath.Join(installDir, filepath.FromSlash(p.manifest.BinaryPath)), nil
+	}
+	pathResolver := NewPathResolver(p.manager.GetToolsDir())
+	binDir, err := pathResolver.FindBinaryParentDir(installDir, p.GetBinaryName())
+	if err != nil {
+		return "", err
+	}
+	return binDir, nil
+}
+
+// Verify checks if the installation is working correctly
+func (p *PluginTool) Verify(version string, cfg config.ToolConfig) error {
+	binDir, err := p.getInstalledPath(version, cfg)
+	if err != nil {
+		return VerifyError(p.GetToolName(), version, fmt.Errorf("failed to get binary path: %w", err))
+	}
+	if !p.BaseTool.IsInstalled(binDir) {
+		return VerifyError(p.GetToolName(), version, fmt.Errorf("binary %s not found in %s", p.GetBinaryName(), binDir))
+	}
+	return nil
+}
+
+// ListVersions returns the versions declared in the plugin manifest
+func (p *PluginTool) ListVersions() ([]string, error) {
+	if len(p.manifest.Versions) == 0 {
+		return nil, fmt.Errorf("plugin %s does not declare any versions", p.GetToolName())
+	}
+	return version.SortVersions(p.manifest.Versions), nil
+}
+
+// GetDisplayName returns the human-readable name for this plugin tool
+func (p *PluginTool) GetDisplayName() string {
+	if p.manifest.DisplayName != "" {
+		return p.manifest.DisplayName
+	}
+	return fmt.Sprintf("%s (plugin)", p.GetToolName())
+}
+
+// ResolveVersion resolves a version specification against the manifest's versions
+func (p *PluginTool) ResolveVersion(versionSpec, distribution string) (string, error) {
+	if len(p.manifest.Versions) == 0 {
+		// No declared versions: treat the spec as an opaque concrete version
+		return versionSpec, nil
+	}
+
+	spec, err := version.ParseSpec(versionSpec)
+	if err != nil {
+		return "", fmt.Errorf("invalid version specification %s: %w", versionSpec, err)
+	}
+
+	resolved, err := spec.Resolve(p.manifest.Versions)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve %s version %s: %w", p.GetToolName(), versionSpec, err)
+	}
+	return resolved, nil
+}
+
+// getDownloadURL returns the download URL for the specified version
+func (p *PluginTool) getDownloadURL(version string) string {
+	platformMapper := NewPlatformMapper()
+
+	urlTemplate := p.manifest.URL
+	if len(p.manifest.URLs) > 0 {
+		if url, exists := p.manifest.URLs[platformMapper.GetGenericPlatform()]; exists {
+			urlTemplate = url
+		} else if url, exists := p.manifest.URLs["default"]; exists {
+			urlTemplate = url
+		}
+	}
+
+	replacer := strings.NewReplacer(
+		"{version}", version,
+		"{os}", platformMapper.GetOS(),
+		"{arch}", platformMapper.GetArch(),
+	)
+	return replacer.Replace(urlTemplate)
+}
+
+// GetDownloadURL implements URLProvider interface for plugin tools
+func (p *PluginTool) GetDownloadURL(version string) string {
+	return p.getDownloadURL(version)
+}
+
+// GetChecksum implements ChecksumProvider interface using manifest checksums
+func (p *PluginTool) GetChecksum(version string, cfg config.ToolConfig, filename string) (ChecksumInfo, error) {
+	checksumCfg, exists := p.manifest.Checksums[version]
+	if !exists || (checksumCfg.Value == "" && checksumCfg.URL == "") {
+		return ChecksumInfo{}, fmt.Errorf("no checksum declared for %s %s", p.GetToolName(), version)
+	}
+
+	checksumType := ChecksumType(checksumCfg.Type)
+	if checksumType == "" {
+		checksumType = SHA256
+	}
+
+	return ChecksumInfo{
+		Type:     checksumType,
+		Value:    checksumCfg.Value,
+		URL:      checksumCfg.URL,
+		Filename: checksumCfg.Filename,
+	}, nil
+}
+
+// discoverPluginTools loads plugin manifests from ~/.mvx/plugins and registers them
+func (m *Manager) discoverPluginTools() {
+	pluginsDir := filepath.Join(m.cacheDir, "plugins")
+	entries, err := os.ReadDir(pluginsDir)
+	if err != nil {
+		return // no plugins directory, nothing to do
+	}
+
+	for _, entry := range entries {
+		if entry.IsDir() {
+			continue
+		}
+		name := entry.Name()
+		if !strings.HasSuffix(name, ".json5") && !strings.HasSuffix(name, ".json") {
+			continue
+		}
+		if err := m.registerPluginManifest(filepath.Join(pluginsDir, name)); err != nil {
+			util.LogVerbose("Skipping plugin manifest %s: %v", name, err)
+		}
+	}
+}
+
+// RegisterProjectPlugins registers plugin manifests listed in the project
+// configuration's "plugins" section (paths relative to the project root)
+func (m *Manager) RegisterProjectPlugins(projectRoot string, cfg *config.Config) {
+	for _, manifestPath := range cfg.Plugins {
+		if !filepath.IsAbs(manifestPath) {
+			manifestPath = filepath.Join(projectRoot, manifestPath)
+		}
+		if err := m.registerPluginManifest(manifestPath); err != nil {
+			util.LogVerbose("Skipping project plugin %s: %v", manifestPath, err)
+		}
+	}
+}
+
+// registerPluginManifest loads, validates and registers a single plugin manifest
+func (m *Manager) registerPluginManifest(path string) error {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return fmt.Errorf("failed to read plugin manifest: %w", err)
+	}
+
+	var manifest PluginManifest
+	if err := config.ParseJSON5(data, &manifest); err != nil {
+		return fmt.Errorf("failed to parse plugin manifest: %w", err)
+	}
+
+	if err := manifest.Validate(); err != nil {
+		return err
+	}
+
+	if _, exists := m.tools[manifest.Name]; exists {
+		return fmt.Errorf("tool %s is already registered (built-in tools win)", manifest.Name)
+	}
+
+	m.RegisterTool(NewPluginTool(m, manifest))
+	util.LogVerbose("Registered plugin tool: %s (from %s)", manifest.Name, path)
+	return nil
+}
@@ -0,0 +1,77 @@
+package tools
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Known projects registry: every project whose lockfile is loaded gets
+// recorded in ~/.mvx/projects.json, so cache pruning can tell which
+// installed tool versions are still referenced by some project on this
+// machine.
+
+// projectsRegistryPath returns the registry file path, or "" without a home
+func projectsRegistryPath() string {
+	mvxHome, err := util.MvxHome()
+	if err != nil {
+		return ""
+	}
+	return filepath.Join(mvxHome, "projects.json")
+}
+
+// RegisterProjectUsage records that a project root uses mvx (best effort)
+func RegisterProjectUsage(projectRoot string) {
+	path := projectsRegistryPath()
+	if path == "" {
+		return
+	}
+	abs, err := filepath.Abs(projectRoot)
+	if err != nil {
+		return
+	}
+
+	registry := loadProjectsRegistry()
+	registry[abs] = time.Now().UTC().Format(time.RFC3339)
+
+	data, err := json.MarshalIndent(registry, "", "  ")
+	if err != nil {
+		return
+	}
+	data = append(data, '\n')
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err == nil {
+		_ = os.WriteFile(path, data, 0644)
+	}
+}
+
+// KnownProjects returns the recorded project roots that still exist on disk,
+// sorted for stable output
+func KnownProjects() []string {
+	var roots []string
+	for root := range loadProjectsRegistry() {
+		if info, err := os.Stat(root); err == nil && info.IsDir() {
+			roots = append(roots, root)
+		}
+	}
+	sort.Strings(roots)
+	return roots
+}
+
+// loadProjectsRegistry reads the registry, returning an empty map on any error
+func loadProjectsRegistry() map[string]string {
+	registry := make(map[string]string)
+	path := projectsRegistryPath()
+	if path == "" {
+		return registry
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return registry
+	}
+	_ = json.Unmarshal(data, &registry)
+	return registry
+}
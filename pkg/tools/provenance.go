@@ -0,0 +1,156 @@
+package tools
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"sync"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Provenance verification policy: when security.requireProvenance is enabled
+// in the project config (or MVX_REQUIRE_PROVENANCE=true is set by an org
+// policy), downloads from tools that publish SLSA/GitHub attestations are
+// verified with the external gh / cosign binaries before extraction.
+// Verification results are recorded per tool@version so the lockfile and
+// SBOM can report them.
+var (
+	provenanceMutex          sync.RWMutex
+	projectRequireProvenance bool
+	provenanceResults        = map[string]string{}
+)
+
+// configureProvenancePolicy records the project's provenance verification
+// setting
+func configureProvenancePolicy(cfg *config.Config) {
+	provenanceMutex.Lock()
+	defer provenanceMutex.Unlock()
+	projectRequireProvenance = cfg.Security != nil && cfg.Security.RequireProvenance
+}
+
+// provenanceRequired reports whether provenance checks are enabled by the
+// project config or the MVX_REQUIRE_PROVENANCE environment variable
+func provenanceRequired() bool {
+	provenanceMutex.RLock()
+	project := projectRequireProvenance
+	provenanceMutex.RUnlock()
+	return project || os.Getenv("MVX_REQUIRE_PROVENANCE") == "true"
+}
+
+// ProvenanceInfo describes where a download's attestation can be verified
+type ProvenanceInfo struct {
+	Repo      string // GitHub "owner/name" that published the attestation
+	BundleURL string // explicit Sigstore attestation bundle URL, when published
+}
+
+// ProvenanceProvider is implemented by tools whose releases carry
+// SLSA/GitHub attestations (gh, buf, many single-binary Go and Rust tools)
+type ProvenanceProvider interface {
+	GetProvenance(version string, cfg config.ToolConfig, filename string) (ProvenanceInfo, error)
+}
+
+// verifyProvenance checks the downloaded archive against the tool's
+// published attestation. Tools without a ProvenanceProvider implementation
+// are skipped; the outcome is recorded either way.
+func verifyProvenance(filePath string, dlConfig *DownloadConfig) error {
+	if !provenanceRequired() || dlConfig.Tool == nil {
+		return nil
+	}
+	provider, ok := dlConfig.Tool.(ProvenanceProvider)
+	if !ok {
+		util.LogVerbose("No provenance source for %s, skipping provenance verification", dlConfig.ToolName)
+		recordProvenanceResult(dlConfig.ToolName, dlConfig.Version, "unsupported")
+		return nil
+	}
+
+	info, err := provider.GetProvenance(dlConfig.Version, dlConfig.Config, extractFilenameFromURL(dlConfig.URL))
+	if err != nil || (info.Repo == "" && info.BundleURL == "") {
+		util.LogVerbose("No attestation published for %s %s: %v", dlConfig.ToolName, dlConfig.Version, err)
+		recordProvenanceResult(dlConfig.ToolName, dlConfig.Version, "unavailable")
+		return nil
+	}
+
+	var method string
+	if info.BundleURL != "" {
+		method = "cosign"
+		err = runCosignAttestationVerify(info.BundleURL, filePath)
+	} else {
+		method = "gh"
+		err = runGhAttestationVerify(info.Repo, filePath)
+	}
+	if err != nil {
+		os.Remove(filePath)
+		return fmt.Errorf("provenance verification failed for %s %s: %w", dlConfig.ToolName, dlConfig.Version, err)
+	}
+
+	recordProvenanceResult(dlConfig.ToolName, dlConfig.Version, "verified:"+method)
+	util.LogInfo("  ✅ Provenance attestation verified")
+	return nil
+}
+
+// recordProvenanceResult stores the verification outcome for a tool@version
+func recordProvenanceResult(toolName, version, result string) {
+	provenanceMutex.Lock()
+	defer provenanceMutex.Unlock()
+	provenanceResults[toolName+"@"+version] = result
+}
+
+// ProvenanceResult returns the recorded verification outcome for a
+// tool@version installed in this process ("verified:gh", "unavailable", ...)
+// or "" when nothing was verified
+func ProvenanceResult(toolName, version string) string {
+	provenanceMutex.RLock()
+	defer provenanceMutex.RUnlock()
+	return provenanceResults[toolName+"@"+version]
+}
+
+// runGhAttestationVerify verifies a GitHub attestation with the gh binary
+func runGhAttestationVerify(repo, filePath string) error {
+	gh, err := exec.LookPath("gh")
+	if err != nil {
+		return fmt.Errorf("provenance verification is enabled but gh is not installed")
+	}
+
+	cmd := exec.Command(gh, "attestation", "verify", filePath, "--repo", repo)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("gh attestation verify failed: %w\n%s", err, string(output))
+	}
+	return nil
+}
+
+// runCosignAttestationVerify verifies a Sigstore attestation bundle with the
+// cosign binary. The expected signer identity comes from MVX_COSIGN_IDENTITY
+// and MVX_COSIGN_OIDC_ISSUER, like signature verification.
+func runCosignAttestationVerify(bundleURL, filePath string) error {
+	cosign, err := exec.LookPath("cosign")
+	if err != nil {
+		return fmt.Errorf("provenance verification is enabled but cosign is not installed")
+	}
+
+	bundlePath, err := fetchSignatureFile(bundleURL)
+	if err != nil {
+		return fmt.Errorf("failed to fetch attestation bundle: %w", err)
+	}
+	defer os.Remove(bundlePath)
+
+	identity := os.Getenv("MVX_COSIGN_IDENTITY")
+	if identity == "" {
+		identity = ".*"
+	}
+	issuer := os.Getenv("MVX_COSIGN_OIDC_ISSUER")
+	if issuer == "" {
+		issuer = ".*"
+	}
+
+	cmd := exec.Command(cosign, "verify-blob-attestation",
+		"--bundle", bundlePath,
+		"--certificate-identity-regexp", identity,
+		"--certificate-oidc-issuer-regexp", issuer,
+		filePath)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("cosign verify-blob-attestation failed: %w\n%s", err, string(output))
+	}
+	return nil
+}
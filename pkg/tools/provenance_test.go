@@ -0,0 +1,37 @@
+package tools
+
+import (
+	"testing"
+
+	"github.com/gnodet/mvx/pkg/config"
+)
+
+func TestProvenancePolicy(t *testing.T) {
+	t.Setenv("MVX_REQUIRE_PROVENANCE", "")
+
+	configureProvenancePolicy(&config.Config{})
+	if provenanceRequired() {
+		t.Error("provenance must be off by default")
+	}
+
+	configureProvenancePolicy(&config.Config{Security: &config.SecurityConfig{RequireProvenance: true}})
+	if !provenanceRequired() {
+		t.Error("security.requireProvenance should enable verification")
+	}
+
+	configureProvenancePolicy(&config.Config{})
+	t.Setenv("MVX_REQUIRE_PROVENANCE", "true")
+	if !provenanceRequired() {
+		t.Error("MVX_REQUIRE_PROVENANCE=true should enable verification")
+	}
+}
+
+func TestProvenanceResultRecording(t *testing.T) {
+	if got := ProvenanceResult("nosuch", "1.0.0"); got != "" {
+		t.Errorf("expected no result for an uninstalled tool, got %q", got)
+	}
+	recordProvenanceResult("gh", "2.63.0", "verified:gh")
+	if got := ProvenanceResult("gh", "2.63.0"); got != "verified:gh" {
+		t.Errorf("unexpected recorded result: %q", got)
+	}
+}
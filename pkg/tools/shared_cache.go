@@ -0,0 +1,116 @@
+package tools
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// EnvSharedToolsDir points at a read-only shared tool cache layer.
+// On shared build agents several users can point MVX_SHARED_TOOLS_DIR at a
+// pre-provisioned tool cache (e.g. baked into the agent image): installations
+// found there are used directly, while anything missing is installed into the
+// per-user writable cache (~/.mvx/tools). mvx never writes to the shared
+// layer, so agents don't accumulate files owned by other users.
+const EnvSharedToolsDir = "MVX_SHARED_TOOLS_DIR"
+
+// GetSharedToolsDir returns the read-only shared tools directory, or empty
+// string when no shared layer is configured
+func GetSharedToolsDir() string {
+	return os.Getenv(EnvSharedToolsDir)
+}
+
+// EnvSharedCacheDir points at a read-only secondary archive cache tier with
+// the same layout as ~/.mvx/cache (archives keyed by the SHA-256 of their
+// download URL, as populated by mvx bundle install). Platform teams can
+// pre-seed common JDK and Maven archives there; downloads consult it before
+// reaching for the network, and installs still land in the writable cache.
+const EnvSharedCacheDir = "MVX_SHARED_CACHE_DIR"
+
+// GetSharedCacheDir returns the read-only shared cache directory, or empty
+// string when no shared cache tier is configured
+func GetSharedCacheDir() string {
+	return os.Getenv(EnvSharedCacheDir)
+}
+
+// sharedCachedArchivePath returns the shared-tier archive location for a
+// download URL, or empty string when no shared cache tier is configured
+func sharedCachedArchivePath(rawURL string) string {
+	sharedDir := GetSharedCacheDir()
+	if sharedDir == "" {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(rawURL))
+	return filepath.Join(sharedDir, "archives", hex.EncodeToString(sum[:]))
+}
+
+// sharedToolVersionDir returns the shared-layer directory for a tool version,
+// or empty string if the shared layer is not configured or does not contain it
+func sharedToolVersionDir(toolName, version, distribution string) string {
+	sharedDir := GetSharedToolsDir()
+	if sharedDir == "" {
+		return ""
+	}
+
+	versionDir := version
+	if distribution != "" {
+		versionDir = version + "@" + distribution
+	}
+
+	dir := filepath.Join(sharedDir, toolName, versionDir)
+	if info, err := os.Stat(dir); err == nil && info.IsDir() {
+		return dir
+	}
+	return ""
+}
+
+// listSharedInstalledVersions lists tool versions available in the shared layer
+func listSharedInstalledVersions(toolName string) []InstalledVersion {
+	sharedDir := GetSharedToolsDir()
+	if sharedDir == "" {
+		return nil
+	}
+
+	toolDir := filepath.Join(sharedDir, toolName)
+	entries, err := os.ReadDir(toolDir)
+	if err != nil {
+		return nil
+	}
+
+	var installed []InstalledVersion
+	for _, entry := range entries {
+		if !entry.IsDir() {
+			continue
+		}
+		name := entry.Name()
+		versionPart, distPart, hasDistribution := strings.Cut(name, "@")
+		if !hasDistribution {
+			versionPart = name
+			distPart = ""
+		}
+		installed = append(installed, InstalledVersion{
+			Version:      versionPart,
+			Distribution: distPart,
+			Path:         filepath.Join(toolDir, name),
+		})
+	}
+	return installed
+}
+
+// ensureUserCachePermissions makes sure the per-user writable cache directory
+// was created with sane permissions even under a restrictive or permissive
+// umask, so cleanup by the same user always works and other users on the
+// agent cannot tamper with the cache
+func ensureUserCachePermissions(cacheDir string) {
+	if info, err := os.Stat(cacheDir); err == nil {
+		if info.Mode().Perm() != 0755 {
+			if err := os.Chmod(cacheDir, 0755); err != nil {
+				util.LogVerbose("Failed to normalize permissions on %s: %v", cacheDir, err)
+			}
+		}
+	}
+}
@@ -0,0 +1,237 @@
+package tools
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sync"
+	"time"
+
+	"github.com/gnodet/mvx/pkg/config"
+	"github.com/gnodet/mvx/pkg/util"
+)
+
+// Signature verification policy: when security.verifySignatures is enabled
+// in the project config (or MVX_VERIFY_SIGNATURES=true is set by an org
+// policy), downloads from tools that publish signatures are verified with
+// the external gpg / cosign binaries before extraction.
+var (
+	signatureMutex          sync.RWMutex
+	projectVerifySignatures bool
+)
+
+// configureSignaturePolicy records the project's signature verification
+// setting
+func configureSignaturePolicy(cfg *config.Config) {
+	signatureMutex.Lock()
+	defer signatureMutex.Unlock()
+	projectVerifySignatures = cfg.Security != nil && cfg.Security.VerifySignatures
+}
+
+// signatureVerificationEnabled reports whether signature checks are enabled
+// by the project config or the MVX_VERIFY_SIGNATURES environment variable
+func signatureVerificationEnabled() bool {
+	signatureMutex.RLock()
+	project := projectVerifySignatures
+	signatureMutex.RUnlock()
+	return project || os.Getenv("MVX_VERIFY_SIGNATURES") == "true"
+}
+
+// SignatureInfo describes a published signature for a download
+type SignatureInfo struct {
+	Type     string // "pgp" (detached .asc/.sig) or "cosign" (Sigstore)
+	URL      string // detached signature URL
+	CertURL  string // cosign certificate URL, when applicable
+	KeysURL  string // publisher's KEYS file with the pinned signing keys (pgp)
+	Identity string // expected certificate identity regexp (cosign)
+	Issuer   string // expected certificate OIDC issuer regexp (cosign)
+}
+
+// Size caps for fetched signature material: detached signatures are tiny,
+// publisher KEYS files can hold decades of release keys
+const (
+	maxSignatureFileSize = 1 << 20
+	maxKeysFileSize      = 8 << 20
+)
+
+// SignatureProvider is implemented by tools whose releases are signed
+// (Apache .asc signatures, cosign signatures on GitHub releases)
+type SignatureProvider interface {
+	GetSignature(version string, cfg config.ToolConfig, filename string) (SignatureInfo, error)
+}
+
+// verifySignature checks the downloaded archive against the tool's published
+// signature. Tools without a SignatureProvider implementation are skipped.
+func verifySignature(filePath string, dlConfig *DownloadConfig) error {
+	if !signatureVerificationEnabled() || dlConfig.Tool == nil {
+		return nil
+	}
+	provider, ok := dlConfig.Tool.(SignatureProvider)
+	if !ok {
+		util.LogVerbose("No signature source for %s, skipping signature verification", dlConfig.ToolName)
+		return nil
+	}
+
+	info, err := provider.GetSignature(dlConfig.Version, dlConfig.Config, extractFilenameFromURL(dlConfig.URL))
+	if err != nil || info.URL == "" {
+		util.LogVerbose("No signature published for %s %s: %v", dlConfig.ToolName, dlConfig.Version, err)
+		return nil
+	}
+
+	sigPath, err := fetchSignatureFile(info.URL, maxSignatureFileSize)
+	if err != nil {
+		return fmt.Errorf("failed to fetch signature for %s %s: %w", dlConfig.ToolName, dlConfig.Version, err)
+	}
+	defer os.Remove(sigPath)
+
+	switch info.Type {
+	case "cosign":
+		err = runCosignVerify(sigPath, info, filePath)
+	default:
+		err = runGPGVerify(sigPath, filePath, dlConfig.ToolName, info.KeysURL)
+	}
+	if err != nil {
+		os.Remove(filePath)
+		return fmt.Errorf("signature verification failed for %s %s: %w", dlConfig.ToolName, dlConfig.Version, err)
+	}
+
+	util.LogInfo("  ✅ Signature verified successfully")
+	return nil
+}
+
+// fetchSignatureFile downloads signature material (a detached signature, a
+// certificate or a KEYS file) to a temporary file, capped at limit bytes
+func fetchSignatureFile(url string, limit int64) (string, error) {
+	if err := checkHostAllowed(url); err != nil {
+		return "", err
+	}
+
+	resp, err := util.HTTPClient(30 * time.Second).Get(url)
+	if err != nil {
+		return "", err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return "", fmt.Errorf("signature URL returned status %d", resp.StatusCode)
+	}
+
+	tempFile, err := os.CreateTemp("", "mvx-signature-*.sig")
+	if err != nil {
+		return "", err
+	}
+	defer tempFile.Close()
+
+	if _, err := io.Copy(tempFile, io.LimitReader(resp.Body, limit)); err != nil {
+		os.Remove(tempFile.Name())
+		return "", err
+	}
+	return tempFile.Name(), nil
+}
+
+// runGPGVerify verifies a detached PGP signature with the gpg binary against
+// a keyring pinned to the publisher's KEYS file. Keys are never fetched from
+// keyservers, and signatures made by keys outside the pinned keyring fail.
+func runGPGVerify(sigPath, filePath, toolName, keysURL string) error {
+	gpg, err := exec.LookPath("gpg")
+	if err != nil {
+		return fmt.Errorf("signature verification is enabled but gpg is not installed")
+	}
+	if keysURL == "" {
+		return fmt.Errorf("no pinned signing keys published for %s; refusing to verify against the default keyring", toolName)
+	}
+
+	keyring, err := preparePinnedKeyring(gpg, toolName, keysURL)
+	if err != nil {
+		return err
+	}
+
+	cmd := exec.Command(gpg, "--batch",
+		"--no-default-keyring", "--keyring", keyring,
+		"--verify", sigPath, filePath)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("gpg --verify failed: %w\n%s", err, string(output))
+	}
+	return nil
+}
+
+// preparePinnedKeyring imports the publisher's KEYS file into a dedicated
+// per-tool keyring under ~/.mvx/keyrings, created on first use and reused by
+// later verifications
+func preparePinnedKeyring(gpg, toolName, keysURL string) (string, error) {
+	mvxHome, err := util.MvxHome()
+	if err != nil {
+		return "", err
+	}
+	keyringDir := filepath.Join(mvxHome, "keyrings")
+	if err := os.MkdirAll(keyringDir, 0700); err != nil {
+		return "", fmt.Errorf("failed to create keyring directory: %w", err)
+	}
+
+	keyringPath := filepath.Join(keyringDir, toolName+".gpg")
+	if _, err := os.Stat(keyringPath); err == nil {
+		return keyringPath, nil
+	}
+
+	keysPath, err := fetchSignatureFile(keysURL, maxKeysFileSize)
+	if err != nil {
+		return "", fmt.Errorf("failed to fetch publisher KEYS file: %w", err)
+	}
+	defer os.Remove(keysPath)
+
+	cmd := exec.Command(gpg, "--batch",
+		"--no-default-keyring", "--keyring", keyringPath,
+		"--import", keysPath)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		os.Remove(keyringPath)
+		return "", fmt.Errorf("failed to import publisher keys: %w\n%s", err, string(output))
+	}
+	return keyringPath, nil
+}
+
+// runCosignVerify verifies a Sigstore signature with the cosign binary. The
+// expected signer identity and OIDC issuer come from the tool's SignatureInfo
+// or the MVX_COSIGN_IDENTITY / MVX_COSIGN_OIDC_ISSUER environment variables
+// (regular expressions); verification fails closed when either is missing,
+// since matching any certificate would accept any signer.
+func runCosignVerify(sigPath string, info SignatureInfo, filePath string) error {
+	cosign, err := exec.LookPath("cosign")
+	if err != nil {
+		return fmt.Errorf("signature verification is enabled but cosign is not installed")
+	}
+
+	identity := info.Identity
+	if env := os.Getenv("MVX_COSIGN_IDENTITY"); env != "" {
+		identity = env
+	}
+	issuer := info.Issuer
+	if env := os.Getenv("MVX_COSIGN_OIDC_ISSUER"); env != "" {
+		issuer = env
+	}
+	if identity == "" || issuer == "" {
+		return fmt.Errorf("cosign verification requires an expected certificate identity and OIDC issuer; set MVX_COSIGN_IDENTITY and MVX_COSIGN_OIDC_ISSUER")
+	}
+
+	args := []string{"verify-blob",
+		"--signature", sigPath,
+		"--certificate-identity-regexp", identity,
+		"--certificate-oidc-issuer-regexp", issuer,
+	}
+	if info.CertURL != "" {
+		certPath, err := fetchSignatureFile(info.CertURL, maxSignatureFileSize)
+		if err != nil {
+			return fmt.Errorf("failed to fetch cosign certificate: %w", err)
+		}
+		defer os.Remove(certPath)
+		args = append(args, "--certificate", certPath)
+	}
+	args = append(args, filePath)
+
+	cmd := exec.Command(cosign, args...)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("cosign verify-blob failed: %w\n%s", err, string(output))
+	}
+	return nil
+}
@@ -0,0 +1,23 @@
+package util
+
+import "os"
+
+// IsCI reports whether CI mode is active: prompts are disabled, progress
+// animations are suppressed and output is annotation-friendly. The --ci
+// flag (MVX_CI=true) forces it on, MVX_CI=false forces it off, and
+// otherwise the usual CI environment variables are auto-detected.
+func IsCI() bool {
+	switch os.Getenv("MVX_CI") {
+	case "true":
+		return true
+	case "false":
+		return false
+	}
+	return os.Getenv("CI") != "" || os.Getenv("GITHUB_ACTIONS") != "" || os.Getenv("GITLAB_CI") != ""
+}
+
+// IsGitHubActions reports whether we are running inside GitHub Actions,
+// where workflow commands (::error::, ::group::) render as annotations
+func IsGitHubActions() bool {
+	return os.Getenv("GITHUB_ACTIONS") != ""
+}
@@ -0,0 +1,11 @@
+package util
+
+import "os"
+
+// IsHermetic reports whether hermetic mode is forced for all commands
+// (--hermetic or MVX_HERMETIC). Commands can also opt in individually with
+// hermetic: true in their configuration.
+func IsHermetic() bool {
+	hermetic := os.Getenv("MVX_HERMETIC")
+	return hermetic == "1" || hermetic == "true"
+}
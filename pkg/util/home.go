@@ -0,0 +1,82 @@
+package util
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// The mvx home holds everything mvx manages per user: installed tools, the
+// download/archive caches, the global config, locks and the secrets key.
+// It defaults to ~/.mvx and can be relocated with MVX_HOME (CI images often
+// point it at a mounted volume). Set MVX_USE_XDG=true to follow the XDG
+// base-directory layout instead: data under $XDG_DATA_HOME/mvx and cache
+// under $XDG_CACHE_HOME/mvx. A pre-existing ~/.mvx always wins so upgraded
+// installations keep their tools.
+
+// MvxHome returns the root directory for mvx-managed state
+func MvxHome() (string, error) {
+	if custom := os.Getenv("MVX_HOME"); custom != "" {
+		return normalizeHomePath(custom), nil
+	}
+
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to get user home directory: %w", err)
+	}
+	legacy := filepath.Join(home, ".mvx")
+
+	if useXDGLayout() {
+		if _, err := os.Stat(legacy); err == nil {
+			return legacy, nil
+		}
+		if data := os.Getenv("XDG_DATA_HOME"); data != "" {
+			return filepath.Join(data, "mvx"), nil
+		}
+		return filepath.Join(home, ".local", "share", "mvx"), nil
+	}
+
+	return legacy, nil
+}
+
+// MvxCacheDir returns the directory for re-downloadable cache data (download
+// partials, cached archives, the content store). Under the XDG layout this
+// lives apart from the data directory so cache cleaners can reclaim it.
+func MvxCacheDir() (string, error) {
+	if custom := os.Getenv("MVX_HOME"); custom != "" {
+		return filepath.Join(normalizeHomePath(custom), "cache"), nil
+	}
+
+	if useXDGLayout() {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			return "", fmt.Errorf("failed to get user home directory: %w", err)
+		}
+		legacy := filepath.Join(home, ".mvx")
+		if _, err := os.Stat(legacy); err == nil {
+			return filepath.Join(legacy, "cache"), nil
+		}
+		if cache := os.Getenv("XDG_CACHE_HOME"); cache != "" {
+			return filepath.Join(cache, "mvx"), nil
+		}
+		return filepath.Join(home, ".cache", "mvx"), nil
+	}
+
+	mvxHome, err := MvxHome()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(mvxHome, "cache"), nil
+}
+
+// useXDGLayout reports whether the XDG base-directory layout is enabled
+func useXDGLayout() bool {
+	return os.Getenv("MVX_USE_XDG") == "true"
+}
+
+// normalizeHomePath cleans an MVX_HOME override, converting forward slashes
+// on Windows so UNC locations like //server/share/mvx and \\server\share\mvx
+// are handled uniformly
+func normalizeHomePath(path string) string {
+	return filepath.Clean(filepath.FromSlash(path))
+}
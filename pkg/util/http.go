@@ -0,0 +1,84 @@
+package util
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"net/http"
+	"os"
+	"sync"
+	"time"
+)
+
+// IsOffline reports whether offline mode is active (--offline or
+// MVX_OFFLINE). Offline mode forbids all network access: version resolution
+// uses only the lockfile and cached metadata, and setup uses only what is
+// already installed or cached.
+func IsOffline() bool {
+	offline := os.Getenv("MVX_OFFLINE")
+	return offline == "1" || offline == "true"
+}
+
+// Corporate network support: every mvx HTTP client honors the standard
+// HTTP_PROXY/HTTPS_PROXY/NO_PROXY environment variables and can trust
+// additional CA certificates (corporate MITM proxies) from a PEM bundle
+// named by MVX_CA_BUNDLE, appended to the system roots.
+var (
+	transportOnce   sync.Once
+	sharedTransport *http.Transport
+)
+
+// HTTPTransport returns the shared transport with proxy and custom CA
+// support, built once per process
+func HTTPTransport() *http.Transport {
+	transportOnce.Do(func() {
+		sharedTransport = NewHTTPTransport()
+	})
+	return sharedTransport
+}
+
+// NewHTTPTransport returns a fresh transport with proxy and custom CA
+// support, for callers that need to tune per-transport timeouts
+func NewHTTPTransport() *http.Transport {
+	transport := &http.Transport{
+		Proxy: http.ProxyFromEnvironment,
+	}
+	if tlsConfig := customTLSConfig(); tlsConfig != nil {
+		transport.TLSClientConfig = tlsConfig
+	}
+	return transport
+}
+
+// HTTPClient returns a client using the shared transport with the given
+// overall timeout
+func HTTPClient(timeout time.Duration) *http.Client {
+	return &http.Client{
+		Transport: TraceTransport(HTTPTransport()),
+		Timeout:   timeout,
+	}
+}
+
+// customTLSConfig builds a TLS config trusting the MVX_CA_BUNDLE PEM file on
+// top of the system roots, or nil when no bundle is configured
+func customTLSConfig() *tls.Config {
+	bundle := os.Getenv("MVX_CA_BUNDLE")
+	if bundle == "" {
+		return nil
+	}
+
+	pem, err := os.ReadFile(bundle)
+	if err != nil {
+		LogVerbose("Warning: failed to read MVX_CA_BUNDLE %s: %v", bundle, err)
+		return nil
+	}
+
+	pool, err := x509.SystemCertPool()
+	if err != nil || pool == nil {
+		pool = x509.NewCertPool()
+	}
+	if !pool.AppendCertsFromPEM(pem) {
+		LogVerbose("Warning: no certificates found in MVX_CA_BUNDLE %s", bundle)
+		return nil
+	}
+
+	return &tls.Config{RootCAs: pool}
+}
@@ -0,0 +1,137 @@
+package util
+
+import (
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+	"sync"
+	"time"
+)
+
+// HTTP trace mode (--trace-http or MVX_TRACE_HTTP) logs every request with
+// URL, method, status, duration, retry attempt, proxy and cache status, so
+// mirror, proxy and rate-limit problems can be diagnosed without guesswork.
+// Secrets in URLs (userinfo, token-style query parameters) are redacted.
+
+// TraceHTTPEnabled reports whether HTTP trace mode is active
+func TraceHTTPEnabled() bool {
+	trace := os.Getenv("MVX_TRACE_HTTP")
+	return trace == "1" || trace == "true"
+}
+
+// TraceTransport wraps the transport with request logging when trace mode is
+// active, and returns it unchanged otherwise
+func TraceTransport(base http.RoundTripper) http.RoundTripper {
+	if !TraceHTTPEnabled() {
+		return base
+	}
+	return &traceTransport{base: base}
+}
+
+// traceTransport logs one line per request to stderr. Attempts are counted
+// per URL across the process, so retries show up as attempt=2, attempt=3.
+type traceTransport struct {
+	base http.RoundTripper
+
+	mu       sync.Mutex
+	attempts map[string]int
+}
+
+func (t *traceTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	t.mu.Lock()
+	if t.attempts == nil {
+		t.attempts = make(map[string]int)
+	}
+	t.attempts[req.URL.String()]++
+	attempt := t.attempts[req.URL.String()]
+	t.mu.Unlock()
+
+	started := time.Now()
+	resp, err := t.base.RoundTrip(req)
+	elapsed := time.Since(started).Round(time.Millisecond)
+
+	line := fmt.Sprintf("[http] %s %s", req.Method, redactTraceURL(req.URL))
+	if err != nil {
+		line += fmt.Sprintf(" -> error: %v", err)
+	} else {
+		line += fmt.Sprintf(" -> %d", resp.StatusCode)
+	}
+	line += fmt.Sprintf(" (%s)", elapsed)
+	if attempt > 1 {
+		line += fmt.Sprintf(" attempt=%d", attempt)
+	}
+	if proxy := proxyForRequest(req); proxy != "" {
+		line += " proxy=" + proxy
+	}
+	if resp != nil {
+		if cache := cacheStatus(resp); cache != "" {
+			line += " cache=" + cache
+		}
+	}
+	fmt.Fprintln(os.Stderr, line)
+
+	return resp, err
+}
+
+// proxyForRequest resolves the proxy the environment selects for the
+// request's URL, host only
+func proxyForRequest(req *http.Request) string {
+	proxy, err := http.ProxyFromEnvironment(req)
+	if err != nil || proxy == nil {
+		return ""
+	}
+	return proxy.Host
+}
+
+// cacheStatus extracts the cache verdict from common CDN headers, or
+// reports a 304 as a hit
+func cacheStatus(resp *http.Response) string {
+	for _, header := range []string{"X-Cache", "CF-Cache-Status", "X-Cache-Status"} {
+		value := strings.ToUpper(resp.Header.Get(header))
+		switch {
+		case strings.Contains(value, "HIT"):
+			return "HIT"
+		case strings.Contains(value, "MISS"):
+			return "MISS"
+		}
+	}
+	if resp.StatusCode == http.StatusNotModified {
+		return "HIT"
+	}
+	return ""
+}
+
+// sensitiveQueryParams are query parameters whose values never belong in a
+// log line
+var sensitiveQueryParams = []string{
+	"token", "access_token", "apikey", "api_key", "key",
+	"secret", "client_secret", "password", "sig", "signature",
+}
+
+// redactTraceURL renders the URL with userinfo and token-style query
+// parameter values replaced by ***
+func redactTraceURL(u *url.URL) string {
+	redacted := *u
+	if redacted.User != nil {
+		redacted.User = url.User("***")
+	}
+
+	query := redacted.Query()
+	changed := false
+	for name := range query {
+		lower := strings.ToLower(name)
+		for _, sensitive := range sensitiveQueryParams {
+			if lower == sensitive {
+				query.Set(name, "***")
+				changed = true
+				break
+			}
+		}
+	}
+	if changed {
+		redacted.RawQuery = query.Encode()
+	}
+	return redacted.String()
+}
@@ -10,9 +10,22 @@ func IsVerbose() bool {
 	return os.Getenv("MVX_VERBOSE") == "true"
 }
 
-// LogVerbose prints verbose log messages
+// IsQuiet returns true if quiet mode is enabled (--quiet / MVX_QUIET)
+func IsQuiet() bool {
+	return os.Getenv("MVX_QUIET") == "true"
+}
+
+// LogInfo prints user-facing progress messages with registered secrets
+// redacted, unless quiet mode is active. Quiet runs only ever print errors.
+func LogInfo(format string, args ...interface{}) {
+	if !IsQuiet() {
+		fmt.Print(RedactText(fmt.Sprintf(format+"\n", args...)))
+	}
+}
+
+// LogVerbose prints verbose log messages with registered secrets redacted
 func LogVerbose(format string, args ...interface{}) {
 	if IsVerbose() {
-		fmt.Printf("[VERBOSE] "+format+"\n", args...)
+		fmt.Print(RedactText(fmt.Sprintf("[VERBOSE] "+format+"\n", args...)))
 	}
 }
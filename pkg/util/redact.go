@@ -0,0 +1,76 @@
+package util
+
+import (
+	"strings"
+	"sync"
+)
+
+// sensitiveNameParts flags env var names that are sensitive by convention,
+// even without explicit configuration
+var sensitiveNameParts = []string{"TOKEN", "SECRET", "PASSWORD", "PASSWD", "APIKEY", "API_KEY", "CREDENTIAL", "PRIVATE_KEY"}
+
+var (
+	redactMutex     sync.RWMutex
+	sensitiveNames  = make(map[string]bool) // explicitly configured sensitive env var names
+	sensitiveValues []string                // values to scrub from any logged text
+)
+
+// MarkSensitive registers env var names from configuration as sensitive
+func MarkSensitive(names ...string) {
+	redactMutex.Lock()
+	defer redactMutex.Unlock()
+	for _, name := range names {
+		sensitiveNames[strings.ToUpper(name)] = true
+	}
+}
+
+// RegisterSensitiveValue registers a concrete secret value so it gets
+// scrubbed from verbose logs and error reports
+func RegisterSensitiveValue(value string) {
+	if len(value) < 4 {
+		return // too short to scrub without mangling unrelated output
+	}
+	redactMutex.Lock()
+	defer redactMutex.Unlock()
+	sensitiveValues = append(sensitiveValues, value)
+}
+
+// IsSensitiveEnvName reports whether an env var name holds a secret, either
+// by explicit configuration or by naming convention
+func IsSensitiveEnvName(name string) bool {
+	upper := strings.ToUpper(name)
+
+	redactMutex.RLock()
+	configured := sensitiveNames[upper]
+	redactMutex.RUnlock()
+	if configured {
+		return true
+	}
+
+	for _, part := range sensitiveNameParts {
+		if strings.Contains(upper, part) {
+			return true
+		}
+	}
+	return false
+}
+
+// RedactEnvValue returns the value to display for an env var, masking it
+// when the name is sensitive
+func RedactEnvValue(name, value string) string {
+	if value != "" && IsSensitiveEnvName(name) {
+		return "****"
+	}
+	return value
+}
+
+// RedactText scrubs all registered secret values from arbitrary text, so
+// secrets embedded in URLs or error messages never reach the logs
+func RedactText(text string) string {
+	redactMutex.RLock()
+	defer redactMutex.RUnlock()
+	for _, value := range sensitiveValues {
+		text = strings.ReplaceAll(text, value, "****")
+	}
+	return text
+}
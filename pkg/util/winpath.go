@@ -0,0 +1,53 @@
+package util
+
+import (
+	"path/filepath"
+	"runtime"
+	"strings"
+)
+
+// Windows path handling. Plain Win32 paths are limited to MAX_PATH (260)
+// characters; JDK archives routinely nest deeper than that once extracted
+// under MVX_HOME. Prefixing an absolute path with \\?\ switches the API to
+// extended-length parsing, raising the limit to ~32K characters. UNC
+// locations (\\server\share) use the \\?\UNC\server\share form.
+
+// windowsLongPathThreshold leaves headroom under MAX_PATH for the short
+// names the filesystem may still generate alongside a created entry
+const windowsLongPathThreshold = 240
+
+// LongPath returns the extended-length form of an absolute path on Windows
+// when it is long enough to approach MAX_PATH; on other systems, and for
+// short or relative paths, it returns the path unchanged
+func LongPath(path string) string {
+	if runtime.GOOS != "windows" {
+		return path
+	}
+	if strings.HasPrefix(path, `\\?\`) || len(path) < windowsLongPathThreshold {
+		return path
+	}
+	if !filepath.IsAbs(path) {
+		return path
+	}
+	clean := filepath.Clean(path)
+	if strings.HasPrefix(clean, `\\`) {
+		// UNC: \\server\share\... -> \\?\UNC\server\share\...
+		return `\\?\UNC` + clean[1:]
+	}
+	return `\\?\` + clean
+}
+
+// QuotePosixArg single-quotes a string for generated POSIX shell scripts,
+// so paths containing spaces survive word splitting
+func QuotePosixArg(s string) string {
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+// QuoteCmdArg double-quotes a string for generated Windows batch scripts
+// when it contains whitespace
+func QuoteCmdArg(s string) string {
+	if strings.ContainsAny(s, " \t") {
+		return `"` + s + `"`
+	}
+	return s
+}
@@ -0,0 +1,60 @@
+package util
+
+import (
+	"runtime"
+	"strings"
+	"testing"
+)
+
+func TestLongPathLeavesShortPathsAlone(t *testing.T) {
+	for _, path := range []string{"/tmp/mvx", `C:\mvx\tools`, "relative/path", ""} {
+		if got := LongPath(path); got != path {
+			t.Errorf("LongPath(%q) = %q, expected unchanged", path, got)
+		}
+	}
+}
+
+func TestLongPathPrefixesLongAbsolutePaths(t *testing.T) {
+	if runtime.GOOS != "windows" {
+		t.Skip("extended-length prefixes are Windows-only")
+	}
+
+	long := `C:\mvx\` + strings.Repeat("a", 300)
+	got := LongPath(long)
+	if !strings.HasPrefix(got, `\\?\C:\`) {
+		t.Errorf("expected extended-length prefix, got %q", got)
+	}
+	// Idempotent: an already-prefixed path is not prefixed again
+	if again := LongPath(got); again != got {
+		t.Errorf("LongPath is not idempotent: %q -> %q", got, again)
+	}
+
+	unc := `\\server\share\` + strings.Repeat("b", 300)
+	if got := LongPath(unc); !strings.HasPrefix(got, `\\?\UNC\server\share\`) {
+		t.Errorf("expected UNC extended-length prefix, got %q", got)
+	}
+}
+
+func TestQuotePosixArg(t *testing.T) {
+	cases := []struct {
+		in, want string
+	}{
+		{"/usr/local/bin/mvx", "'/usr/local/bin/mvx'"},
+		{"/opt/my tools/mvx", "'/opt/my tools/mvx'"},
+		{"/it's here/mvx", `'/it'\''s here/mvx'`},
+	}
+	for _, tc := range cases {
+		if got := QuotePosixArg(tc.in); got != tc.want {
+			t.Errorf("QuotePosixArg(%q) = %q, want %q", tc.in, got, tc.want)
+		}
+	}
+}
+
+func TestQuoteCmdArg(t *testing.T) {
+	if got := QuoteCmdArg(`C:\mvx\mvx.exe`); got != `C:\mvx\mvx.exe` {
+		t.Errorf("expected no quoting without spaces, got %q", got)
+	}
+	if got := QuoteCmdArg(`C:\Program Files\mvx\mvx.exe`); got != `"C:\Program Files\mvx\mvx.exe"` {
+		t.Errorf("expected quoting with spaces, got %q", got)
+	}
+}
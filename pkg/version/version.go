@@ -2,6 +2,7 @@ package version
 
 import (
 	"fmt"
+	"os"
 	"regexp"
 	"sort"
 	"strconv"
@@ -25,6 +26,17 @@ type Spec struct {
 	Minor      int
 	Patch      int
 	Pre        string
+	ranges     []rangeBound // comparator list for "range" specs
+
+	// IncludePrereleases lets non-exact specs match RCs, betas and
+	// milestone builds, which are excluded by default
+	IncludePrereleases bool
+}
+
+// rangeBound is one comparator of a range spec (e.g. ">=3.9")
+type rangeBound struct {
+	op      string // ">=", ">", "<=", "<", "="
+	version *Version
 }
 
 // ParseVersion parses a version string into a Version struct
@@ -72,9 +84,42 @@ func ParseSpec(spec string) (*Spec, error) {
 		}, nil
 	}
 
-	// Handle range specifications (future enhancement)
-	if strings.Contains(spec, ">=") || strings.Contains(spec, "<=") || strings.Contains(spec, "~") || strings.Contains(spec, "^") {
-		return nil, fmt.Errorf("range specifications not yet implemented: %s", spec)
+	// "latest-3" is an alias for the newest version in a major line, so specs
+	// read naturally ("always the current Maven 3")
+	if rest, ok := strings.CutPrefix(spec, "latest-"); ok {
+		v, err := ParseVersion(rest)
+		if err != nil || strings.Contains(rest, ".") {
+			return nil, fmt.Errorf("invalid version specification %s (expected latest-<major>)", spec)
+		}
+		return &Spec{
+			Raw:        spec,
+			Constraint: "major",
+			Major:      v.Major,
+		}, nil
+	}
+
+	// Wildcard specs ("17.x", "17.2.*") behave like the bare prefix
+	if trimmed, ok := trimWildcard(spec); ok {
+		s, err := ParseSpec(trimmed)
+		if err != nil || trimmed == "" {
+			return nil, fmt.Errorf("invalid version specification %s", spec)
+		}
+		s.Raw = spec
+		return s, nil
+	}
+
+	// Range specifications: comparator lists (">=3.9 <4"), tilde (~1.0.2)
+	// and caret (^1.2.3) shorthands
+	if strings.ContainsAny(spec, "><=~^") {
+		bounds, err := parseRangeBounds(spec)
+		if err != nil {
+			return nil, err
+		}
+		return &Spec{
+			Raw:        spec,
+			Constraint: "range",
+			ranges:     bounds,
+		}, nil
 	}
 
 	// Parse as version
@@ -103,6 +148,142 @@ func ParseSpec(spec string) (*Spec, error) {
 	}, nil
 }
 
+// trimWildcard strips a trailing wildcard component (".x", ".X" or ".*"),
+// reporting whether the spec had one
+func trimWildcard(spec string) (string, bool) {
+	for _, suffix := range []string{".x", ".X", ".*"} {
+		if trimmed, ok := strings.CutSuffix(spec, suffix); ok {
+			return trimmed, true
+		}
+	}
+	return spec, false
+}
+
+// parseRangeBounds parses a range spec into its comparators. Tilde and caret
+// shorthands expand to the equivalent comparator pair.
+func parseRangeBounds(spec string) ([]rangeBound, error) {
+	var bounds []rangeBound
+
+	for _, part := range strings.Fields(strings.ReplaceAll(spec, ",", " ")) {
+		switch {
+		case strings.HasPrefix(part, "~"):
+			expanded, err := expandTilde(part[1:])
+			if err != nil {
+				return nil, fmt.Errorf("invalid version specification %s: %w", spec, err)
+			}
+			bounds = append(bounds, expanded...)
+		case strings.HasPrefix(part, "^"):
+			expanded, err := expandCaret(part[1:])
+			if err != nil {
+				return nil, fmt.Errorf("invalid version specification %s: %w", spec, err)
+			}
+			bounds = append(bounds, expanded...)
+		default:
+			op := "="
+			rest := part
+			for _, candidate := range []string{">=", "<=", ">", "<", "="} {
+				if trimmed, ok := strings.CutPrefix(part, candidate); ok {
+					op = candidate
+					rest = trimmed
+					break
+				}
+			}
+			v, err := ParseVersion(strings.TrimSpace(rest))
+			if err != nil {
+				return nil, fmt.Errorf("invalid version specification %s: %w", spec, err)
+			}
+			bounds = append(bounds, rangeBound{op: op, version: v})
+		}
+	}
+
+	if len(bounds) == 0 {
+		return nil, fmt.Errorf("invalid version specification %s", spec)
+	}
+	return bounds, nil
+}
+
+// expandTilde expands "~1.0.2" to ">=1.0.2 <1.1.0" (patch-level changes
+// allowed; "~1.2" and "~1" allow minor-level changes)
+func expandTilde(raw string) ([]rangeBound, error) {
+	v, err := ParseVersion(raw)
+	if err != nil {
+		return nil, err
+	}
+	upper := &Version{Major: v.Major, Minor: v.Minor + 1}
+	if strings.Count(raw, ".") == 0 {
+		upper = &Version{Major: v.Major + 1}
+	}
+	return []rangeBound{
+		{op: ">=", version: v},
+		{op: "<", version: upper},
+	}, nil
+}
+
+// expandCaret expands "^1.2.3" to ">=1.2.3 <2.0.0" (no breaking changes;
+// the leftmost non-zero component is held fixed)
+func expandCaret(raw string) ([]rangeBound, error) {
+	v, err := ParseVersion(raw)
+	if err != nil {
+		return nil, err
+	}
+	var upper *Version
+	switch {
+	case v.Major > 0:
+		upper = &Version{Major: v.Major + 1}
+	case v.Minor > 0:
+		upper = &Version{Minor: v.Minor + 1}
+	default:
+		upper = &Version{Patch: v.Patch + 1}
+	}
+	return []rangeBound{
+		{op: ">=", version: v},
+		{op: "<", version: upper},
+	}, nil
+}
+
+// matchesRange reports whether a version satisfies every comparator.
+// Pre-release filtering happens in Matches before this is called.
+func (s *Spec) matchesRange(v *Version) bool {
+	for _, bound := range s.ranges {
+		cmp := v.Compare(bound.version)
+		switch bound.op {
+		case ">=":
+			if cmp < 0 {
+				return false
+			}
+		case ">":
+			if cmp <= 0 {
+				return false
+			}
+		case "<=":
+			if cmp > 0 {
+				return false
+			}
+		case "<":
+			if cmp >= 0 {
+				return false
+			}
+		case "=":
+			if cmp != 0 {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+// rangeAllowsPre reports whether any comparator opts in to pre-releases of
+// this version's release triple
+func (s *Spec) rangeAllowsPre(v *Version) bool {
+	for _, bound := range s.ranges {
+		b := bound.version
+		if b.Pre != "" && b.Major == v.Major && b.Minor == v.Minor && b.Patch == v.Patch {
+			return true
+		}
+	}
+	return false
+}
+
 // String returns the string representation of a version
 func (v *Version) String() string {
 	result := fmt.Sprintf("%d.%d.%d", v.Major, v.Minor, v.Patch)
@@ -152,8 +333,25 @@ func (v *Version) Compare(other *Version) int {
 	return 0
 }
 
+// allowsPrerelease reports whether a matching pre-release version may be
+// selected. Exact specs and ranges naming a pre-release always allow it;
+// otherwise the spec's IncludePrereleases opt-in or the global
+// MVX_INCLUDE_PRERELEASES environment variable is required.
+func (s *Spec) allowsPrerelease(v *Version) bool {
+	if s.Constraint == "exact" {
+		return true
+	}
+	if s.Constraint == "range" && s.rangeAllowsPre(v) {
+		return true
+	}
+	return s.IncludePrereleases || os.Getenv("MVX_INCLUDE_PRERELEASES") == "true"
+}
+
 // Matches checks if a version matches a specification
 func (s *Spec) Matches(v *Version) bool {
+	if v.Pre != "" && !s.allowsPrerelease(v) {
+		return false
+	}
 	switch s.Constraint {
 	case "latest":
 		return true // Latest matches any version (resolver will pick the highest)
@@ -163,6 +361,8 @@ func (s *Spec) Matches(v *Version) bool {
 		return s.Major == v.Major
 	case "minor":
 		return s.Major == v.Major && s.Minor == v.Minor
+	case "range":
+		return s.matchesRange(v)
 	default:
 		return false
 	}
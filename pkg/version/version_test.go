@@ -0,0 +1,140 @@
+package version
+
+import "testing"
+
+func TestParseSpecConstraints(t *testing.T) {
+	tests := []struct {
+		spec       string
+		constraint string
+	}{
+		{"17", "major"},
+		{"17.x", "major"},
+		{"17.X", "major"},
+		{"3.9", "minor"},
+		{"3.9.*", "minor"},
+		{"3.9.6", "exact"},
+		{"latest", "latest"},
+		{">=3.9 <4", "range"},
+		{"~1.0.2", "range"},
+		{"^1.2.3", "range"},
+	}
+
+	for _, test := range tests {
+		spec, err := ParseSpec(test.spec)
+		if err != nil {
+			t.Errorf("ParseSpec(%q) failed: %v", test.spec, err)
+			continue
+		}
+		if spec.Constraint != test.constraint {
+			t.Errorf("ParseSpec(%q).Constraint = %q, want %q", test.spec, spec.Constraint, test.constraint)
+		}
+	}
+}
+
+func TestParseSpecInvalid(t *testing.T) {
+	for _, spec := range []string{"not-a-version", ">=banana", "~", ".x"} {
+		if _, err := ParseSpec(spec); err == nil {
+			t.Errorf("ParseSpec(%q) should fail", spec)
+		}
+	}
+}
+
+func TestResolveRanges(t *testing.T) {
+	available := []string{"3.8.8", "3.9.6", "3.9.9", "4.0.0-rc-1", "4.0.0", "1.0.2", "1.0.9", "1.1.0", "2.0.0"}
+
+	tests := []struct {
+		spec string
+		want string
+	}{
+		{"17.x", ""}, // no match
+		{"3.x", "3.9.9"},
+		{"3.9", "3.9.9"},
+		{">=3.9 <4", "3.9.9"},
+		{">=3.9, <4", "3.9.9"},
+		{"~1.0.2", "1.0.9"},
+		{"^1.0.2", "1.1.0"},
+		{"<=3.8.8", "3.8.8"},
+		{">3.9.6 <=4.0.0", "4.0.0"},
+	}
+
+	for _, test := range tests {
+		spec, err := ParseSpec(test.spec)
+		if err != nil {
+			t.Errorf("ParseSpec(%q) failed: %v", test.spec, err)
+			continue
+		}
+		resolved, err := spec.Resolve(available)
+		if test.want == "" {
+			if err == nil {
+				t.Errorf("Resolve(%q) = %q, want no match", test.spec, resolved)
+			}
+			continue
+		}
+		if err != nil {
+			t.Errorf("Resolve(%q) failed: %v", test.spec, err)
+			continue
+		}
+		if resolved != test.want {
+			t.Errorf("Resolve(%q) = %q, want %q", test.spec, resolved, test.want)
+		}
+	}
+}
+
+func TestIncludePrereleases(t *testing.T) {
+	available := []string{"3.9.9", "4.0.0-rc-4"}
+
+	// Excluded by default
+	spec, err := ParseSpec("latest")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+	if resolved, err := spec.Resolve(available); err != nil || resolved != "3.9.9" {
+		t.Errorf("expected 3.9.9 by default, got %q (err=%v)", resolved, err)
+	}
+
+	// Opt-in via the spec
+	spec.IncludePrereleases = true
+	if resolved, err := spec.Resolve(available); err != nil || resolved != "4.0.0-rc-4" {
+		t.Errorf("expected 4.0.0-rc-4 with opt-in, got %q (err=%v)", resolved, err)
+	}
+
+	// Opt-in via the environment
+	spec.IncludePrereleases = false
+	t.Setenv("MVX_INCLUDE_PRERELEASES", "true")
+	if resolved, err := spec.Resolve(available); err != nil || resolved != "4.0.0-rc-4" {
+		t.Errorf("expected 4.0.0-rc-4 with env opt-in, got %q (err=%v)", resolved, err)
+	}
+
+	// Exact pre-release pins always work
+	t.Setenv("MVX_INCLUDE_PRERELEASES", "")
+	exact, err := ParseSpec("4.0.0-rc-4")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+	if resolved, err := exact.Resolve(available); err != nil || resolved != "4.0.0-rc-4" {
+		t.Errorf("expected exact pin to match, got %q (err=%v)", resolved, err)
+	}
+}
+
+func TestRangeExcludesPreReleases(t *testing.T) {
+	spec, err := ParseSpec(">=4.0.0-rc-1 <5")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+
+	// A comparator naming a pre-release opts in to pre-releases of that triple
+	resolved, err := spec.Resolve([]string{"4.0.0-rc-1"})
+	if err != nil || resolved != "4.0.0-rc-1" {
+		t.Errorf("expected 4.0.0-rc-1 to match, got %q (err=%v)", resolved, err)
+	}
+
+	// Plain ranges skip pre-releases entirely
+	spec, err = ParseSpec(">=3.9 <5")
+	if err != nil {
+		t.Fatalf("ParseSpec failed: %v", err)
+	}
+	resolved, err = spec.Resolve([]string{"4.0.0-rc-1", "3.9.9"})
+	if err != nil || resolved != "3.9.9" {
+		t.Errorf("expected 3.9.9 (pre-releases excluded), got %q (err=%v)", resolved, err)
+	}
+}
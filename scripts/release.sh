@@ -143,6 +143,7 @@ expected_binaries=(
     "dist/mvx-darwin-amd64"
     "dist/mvx-darwin-arm64"
     "dist/mvx-windows-amd64.exe"
+    "dist/mvx-windows-arm64.exe"
 )
 
 for binary in "${expected_binaries[@]}"; do